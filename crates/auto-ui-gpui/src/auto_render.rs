@@ -6,15 +6,20 @@
 // Phase 2 Integration: Now supports unified styling system with Style objects.
 // Phase 3 Integration: Native GPUI Select widget support with pre-initialization.
 
-use auto_ui::{Component, View, Style, SelectCallback};
+use auto_ui::{Command, Component, View, Style, SelectCallback, InputCallback};
+use auto_ui::subscription::{watch_path, KeyEvent, Modifiers, SourceKey, SubscriptionEvent, WindowSize};
 use auto_ui::style::gpui_adapter::{GpuiStyle, GpuiFontWeight};
+use auto_ui::style::{StyleState, Theme};
 use gpui::*;
 use gpui::{InteractiveElement, ParentElement, StatefulInteractiveElement};
 use gpui_component::{button::Button, button::ButtonVariants, scroll::ScrollableElement, select::*, *};
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::radio::{Radio, RadioGroup};
 use gpui_component::slider::SliderState;
 use std::fmt::Debug;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 
 // Custom drag type for slider interaction
 #[derive(Clone, Debug)]
@@ -35,6 +40,18 @@ pub struct GpuiComponentState<C: Component> {
     select_states: HashMap<String, Entity<SelectState<Vec<String>>>>,
     /// Cache of select callbacks for event handling
     select_callbacks: HashMap<String, SelectCallback<C::Msg>>,
+    /// Text input states keyed by position in the view tree (see `input_key`)
+    input_states: HashMap<String, Entity<InputState>>,
+    /// Latest change callback per text input
+    input_callbacks: HashMap<String, InputCallback<C::Msg>>,
+    /// Path of the view being scanned or rendered
+    view_path: Vec<String>,
+    /// Sources of the currently running subscription
+    subscription_keys: Vec<SourceKey>,
+    /// Timer and file watcher tasks (dropping a task cancels it)
+    subscription_tasks: Vec<Task<()>>,
+    /// Keyboard and window observers
+    event_subscriptions: Vec<gpui::Subscription>,
 }
 
 impl<C: Component + 'static> GpuiComponentState<C>
@@ -47,12 +64,160 @@ where
             slider_states: HashMap::new(),
             select_states: HashMap::new(),
             select_callbacks: HashMap::new(),
+            input_states: HashMap::new(),
+            input_callbacks: HashMap::new(),
+            view_path: Vec::new(),
+            subscription_keys: Vec::new(),
+            subscription_tasks: Vec::new(),
+            event_subscriptions: Vec::new(),
         }
     }
 
-    /// Handle a message and update the component
-    pub fn handle(&mut self, msg: C::Msg) {
-        self.component.on(msg);
+    /// Handle a message, update the component and run the returned command
+    pub fn handle(&mut self, msg: C::Msg, cx: &mut Context<Self>) {
+        let command = self.component.update(msg);
+        self.execute(command, cx);
+        self.sync_subscriptions(cx);
+    }
+
+    /// Execute a command returned from `Component::update`
+    ///
+    /// `Perform` futures run on GPUI's foreground executor; their output is fed
+    /// back through `handle` and triggers a re-render.
+    pub fn execute(&mut self, command: Command<C::Msg>, cx: &mut Context<Self>) {
+        match command {
+            Command::None => {}
+            Command::Batch(commands) => {
+                for command in commands {
+                    self.execute(command, cx);
+                }
+            }
+            Command::Perform(future) => {
+                cx.spawn(async move |this, cx| {
+                    let msg = future.await;
+                    this.update(cx, |state, cx| {
+                        state.handle(msg, cx);
+                        cx.notify();
+                    })
+                    .ok();
+                })
+                .detach();
+            }
+            Command::Exit => cx.quit(),
+            Command::SetTheme(theme) => {
+                Theme::set_active(theme);
+                cx.notify();
+            }
+        }
+    }
+
+    /// Deliver a subscription event to the component
+    pub fn dispatch_event(&mut self, event: SubscriptionEvent, cx: &mut Context<Self>) {
+        let msgs = self.component.subscription().dispatch(event);
+        if msgs.is_empty() {
+            return;
+        }
+        for msg in msgs {
+            self.handle(msg, cx);
+        }
+        cx.notify();
+    }
+
+    /// Start timers and file watchers declared by `Component::subscription`
+    ///
+    /// Running sources are kept as long as the declared sources don't change;
+    /// otherwise all tasks are dropped and restarted.
+    pub fn sync_subscriptions(&mut self, cx: &mut Context<Self>) {
+        let keys = self.component.subscription().keys();
+        if keys == self.subscription_keys {
+            return;
+        }
+
+        self.subscription_tasks.clear();
+
+        for (index, key) in keys.iter().enumerate() {
+            match key {
+                SourceKey::Every(interval) => {
+                    let interval = *interval;
+                    let task = cx.spawn(async move |this, cx| {
+                        loop {
+                            cx.background_executor().timer(interval).await;
+                            let event = SubscriptionEvent::Tick { index, at: Instant::now() };
+                            if this.update(cx, |state, cx| state.dispatch_event(event, cx)).is_err() {
+                                break;
+                            }
+                        }
+                    });
+                    self.subscription_tasks.push(task);
+                }
+                SourceKey::FileChanged(path) => match watch_path(path) {
+                    Ok((watcher, mut changes)) => {
+                        let task = cx.spawn(async move |this, cx| {
+                            // Keep the watcher alive as long as the task runs
+                            let _watcher = watcher;
+                            while let Some(path) = changes.recv().await {
+                                let event = SubscriptionEvent::FileChanged { index, path };
+                                if this.update(cx, |state, cx| state.dispatch_event(event, cx)).is_err() {
+                                    break;
+                                }
+                            }
+                        });
+                        self.subscription_tasks.push(task);
+                    }
+                    Err(e) => eprintln!("Failed to watch {}: {}", path.display(), e),
+                },
+                // Observed once per window in `observe_window_events`
+                SourceKey::Keyboard | SourceKey::WindowResized | SourceKey::WindowCloseRequested => {}
+            }
+        }
+
+        self.subscription_keys = keys;
+    }
+
+    /// Whether the window may close; a component handling close requests gets
+    /// the request as a message instead and closes it with `Command::exit`
+    pub fn close_requested(&mut self, cx: &mut Context<Self>) -> bool {
+        if !self.component.subscription().handles_close_requests() {
+            return true;
+        }
+        self.dispatch_event(SubscriptionEvent::WindowCloseRequested, cx);
+        false
+    }
+
+    /// Forward key presses, window resizes and close requests to the component's subscription
+    pub fn observe_window_events(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let this = cx.entity().downgrade();
+        let keystrokes = cx.observe_keystrokes(move |event, _window, cx| {
+            let keystroke = &event.keystroke;
+            let modifiers = Modifiers {
+                shift: keystroke.modifiers.shift,
+                control: keystroke.modifiers.control,
+                alt: keystroke.modifiers.alt,
+                logo: keystroke.modifiers.platform,
+            };
+            let event = SubscriptionEvent::Key(KeyEvent::new(keystroke.key.clone(), modifiers));
+            this.update(cx, |state, cx| state.dispatch_event(event, cx)).ok();
+        });
+
+        // Responsive styles and WindowSize::current follow the viewport
+        let viewport = window.viewport_size();
+        WindowSize::set_current(WindowSize::new(f32::from(viewport.width), f32::from(viewport.height)));
+
+        let bounds = cx.observe_window_bounds(window, |state, window, cx| {
+            let size = window.viewport_size();
+            let size = WindowSize::new(f32::from(size.width), f32::from(size.height));
+            WindowSize::set_current(size);
+            state.dispatch_event(SubscriptionEvent::WindowResized(size), cx);
+            // Re-render so sm:/md:/lg:/xl: classes resolve against the new width
+            cx.notify();
+        });
+
+        let this = cx.entity().downgrade();
+        window.on_window_should_close(cx, move |_window, cx| {
+            this.update(cx, |state, cx| state.close_requested(cx)).unwrap_or(true)
+        });
+
+        self.event_subscriptions = vec![keystrokes, bounds];
     }
 
     /// Get a reference to the component
@@ -158,7 +323,7 @@ where
                             // Get the callback and invoke it
                             if let Some(callback) = comp.select_callbacks.get(&key_clone) {
                                 let msg = callback.call(index, lang_value);
-                                comp.handle(msg);
+                                comp.handle(msg, _cx);
                                 _cx.notify();
                             }
                         }
@@ -171,23 +336,126 @@ where
         }
     }
 
-    /// Scan the view tree and pre-create all SelectState entities
+    /// Get or create a text input state entity for the given key
+    ///
+    /// The input is subscribed once; each edit looks up the latest callback for
+    /// `key` and passes it the full text of the input. An existing input whose
+    /// text differs from `value` is reset to it, so the component stays in control.
+    pub fn get_or_create_input_state(
+        &mut self,
+        key: String,
+        placeholder: String,
+        value: String,
+        password: bool,
+        callback: Option<InputCallback<C::Msg>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<InputState> {
+        match callback {
+            Some(cb) => {
+                self.input_callbacks.insert(key.clone(), cb);
+            }
+            None => {
+                self.input_callbacks.remove(&key);
+            }
+        }
+
+        if let Some(existing) = self.input_states.get(&key).cloned() {
+            if existing.read(cx).value().to_string() != value {
+                existing.update(cx, |input, cx| input.set_value(value, window, cx));
+            }
+            return existing;
+        }
+
+        let new_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(placeholder)
+                .masked(password)
+                .default_value(value)
+        });
+        self.input_states.insert(key.clone(), new_state.clone());
+
+        cx.subscribe_in(&new_state, window, move |comp: &mut Self, entity: &Entity<InputState>, event: &InputEvent, _window: &mut Window, cx: &mut Context<Self>| {
+            if matches!(event, InputEvent::Change) {
+                let text = entity.read(cx).value().to_string();
+                if let Some(callback) = comp.input_callbacks.get(&key) {
+                    let msg = callback.call(text);
+                    comp.handle(msg, cx);
+                    cx.notify();
+                }
+            }
+        })
+        .detach();
+
+        new_state
+    }
+
+    /// Scan the view tree and pre-create all SelectState and InputState entities
     ///
     /// This method should be called during component initialization (before rendering)
-    /// to ensure all Select widgets have their GPUI entities ready.
+    /// to ensure all Select and Input widgets have their GPUI entities ready.
     pub fn preinitialize_selects(
         &mut self,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
         let view = self.component.view();
-        self.scan_view_for_selects(view, window, cx);
+        self.sync_inputs(view, window, cx);
+    }
+
+    /// Bring the Select and Input states in line with `view`
+    ///
+    /// Runs before every render: inputs that appeared get a state, existing ones
+    /// get the latest callback and value, and states of removed inputs are dropped.
+    pub fn sync_inputs(
+        &mut self,
+        view: View<C::Msg>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut seen = HashSet::new();
+        self.view_path.clear();
+        self.scan_view_for_selects(view, &mut seen, window, cx);
+        self.input_states.retain(|key, _| seen.contains(key));
+        self.input_callbacks.retain(|key, _| seen.contains(key));
     }
 
-    /// Recursively scan a view tree for Select widgets and create their states
+    /// Run `f` with `segment` appended to the current view path
+    fn with_path_segment<R>(&mut self, segment: String, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.view_path.push(segment);
+        let result = f(self);
+        self.view_path.pop();
+        result
+    }
+
+    /// Run `f` with the last path segment replaced by a `View::Keyed` key
+    ///
+    /// Keyed inputs keep their state when reordered among their siblings.
+    fn with_view_key<R>(&mut self, key: &str, f: impl FnOnce(&mut Self) -> R) -> R {
+        let position = self.view_path.pop();
+        let result = self.with_path_segment(format!("#{}", key), f);
+        self.view_path.extend(position);
+        result
+    }
+
+    /// Key of the text input at the current view path
+    fn input_key(&self) -> String {
+        path_key("input", &self.view_path)
+    }
+
+    /// Element id of the radio group at the current view path
+    fn radio_group_key(&self) -> String {
+        path_key("radio_group", &self.view_path)
+    }
+
+    /// Recursively scan a view tree for Select and Input widgets and create their states
+    ///
+    /// Children are visited under the same path segments as in
+    /// `into_gpui_impl_with_context`, so both agree on each input's key.
     fn scan_view_for_selects(
         &mut self,
         view: View<C::Msg>,
+        seen: &mut HashSet<String>,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
@@ -207,23 +475,42 @@ where
                     cx,
                 );
             }
-            View::Row { children, .. } | View::Column { children, .. } => {
-                for child in children {
-                    self.scan_view_for_selects(child, window, cx);
+            View::Input { placeholder, value, on_change, password, .. } => {
+                let key = self.input_key();
+                seen.insert(key.clone());
+                self.get_or_create_input_state(key, placeholder, value, password, on_change, window, cx);
+            }
+            View::Row { children, .. } | View::Column { children, .. } | View::List { items: children, .. } => {
+                for (index, child) in children.into_iter().enumerate() {
+                    self.with_path_segment(index.to_string(), |this| this.scan_view_for_selects(child, seen, window, cx));
                 }
             }
-            View::Container { child, .. } => {
-                self.scan_view_for_selects(*child, window, cx);
+            View::Container { child, .. } | View::Scrollable { child, .. } => {
+                self.with_path_segment("0".to_string(), |this| this.scan_view_for_selects(*child, seen, window, cx));
             }
-            View::Scrollable { child, .. } => {
-                self.scan_view_for_selects(*child, window, cx);
+            View::Sidebar { content, .. } => {
+                self.with_path_segment("0".to_string(), |this| this.scan_view_for_selects(*content, seen, window, cx));
             }
-            View::List { items, .. } => {
-                for item in items {
-                    self.scan_view_for_selects(item, window, cx);
+            // Every tab is scanned so hidden tabs keep their edited text
+            View::Tabs { contents, .. } => {
+                for (index, content) in contents.into_iter().enumerate() {
+                    self.with_path_segment(index.to_string(), |this| this.scan_view_for_selects(content, seen, window, cx));
                 }
             }
-            // Other view types don't contain Select widgets
+            View::Table { headers, rows, .. } => {
+                for (col, header) in headers.into_iter().enumerate() {
+                    self.with_path_segment(format!("h{}", col), |this| this.scan_view_for_selects(header, seen, window, cx));
+                }
+                for (row, cells) in rows.into_iter().enumerate() {
+                    for (col, cell) in cells.into_iter().enumerate() {
+                        self.with_path_segment(format!("{}.{}", row, col), |this| this.scan_view_for_selects(cell, seen, window, cx));
+                    }
+                }
+            }
+            View::Keyed { key, child } => {
+                self.with_view_key(&key, |this| this.scan_view_for_selects(*child, seen, window, cx));
+            }
+            // Other view types don't contain Select or Input widgets
             _ => {}
         }
     }
@@ -234,9 +521,11 @@ impl<C: Component + 'static> Render for GpuiComponentState<C>
 where
     C::Msg: Clone + Debug + 'static,
 {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         // Get the view from the component and render it with auto message handling
-        self.component.view().render_gpui_with(self, cx)
+        let view = self.component.view();
+        self.sync_inputs(view.clone(), window, cx);
+        view.render_gpui_with(self, cx)
     }
 }
 
@@ -288,14 +577,39 @@ pub trait IntoGpuiElementWithHandler<M: Clone + Debug + 'static> {
         C: Component<Msg = M> + 'static;
 }
 
-impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
-    fn into_gpui_impl<F>(self, handle_msg: F) -> AnyElement
+/// Handler-based conversion that tracks the view path for stable element ids
+trait IntoGpuiElementAt<M: Clone + Debug + 'static> {
+    fn into_gpui_at<F>(self, path: &mut Vec<String>, handle_msg: F) -> AnyElement
+    where
+        F: Fn(M) + Clone + 'static;
+}
+
+/// Run `f` with `segment` appended to `path`
+fn at_path_segment<R>(path: &mut Vec<String>, segment: String, f: impl FnOnce(&mut Vec<String>) -> R) -> R {
+    path.push(segment);
+    let result = f(path);
+    path.pop();
+    result
+}
+
+/// `kind` plus the view path, unique per widget in the tree
+fn path_key(kind: &str, path: &[String]) -> String {
+    format!("{}_/{}", kind, path.join("/"))
+}
+
+impl<M: Clone + Debug + 'static> IntoGpuiElementAt<M> for View<M> {
+    fn into_gpui_at<F>(self, path: &mut Vec<String>, handle_msg: F) -> AnyElement
     where
         F: Fn(M) + Clone + 'static,
     {
         // Use a counter for unique button IDs
         let mut button_counter = 0u64;
 
+        // `hidden` (possibly behind a breakpoint) removes the view
+        if self.is_hidden() {
+            return div().into_any();
+        }
+
         // This is the simple version without context - used for static rendering
         match self {
             View::Empty => div().into_any(),
@@ -309,7 +623,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 text_div.into_any()
             }
 
-            View::Button { label, onclick, style } => {
+            View::Button { label, onclick, disabled, style } => {
                 let msg = onclick;
                 let handle_msg_clone = handle_msg.clone();
                 // Create a 'static string for the button ID
@@ -317,8 +631,21 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 let id = (label_static, button_counter);
                 button_counter += 1;
 
+                // State variants need a plain div; see interactive_button
+                if let Some(style) = style.as_ref().filter(|s| s.is_interactive()) {
+                    let button = interactive_button(id, label, style, disabled);
+                    if disabled {
+                        return button.into_any_element();
+                    }
+                    return button
+                        .on_click(move |_event, _window, _cx| {
+                            handle_msg_clone(msg.clone());
+                        })
+                        .into_any_element();
+                }
+
                 // Apply unified styling if present
-                let mut button = Button::new(id).label(label);
+                let mut button = Button::new(id).label(label).disabled(disabled);
                 if let Some(style) = style {
                     button = apply_style_to_button(button, &style);
                 } else {
@@ -342,8 +669,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     row_div = row_div.gap(px(spacing as f32)).p(px(padding as f32));
                 }
 
-                for child in children {
-                    row_div = row_div.child(child.clone().into_gpui_impl(handle_msg.clone()));
+                for (index, child) in children.into_iter().enumerate() {
+                    row_div = row_div.child(at_path_segment(path, index.to_string(), |path| child.into_gpui_at(path, handle_msg.clone())));
                 }
                 row_div.into_any()
             }
@@ -359,17 +686,17 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     col_div = col_div.gap(px(spacing as f32)).p(px(padding as f32));
                 }
 
-                for child in children {
-                    col_div = col_div.child(child.clone().into_gpui_impl(handle_msg.clone()));
+                for (index, child) in children.into_iter().enumerate() {
+                    col_div = col_div.child(at_path_segment(path, index.to_string(), |path| child.into_gpui_at(path, handle_msg.clone())));
                 }
                 col_div.into_any()
             }
 
-            View::Input { placeholder, value, style, .. } => {
+            View::Input { placeholder, value, disabled, style, .. } => {
                 let mut input_div = div().child(format!("{}: {}", placeholder, value));
                 // Apply unified styling if present
                 if let Some(style) = style {
-                    input_div = apply_style_to_div(input_div, &style);
+                    input_div = styled_input_wrapper(input_div, &style, disabled);
                 }
                 input_div.into_any()
             }
@@ -407,12 +734,12 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     }
                 }
 
-                container_div.child(child.clone().into_gpui_impl(handle_msg_clone)).into_any()
+                container_div.child(at_path_segment(path, "0".to_string(), |path| child.into_gpui_at(path, handle_msg_clone))).into_any()
             }
 
             View::Scrollable { child, width, height, style } => {
                 let handle_msg_clone = handle_msg.clone();
-                let child_element = child.clone().into_gpui_impl(handle_msg_clone);
+                let child_element = at_path_segment(path, "0".to_string(), |path| child.into_gpui_at(path, handle_msg_clone));
 
                 // Apply styling before wrapping in scrollable
                 let mut inner_div = div();
@@ -440,6 +767,24 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 radio_div.into_any()
             }
 
+            View::RadioGroup { options, selected_index, on_select, style } => {
+                let mut group = radio_group(path_key("radio_group", path), &options, selected_index);
+
+                if let Some(callback) = on_select {
+                    let handle_msg_clone = handle_msg.clone();
+                    group = group.on_click(move |index, _window, _cx| {
+                        let value = options.get(*index).map(String::as_str).unwrap_or_default();
+                        handle_msg_clone(callback.call(*index, value));
+                    });
+                }
+
+                let mut group_div = div().child(group);
+                if let Some(style) = style {
+                    group_div = apply_style_to_div(group_div, &style);
+                }
+                group_div.into_any()
+            }
+
             View::Select { options, selected_index, on_select, style } => {
                 // Note: We now have callback support! Full native Select widget with
                 // entity state management is coming soon. For now, showing the selection.
@@ -482,8 +827,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     list_div = list_div.gap(px(spacing as f32));
                 }
 
-                for item in items {
-                    list_div = list_div.child(item.clone().into_gpui_impl(handle_msg.clone()));
+                for (index, item) in items.into_iter().enumerate() {
+                    list_div = list_div.child(at_path_segment(path, index.to_string(), |path| item.into_gpui_at(path, handle_msg.clone())));
                 }
                 list_div.into_any()
             }
@@ -500,15 +845,15 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 }
 
                 let mut header_row_div = div().h_flex().gap(px(col_spacing as f32));
-                for header in headers {
-                    header_row_div = header_row_div.child(header.clone().into_gpui_impl(handle_msg.clone()));
+                for (col, header) in headers.into_iter().enumerate() {
+                    header_row_div = header_row_div.child(at_path_segment(path, format!("h{}", col), |path| header.into_gpui_at(path, handle_msg.clone())));
                 }
                 table_div = table_div.child(header_row_div);
 
-                for row_data in rows {
+                for (row, row_data) in rows.into_iter().enumerate() {
                     let mut row_div = div().h_flex().gap(px(col_spacing as f32));
-                    for cell in row_data {
-                        row_div = row_div.child(cell.clone().into_gpui_impl(handle_msg.clone()));
+                    for (col, cell) in row_data.into_iter().enumerate() {
+                        row_div = row_div.child(at_path_segment(path, format!("{}.{}", row, col), |path| cell.into_gpui_at(path, handle_msg.clone())));
                     }
                     table_div = table_div.child(row_div);
                 }
@@ -607,7 +952,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
             } => {
                 let mut accordion = div().flex().flex_col().gap_2().p_4();
 
-                for (_idx, item) in items.into_iter().enumerate() {
+                for (idx, item) in items.into_iter().enumerate() {
                     let header_text = if let Some(icon) = item.icon {
                         format!("{} {}", icon, item.title)
                     } else {
@@ -626,8 +971,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
 
                     let children_div = if item.expanded && !item.children.is_empty() {
                         let mut children_col = div().flex().flex_col().gap_1().p_2().pl_6();
-                        for child in item.children {
-                            let child_element = child.into_gpui_impl(handle_msg.clone());
+                        for (child_idx, child) in item.children.into_iter().enumerate() {
+                            let child_element = at_path_segment(path, format!("{}.{}", idx, child_idx), |path| child.into_gpui_at(path, handle_msg.clone()));
                             children_col = children_col.child(child_element);
                         }
                         children_col
@@ -663,7 +1008,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     .bg(rgb(0x1a1a1a))
                     .border_r_1()
                     .border_color(rgb(0x333333))
-                    .child(content.into_gpui_impl(handle_msg));
+                    .child(at_path_segment(path, "0".to_string(), |path| content.into_gpui_at(path, handle_msg)));
 
                 sidebar.into_any()
             }
@@ -706,7 +1051,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                         .border_1()
                         .border_color(rgb(0x444444))
                         .rounded_md()
-                        .child(content.clone().into_gpui_impl(handle_msg));
+                        .child(at_path_segment(path, selected.to_string(), |path| content.clone().into_gpui_at(path, handle_msg)));
 
                     tabs = tabs.child(content_div);
                 }
@@ -761,8 +1106,24 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
 
                 rail.into_any()
             }
+
+            View::Keyed { key, child } => {
+                let position = path.pop();
+                let element = at_path_segment(path, format!("#{}", key), |path| child.into_gpui_at(path, handle_msg));
+                path.extend(position);
+                element
+            }
         }
     }
+}
+
+impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
+    fn into_gpui_impl<F>(self, handle_msg: F) -> AnyElement
+    where
+        F: Fn(M) + Clone + 'static,
+    {
+        self.into_gpui_at(&mut Vec::new(), handle_msg)
+    }
 
     fn into_gpui_impl_with_context<C>(
         self,
@@ -775,6 +1136,10 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
         // Use a counter for unique button IDs
         let mut button_counter = 0u64;
 
+        if self.is_hidden() {
+            return div().into_any();
+        }
+
         // This version has access to state and cx for proper message handling
         match self {
             View::Empty => div().into_any(),
@@ -788,15 +1153,29 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 text_div.into_any()
             }
 
-            View::Button { label, onclick, style } => {
+            View::Button { label, onclick, disabled, style } => {
                 let msg = onclick.clone();
                 // Create a 'static string for the button ID
                 let label_static: &'static str = Box::leak(label.clone().into_boxed_str());
                 let id = (label_static, button_counter);
                 button_counter += 1;
 
+                // State variants need a plain div; see interactive_button
+                if let Some(style) = style.as_ref().filter(|s| s.is_interactive()) {
+                    let button = interactive_button(id, label, style, disabled);
+                    if disabled {
+                        return button.into_any_element();
+                    }
+                    return button
+                        .on_click(cx.listener(move |state: &mut GpuiComponentState<C>, _event, _window, _cx| {
+                            state.handle(msg.clone(), _cx);
+                            _cx.notify();
+                        }))
+                        .into_any_element();
+                }
+
                 // Apply unified styling if present
-                let mut button = Button::new(id).label(label);
+                let mut button = Button::new(id).label(label).disabled(disabled);
                 if let Some(style) = style {
                     button = apply_style_to_button(button, &style);
                 } else {
@@ -804,7 +1183,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 }
 
                 button.on_click(cx.listener(move |state: &mut GpuiComponentState<C>, _event, _window, _cx| {
-                    state.handle(msg.clone());
+                    state.handle(msg.clone(), _cx);
                     _cx.notify();
                 }))
                 .into_any_element()
@@ -821,8 +1200,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     row_div = row_div.gap(px(spacing as f32)).p(px(padding as f32));
                 }
 
-                for child in children {
-                    row_div = row_div.child(child.clone().into_gpui_impl_with_context(state, cx));
+                for (index, child) in children.into_iter().enumerate() {
+                    row_div = row_div.child(state.with_path_segment(index.to_string(), |state| child.into_gpui_impl_with_context(state, cx)));
                 }
                 row_div.into_any()
             }
@@ -838,17 +1217,22 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     col_div = col_div.gap(px(spacing as f32)).p(px(padding as f32));
                 }
 
-                for child in children {
-                    col_div = col_div.child(child.clone().into_gpui_impl_with_context(state, cx));
+                for (index, child) in children.into_iter().enumerate() {
+                    col_div = col_div.child(state.with_path_segment(index.to_string(), |state| child.into_gpui_impl_with_context(state, cx)));
                 }
                 col_div.into_any()
             }
 
-            View::Input { placeholder, value, style, .. } => {
-                let mut input_div = div().child(format!("{}: {}", placeholder, value));
+            View::Input { placeholder, value, disabled, style, .. } => {
+                // Use the InputState synced before this render so typed text reaches on_change
+                let mut input_div = match state.input_states.get(&state.input_key()) {
+                    Some(input_state) => div().child(Input::new(input_state).disabled(disabled)),
+                    // Fallback: rendered without `sync_inputs` (e.g. via `render_gpui_with` directly)
+                    None => div().child(format!("{}: {}", placeholder, value)),
+                };
                 // Apply unified styling if present
                 if let Some(style) = style {
-                    input_div = apply_style_to_div(input_div, &style);
+                    input_div = styled_input_wrapper(input_div, &style, disabled);
                 }
                 input_div.into_any()
             }
@@ -885,11 +1269,11 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     }
                 }
 
-                container_div.child(child.clone().into_gpui_impl_with_context(state, cx)).into_any()
+                container_div.child(state.with_path_segment("0".to_string(), |state| child.into_gpui_impl_with_context(state, cx))).into_any()
             }
 
             View::Scrollable { child, width, height, style } => {
-                let child_element = child.clone().into_gpui_impl_with_context(state, cx);
+                let child_element = state.with_path_segment("0".to_string(), |state| child.into_gpui_impl_with_context(state, cx));
 
                 // Apply styling before wrapping in scrollable
                 let mut inner_div = div();
@@ -917,6 +1301,24 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 radio_div.into_any()
             }
 
+            View::RadioGroup { options, selected_index, on_select, style } => {
+                let mut group = radio_group(state.radio_group_key(), &options, selected_index);
+
+                if let Some(callback) = on_select {
+                    group = group.on_click(cx.listener(move |state: &mut GpuiComponentState<C>, index: &usize, _window, _cx| {
+                        let value = options.get(*index).map(String::as_str).unwrap_or_default();
+                        state.handle(callback.call(*index, value), _cx);
+                        _cx.notify();
+                    }));
+                }
+
+                let mut group_div = div().child(group);
+                if let Some(style) = style {
+                    group_div = apply_style_to_div(group_div, &style);
+                }
+                group_div.into_any()
+            }
+
             View::Select { options, selected_index, on_select, style } => {
                 // Generate the same key used during pre-initialization
                 let key = format!("select_{:?}", options);
@@ -975,8 +1377,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     list_div = list_div.gap(px(spacing as f32));
                 }
 
-                for item in items {
-                    list_div = list_div.child(item.clone().into_gpui_impl_with_context(state, cx));
+                for (index, item) in items.into_iter().enumerate() {
+                    list_div = list_div.child(state.with_path_segment(index.to_string(), |state| item.into_gpui_impl_with_context(state, cx)));
                 }
                 list_div.into_any()
             }
@@ -993,15 +1395,15 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                 }
 
                 let mut header_row_div = div().h_flex().gap(px(col_spacing as f32));
-                for header in headers {
-                    header_row_div = header_row_div.child(header.clone().into_gpui_impl_with_context(state, cx));
+                for (col, header) in headers.into_iter().enumerate() {
+                    header_row_div = header_row_div.child(state.with_path_segment(format!("h{}", col), |state| header.into_gpui_impl_with_context(state, cx)));
                 }
                 table_div = table_div.child(header_row_div);
 
-                for row_data in rows {
+                for (row, row_data) in rows.into_iter().enumerate() {
                     let mut row_div = div().h_flex().gap(px(col_spacing as f32));
-                    for cell in row_data {
-                        row_div = row_div.child(cell.clone().into_gpui_impl_with_context(state, cx));
+                    for (col, cell) in row_data.into_iter().enumerate() {
+                        row_div = row_div.child(state.with_path_segment(format!("{}.{}", row, col), |state| cell.into_gpui_impl_with_context(state, cx)));
                     }
                     table_div = table_div.child(row_div);
                 }
@@ -1042,8 +1444,8 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                                 let new_value = slider_value.start();
 
                                 // Call the user's callback with the new value
-                                let msg = msg_callback(new_value);
-                                comp_state.handle(msg);
+                                let msg = msg_callback.call(new_value);
+                                comp_state.handle(msg, cx);
                                 cx.notify();
                             }
                         }
@@ -1121,7 +1523,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                             .w(px(280.0))
                             .on_click(cx.listener(move |state: &mut GpuiComponentState<C>, _event, _window, _cx| {
                                 let msg = callback_clone.call(idx, !item_expanded);
-                                state.handle(msg);
+                                state.handle(msg, _cx);
                                 _cx.notify();
                             }))
                             .into_any_element()
@@ -1178,7 +1580,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                     .bg(rgb(0x1a1a1a))
                     .border_r_1()
                     .border_color(rgb(0x333333))
-                    .child(content.into_gpui_impl_with_context(state, cx));
+                    .child(state.with_path_segment("0".to_string(), |state| content.into_gpui_impl_with_context(state, cx)));
 
                 sidebar.into_any()
             }
@@ -1213,7 +1615,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                             .small()
                             .on_click(cx.listener(move |state: &mut GpuiComponentState<C>, _event, _window, _cx| {
                                 let msg = callback_clone.call(idx);
-                                state.handle(msg);
+                                state.handle(msg, _cx);
                                 _cx.notify();
                             }))
                             .into_any_element()
@@ -1238,7 +1640,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                         .border_1()
                         .border_color(rgb(0x444444))
                         .rounded_md()
-                        .child(content.clone().into_gpui_impl_with_context(state, cx));
+                        .child(state.with_path_segment(selected.to_string(), |state| content.clone().into_gpui_impl_with_context(state, cx)));
 
                     tabs = tabs.child(content_div);
                 }
@@ -1290,7 +1692,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
                             .w(px(width - 16.0))
                             .on_click(cx.listener(move |state: &mut GpuiComponentState<C>, _event, _window, _cx| {
                                 let msg = callback_clone.call(idx);
-                                state.handle(msg);
+                                state.handle(msg, _cx);
                                 _cx.notify();
                             }))
                             .into_any_element()
@@ -1311,14 +1713,36 @@ impl<M: Clone + Debug + 'static> IntoGpuiElementWithHandler<M> for View<M> {
 
                 rail.into_any()
             }
+
+            View::Keyed { key, child } => state.with_view_key(&key, |state| child.into_gpui_impl_with_context(state, cx)),
         }
     }
 }
 
+/// Build a vertical GPUI radio group with one radio per option
+///
+/// `id` comes from the group's view path, so it stays stable across renders
+/// and two groups with the same options keep separate state.
+fn radio_group(id: String, options: &[String], selected_index: Option<usize>) -> RadioGroup {
+    let mut group = RadioGroup::vertical(SharedString::from(id))
+        .selected_index(selected_index);
+    for (index, option) in options.iter().enumerate() {
+        group = group.child(Radio::new(index).label(option.clone()));
+    }
+    group
+}
+
 /// Apply a Style to a GPUI div element
-fn apply_style_to_div(div: Div, style: &Style) -> Div {
-    let gpui_style = GpuiStyle::from_style(style);
-    let mut result = div;
+pub(crate) fn apply_style_to_div(div: Div, style: &Style) -> Div {
+    apply_gpui_style(div, &GpuiStyle::from_style(style))
+}
+
+/// Apply converted style values to anything `Styled`
+///
+/// Besides elements this covers `StyleRefinement`, which is what `.hover()`
+/// and `.active()` hand to their closures.
+pub(crate) fn apply_gpui_style<E: Styled>(element: E, gpui_style: &GpuiStyle) -> E {
+    let mut result = element;
 
     // Apply spacing (L1 + L2)
     if let Some(padding) = &gpui_style.padding {
@@ -1426,27 +1850,8 @@ fn apply_style_to_div(div: Div, style: &Style) -> Div {
 
     // Apply border radius (L1 + L2)
     if gpui_style.rounded {
-        if let Some(rounded_size) = gpui_style.rounded_size {
-            match rounded_size {
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Sm => {
-                    result = result.rounded(px(2.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Md => {
-                    result = result.rounded(px(4.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Lg => {
-                    result = result.rounded(px(8.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Xl => {
-                    result = result.rounded(px(12.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Xxl => {
-                    result = result.rounded(px(16.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Full => {
-                    result = result.rounded(px(9999.0));
-                }
-            }
+        if let Some(radius) = gpui_style.border_radius {
+            result = result.rounded(px(radius));
         }
     }
 
@@ -1484,9 +1889,58 @@ fn apply_style_to_div(div: Div, style: &Style) -> Div {
         result = result.opacity(opacity);
     }
 
+    // Apply visibility
+    if gpui_style.hidden {
+        result = result.hidden();
+    }
+
     result
 }
 
+/// Clickable div for a button whose style has state variants
+///
+/// gpui-component's Button draws its own hover and pressed colors, so styles
+/// with `hover:`/`active:` classes render on a stateful div instead. A disabled
+/// button shows its `disabled:` classes and gets no click handler.
+pub(crate) fn interactive_button(
+    id: impl Into<ElementId>,
+    label: String,
+    style: &Style,
+    disabled: bool,
+) -> Stateful<Div> {
+    let mut base = apply_style_to_div(div().flex().items_center().justify_center(), style).child(label);
+    if disabled {
+        if let Some(delta) = GpuiStyle::from_variant(style, StyleState::Disabled) {
+            base = apply_gpui_style(base, &delta);
+        }
+        return base.id(id);
+    }
+
+    let mut button = base.id(id).cursor_pointer();
+    if let Some(delta) = GpuiStyle::from_variant(style, StyleState::Hover) {
+        button = button.hover(move |refinement| apply_gpui_style(refinement, &delta));
+    }
+    if let Some(delta) = GpuiStyle::from_variant(style, StyleState::Active) {
+        button = button.active(move |refinement| apply_gpui_style(refinement, &delta));
+    }
+    button
+}
+
+/// Wrapper div for an input, with `hover:` and `disabled:` variants applied
+///
+/// The text field inside keeps its own focus ring.
+pub(crate) fn styled_input_wrapper(input: Div, style: &Style, disabled: bool) -> Div {
+    let mut wrapper = apply_style_to_div(input, style);
+    if disabled {
+        if let Some(delta) = GpuiStyle::from_variant(style, StyleState::Disabled) {
+            wrapper = apply_gpui_style(wrapper, &delta);
+        }
+    } else if let Some(delta) = GpuiStyle::from_variant(style, StyleState::Hover) {
+        wrapper = wrapper.hover(move |refinement| apply_gpui_style(refinement, &delta));
+    }
+    wrapper
+}
+
 /// Apply a Style to a GPUI Button element
 fn apply_style_to_button(button: Button, style: &Style) -> Button {
     let gpui_style = GpuiStyle::from_style(style);
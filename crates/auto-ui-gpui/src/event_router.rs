@@ -30,7 +30,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 // 导入 VNodeId
-use auto_ui::vnode::VNodeId;
+use auto_ui::vnode::{EventSlot, VNodeId};
 
 // 导入 DynamicMessage（仅在 interpreter feature 启用时）
 #[cfg(feature = "interpreter")]
@@ -72,6 +72,18 @@ pub enum EventType {
     Select(usize),
 }
 
+impl EventType {
+    /// 事件对应的 VNode 回调槽位
+    pub fn slot(&self) -> EventSlot {
+        match self {
+            EventType::Click => EventSlot::Click,
+            EventType::Change(_) => EventSlot::Change,
+            EventType::Toggle(_) => EventSlot::Toggle,
+            EventType::Select(_) => EventSlot::Select,
+        }
+    }
+}
+
 /// 事件处理器回调
 pub type EventHandler = dyn Fn(EventContext) -> DynamicMessage + Send + Sync;
 
@@ -384,4 +396,12 @@ mod tests {
         let result = router.on_click(node_id);
         assert!(result.is_some());
     }
+
+    #[test]
+    fn test_event_type_slot() {
+        assert_eq!(EventType::Click.slot(), EventSlot::Click);
+        assert_eq!(EventType::Change("a".to_string()).slot(), EventSlot::Change);
+        assert_eq!(EventType::Toggle(true).slot(), EventSlot::Toggle);
+        assert_eq!(EventType::Select(2).slot(), EventSlot::Select);
+    }
 }
@@ -24,12 +24,20 @@ use std::sync::{Arc, RwLock};
 // 导入 auto-ui 的类型
 #[cfg(feature = "interpreter")]
 use auto_ui::{
-    interpreter::{InterpreterBridge, DynamicMessage},
+    interpreter::{InterpreterBridge, DynamicMessage, MigrationReport},
     node_converter::convert_node_dynamic,
+    style::Theme,
     view::View,
     vnode_converter::view_to_vtree,
     vnode::{VTree, VNodeId},
 };
+#[cfg(feature = "interpreter")]
+use gpui_component::button::Button;
+#[cfg(feature = "interpreter")]
+use crate::vnode_entity::{
+    apply_legacy_layout, apply_vnode_style, render_accordion_node, render_navigation_rail_node,
+    render_radio_group_node, render_sidebar_node, render_table_node, render_tabs_node,
+};
 
 /// GPUI 动态解释器组件
 ///
@@ -51,6 +59,10 @@ pub struct DynamicInterpreterComponent {
     #[cfg(feature = "interpreter")]
     vtree: Option<VTree>,
 
+    /// 最近一次热重载的状态迁移报告（有字段被丢弃或重置时显示为浮层）
+    #[cfg(feature = "interpreter")]
+    migration_report: Option<MigrationReport>,
+
     /// 焦点句柄
     focus_handle: FocusHandle,
 
@@ -70,6 +82,7 @@ impl DynamicInterpreterComponent {
             bridge: bridge.clone(),
             file_path: path.clone(),
             vtree: None,  // Plan 012: 使用 VTree 而不是 View
+            migration_report: None,
             focus_handle: cx.focus_handle(),
             error: None,
         };
@@ -92,13 +105,10 @@ impl DynamicInterpreterComponent {
         bridge.load_file(path)
             .map_err(|e| format!("解释失败: {}", e))?;
 
-        // 获取主视图
-        let node = bridge.get_main_view()
-            .map_err(|e| format!("获取视图失败: {}", e))?;
+        apply_app_theme(&mut bridge, path)?;
 
-        // 转换 Node → View<DynamicMessage>
-        let view = convert_node_dynamic(&node, None)
-            .map_err(|e| format!("转换视图失败: {}", e))?;
+        // 获取主视图并转换 Node → View<DynamicMessage>
+        let view = main_view(&mut bridge)?;
 
         // Plan 012: 将 View 转换为 VTree（扁平化）
         let vtree = view_to_vtree(view);
@@ -113,13 +123,84 @@ impl DynamicInterpreterComponent {
     }
 
     /// 重新加载文件（热重载）
+    ///
+    /// 保留兼容的 Widget 状态；有字段被丢弃或重置时显示可关闭的迁移报告。
     #[cfg(feature = "interpreter")]
     pub fn reload(&mut self, cx: &mut Context<Self>) {
-        let path = self.file_path.clone();
-        if let Err(e) = self.load_file(&path, cx) {
-            self.error = Some(format!("重载失败: {}", e));
-            cx.notify();
+        match self.reload_file(cx) {
+            Ok(report) => {
+                self.migration_report = if report.is_clean() { None } else { Some(report) };
+            }
+            Err(e) => self.error = Some(format!("重载失败: {}", e)),
         }
+        cx.notify();
+    }
+
+    /// 重新读取文件并迁移状态
+    #[cfg(feature = "interpreter")]
+    fn reload_file(&mut self, cx: &mut Context<Self>) -> Result<MigrationReport, String> {
+        let code = std::fs::read_to_string(&self.file_path)
+            .map_err(|e| format!("读取文件失败: {}", e))?;
+
+        let mut bridge = self.bridge.write()
+            .map_err(|e| format!("获取解释器锁失败: {}", e))?;
+
+        let report = bridge.reload(&code)
+            .map_err(|e| format!("解释失败: {}", e))?;
+
+        apply_app_theme(&mut bridge, &self.file_path)?;
+
+        let view = main_view(&mut bridge)?;
+
+        self.vtree = Some(view_to_vtree(view));
+        self.error = None;
+        cx.notify();
+
+        Ok(report)
+    }
+
+    /// 渲染状态迁移报告浮层
+    #[cfg(feature = "interpreter")]
+    fn render_migration_overlay(&self, cx: &mut Context<Self>) -> Option<Div> {
+        let report = self.migration_report.as_ref()?;
+
+        let lines = report
+            .dropped
+            .iter()
+            .map(|field| format!("丢弃: {}", field))
+            .chain(report.reset.iter().map(|field| format!("重置: {}", field)))
+            .chain(report.removed_widgets.iter().map(|widget| format!("移除 Widget: {}", widget)));
+
+        Some(
+            div()
+                .absolute()
+                .top_2()
+                .right_2()
+                .max_w(px(360.0))
+                .p_3()
+                .gap_1()
+                .flex()
+                .flex_col()
+                .rounded_md()
+                .bg(rgb(0x3c331a))
+                .text_color(rgb(0xffd479))
+                .text_sm()
+                .child(
+                    div()
+                        .flex()
+                        .justify_between()
+                        .child("⚠️ 热重载状态迁移")
+                        .child(
+                            Button::new("migration-report-dismiss")
+                                .label("×")
+                                .on_click(cx.listener(|this: &mut Self, _event, _window, cx| {
+                                    this.migration_report = None;
+                                    cx.notify();
+                                })),
+                        ),
+                )
+                .children(lines.map(|line| div().child(line))),
+        )
     }
 
     /// 处理动态消息事件
@@ -141,19 +222,10 @@ impl DynamicInterpreterComponent {
         }
 
         // 重新获取视图（状态可能已改变）
-        let node = match bridge.get_main_view() {
-            Ok(n) => n,
-            Err(e) => {
-                self.error = Some(format!("获取更新后的视图失败: {}", e));
-                cx.notify();
-                return;
-            }
-        };
-
-        let view = match convert_node_dynamic(&node, None) {
+        let view = match main_view(&mut bridge) {
             Ok(v) => v,
             Err(e) => {
-                self.error = Some(format!("转换更新后的视图失败: {}", e));
+                self.error = Some(format!("更新视图失败: {}", e));
                 cx.notify();
                 return;
             }
@@ -168,6 +240,35 @@ impl DynamicInterpreterComponent {
     }
 }
 
+/// 获取主视图并转换为 `View<DynamicMessage>`
+///
+/// 带上主 Widget 名作为元数据，输入框和单选组才会发出携带值的类型化消息。
+#[cfg(feature = "interpreter")]
+fn main_view(bridge: &mut InterpreterBridge) -> Result<View<DynamicMessage>, String> {
+    let node = bridge.get_main_view()
+        .map_err(|e| format!("获取视图失败: {}", e))?;
+
+    let universe = bridge.universe();
+    let universe = universe.borrow();
+    let metadata = bridge.main_widget().map(|widget_name| (widget_name, &*universe));
+
+    convert_node_dynamic(&node, metadata)
+        .map_err(|e| format!("转换视图失败: {}", e))
+}
+
+/// 按 App 的 `theme` 字段切换当前主题
+///
+/// 主题文件路径相对于 .at 文件所在目录；没有 App 或未设置时保持当前主题。
+#[cfg(feature = "interpreter")]
+fn apply_app_theme(bridge: &mut InterpreterBridge, path: &std::path::Path) -> Result<(), String> {
+    if let Some(spec) = bridge.app_theme() {
+        let theme = Theme::select(&spec, path.parent())
+            .map_err(|e| format!("加载主题失败: {}", e))?;
+        Theme::set_active(theme);
+    }
+    Ok(())
+}
+
 impl Focusable for DynamicInterpreterComponent {
     fn focus_handle(&self, _: &App) -> FocusHandle {
         self.focus_handle.clone()
@@ -211,8 +312,11 @@ impl Render for DynamicInterpreterComponent {
 
                 // 获取根节点并渲染
                 if let Some(root) = vtree.root() {
-                    // 将 AnyElement 包装在 Div 中
-                    return div().child(self.render_vnode_with_events(root.id, vtree, cx));
+                    // 将 AnyElement 包装在 Div 中，迁移报告浮在其上
+                    return div()
+                        .relative()
+                        .child(self.render_vnode_with_events(root.id, vtree, cx))
+                        .children(self.render_migration_overlay(cx));
                 }
             }
 
@@ -255,19 +359,19 @@ impl DynamicInterpreterComponent {
             }
         };
 
-        match &node.kind {
+        let element = match &node.kind {
             VNodeKind::Text => {
                 let content = match &node.props {
                     VNodeProps::Text { content } => content.clone(),
                     VNodeProps::Empty => String::new(),
                     _ => String::from("(无效)"),
                 };
-                div().text_sm().child(content).into_any()
+                div().text_sm().child(content)
             }
 
             VNodeKind::Button => {
                 let label = match &node.props {
-                    VNodeProps::Button { label } => label.clone(),
+                    VNodeProps::Button { label, .. } => label.clone(),
                     _ => String::from("Button"),
                 };
                 div()
@@ -277,7 +381,6 @@ impl DynamicInterpreterComponent {
                     .rounded_md()
                     .cursor_pointer()
                     .child(label)
-                    .into_any()
             }
 
             VNodeKind::Column => {
@@ -286,16 +389,14 @@ impl DynamicInterpreterComponent {
                     _ => (10, 0),
                 };
 
-                let mut col = div()
-                    .flex()
-                    .flex_col()
-                    .gap(px(spacing as f32));
+                let mut col = div().flex().flex_col();
+                col = apply_legacy_layout(col, node, Some(spacing as f32), None);
 
                 for child_id in &node.children {
                     col = col.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
 
-                col.into_any()
+                col
             }
 
             VNodeKind::Row => {
@@ -304,16 +405,14 @@ impl DynamicInterpreterComponent {
                     _ => (10, 0),
                 };
 
-                let mut row = div()
-                    .flex()
-                    .flex_row()
-                    .gap(px(spacing as f32));
+                let mut row = div().flex().flex_row();
+                row = apply_legacy_layout(row, node, Some(spacing as f32), None);
 
                 for child_id in &node.children {
                     row = row.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
 
-                row.into_any()
+                row
             }
 
             VNodeKind::Container => {
@@ -322,6 +421,7 @@ impl DynamicInterpreterComponent {
                         padding,
                         center_x,
                         center_y,
+                        ..
                     } => (*padding, *center_x, *center_y),
                     _ => (0, false, false),
                 };
@@ -332,7 +432,7 @@ impl DynamicInterpreterComponent {
                     container = container.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
 
-                container.into_any()
+                container
             }
 
             VNodeKind::Scrollable => {
@@ -345,7 +445,7 @@ impl DynamicInterpreterComponent {
                     scrollable = scrollable.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
 
-                scrollable.into_any_element()
+                scrollable
             }
 
             VNodeKind::Input => {
@@ -362,7 +462,6 @@ impl DynamicInterpreterComponent {
                     .rounded_md()
                     .text_sm()
                     .child(format!("{}: {}", placeholder, "(输入框)"))
-                    .into_any()
             }
 
             VNodeKind::Checkbox => {
@@ -393,7 +492,6 @@ impl DynamicInterpreterComponent {
                             .rounded_sm(),
                     )
                     .child(label)
-                    .into_any()
             }
 
             VNodeKind::Radio => {
@@ -419,9 +517,10 @@ impl DynamicInterpreterComponent {
                             .rounded_full(),
                     )
                     .child(label)
-                    .into_any()
             }
 
+            VNodeKind::RadioGroup => render_radio_group_node(node),
+
             VNodeKind::Select => {
                 let (options, selected_index) = match &node.props {
                     VNodeProps::Select {
@@ -443,7 +542,6 @@ impl DynamicInterpreterComponent {
                     .rounded_md()
                     .text_sm()
                     .child(selected)
-                    .into_any()
             }
 
             VNodeKind::List => {
@@ -452,36 +550,16 @@ impl DynamicInterpreterComponent {
                     _ => 8,
                 };
 
-                let mut list = div().flex().flex_col().gap(px(spacing as f32));
+                let mut list = div().flex().flex_col();
+                list = apply_legacy_layout(list, node, Some(spacing as f32), None);
                 for child_id in &node.children {
                     list = list.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
-                list.into_any()
+                list
             }
 
             VNodeKind::Table => {
-                let (_spacing, _col_spacing) = match &node.props {
-                    VNodeProps::Table {
-                        spacing,
-                        col_spacing,
-                    } => (*spacing, *col_spacing),
-                    _ => (5, 10),
-                };
-
-                let mut table = div().flex().flex_col();
-                for child_id in &node.children {
-                    let child = match vtree.get(*child_id) {
-                        Some(c) => c,
-                        None => continue,
-                    };
-
-                    let mut row = div().flex().flex_row().gap_2();
-                    for cell_id in &child.children {
-                        row = row.child(self.render_vnode_with_events(*cell_id, vtree, cx));
-                    }
-                    table = table.child(row);
-                }
-                table.into_any()
+                render_table_node(node, &mut |id| self.render_vnode_with_events(id, vtree, cx))
             }
 
             VNodeKind::Slider => {
@@ -535,7 +613,6 @@ impl DynamicInterpreterComponent {
                             .border_color(rgb(0x3b82f6))
                             .shadow_lg(),
                     )
-                    .into_any()
             }
 
             VNodeKind::ProgressBar => {
@@ -553,7 +630,6 @@ impl DynamicInterpreterComponent {
                     .border_1()
                     .border_color(rgb(0x444444))
                     .child(div().w(px(filled_width)).h(px(20.0)).bg(rgb(0x3b82f6)))
-                    .into_any()
             }
 
             VNodeKind::Center => {
@@ -561,17 +637,25 @@ impl DynamicInterpreterComponent {
                 if let Some(child_id) = node.children.first() {
                     center = center.child(self.render_vnode_with_events(*child_id, vtree, cx));
                 }
-                center.into_any()
+                center
             }
 
-            // 高级组件占位符
-            VNodeKind::Accordion | VNodeKind::Sidebar | VNodeKind::Tabs | VNodeKind::NavigationRail => {
-                div()
-                    .text_color(rgb(0xf59e0b))
-                    .child(format!("🔧 {:?} 组件暂未实现", node.kind))
-                    .into_any()
+            VNodeKind::Accordion => {
+                render_accordion_node(node, &mut |id| self.render_vnode_with_events(id, vtree, cx))
             }
-        }
+
+            VNodeKind::Sidebar => {
+                render_sidebar_node(node, &mut |id| self.render_vnode_with_events(id, vtree, cx))
+            }
+
+            VNodeKind::Tabs => {
+                render_tabs_node(node, &mut |id| self.render_vnode_with_events(id, vtree, cx))
+            }
+
+            VNodeKind::NavigationRail => render_navigation_rail_node(node),
+        };
+
+        apply_vnode_style(element, node).into_any()
     }
 }
 
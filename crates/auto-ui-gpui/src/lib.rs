@@ -79,6 +79,11 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
         // Use a counter for unique button IDs
         let mut button_counter = 0u64;
 
+        // `hidden` (possibly behind a breakpoint) removes the view
+        if self.is_hidden() {
+            return div().into_any();
+        }
+
         match self {
             AbstractView::Empty => {
                 // Empty view renders as empty div
@@ -94,13 +99,21 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
                 text_div.into_any()
             }
 
-            AbstractView::Button { label, onclick: _, style } => {
+            AbstractView::Button { label, onclick: _, disabled, style } => {
                 // Button with click handler - note: we can't directly handle messages
                 // in GPUI's Button without proper context. This is a simplified version.
                 button_counter += 1;
+
+                // Hover/active/disabled variants render on a stateful div
+                if let Some(style) = style.as_ref().filter(|s| s.is_interactive()) {
+                    return auto_render::interactive_button(("button", button_counter), label, style, disabled)
+                        .into_any_element();
+                }
+
                 let label_clone = label.clone();
                 let mut button = Button::new(("button", button_counter))
-                    .label(label_clone);
+                    .label(label_clone)
+                    .disabled(disabled);
 
                 // Apply style if present
                 if style.is_some() {
@@ -159,6 +172,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
                 on_change: _,
                 width: _,
                 password: _,
+                disabled,
                 style,
             } => {
                 // GPUI text input
@@ -166,7 +180,7 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
                 // For now, we use a simple div that shows the value
                 let mut input_div = div().child(format!("{}: {}", placeholder, value));
                 if let Some(style) = style {
-                    input_div = apply_gpui_style_to_div(input_div, &style);
+                    input_div = auto_render::styled_input_wrapper(input_div, &style, disabled);
                 }
                 input_div.into_any()
             }
@@ -283,6 +297,24 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
                 radio_div.into_any()
             }
 
+            AbstractView::RadioGroup {
+                options,
+                selected_index,
+                on_select: _,
+                style,
+            } => {
+                // Radio group - simplified version, one line per option
+                let mut group_div = div().flex().flex_col().gap_1();
+                for (index, option) in options.iter().enumerate() {
+                    let marker = if selected_index == Some(index) { "(•)" } else { "( )" };
+                    group_div = group_div.child(format!("{} {}", marker, option));
+                }
+                if let Some(style) = style {
+                    group_div = apply_gpui_style_to_div(group_div, &style);
+                }
+                group_div.into_any()
+            }
+
             AbstractView::Select {
                 options,
                 selected_index,
@@ -616,6 +648,9 @@ impl<M: Clone + Debug + 'static> IntoGpuiElement<M> for AbstractView<M> {
 
                 rail.into_any()
             }
+
+            // Keys only matter for VTree diffing; render the wrapped view as-is
+            AbstractView::Keyed { child, .. } => child.into_gpui(handle_msg),
         }
     }
 }
@@ -653,27 +688,8 @@ fn apply_gpui_style_to_div(div: Div, style: &Style) -> Div {
 
     // Apply border radius (L1 + L2)
     if gpui_style.rounded {
-        if let Some(rounded_size) = gpui_style.rounded_size {
-            match rounded_size {
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Sm => {
-                    result = result.rounded(px(2.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Md => {
-                    result = result.rounded(px(4.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Lg => {
-                    result = result.rounded(px(8.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Xl => {
-                    result = result.rounded(px(12.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Xxl => {
-                    result = result.rounded(px(16.0));
-                }
-                auto_ui::style::gpui_adapter::GpuiRoundedSize::Full => {
-                    result = result.rounded(px(9999.0));
-                }
-            }
+        if let Some(radius) = gpui_style.border_radius {
+            result = result.rounded(px(radius));
         }
     }
 
@@ -732,8 +748,9 @@ impl<C: Component> GpuiMessageBridge<C> {
         Self { component }
     }
 
-    pub fn update(&mut self, msg: C::Msg) {
-        self.component.on(msg);
+    /// Update the component; the caller is responsible for executing the returned command
+    pub fn update(&mut self, msg: C::Msg) -> auto_ui::Command<C::Msg> {
+        self.component.update(msg)
     }
 
     pub fn render<F>(&self, handle_msg: F) -> AnyElement
@@ -822,6 +839,9 @@ where
                     // This must be done before rendering to create SelectState entities
                     state.update(cx, |state, cx| {
                         state.preinitialize_selects(window, cx);
+                        // Start timers, watchers and window observers (Component::subscription)
+                        state.observe_window_events(window, cx);
+                        state.sync_subscriptions(cx);
                     });
 
                     // Build the UI using the state's render implementation
@@ -30,11 +30,16 @@ use gpui::{prelude::*, InteractiveElement, *};
 use std::sync::Arc;
 
 // 导入 auto-ui 的 VNode 类型
-use auto_ui::vnode::{VNodeId, VNodeKind, VNodeProps, VTree};
+use auto_ui::vnode::{VNode, VNodeId, VNodeKind, VNodeProps, VTree};
+use auto_ui::vnode_diff::Patch;
+use auto_ui::view::{SidebarPosition, TabsPosition};
 
 #[cfg(feature = "interpreter")]
 use auto_ui::interpreter::DynamicMessage;
 
+use auto_ui::style::gpui_adapter::GpuiStyle;
+
+use crate::auto_render::apply_style_to_div;
 use crate::event_router::{EventType};
 
 /// VNode Entity - GPUI 渲染器
@@ -49,6 +54,9 @@ pub struct VNodeEntity {
 
     /// 错误信息（如果有）
     error: Option<String>,
+
+    /// 当前树是否已通过验证（树变化后重新验证）
+    validated: bool,
 }
 
 impl VNodeEntity {
@@ -60,6 +68,7 @@ impl VNodeEntity {
             vtree,
             focus_handle,
             error: None,
+            validated: false,
         }
     }
 
@@ -70,12 +79,39 @@ impl VNodeEntity {
 
     /// 获取 VTree 的可变引用
     pub fn vtree_mut(&mut self) -> &mut VTree {
+        self.validated = false;
         &mut self.vtree
     }
 
     /// 更新 VTree
+    ///
+    /// 与当前树做 diff，只应用变化的部分：未变化的节点保持原 ID，
+    /// 没有任何变化时不会触发重绘。
     pub fn update_vtree(&mut self, vtree: VTree, cx: &mut Context<Self>) {
-        self.vtree = vtree;
+        let patches = VTree::diff(&self.vtree, &vtree);
+        if patches.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.vtree.apply(patches) {
+            // 补丁应用失败时回退为整体替换
+            eprintln!("⚠️ 应用 VTree 补丁失败，整体替换: {}", e);
+            self.vtree = vtree;
+        }
+        self.validated = false;
+        cx.notify();
+    }
+
+    /// 直接应用补丁列表（补丁需以当前树为旧树计算）
+    pub fn apply_patches(&mut self, patches: Vec<Patch>, cx: &mut Context<Self>) {
+        if patches.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.vtree.apply(patches) {
+            self.error = Some(format!("补丁应用失败: {}", e));
+        }
+        self.validated = false;
         cx.notify();
     }
 
@@ -91,7 +127,7 @@ impl VNodeEntity {
             }
         };
 
-        match &node.kind {
+        let element = match &node.kind {
             VNodeKind::Text => self.render_text(node),
             VNodeKind::Button => self.render_button(node, cx),
             VNodeKind::Column => self.render_column(node, cx),
@@ -101,21 +137,24 @@ impl VNodeEntity {
             VNodeKind::Input => self.render_input(node, cx),
             VNodeKind::Checkbox => self.render_checkbox(node, cx),
             VNodeKind::Radio => self.render_radio(node, cx),
+            VNodeKind::RadioGroup => render_radio_group_node(node),
             VNodeKind::Select => self.render_select(node),
             VNodeKind::List => self.render_list(node, cx),
             VNodeKind::Table => self.render_table(node, cx),
             VNodeKind::Slider => self.render_slider(node),
             VNodeKind::ProgressBar => self.render_progress_bar(node),
             VNodeKind::Center => self.render_center(node, cx),
-            // 高级组件占位符
-            VNodeKind::Accordion | VNodeKind::Sidebar | VNodeKind::Tabs | VNodeKind::NavigationRail => {
-                self.render_placeholder(node)
-            }
-        }
+            VNodeKind::Accordion => render_accordion_node(node, &mut |id| self.render_vnode(id, cx)),
+            VNodeKind::Sidebar => render_sidebar_node(node, &mut |id| self.render_vnode(id, cx)),
+            VNodeKind::Tabs => render_tabs_node(node, &mut |id| self.render_vnode(id, cx)),
+            VNodeKind::NavigationRail => render_navigation_rail_node(node),
+        };
+
+        apply_vnode_style(element, node).into_any()
     }
 
     /// 渲染文本节点
-    fn render_text(&self, node: &auto_ui::vnode::VNode) -> AnyElement {
+    fn render_text(&self, node: &VNode) -> Div {
         let content = match &node.props {
             VNodeProps::Text { content } => content.clone(),
             VNodeProps::Empty => String::new(),
@@ -125,13 +164,12 @@ impl VNodeEntity {
         div()
             .text_sm()
             .child(content)
-            .into_any()
     }
 
     /// 渲染按钮节点
-    fn render_button(&self, node: &auto_ui::vnode::VNode, _cx: &mut Context<Self>) -> AnyElement {
+    fn render_button(&self, node: &VNode, _cx: &mut Context<Self>) -> Div {
         let label = match &node.props {
-            VNodeProps::Button { label } => label.clone(),
+            VNodeProps::Button { label, .. } => label.clone(),
             _ => String::from("Button"),
         };
 
@@ -145,63 +183,58 @@ impl VNodeEntity {
             .rounded_md()
             .cursor_pointer()
             .child(label)
-            .into_any()
     }
 
     /// 渲染列布局节点
-    fn render_column(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_column(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let (spacing, padding) = match &node.props {
             VNodeProps::Layout { spacing, padding } => (*spacing, *padding),
             _ => (10, 0),
         };
 
-        let mut col = div()
-            .flex()
-            .flex_col()
-            .gap(px(spacing as f32))
-            .p(px(padding as f32));
+        let mut col = div().flex().flex_col();
+        col = apply_legacy_layout(col, node, Some(spacing as f32), Some(padding as f32));
 
         // 递归渲染子节点
         for child_id in &node.children {
             col = col.child(self.render_vnode(*child_id, cx));
         }
 
-        col.into_any()
+        col
     }
 
     /// 渲染行布局节点
-    fn render_row(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_row(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let (spacing, padding) = match &node.props {
             VNodeProps::Layout { spacing, padding } => (*spacing, *padding),
             _ => (10, 0),
         };
 
-        let mut row = div()
-            .flex()
-            .flex_row()
-            .gap(px(spacing as f32))
-            .p(px(padding as f32));
+        let mut row = div().flex().flex_row();
+        row = apply_legacy_layout(row, node, Some(spacing as f32), Some(padding as f32));
 
         // 递归渲染子节点
         for child_id in &node.children {
             row = row.child(self.render_vnode(*child_id, cx));
         }
 
-        row.into_any()
+        row
     }
 
     /// 渲染容器节点
-    fn render_container(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_container(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let (padding, center_x, center_y) = match &node.props {
             VNodeProps::Container {
                 padding,
                 center_x,
                 center_y,
+                ..
             } => (*padding, *center_x, *center_y),
             _ => (0, false, false),
         };
 
-        let mut container = div().flex().p(px(padding as f32));
+        let mut container = div().flex();
+        container = apply_legacy_layout(container, node, None, Some(padding as f32));
 
         if center_x {
             container = container.items_center();
@@ -215,11 +248,11 @@ impl VNodeEntity {
             container = container.child(self.render_vnode(*child_id, cx));
         }
 
-        container.into_any()
+        container
     }
 
     /// 渲染滚动容器节点
-    fn render_scrollable(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_scrollable(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let mut scrollable = div()
             .flex()
             .flex_col()
@@ -230,16 +263,17 @@ impl VNodeEntity {
             scrollable = scrollable.child(self.render_vnode(*child_id, cx));
         }
 
-        scrollable.into_any()
+        scrollable
     }
 
     /// 渲染输入框节点
-    fn render_input(&self, node: &auto_ui::vnode::VNode, _cx: &mut Context<Self>) -> AnyElement {
+    fn render_input(&self, node: &VNode, _cx: &mut Context<Self>) -> Div {
         let (placeholder, value, _password) = match &node.props {
             VNodeProps::Input {
                 placeholder,
                 value,
                 password,
+                ..
             } => (placeholder.clone(), value.clone(), *password),
             _ => (String::new(), String::new(), false),
         };
@@ -260,11 +294,10 @@ impl VNodeEntity {
             .rounded_md()
             .text_sm()
             .child(display_text)
-            .into_any()
     }
 
     /// 渲染复选框节点
-    fn render_checkbox(&self, node: &auto_ui::vnode::VNode, _cx: &mut Context<Self>) -> AnyElement {
+    fn render_checkbox(&self, node: &VNode, _cx: &mut Context<Self>) -> Div {
         let (label, is_checked) = match &node.props {
             VNodeProps::Checkbox { label, is_checked } => (label.clone(), *is_checked),
             _ => (String::new(), false),
@@ -293,11 +326,10 @@ impl VNodeEntity {
                     .rounded_sm(),
             )
             .child(label)
-            .into_any()
     }
 
     /// 渲染单选框节点
-    fn render_radio(&self, node: &auto_ui::vnode::VNode, _cx: &mut Context<Self>) -> AnyElement {
+    fn render_radio(&self, node: &VNode, _cx: &mut Context<Self>) -> Div {
         let (label, is_selected) = match &node.props {
             VNodeProps::Radio { label, is_selected } => (label.clone(), *is_selected),
             _ => (String::new(), false),
@@ -321,11 +353,10 @@ impl VNodeEntity {
                     .rounded_full(),
             )
             .child(label)
-            .into_any()
     }
 
     /// 渲染选择框节点
-    fn render_select(&self, node: &auto_ui::vnode::VNode) -> AnyElement {
+    fn render_select(&self, node: &VNode) -> Div {
         let (options, selected_index) = match &node.props {
             VNodeProps::Select {
                 options,
@@ -348,61 +379,33 @@ impl VNodeEntity {
             .rounded_md()
             .text_sm()
             .child(selected)
-            .into_any()
     }
 
     /// 渲染列表节点
-    fn render_list(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_list(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let spacing = match &node.props {
             VNodeProps::List { spacing } => *spacing,
             _ => 8,
         };
 
-        let mut list = div().flex().flex_col().gap(px(spacing as f32));
+        let mut list = div().flex().flex_col();
+        list = apply_legacy_layout(list, node, Some(spacing as f32), None);
 
         // 递归渲染子节点
         for child_id in &node.children {
             list = list.child(self.render_vnode(*child_id, cx));
         }
 
-        list.into_any()
+        list
     }
 
     /// 渲染表格节点
-    fn render_table(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
-        let (spacing, col_spacing) = match &node.props {
-            VNodeProps::Table {
-                spacing,
-                col_spacing,
-            } => (*spacing, *col_spacing),
-            _ => (5, 10),
-        };
-
-        let mut table = div().flex().flex_col().gap(px(spacing as f32));
-
-        // 递归渲染所有子节点（headers + rows）
-        for child_id in &node.children {
-            let child = match self.vtree.get(*child_id) {
-                Some(c) => c,
-                None => continue,
-            };
-
-            // 为每一行创建 flex_row
-            let mut row_div = div().flex().flex_row().gap(px(col_spacing as f32));
-
-            // 渲染该行的子节点（单元格）
-            for cell_id in &child.children {
-                row_div = row_div.child(self.render_vnode(*cell_id, cx));
-            }
-
-            table = table.child(row_div);
-        }
-
-        table.into_any()
+    fn render_table(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
+        render_table_node(node, &mut |id| self.render_vnode(id, cx))
     }
 
     /// 渲染滑块节点
-    fn render_slider(&self, node: &auto_ui::vnode::VNode) -> AnyElement {
+    fn render_slider(&self, node: &VNode) -> Div {
         let (min, max, value, _step) = match &node.props {
             VNodeProps::Slider {
                 min,
@@ -453,11 +456,10 @@ impl VNodeEntity {
                     .border_color(rgb(0x3b82f6))
                     .shadow_lg(),
             )
-            .into_any()
     }
 
     /// 渲染进度条节点
-    fn render_progress_bar(&self, node: &auto_ui::vnode::VNode) -> AnyElement {
+    fn render_progress_bar(&self, node: &VNode) -> Div {
         let progress = match &node.props {
             VNodeProps::ProgressBar { progress } => *progress,
             _ => 0.0,
@@ -472,11 +474,10 @@ impl VNodeEntity {
             .border_1()
             .border_color(rgb(0x444444))
             .child(div().w(px(filled_width)).h(px(20.0)).bg(rgb(0x3b82f6)))
-            .into_any()
     }
 
     /// 渲染居中容器节点
-    fn render_center(&self, node: &auto_ui::vnode::VNode, cx: &mut Context<Self>) -> AnyElement {
+    fn render_center(&self, node: &VNode, cx: &mut Context<Self>) -> Div {
         let mut center = div().flex().items_center().justify_center().size_full();
 
         // 渲染子节点
@@ -484,17 +485,305 @@ impl VNodeEntity {
             center = center.child(self.render_vnode(*child_id, cx));
         }
 
-        center.into_any()
+        center
     }
+}
 
-    /// 渲染高级组件占位符
-    fn render_placeholder(&self, node: &auto_ui::vnode::VNode) -> AnyElement {
-        let name = format!("{:?}", node.kind);
+// ==================== 共享渲染函数 ====================
+// VNodeEntity 与 DynamicInterpreterComponent 共用，`render_child` 负责递归渲染子节点
+
+/// 渲染表格：子节点依次为表头单元格和各行单元格，按 `headers` / `rows` 分组
+pub(crate) fn render_table_node(
+    node: &VNode,
+    render_child: &mut dyn FnMut(VNodeId) -> AnyElement,
+) -> Div {
+    let (spacing, col_spacing, headers, rows) = match &node.props {
+        VNodeProps::Table {
+            spacing,
+            col_spacing,
+            headers,
+            rows,
+        } => (*spacing, *col_spacing, *headers, rows.clone()),
+        _ => (5, 10, 0, Vec::new()),
+    };
+
+    let mut table = div().flex().flex_col();
+    table = apply_legacy_layout(table, node, Some(spacing as f32), None);
+
+    let mut cells = node.children.iter();
+    for (index, count) in std::iter::once(headers).chain(rows).enumerate() {
+        let mut row = div().flex().flex_row().gap(px(col_spacing as f32));
+        if index == 0 {
+            // 没有表头时不渲染空行
+            if count == 0 {
+                continue;
+            }
+            row = row.font_weight(FontWeight::BOLD);
+        }
 
-        div()
-            .text_color(rgb(0xf59e0b))
-            .child(format!("🔧 {} 组件暂未实现", name))
-            .into_any()
+        for cell_id in cells.by_ref().take(count) {
+            row = row.child(render_child(*cell_id));
+        }
+        table = table.child(row);
+    }
+
+    table
+}
+
+/// 渲染手风琴：每个分组一个标题栏，展开的分组显示其子节点
+pub(crate) fn render_accordion_node(
+    node: &VNode,
+    render_child: &mut dyn FnMut(VNodeId) -> AnyElement,
+) -> Div {
+    let items = match &node.props {
+        VNodeProps::Accordion { items, .. } => items.as_slice(),
+        _ => &[][..],
+    };
+
+    let mut accordion = div().flex().flex_col().gap_1();
+    let mut children = node.children.iter();
+
+    for item in items {
+        let marker = if item.expanded { "▾" } else { "▸" };
+        let title = match item.icon {
+            Some(icon) => format!("{} {} {}", marker, icon, item.title),
+            None => format!("{} {}", marker, item.title),
+        };
+
+        let mut section = div().flex().flex_col().child(
+            div()
+                .px_3()
+                .py_2()
+                .bg(rgb(0x2a2a2a))
+                .rounded_md()
+                .cursor_pointer()
+                .child(title),
+        );
+
+        // 折叠的分组也要消耗掉自己的子节点
+        let ids: Vec<VNodeId> = children.by_ref().take(item.children).copied().collect();
+        if item.expanded {
+            let mut body = div().flex().flex_col().gap_2().pl_4().py_2();
+            for id in ids {
+                body = body.child(render_child(id));
+            }
+            section = section.child(body);
+        }
+
+        accordion = accordion.child(section);
+    }
+
+    accordion
+}
+
+/// 渲染侧边栏：固定宽度的面板，唯一子节点为内容
+pub(crate) fn render_sidebar_node(
+    node: &VNode,
+    render_child: &mut dyn FnMut(VNodeId) -> AnyElement,
+) -> Div {
+    let (width, position) = match &node.props {
+        VNodeProps::Sidebar {
+            width, position, ..
+        } => (*width, *position),
+        _ => (250.0, SidebarPosition::Left),
+    };
+
+    let sidebar = div()
+        .flex()
+        .flex_col()
+        .h_full()
+        .w(px(width))
+        .bg(rgb(0x1f1f1f));
+    let mut sidebar = match position {
+        SidebarPosition::Left => sidebar.border_r_1(),
+        SidebarPosition::Right => sidebar.border_l_1(),
+    }
+    .border_color(rgb(0x3a3a3a));
+
+    if let Some(child_id) = node.children.first() {
+        sidebar = sidebar.child(render_child(*child_id));
+    }
+
+    sidebar
+}
+
+/// 渲染标签页：标签栏 + 当前选中标签的内容
+pub(crate) fn render_tabs_node(
+    node: &VNode,
+    render_child: &mut dyn FnMut(VNodeId) -> AnyElement,
+) -> Div {
+    let (labels, selected, position) = match &node.props {
+        VNodeProps::Tabs {
+            labels,
+            selected,
+            position,
+        } => (labels.as_slice(), *selected, *position),
+        _ => (&[][..], 0, TabsPosition::Top),
+    };
+    let vertical = matches!(position, TabsPosition::Left | TabsPosition::Right);
+
+    let mut bar = if vertical {
+        div().flex().flex_col().gap_1()
+    } else {
+        div().flex().flex_row().gap_1()
+    };
+    for (index, label) in labels.iter().enumerate() {
+        let tab = div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .child(label.clone());
+        bar = bar.child(if index == selected {
+            tab.bg(rgb(0x3b82f6))
+        } else {
+            tab.bg(rgb(0x2a2a2a))
+        });
+    }
+
+    let body = div()
+        .flex_1()
+        .children(node.children.get(selected).map(|id| render_child(*id)));
+
+    let tabs = if vertical {
+        div().flex().flex_row().gap_2()
+    } else {
+        div().flex().flex_col().gap_2()
+    };
+    match position {
+        TabsPosition::Top | TabsPosition::Left => tabs.child(bar).child(body),
+        TabsPosition::Bottom | TabsPosition::Right => tabs.child(body).child(bar),
+    }
+}
+
+/// 渲染单选组：每个选项一行，只有选中项的圆点被填充
+pub(crate) fn render_radio_group_node(node: &VNode) -> Div {
+    let (options, selected_index) = match &node.props {
+        VNodeProps::RadioGroup {
+            options,
+            selected_index,
+        } => (options.as_slice(), *selected_index),
+        _ => (&[][..], None),
+    };
+
+    let mut group = div().flex().flex_col().gap_2();
+
+    for (index, option) in options.iter().enumerate() {
+        let is_selected = selected_index == Some(index);
+        let mut dot = div()
+            .w_4()
+            .h_4()
+            .border_1()
+            .rounded_full()
+            .flex()
+            .items_center()
+            .justify_center()
+            .border_color(if is_selected {
+                rgb(0x3b82f6)
+            } else {
+                rgb(0x6c6c6c)
+            });
+        if is_selected {
+            dot = dot.child(div().w_2().h_2().rounded_full().bg(rgb(0x3b82f6)));
+        }
+
+        group = group.child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .cursor_pointer()
+                .child(dot)
+                .child(option.clone()),
+        );
+    }
+
+    group
+}
+
+/// 渲染导航栏：图标 + 可选标签 + 徽标
+pub(crate) fn render_navigation_rail_node(node: &VNode) -> Div {
+    let (items, selected, width, show_labels) = match &node.props {
+        VNodeProps::NavigationRail {
+            items,
+            selected,
+            width,
+            show_labels,
+        } => (items.as_slice(), *selected, *width, *show_labels),
+        _ => (&[][..], 0, 72.0, true),
+    };
+
+    let mut rail = div()
+        .flex()
+        .flex_col()
+        .items_center()
+        .gap_2()
+        .py_2()
+        .h_full()
+        .w(px(width))
+        .bg(rgb(0x1f1f1f));
+
+    for (index, item) in items.iter().enumerate() {
+        let badge = item.badge.clone().map(|badge| {
+            div()
+                .absolute()
+                .top(px(-4.0))
+                .right(px(-10.0))
+                .px_1()
+                .rounded_full()
+                .bg(rgb(0xef4444))
+                .text_xs()
+                .child(badge)
+        });
+
+        let mut entry = div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .child(div().relative().child(item.icon.to_string()).children(badge));
+        if show_labels {
+            entry = entry.child(div().text_xs().child(item.label.clone()));
+        }
+        if index == selected {
+            entry = entry.bg(rgb(0x3b82f6));
+        }
+
+        rail = rail.child(entry);
+    }
+
+    rail
+}
+
+/// 应用旧式 spacing/padding 字段
+///
+/// 只有节点样式自己设置了 `gap-*` 或 `p-*`/`px-*`/`py-*` 时才由样式覆盖，
+/// 所以 `style: "bg-white"` 的列仍保留它的 spacing。
+pub(crate) fn apply_legacy_layout(div: Div, node: &VNode, spacing: Option<f32>, padding: Option<f32>) -> Div {
+    let style = node.style.as_ref().map(GpuiStyle::from_style);
+    let sets_gap = style.as_ref().is_some_and(|style| style.gap.is_some());
+    let sets_padding = style.as_ref().is_some_and(|style| {
+        style.padding.is_some() || style.padding_x.is_some() || style.padding_y.is_some()
+    });
+
+    let mut div = div;
+    if let Some(spacing) = spacing.filter(|_| !sets_gap) {
+        div = div.gap(px(spacing));
+    }
+    if let Some(padding) = padding.filter(|_| !sets_padding) {
+        div = div.p(px(padding));
+    }
+    div
+}
+
+/// 应用节点携带的统一样式（Style 优先于节点的默认外观和旧式字段）
+pub(crate) fn apply_vnode_style(div: Div, node: &VNode) -> Div {
+    match &node.style {
+        Some(style) => apply_style_to_div(div, style),
+        None => div,
     }
 }
 
@@ -526,18 +815,21 @@ impl Render for VNodeEntity {
         // 获取根节点并渲染
         match self.vtree.root() {
             Some(root) => {
-                // 验证树结构
-                if let Err(e) = self.vtree.validate() {
-                    self.error = Some(format!("树结构无效: {}", e));
-                    cx.notify();
-                    return div()
-                        .size_full()
-                        .bg(rgb(0x1a1a1a))
-                        .flex()
-                        .items_center()
-                        .justify_center()
-                        .text_color(rgb(0xff6b6b))
-                        .child("❌ 树结构验证失败");
+                // 验证树结构（仅在树变化后）
+                if !self.validated {
+                    if let Err(e) = self.vtree.validate() {
+                        self.error = Some(format!("树结构无效: {}", e));
+                        cx.notify();
+                        return div()
+                            .size_full()
+                            .bg(rgb(0x1a1a1a))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .text_color(rgb(0xff6b6b))
+                            .child("❌ 树结构验证失败");
+                    }
+                    self.validated = true;
                 }
 
                 // 递归渲染整棵树 - 将 AnyElement 包装为 Div
@@ -3,9 +3,13 @@
 // This crate provides adapter traits to convert auto-ui's abstract View<M>
 // into iced's Element for rendering.
 
-use auto_ui::{View as AbstractView, Component};
+use auto_ui::{View as AbstractView, Command, Component, Style, Subscription};
+use auto_ui::style::iced_adapter::{to_iced_theme, IcedStateStyle, IcedStyle};
+use auto_ui::style::Theme;
+use auto_ui::subscription::{watch_path, KeyEvent, Modifiers, Source, SubscriptionEvent, WindowSize};
 use std::fmt::Debug;
-use iced::widget::{button, checkbox, column, pick_list, row, text, text_input};
+use std::path::PathBuf;
+use iced::widget::{button, checkbox, column, container, pick_list, radio, row, text, text_input, Container, Text};
 
 /// Trait for converting abstract View<M> into iced Element
 ///
@@ -26,48 +30,113 @@ pub trait IntoIcedElement<M: Clone + Debug + 'static> {
 
 impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
     fn into_iced(self) -> iced::Element<'static, M> {
+        // `hidden` (possibly behind a breakpoint) removes the view from the layout
+        if self.is_hidden() {
+            return iced::widget::space().into();
+        }
+
         match self {
             AbstractView::Empty => {
                 // Empty view renders as empty text
                 text("").into()
             }
 
-            AbstractView::Text { content, style: _ } => {
-                // Direct text rendering (style ignored for now in Iced)
-                text(content).into()
+            AbstractView::Text { content, style } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
+                styled(styled_text(content, style.as_ref()).into(), style.as_ref())
             }
 
-            AbstractView::Button { label, onclick, style: _ } => {
-                // Button with click handler (style ignored for now)
-                button(text(label))
-                    .on_press(onclick)
-                    .into()
+            AbstractView::Button { label, onclick, disabled, style } => {
+                let states = style.as_ref().map(IcedStateStyle::from_style);
+                let label_style = states.as_ref().map(|s| if disabled { &s.disabled } else { &s.base });
+                let mut button_widget = button(styled_text(label, label_style));
+
+                // Style classes size the button itself and layer its colors
+                // over the theme's primary appearance; state variants are
+                // picked from the status iced reports
+                if let Some(s) = states {
+                    if let Some(p) = s.base.padding() {
+                        button_widget = button_widget.padding(p);
+                    }
+                    if let Some(w) = s.base.width_length() {
+                        button_widget = button_widget.width(w);
+                    }
+                    if let Some(h) = s.base.height_length() {
+                        button_widget = button_widget.height(h);
+                    }
+                    let interactive = style.as_ref().is_some_and(Style::is_interactive);
+                    if s.base.has_decoration() || interactive {
+                        button_widget = button_widget
+                            .style(move |theme, status| s.button_style(button::primary(theme, status), status));
+                    }
+                }
+
+                // Without a press handler iced reports the Disabled status
+                button_widget.on_press_maybe((!disabled).then_some(onclick)).into()
             }
 
-            AbstractView::Row { children, spacing, padding, style: _ } => {
+            AbstractView::Row { children, spacing, padding, style } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
                 let mut row_widget = row([]);
-                row_widget = row_widget.spacing(spacing as f32);
-                row_widget = row_widget.padding(padding as f32);
+
+                // Style fields replace the legacy spacing/padding
+                match &style {
+                    Some(s) => {
+                        row_widget = row_widget.spacing(s.gap.unwrap_or(0.0));
+                        if let Some(p) = s.padding() {
+                            row_widget = row_widget.padding(p);
+                        }
+                        if let Some(w) = s.width_length() {
+                            row_widget = row_widget.width(w);
+                        }
+                        if let Some(h) = s.height_length() {
+                            row_widget = row_widget.height(h);
+                        }
+                    }
+                    None => {
+                        row_widget = row_widget.spacing(spacing as f32);
+                        row_widget = row_widget.padding(padding as f32);
+                    }
+                }
 
                 // Recursively convert children
                 for child in children {
                     row_widget = row_widget.push(child.into_iced());
                 }
 
-                row_widget.into()
+                decorated(row_widget.into(), style.as_ref())
             }
 
-            AbstractView::Column { children, spacing, padding, style: _ } => {
+            AbstractView::Column { children, spacing, padding, style } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
                 let mut col_widget = column([]);
-                col_widget = col_widget.spacing(spacing as f32);
-                col_widget = col_widget.padding(padding as f32);
+
+                // Style fields replace the legacy spacing/padding
+                match &style {
+                    Some(s) => {
+                        col_widget = col_widget.spacing(s.gap.unwrap_or(0.0));
+                        if let Some(p) = s.padding() {
+                            col_widget = col_widget.padding(p);
+                        }
+                        if let Some(w) = s.width_length() {
+                            col_widget = col_widget.width(w);
+                        }
+                        if let Some(h) = s.height_length() {
+                            col_widget = col_widget.height(h);
+                        }
+                    }
+                    None => {
+                        col_widget = col_widget.spacing(spacing as f32);
+                        col_widget = col_widget.padding(padding as f32);
+                    }
+                }
 
                 // Recursively convert children
                 for child in children {
                     col_widget = col_widget.push(child.into_iced());
                 }
 
-                col_widget.into()
+                decorated(col_widget.into(), style.as_ref())
             }
 
             AbstractView::Input {
@@ -76,25 +145,56 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 on_change,
                 width,
                 password: _,
-                style: _,
+                disabled,
+                style,
             } => {
+                let states = style.as_ref().map(IcedStateStyle::from_style);
+                let interactive = style.as_ref().is_some_and(Style::is_interactive);
+                let style = style.as_ref().map(IcedStyle::from_style);
+
                 // Create text input widget
                 let mut input_widget = text_input(&placeholder, &value);
 
-                // Apply width
-                if let Some(w) = width {
-                    input_widget = input_widget.width(iced::Length::Fixed(w as f32));
+                // Apply width (w-* wins over the legacy width)
+                match style.as_ref().and_then(IcedStyle::width_length) {
+                    Some(w) => input_widget = input_widget.width(w),
+                    None => {
+                        if let Some(w) = width {
+                            input_widget = input_widget.width(iced::Length::Fixed(w as f32));
+                        }
+                    }
                 }
 
-                // Add change handler if provided
-                if let Some(msg) = on_change {
-                    input_widget.on_input(move |_| msg.clone()).into()
-                } else {
-                    input_widget.into()
+                if let Some(s) = &style {
+                    if let Some(p) = s.padding() {
+                        input_widget = input_widget.padding(p);
+                    }
+                    if let Some(size) = s.text_size() {
+                        input_widget = input_widget.size(size);
+                    }
+                    if let Some(font) = s.font() {
+                        input_widget = input_widget.font(font);
+                    }
+                }
+
+                // Border, focus and disabled looks go through the input's own
+                // status styling rather than the wrapping container
+                if let Some(s) = states.filter(|_| interactive) {
+                    input_widget = input_widget
+                        .style(move |theme, status| s.text_input_style(text_input::default(theme, status), status));
                 }
+
+                // Add change handler if provided; it receives the edited text.
+                // A disabled input gets none, which iced renders as Disabled
+                let element = match on_change {
+                    Some(callback) if !disabled => input_widget.on_input(move |text| callback.call(text)).into(),
+                    _ => input_widget.into(),
+                };
+
+                decorated(element, style.as_ref().filter(|_| !interactive))
             }
 
-            AbstractView::Checkbox { is_checked, label, on_toggle, style: _ } => {
+            AbstractView::Checkbox { is_checked, label, on_toggle, style } => {
                 // Checkbox with label - use row to combine checkbox and text
                 let checkbox_widget = checkbox(is_checked);
 
@@ -106,9 +206,11 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 };
 
                 // Combine with label in a row
-                row![checkbox_with_handler, text(label)]
-                    .spacing(4)
-                    .into()
+                let style = style.as_ref().map(IcedStyle::from_style);
+                let checkbox_row = row![checkbox_with_handler, styled_text(label, style.as_ref())]
+                    .spacing(style.as_ref().and_then(|s| s.gap).unwrap_or(4.0));
+
+                styled(checkbox_row.into(), style.as_ref())
             }
 
             AbstractView::Container {
@@ -118,25 +220,41 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 height,
                 center_x,
                 center_y,
-                style: _,
+                style,
             } => {
-                use iced::widget::container;
-
+                let style = style.as_ref().map(IcedStyle::from_style);
                 let mut container_widget = container(child.into_iced());
 
-                // Apply padding
-                if padding > 0 {
-                    container_widget = container_widget.padding(padding as f32);
-                }
+                match &style {
+                    // Style fields replace the legacy padding/width/height
+                    Some(s) => {
+                        if let Some(p) = s.padding() {
+                            container_widget = container_widget.padding(p);
+                        }
+                        if let Some(w) = s.width_length() {
+                            container_widget = container_widget.width(w);
+                        }
+                        if let Some(h) = s.height_length() {
+                            container_widget = container_widget.height(h);
+                        }
+                        container_widget = with_appearance(container_widget, s);
+                    }
+                    None => {
+                        // Apply padding
+                        if padding > 0 {
+                            container_widget = container_widget.padding(padding as f32);
+                        }
 
-                // Apply width
-                if let Some(w) = width {
-                    container_widget = container_widget.width(iced::Length::Fixed(w as f32));
-                }
+                        // Apply width
+                        if let Some(w) = width {
+                            container_widget = container_widget.width(iced::Length::Fixed(w as f32));
+                        }
 
-                // Apply height
-                if let Some(h) = height {
-                    container_widget = container_widget.height(iced::Length::Fixed(h as f32));
+                        // Apply height
+                        if let Some(h) = height {
+                            container_widget = container_widget.height(iced::Length::Fixed(h as f32));
+                        }
+                    }
                 }
 
                 // Apply centering (aligns the container content)
@@ -150,90 +268,139 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 container_widget.into()
             }
 
-            AbstractView::Scrollable { child, width, height, style: _ } => {
+            AbstractView::Scrollable { child, width, height, style } => {
                 use iced::widget::scrollable;
 
+                let style = style.as_ref().map(IcedStyle::from_style);
                 let mut scrollable_widget = scrollable(child.into_iced());
 
-                // Apply width
+                // Apply width (w-* wins over the legacy width)
+                let width = style.as_ref().and_then(IcedStyle::width_length)
+                    .or(width.map(|w| iced::Length::Fixed(w as f32)));
                 if let Some(w) = width {
-                    scrollable_widget = scrollable_widget.width(iced::Length::Fixed(w as f32));
+                    scrollable_widget = scrollable_widget.width(w);
                 }
 
-                // Apply height
+                // Apply height (h-* wins over the legacy height)
+                let height = style.as_ref().and_then(IcedStyle::height_length)
+                    .or(height.map(|h| iced::Length::Fixed(h as f32)));
                 if let Some(h) = height {
-                    scrollable_widget = scrollable_widget.height(iced::Length::Fixed(h as f32));
+                    scrollable_widget = scrollable_widget.height(h);
                 }
 
-                scrollable_widget.into()
+                // Padding goes inside the scrolled content's frame
+                match &style {
+                    Some(s) if s.padding().is_some() || s.has_decoration() => {
+                        let mut frame = container(scrollable_widget);
+                        if let Some(p) = s.padding() {
+                            frame = frame.padding(p);
+                        }
+                        with_appearance(frame, s).into()
+                    }
+                    _ => scrollable_widget.into(),
+                }
             }
 
             AbstractView::Radio {
                 label,
                 is_selected,
                 on_select,
-                style: _,
+                style,
             } => {
-                // Note: Iced's radio widget requires a closure, which doesn't fit our
-                // message-based abstraction. We simulate radio with checkbox styling.
-                // In a real implementation, we'd use Iced's radio widget with proper closures.
-                let checkbox_widget = checkbox(is_selected);
-
-                // Add select handler if provided
-                let checkbox_with_handler = if let Some(msg) = on_select {
-                    checkbox_widget.on_toggle(move |_| msg.clone())
-                } else {
-                    checkbox_widget
+                let style = style.as_ref().map(IcedStyle::from_style);
+                let radio_widget = match on_select {
+                    // A lone radio has no value of its own; `()` stands in so that
+                    // iced marks it selected when `is_selected` is set
+                    Some(msg) => radio(label, (), is_selected.then_some(()), move |_| msg.clone()).into(),
+                    None => radio_label(&label, is_selected),
                 };
 
-                // Combine with label in a row
-                row![checkbox_with_handler, text(label)]
-                    .spacing(4)
-                    .into()
+                styled(radio_widget, style.as_ref())
+            }
+
+            AbstractView::RadioGroup {
+                options,
+                selected_index,
+                on_select,
+                style,
+            } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
+
+                // Each option is a native radio keyed by its index, so iced keeps
+                // the selection exclusive
+                let mut group = column([]).spacing(style.as_ref().and_then(|s| s.gap).unwrap_or(4.0));
+
+                for (index, option) in options.into_iter().enumerate() {
+                    group = match &on_select {
+                        Some(callback) => {
+                            let msg = callback.call(index, &option);
+                            group.push(radio(option, index, selected_index, move |_| msg.clone()))
+                        }
+                        None => group.push(radio_label(&option, selected_index == Some(index))),
+                    };
+                }
+
+                styled(group.into(), style.as_ref())
             }
 
             AbstractView::Select {
                 options,
                 selected_index,
                 on_select,
-                style: _,
+                style,
             } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
+
                 // Iced's pick_list for dropdown selection
                 let selected_value = selected_index.and_then(|i| options.get(i).cloned());
 
                 // Use the callback to handle selection
-                match on_select {
+                let select_widget = match on_select {
                     Some(callback) => {
                         let options_clone = options.clone();
-                        let picklist_widget = pick_list(options, selected_value, move |selected_string| {
+                        let mut picklist_widget = pick_list(options, selected_value, move |selected_string| {
                             // Find the index of the selected string
                             let index = options_clone.iter()
                                 .position(|s| *s == selected_string)
                                 .unwrap_or(0);
                             callback.call(index, selected_string.as_str())
                         });
+
+                        // The dropdown sizes itself, so only the frame is left to the wrapper
+                        if let Some(w) = style.as_ref().and_then(IcedStyle::width_length) {
+                            picklist_widget = picklist_widget.width(w);
+                        }
+                        if let Some(font) = style.as_ref().and_then(IcedStyle::font) {
+                            picklist_widget = picklist_widget.font(font);
+                        }
                         picklist_widget.into()
                     }
                     None => {
                         // No handler - display selected value or first option as text
                         let display_text = selected_value
                             .unwrap_or_else(|| options.first().cloned().unwrap_or_default());
-                        text(display_text).into()
+                        styled_text(display_text, style.as_ref()).into()
                     }
-                }
+                };
+
+                styled(select_widget, style.as_ref())
             }
 
-            AbstractView::List { items, spacing, style: _ } => {
-                // List is essentially a column with spacing
+            AbstractView::List { items, spacing, style } => {
+                let style = style.as_ref().map(IcedStyle::from_style);
+
+                // List is essentially a column with spacing (gap-* wins)
                 let mut col_widget = column([]);
-                col_widget = col_widget.spacing(spacing as f32);
+                col_widget = col_widget.spacing(
+                    style.as_ref().and_then(|s| s.gap).unwrap_or(spacing as f32),
+                );
 
                 // Recursively convert items
                 for item in items {
                     col_widget = col_widget.push(item.into_iced());
                 }
 
-                col_widget.into()
+                styled(col_widget.into(), style.as_ref())
             }
 
             AbstractView::Table {
@@ -241,11 +408,15 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 rows,
                 spacing,
                 col_spacing,
-                style: _,
+                style,
             } => {
-                // Table is implemented as a column of rows
+                let style = style.as_ref().map(IcedStyle::from_style);
+
+                // Table is implemented as a column of rows (gap-* wins over row spacing)
                 let mut table_widget = column([]);
-                table_widget = table_widget.spacing(spacing as f32);
+                table_widget = table_widget.spacing(
+                    style.as_ref().and_then(|s| s.gap).unwrap_or(spacing as f32),
+                );
 
                 // Add header row
                 let mut header_row_widget = row([]);
@@ -265,7 +436,7 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                     table_widget = table_widget.push(row_widget);
                 }
 
-                table_widget.into()
+                styled(table_widget.into(), style.as_ref())
             }
 
             AbstractView::Slider {
@@ -274,24 +445,30 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 value,
                 on_change,
                 step,
-                style: _,
+                style,
             } => {
                 use iced::widget::slider;
+
+                let style = style.as_ref().map(IcedStyle::from_style);
+
                 // Create slider widget with proper value handling
-                let mut slider_widget = slider(min..=max, value, on_change);
+                let mut slider_widget = slider(min..=max, value, move |v| on_change.call(v));
 
                 // Apply step if specified
                 if let Some(step_value) = step {
                     slider_widget = slider_widget.step(step_value);
                 }
 
-                slider_widget.into()
+                styled(slider_widget.into(), style.as_ref())
             }
 
-            AbstractView::ProgressBar { progress, style: _ } => {
+            AbstractView::ProgressBar { progress, style } => {
                 use iced::widget::progress_bar;
+
+                let style = style.as_ref().map(IcedStyle::from_style);
+
                 // Progress bar in Iced
-                progress_bar(0.0..=1.0, progress).into()
+                styled(progress_bar(0.0..=1.0, progress).into(), style.as_ref())
             }
 
             // Plan 010: Unified Navigation Components - Iced Implementation
@@ -300,10 +477,9 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 items,
                 allow_multiple: _,
                 on_toggle,
-                style: _,
+                style,
             } => {
                 use auto_ui::AccordionItem;
-                use iced::widget::container;
 
                 // Accordion is implemented as a column of collapsible sections
                 let mut accordion_widget = column([]);
@@ -341,7 +517,11 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                     accordion_widget = accordion_widget.push(section);
                 }
 
-                container(accordion_widget).padding(10).into()
+                // Style classes replace the default padding
+                match style.as_ref().map(IcedStyle::from_style) {
+                    Some(s) => styled(accordion_widget.into(), Some(&s)),
+                    None => container(accordion_widget).padding(10).into(),
+                }
             }
 
             AbstractView::Sidebar {
@@ -349,15 +529,23 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 width,
                 collapsible: _,
                 position,
-                style: _,
+                style,
             } => {
-                use iced::widget::container;
                 use iced::Length;
 
-                // Sidebar is a fixed-width container
+                let style = style.as_ref().map(IcedStyle::from_style);
+
+                // Sidebar is a fixed-width container (w-*/h-* win)
                 let mut sidebar_container = container(content.into_iced())
-                    .width(Length::Fixed(width))
-                    .height(Length::Fill);
+                    .width(style.as_ref().and_then(IcedStyle::width_length).unwrap_or(Length::Fixed(width)))
+                    .height(style.as_ref().and_then(IcedStyle::height_length).unwrap_or(Length::Fill));
+
+                if let Some(s) = &style {
+                    if let Some(p) = s.padding() {
+                        sidebar_container = sidebar_container.padding(p);
+                    }
+                    sidebar_container = with_appearance(sidebar_container, s);
+                }
 
                 // Add border based on position
                 sidebar_container = match position {
@@ -374,9 +562,9 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 selected,
                 position: _,
                 on_select: _,
-                style: _,
+                style,
             } => {
-                use iced::widget::{container, column};
+                use iced::widget::column;
                 use auto_ui::TabsPosition;
 
                 // Tabs are implemented as column with tab buttons + selected content
@@ -403,7 +591,8 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                     tabs_widget = tabs_widget.push(container(content.clone().into_iced()).padding(20));
                 }
 
-                container(tabs_widget).into()
+                let style = style.as_ref().map(IcedStyle::from_style);
+                styled(tabs_widget.into(), style.as_ref())
             }
 
             AbstractView::NavigationRail {
@@ -412,9 +601,9 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                 width,
                 show_labels,
                 on_select: _,
-                style: _,
+                style,
             } => {
-                use iced::widget::{container, column};
+                use iced::widget::column;
                 use iced::Length;
 
                 // NavigationRail is a compact vertical navigation
@@ -439,12 +628,22 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
                     rail_widget = rail_widget.push(nav_button);
                 }
 
-                container(rail_widget)
-                    .width(Length::Fixed(width))
-                    .height(Length::Fill)
-                    .padding(10)
-                    .into()
+                // Style classes replace the default width, height and padding
+                let style = style.as_ref().map(IcedStyle::from_style);
+                let mut rail_container = container(rail_widget)
+                    .width(style.as_ref().and_then(IcedStyle::width_length).unwrap_or(Length::Fixed(width)))
+                    .height(style.as_ref().and_then(IcedStyle::height_length).unwrap_or(Length::Fill))
+                    .padding(style.as_ref().and_then(IcedStyle::padding).unwrap_or(iced::Padding::new(10.0)));
+
+                if let Some(s) = &style {
+                    rail_container = with_appearance(rail_container, s);
+                }
+
+                rail_container.into()
             }
+
+            // Keys only matter for VTree diffing; render the wrapped view as-is
+            AbstractView::Keyed { child, .. } => child.into_iced(),
         }
     }
 }
@@ -467,15 +666,18 @@ impl<M: Clone + Debug + 'static> IntoIcedElement<M> for AbstractView<M> {
 /// }
 ///
 /// // Use with iced::run
-/// iced::run("Title", MyComponent::update, MyComponent::view_iced)
+/// iced::run(MyComponent::update_iced, MyComponent::view_iced)
 /// ```
 pub trait ComponentIced: Component {
     /// Iced-compatible view function
     fn view_iced(&self) -> iced::Element<'static, Self::Msg>;
 
-    /// Iced-compatible update function (delegates to on())
-    fn update(&mut self, msg: Self::Msg) {
-        self.on(msg);
+    /// Iced-compatible update function (delegates to update() and converts the command)
+    fn update_iced(&mut self, msg: Self::Msg) -> iced::Task<Self::Msg>
+    where
+        Self::Msg: Send,
+    {
+        command_to_task(self.update(msg))
     }
 }
 
@@ -489,6 +691,114 @@ where
     }
 }
 
+/// Text carrying the style's size, weight, color and alignment
+fn styled_text(content: String, style: Option<&IcedStyle>) -> Text<'static> {
+    let mut text_widget = text(content);
+
+    if let Some(s) = style {
+        if let Some(size) = s.text_size() {
+            text_widget = text_widget.size(size);
+        }
+        if let Some(font) = s.font() {
+            text_widget = text_widget.font(font);
+        }
+        if let Some(color) = s.text_color() {
+            text_widget = text_widget.color(color);
+        }
+        if let Some(align) = s.text_alignment() {
+            // Alignment only shows when the text spans its parent
+            text_widget = text_widget.align_x(align).width(iced::Length::Fill);
+        }
+    }
+
+    text_widget
+}
+
+/// Wrap an element in a container carrying the style's padding, size and decoration
+///
+/// Leaves without box properties of their own get their Tailwind classes
+/// through this wrapper; unstyled elements are returned untouched.
+fn styled<M: 'static>(
+    element: iced::Element<'static, M>,
+    style: Option<&IcedStyle>,
+) -> iced::Element<'static, M> {
+    let Some(s) = style.filter(|s| s.has_box()) else {
+        return element;
+    };
+
+    let mut wrapper = container(element);
+    if let Some(p) = s.padding() {
+        wrapper = wrapper.padding(p);
+    }
+    if let Some(w) = s.width_length() {
+        wrapper = wrapper.width(w);
+    }
+    if let Some(h) = s.height_length() {
+        wrapper = wrapper.height(h);
+    }
+
+    with_appearance(wrapper, s).into()
+}
+
+/// Wrap an element only for the style's background, border, radius and shadow
+///
+/// Used by widgets that already take padding and size directly.
+fn decorated<M: 'static>(
+    element: iced::Element<'static, M>,
+    style: Option<&IcedStyle>,
+) -> iced::Element<'static, M> {
+    match style {
+        Some(s) if s.has_decoration() => with_appearance(container(element), s).into(),
+        _ => element,
+    }
+}
+
+/// Apply the style's container appearance, if it has any
+fn with_appearance<M: 'static>(
+    wrapper: Container<'static, M>,
+    style: &IcedStyle,
+) -> Container<'static, M> {
+    if style.has_decoration() {
+        let appearance = style.container_style();
+        wrapper.style(move |_| appearance)
+    } else {
+        wrapper
+    }
+}
+
+/// Read-only stand-in for a radio without a handler
+///
+/// iced's `radio` always needs a message, so radios nobody listens to are
+/// drawn as plain text with a selection marker.
+fn radio_label<M: 'static>(label: &str, is_selected: bool) -> iced::Element<'static, M> {
+    let marker = if is_selected { "◉" } else { "○" };
+    text(format!("{} {}", marker, label)).into()
+}
+
+/// Convert an auto-ui Command into an iced Task
+///
+/// - `Command::Perform` runs on iced's executor (tokio)
+/// - `Command::Batch` becomes `Task::batch`
+/// - `Command::Exit` closes the application via `iced::exit()`
+/// - `Command::SetTheme` switches the active theme; the next view picks it up
+pub fn command_to_task<M>(command: Command<M>) -> iced::Task<M>
+where
+    M: Send + 'static,
+{
+    match command {
+        Command::None => iced::Task::none(),
+        Command::Batch(commands) => {
+            iced::Task::batch(commands.into_iter().map(command_to_task))
+        }
+        Command::Perform(future) => iced::Task::perform(future, |msg| msg),
+        Command::Exit => iced::exit(),
+        Command::SetTheme(theme) => {
+            Theme::set_active(theme);
+            iced::Task::none()
+        }
+    }
+}
+
 /// Run an auto-ui Component with Iced backend
 ///
 /// This is the unified entry point for running auto-ui applications with Iced.
@@ -518,15 +828,231 @@ where
     C: Component + Default + 'static,
     C::Msg: Clone + Debug + Send + 'static,
 {
-    Ok(iced::run(C::update, view)?)
+    // iced opens its window at the default size before any resize event arrives
+    let window = iced::window::Settings {
+        // Close requests go through `runtime_update`, so components can veto them
+        exit_on_close_request: false,
+        ..Default::default()
+    };
+    WindowSize::set_current(WindowSize::new(window.size.width, window.size.height));
+
+    Ok(iced::application(IcedRuntime::<C>::default, runtime_update::<C>, runtime_view::<C>)
+        .window(window)
+        .subscription(runtime_subscription::<C>)
+        .theme(runtime_theme::<C>)
+        .run()?)
+}
+
+/// Message type of the iced program created by `run_app`
+///
+/// Wraps component messages together with raw subscription events, which are
+/// turned into component messages by `Subscription::dispatch` during update.
+#[derive(Clone, Debug)]
+pub enum IcedMessage<M> {
+    Component(M),
+    Event(SubscriptionEvent),
+    /// The window was resized; tracked for breakpoints even without a subscription
+    WindowResized(WindowSize),
+    /// The user asked to close the window and the component does not handle it
+    CloseRequested,
+}
+
+/// iced program state wrapping the user component
+#[derive(Default)]
+struct IcedRuntime<C> {
+    component: C,
 }
 
-fn view<C>(component: &C) -> iced::Element<'_, C::Msg>
+fn runtime_update<C>(runtime: &mut IcedRuntime<C>, msg: IcedMessage<C::Msg>) -> iced::Task<IcedMessage<C::Msg>>
 where
     C: Component,
-    C::Msg: Clone + Debug + 'static,
+    C::Msg: Clone + Debug + Send + 'static,
+{
+    let msgs = match msg {
+        IcedMessage::Component(msg) => vec![msg],
+        IcedMessage::Event(event) => {
+            // Keep the size current before the component hears about the resize
+            if let SubscriptionEvent::WindowResized(size) = &event {
+                WindowSize::set_current(*size);
+            }
+            runtime.component.subscription().dispatch(event)
+        }
+        // The view is rebuilt after every update, re-resolving sm:/md:/lg:/xl: classes
+        IcedMessage::WindowResized(size) => {
+            WindowSize::set_current(size);
+            Vec::new()
+        }
+        IcedMessage::CloseRequested => return iced::exit(),
+    };
+
+    let tasks: Vec<_> = msgs
+        .into_iter()
+        .map(|msg| command_to_task(runtime.component.update(msg)).map(IcedMessage::Component))
+        .collect();
+    iced::Task::batch(tasks)
+}
+
+fn runtime_view<C>(runtime: &IcedRuntime<C>) -> iced::Element<'_, IcedMessage<C::Msg>>
+where
+    C: Component,
+    C::Msg: Clone + Debug + Send + 'static,
+{
+    runtime.component.view_iced().map(IcedMessage::Component)
+}
+
+/// Native iced theme following the active auto-ui Theme, so built-in widget
+/// styles (buttons, inputs, scrollbars) switch along with it
+fn runtime_theme<C>(_runtime: &IcedRuntime<C>) -> iced::Theme {
+    to_iced_theme(&Theme::active())
+}
+
+fn runtime_subscription<C>(runtime: &IcedRuntime<C>) -> iced::Subscription<IcedMessage<C::Msg>>
+where
+    C: Component,
+    C::Msg: Clone + Debug + Send + 'static,
+{
+    let subscription = runtime.component.subscription();
+    let mut subscriptions = vec![
+        subscription_to_iced(&subscription),
+        iced::event::listen_with(track_window_size::<C::Msg>),
+    ];
+    // Without a `window_close_requested` source the window closes as usual
+    if !subscription.handles_close_requests() {
+        subscriptions.push(iced::window::close_requests().map(|_| IcedMessage::CloseRequested));
+    }
+    iced::Subscription::batch(subscriptions)
+}
+
+/// Report every resize so responsive styles and `WindowSize::current` follow the window
+fn track_window_size<M>(
+    event: iced::Event,
+    _status: iced::event::Status,
+    _window: iced::window::Id,
+) -> Option<IcedMessage<M>> {
+    match event {
+        iced::Event::Window(iced::window::Event::Resized(size)) => {
+            Some(IcedMessage::WindowResized(WindowSize::new(size.width, size.height)))
+        }
+        _ => None,
+    }
+}
+
+/// Map an auto-ui Subscription onto iced's native event sources
+///
+/// - `every` → `iced::time::every`
+/// - `keyboard` / `window_resized` → `iced::event::listen_with`
+/// - `window_close_requested` → `iced::window::close_requests`
+/// - `file_changed` → a notify watcher stream
+///
+/// Sources are identified by their index, so iced keeps them running across
+/// updates as long as the component keeps declaring them in the same order.
+pub fn subscription_to_iced<M>(subscription: &Subscription<M>) -> iced::Subscription<IcedMessage<M>>
+where
+    M: Clone + Debug + Send + 'static,
 {
-    component.view_iced()
+    let mut subscriptions = Vec::new();
+    let mut listen_events = false;
+
+    for (index, source) in subscription.sources().iter().enumerate() {
+        match source {
+            Source::Every { interval, .. } => {
+                subscriptions.push(
+                    iced::time::every(*interval)
+                        .with(index)
+                        .map(|(index, at)| IcedMessage::Event(SubscriptionEvent::Tick { index, at })),
+                );
+            }
+            Source::Keyboard { .. } | Source::WindowResized { .. } => listen_events = true,
+            Source::WindowCloseRequested { .. } => {
+                subscriptions.push(
+                    iced::window::close_requests()
+                        .map(|_| IcedMessage::Event(SubscriptionEvent::WindowCloseRequested)),
+                );
+            }
+            Source::FileChanged { path, .. } => {
+                subscriptions.push(iced::Subscription::run_with(
+                    (index, path.clone()),
+                    watch_file::<M>,
+                ));
+            }
+        }
+    }
+
+    if listen_events {
+        subscriptions.push(iced::event::listen_with(translate_event::<M>));
+    }
+
+    iced::Subscription::batch(subscriptions)
+}
+
+/// Translate native iced events into subscription events
+fn translate_event<M>(
+    event: iced::Event,
+    _status: iced::event::Status,
+    _window: iced::window::Id,
+) -> Option<IcedMessage<M>> {
+    match event {
+        iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+            let key = key_name(&key)?;
+            let modifiers = Modifiers {
+                shift: modifiers.shift(),
+                control: modifiers.control(),
+                alt: modifiers.alt(),
+                logo: modifiers.logo(),
+            };
+            Some(IcedMessage::Event(SubscriptionEvent::Key(KeyEvent::new(key, modifiers))))
+        }
+        iced::Event::Window(iced::window::Event::Resized(size)) => Some(IcedMessage::Event(
+            SubscriptionEvent::WindowResized(WindowSize::new(size.width, size.height)),
+        )),
+        _ => None,
+    }
+}
+
+/// Convert an iced key into auto-ui's lowercase key name
+fn key_name(key: &iced::keyboard::Key) -> Option<String> {
+    use iced::keyboard::key::Named;
+    use iced::keyboard::Key;
+
+    match key {
+        Key::Character(c) => Some(c.to_lowercase()),
+        Key::Named(named) => Some(match named {
+            Named::ArrowUp => "up".to_string(),
+            Named::ArrowDown => "down".to_string(),
+            Named::ArrowLeft => "left".to_string(),
+            Named::ArrowRight => "right".to_string(),
+            Named::Space => "space".to_string(),
+            other => format!("{:?}", other).to_lowercase(),
+        }),
+        Key::Unidentified => None,
+    }
+}
+
+/// Stream of change events for a `file_changed` source
+fn watch_file<M>(data: &(usize, PathBuf)) -> impl iced::futures::Stream<Item = IcedMessage<M>>
+where
+    M: Send + 'static,
+{
+    use iced::futures::SinkExt;
+
+    let (index, path) = data.clone();
+
+    iced::stream::channel(16, move |mut output: iced::futures::channel::mpsc::Sender<IcedMessage<M>>| async move {
+        let (_watcher, mut changes) = match watch_path(&path) {
+            Ok(watch) => watch,
+            Err(e) => {
+                eprintln!("Failed to watch {}: {}", path.display(), e);
+                return;
+            }
+        };
+
+        while let Some(path) = changes.recv().await {
+            let event = SubscriptionEvent::FileChanged { index, path };
+            if output.send(IcedMessage::Event(event)).await.is_err() {
+                break;
+            }
+        }
+    })
 }
 
 #[cfg(test)]
@@ -570,4 +1096,66 @@ mod tests {
             .on_toggle(TestMessage::Toggle);
         let _element = view.into_iced();
     }
+
+    #[test]
+    fn test_radio_group_conversion() {
+        let view = AbstractView::radio_group(vec!["Small".to_string(), "Large".to_string()])
+            .selected(0)
+            .on_choose(|_, _| TestMessage::Click);
+        let _element = view.into_iced();
+
+        let read_only = AbstractView::<TestMessage>::radio_group(vec!["Small".to_string()]);
+        let _element = read_only.into_iced();
+    }
+
+    #[test]
+    fn test_styled_conversion() {
+        // Every styled variant should convert, whether the style maps onto the
+        // widget itself or onto a wrapping container
+        let view = AbstractView::col()
+            .style("p-4 gap-2 bg-white rounded-lg shadow-md")
+            .child(AbstractView::text_styled("Title", "text-2xl font-bold text-center"))
+            .child(AbstractView::button_styled("Go", TestMessage::Click, "px-4 py-2 bg-blue-500 text-white rounded"))
+            .child(AbstractView::progress_bar_styled(0.5, "h-4 w-full border"))
+            .build();
+        let _element = view.into_iced();
+
+        let container = AbstractView::container(AbstractView::text("Inside"))
+            .padding(20)
+            .style("p-2 w-64 border border-gray-300")
+            .build();
+        let _element = container.into_iced();
+    }
+
+    #[test]
+    fn test_command_to_task() {
+        let _none = command_to_task::<TestMessage>(Command::none());
+        let _batch = command_to_task(Command::batch(vec![
+            Command::perform(async { () }, |_| TestMessage::Click),
+            Command::exit(),
+        ]));
+        // Just ensure it compiles
+    }
+
+    #[test]
+    fn test_key_name() {
+        use iced::keyboard::key::Named;
+        use iced::keyboard::Key;
+
+        assert_eq!(key_name(&Key::Character("A".into())), Some("a".to_string()));
+        assert_eq!(key_name(&Key::Named(Named::ArrowUp)), Some("up".to_string()));
+        assert_eq!(key_name(&Key::Named(Named::Escape)), Some("escape".to_string()));
+        assert_eq!(key_name(&Key::Named(Named::PageDown)), Some("pagedown".to_string()));
+    }
+
+    #[test]
+    fn test_subscription_to_iced() {
+        let subscription = Subscription::batch([
+            auto_ui::subscription::every(std::time::Duration::from_secs(1)).map(|_| TestMessage::Click),
+            auto_ui::subscription::keyboard().map(|_| TestMessage::Click),
+            auto_ui::subscription::window_close_requested().map(|_| TestMessage::Click),
+        ]);
+        let _subscription = subscription_to_iced(&subscription);
+        // Just ensure it compiles
+    }
 }
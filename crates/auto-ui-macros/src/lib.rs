@@ -0,0 +1,182 @@
+// auto-ui-macros: compile-time checked styles for AutoUI
+//
+// `style!("p-4 bg-white hover:bg-gray-100")` parses its classes while the
+// crate is compiled and expands to the same `Style` that `Style::parse` would
+// build at runtime, so a typo is a compile error instead of a panic.
+//
+// The macro cannot depend on auto-ui (auto-ui re-exports it), so both share
+// the class grammar from auto-ui-style; there is only one parser to keep up to date.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, LitStr};
+
+use auto_ui_style::{Color, SizeValue, StyleClass, StyleParser, StyleState};
+
+/// Parse a Tailwind-style class string at compile time
+///
+/// Expands to a constant `auto_ui::style::Style` that borrows its class
+/// tables, so it can also initialize `const` and `static` items.
+/// Invalid classes are reported as compile errors on the offending class,
+/// with a suggestion when the class looks like a typo.
+///
+/// ```ignore
+/// let card = auto_ui::style!("p-4 bg-white rounded-lg hover:bg-gray-100");
+/// View::text_styled("Hello", card)
+///
+/// const BUTTON: Style = auto_ui::style!("px-4 py-2 rounded hover:bg-blue-600");
+/// ```
+#[proc_macro]
+pub fn style(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    expand(&literal)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(literal: &LitStr) -> syn::Result<TokenStream2> {
+    let style = StyleParser::new()
+        .parse_style(&literal.value())
+        .map_err(|error| syn::Error::new(class_span(literal, &error.span), error.to_string()))?;
+
+    let root = quote!(::auto_ui::style);
+    let classes = style.classes.iter().map(class_tokens);
+    let variants = style.variants.iter().map(|(state, class)| {
+        let state = state_tokens(state);
+        let class = class_tokens(class);
+        quote!((#state, #class))
+    });
+    let responsive = style.responsive.iter().map(|(breakpoint, state, class)| {
+        let breakpoint = unit_tokens("Breakpoint", breakpoint);
+        let state = match state {
+            Some(state) => {
+                let state = state_tokens(state);
+                quote!(::core::option::Option::Some(#state))
+            }
+            None => quote!(::core::option::Option::None),
+        };
+        let class = class_tokens(class);
+        quote!((#breakpoint, #state, #class))
+    });
+
+    Ok(quote! {{
+        const CLASSES: &[#root::StyleClass] = &[#(#classes),*];
+        const VARIANTS: &[(#root::StyleState, #root::StyleClass)] = &[#(#variants),*];
+        const RESPONSIVE: &[(#root::Breakpoint, ::core::option::Option<#root::StyleState>, #root::StyleClass)] =
+            &[#(#responsive),*];
+        #root::Style::from_static(CLASSES, VARIANTS, RESPONSIVE)
+    }})
+}
+
+/// Span of the bad class inside the literal, or the whole literal where the
+/// compiler can't point into strings (stable Rust, raw strings, escapes)
+fn class_span(literal: &LitStr, span: &std::ops::Range<usize>) -> Span {
+    let text = literal.token().to_string();
+    let plain = text.starts_with('"') && !text.contains('\\');
+    plain
+        .then(|| literal.token().subspan(span.start + 1..span.end + 1))
+        .flatten()
+        .unwrap_or_else(|| literal.span())
+}
+
+/// Path to a fieldless variant, named after its Debug output
+fn unit_tokens(ty: &str, value: &impl std::fmt::Debug) -> TokenStream2 {
+    let ty = format_ident!("{}", ty);
+    let variant = format_ident!("{}", format!("{:?}", value));
+    quote!(::auto_ui::style::#ty::#variant)
+}
+
+fn state_tokens(state: &StyleState) -> TokenStream2 {
+    unit_tokens("StyleState", state)
+}
+
+fn size_tokens(size: &SizeValue) -> TokenStream2 {
+    match size {
+        SizeValue::Fixed(units) => quote!(::auto_ui::style::SizeValue::Fixed(#units)),
+        SizeValue::Px(px) => quote!(::auto_ui::style::SizeValue::Px(#px)),
+        other => unit_tokens("SizeValue", other),
+    }
+}
+
+fn color_tokens(color: &Color) -> TokenStream2 {
+    if let Some((family, shade)) = color.palette_entry() {
+        let mut chars = family.chars();
+        let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+        let variant = format_ident!("{}{}", first, chars.as_str());
+        return quote!(::auto_ui::style::Color::#variant(#shade));
+    }
+    match color {
+        Color::Rgb { r, g, b } => quote!(::auto_ui::style::Color::Rgb { r: #r, g: #g, b: #b }),
+        Color::Rgba { r, g, b, a } => quote!(::auto_ui::style::Color::Rgba { r: #r, g: #g, b: #b, a: #a }),
+        Color::Hex(value) => quote!(::auto_ui::style::Color::Hex(#value)),
+        other => unit_tokens("Color", other),
+    }
+}
+
+fn class_tokens(class: &StyleClass) -> TokenStream2 {
+    let ty = quote!(::auto_ui::style::StyleClass);
+    let size = |variant: &str, size: &SizeValue| {
+        let variant = format_ident!("{}", variant);
+        let size = size_tokens(size);
+        quote!(#ty::#variant(#size))
+    };
+    let color = |variant: &str, color: &Color| {
+        let variant = format_ident!("{}", variant);
+        let color = color_tokens(color);
+        quote!(#ty::#variant(#color))
+    };
+
+    match class {
+        StyleClass::Padding(s) => size("Padding", s),
+        StyleClass::PaddingX(s) => size("PaddingX", s),
+        StyleClass::PaddingY(s) => size("PaddingY", s),
+        StyleClass::Margin(s) => size("Margin", s),
+        StyleClass::MarginX(s) => size("MarginX", s),
+        StyleClass::MarginY(s) => size("MarginY", s),
+        StyleClass::Gap(s) => size("Gap", s),
+        StyleClass::Width(s) => size("Width", s),
+        StyleClass::Height(s) => size("Height", s),
+        StyleClass::BackgroundColor(c) => color("BackgroundColor", c),
+        StyleClass::TextColor(c) => color("TextColor", c),
+        StyleClass::BorderColor(c) => color("BorderColor", c),
+        StyleClass::RoundedPx(px) => quote!(#ty::RoundedPx(#px)),
+        StyleClass::FontSize(px) => quote!(#ty::FontSize(#px)),
+        StyleClass::Opacity(value) => quote!(#ty::Opacity(#value)),
+        StyleClass::ZIndex(z) => quote!(#ty::ZIndex(#z)),
+        StyleClass::GridCols(n) => quote!(#ty::GridCols(#n)),
+        StyleClass::GridRows(n) => quote!(#ty::GridRows(#n)),
+        StyleClass::ColSpan(n) => quote!(#ty::ColSpan(#n)),
+        StyleClass::RowSpan(n) => quote!(#ty::RowSpan(#n)),
+        StyleClass::ColStart(n) => quote!(#ty::ColStart(#n)),
+        StyleClass::RowStart(n) => quote!(#ty::RowStart(#n)),
+        unit => unit_tokens("StyleClass", unit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: &str) -> syn::Result<String> {
+        expand(&LitStr::new(input, Span::call_site())).map(|tokens| tokens.to_string())
+    }
+
+    #[test]
+    fn test_expands_to_constant_tables() {
+        let tokens = expand_str("p-4 bg-[#1e293b] md:hover:bg-teal-950").unwrap();
+
+        assert!(tokens.contains("StyleClass :: Padding (:: auto_ui :: style :: SizeValue :: Fixed (4u16))"));
+        assert!(tokens.contains("Color :: Rgb { r : 30u8 , g : 41u8 , b : 59u8 }"));
+        assert!(tokens.contains("Breakpoint :: Md"));
+        assert!(tokens.contains("Color :: Teal (950u16)"));
+        assert!(tokens.contains("Style :: from_static"));
+    }
+
+    #[test]
+    fn test_invalid_class_is_a_compile_error() {
+        let error = expand_str("p-4 bg-blu-500").unwrap_err().to_string();
+        assert!(error.contains("`bg-blu-500`"));
+        assert!(error.contains("did you mean `bg-blue-500`?"));
+    }
+}
@@ -3,7 +3,7 @@
 // This enum represents the parsed form of Tailwind-style utility classes.
 // It is backend-agnostic and can be translated to GPUI, Iced, or other backends.
 
-use crate::style::Color;
+use crate::Color;
 
 /// Size value (used for width, height, spacing, etc.)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +16,7 @@ pub enum SizeValue {
     ThreeQuarters, // 75%
     Auto,
     Fixed(u16),  // Pixels (Tailwind uses 4px base unit, so 1 = 4px, 2 = 8px, etc.)
+    Px(u16),     // Arbitrary value in pixels: w-[320px]
 }
 
 impl SizeValue {
@@ -23,16 +24,110 @@ impl SizeValue {
     pub fn to_pixels(&self) -> u16 {
         match self {
             SizeValue::Fixed(units) => units * 4,
+            SizeValue::Px(px) => *px,
             _ => 0, // Full, Auto, etc. are handled differently by backends
         }
     }
 }
 
+/// Interaction state a style variant applies to (`hover:bg-blue-600`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleState {
+    /// Pointer is over the element
+    Hover,
+    /// Element has keyboard focus (text inputs)
+    Focus,
+    /// Element is being pressed
+    Active,
+    /// Element is disabled (buttons and inputs with `disabled` set)
+    Disabled,
+}
+
+impl StyleState {
+    /// All states, in the order their variants are layered
+    ///
+    /// Later states win, so a pressed button shows `active:` over `hover:`,
+    /// and `disabled:` overrides everything.
+    pub const ALL: [StyleState; 4] = [
+        StyleState::Hover,
+        StyleState::Focus,
+        StyleState::Active,
+        StyleState::Disabled,
+    ];
+
+    /// Class prefix without the colon (`hover`, `focus`, ...)
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            StyleState::Hover => "hover",
+            StyleState::Focus => "focus",
+            StyleState::Active => "active",
+            StyleState::Disabled => "disabled",
+        }
+    }
+
+    /// Look up a state by its class prefix
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|state| state.prefix() == prefix)
+    }
+}
+
+/// Responsive breakpoint a class applies from (`md:flex-row`)
+///
+/// Mobile-first like Tailwind: a prefixed class applies when the window is at
+/// least the breakpoint's width, on top of the unprefixed classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// 640px and wider
+    Sm,
+    /// 768px and wider
+    Md,
+    /// 1024px and wider
+    Lg,
+    /// 1280px and wider
+    Xl,
+}
+
+impl Breakpoint {
+    /// All breakpoints from narrowest to widest
+    pub const ALL: [Breakpoint; 4] = [Breakpoint::Sm, Breakpoint::Md, Breakpoint::Lg, Breakpoint::Xl];
+
+    /// Minimum window width in logical pixels
+    pub fn min_width(&self) -> f32 {
+        match self {
+            Breakpoint::Sm => 640.0,
+            Breakpoint::Md => 768.0,
+            Breakpoint::Lg => 1024.0,
+            Breakpoint::Xl => 1280.0,
+        }
+    }
+
+    /// Whether a window of `width` is at or above this breakpoint
+    pub fn matches(&self, width: f32) -> bool {
+        width >= self.min_width()
+    }
+
+    /// Class prefix without the colon (`sm`, `md`, ...)
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+        }
+    }
+
+    /// Look up a breakpoint by its class prefix
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|bp| bp.prefix() == prefix)
+    }
+}
+
 /// Style class IR - represents a single parsed style property
 ///
 /// This enum contains L1 Core + L2 Important features:
 /// - Spacing: p-*, px-*, py-*, m-*, mx-*, my-*, gap-*
 /// - Colors: bg-*, text-*
+/// - Arbitrary values: w-[320px], bg-[#1e293b], text-[15px], rounded-[10px]
 /// - Layout: flex, flex-1, flex-row/col, items-*, justify-*
 /// - Sizing: w-full, w-*, h-full, h-*
 /// - Border Radius: rounded, rounded-*
@@ -135,6 +230,9 @@ pub enum StyleClass {
     /// Border radius: rounded-full (L2)
     RoundedFull,
 
+    /// Border radius: rounded-[10px] (arbitrary value in pixels)
+    RoundedPx(u16),
+
     // ========== Border (L2) ==========
     /// Border: border (default width and color)
     Border,
@@ -167,6 +265,9 @@ pub enum StyleClass {
     /// Font size: text-3xl (30px) - L2
     Text3Xl,
 
+    /// Font size: text-[15px] (arbitrary value in pixels)
+    FontSize(u16),
+
     /// Font weight: font-bold (L2)
     FontBold,
 
@@ -260,9 +361,54 @@ pub enum StyleClass {
 
     /// Grid row start: row-start-{1-7} - L3
     RowStart(u8),
+
+    // ========== Visibility ==========
+    /// Display: none - the element is not rendered
+    Hidden,
+
+    /// Display: block - undoes `hidden`, e.g. `hidden md:block`
+    Block,
 }
 
 impl StyleClass {
+    /// Parse a class that may carry a state prefix (`hover:bg-blue-600`)
+    ///
+    /// Returns `None` as the state for unconditional classes.
+    pub fn parse_variant(class: &str) -> Result<(Option<StyleState>, Self), String> {
+        let class = class.trim();
+        let Some((prefix, rest)) = class.split_once(':') else {
+            return Ok((None, Self::parse_single(class)?));
+        };
+
+        let state = StyleState::from_prefix(prefix)
+            .ok_or_else(|| format!("Unknown state variant: {}:", prefix))?;
+        if rest.contains(':') {
+            return Err(format!("Stacked state variants are not supported: {}", class));
+        }
+        Ok((Some(state), Self::parse_single(rest)?))
+    }
+
+    /// Parse a class that may carry a breakpoint and/or state prefix
+    ///
+    /// The breakpoint comes first, as in Tailwind: `md:hover:bg-blue-600`.
+    pub fn parse_responsive(class: &str) -> Result<(Option<Breakpoint>, Option<StyleState>, Self), String> {
+        let class = class.trim();
+        let breakpoint = class
+            .split_once(':')
+            .and_then(|(prefix, rest)| Some((Breakpoint::from_prefix(prefix)?, rest)));
+
+        match breakpoint {
+            Some((breakpoint, rest)) => {
+                let (state, class) = Self::parse_variant(rest)?;
+                Ok((Some(breakpoint), state, class))
+            }
+            None => {
+                let (state, class) = Self::parse_variant(class)?;
+                Ok((None, state, class))
+            }
+        }
+    }
+
     /// Parse a single style class string into a StyleClass
     pub fn parse_single(class: &str) -> Result<Self, String> {
         let class = class.trim();
@@ -320,8 +466,7 @@ impl StyleClass {
 
         // Parse background: bg-{color}
         if let Some(color_name) = class.strip_prefix("bg-") {
-            let color = Color::from_tailwind(color_name)
-                .or_else(|_| Color::from_hex(color_name))?;
+            let color = parse_color(color_name)?;
             return Ok(StyleClass::BackgroundColor(color));
         }
 
@@ -355,15 +500,29 @@ impl StyleClass {
             _ => {}
         }
 
+        // Parse arbitrary font size: text-[15px] (text-[#hex] is a color)
+        if let Some(value) = class.strip_prefix("text-").and_then(arbitrary) {
+            if value.ends_with("px") {
+                return Ok(StyleClass::FontSize(parse_px(value)?));
+            }
+        }
+
         // Parse text color: text-{color} (must come after text-size/align)
         if let Some(color_name) = class.strip_prefix("text-") {
-            let color = Color::from_tailwind(color_name)
-                .or_else(|_| Color::from_hex(color_name))?;
+            let color = parse_color(color_name)?;
             return Ok(StyleClass::TextColor(color));
         }
 
         // ========== Layout (L1 + L2) ==========
 
+        // ========== Visibility ==========
+
+        match class {
+            "hidden" => return Ok(StyleClass::Hidden),
+            "block" => return Ok(StyleClass::Block),
+            _ => {}
+        }
+
         // Parse flex
         if class == "flex" {
             return Ok(StyleClass::Flex);
@@ -430,6 +589,11 @@ impl StyleClass {
             _ => {}
         }
 
+        // Parse arbitrary radius: rounded-[10px]
+        if let Some(value) = class.strip_prefix("rounded-").and_then(arbitrary) {
+            return Ok(StyleClass::RoundedPx(parse_px(value)?));
+        }
+
         // ========== Border (L2) ==========
 
         // Parse border
@@ -448,8 +612,7 @@ impl StyleClass {
             if color_name == "0" {
                 return Ok(StyleClass::Border0);
             }
-            let color = Color::from_tailwind(color_name)
-                .or_else(|_| Color::from_hex(color_name))?;
+            let color = parse_color(color_name)?;
             return Ok(StyleClass::BorderColor(color));
         }
 
@@ -583,6 +746,10 @@ impl StyleClass {
 
 /// Helper function to parse size values
 fn parse_size_value(input: &str) -> Result<SizeValue, String> {
+    if let Some(value) = arbitrary(input) {
+        return Ok(SizeValue::Px(parse_px(value)?));
+    }
+
     match input {
         "full" => Ok(SizeValue::Full),
         "auto" => Ok(SizeValue::Auto),
@@ -600,6 +767,29 @@ fn parse_size_value(input: &str) -> Result<SizeValue, String> {
     }
 }
 
+/// Inner text of an arbitrary value (`[320px]` -> `320px`)
+fn arbitrary(input: &str) -> Option<&str> {
+    input.strip_prefix('[')?.strip_suffix(']')
+}
+
+/// Parse the pixel count of an arbitrary value (`320px`)
+fn parse_px(value: &str) -> Result<u16, String> {
+    let digits = value
+        .strip_suffix("px")
+        .ok_or_else(|| format!("Arbitrary values must be in px: [{}]", value))?;
+    digits.parse()
+        .map_err(|_| format!("Invalid pixel value: [{}]", value))
+}
+
+/// Parse a color: palette or semantic name, or an arbitrary hex value (`[#1e293b]`)
+fn parse_color(input: &str) -> Result<Color, String> {
+    match arbitrary(input) {
+        Some(value) if value.starts_with('#') => Color::from_hex_rgba(value),
+        Some(value) => Err(format!("Arbitrary colors must be hex: [{}]", value)),
+        None => Color::from_tailwind(input).or_else(|_| Color::from_hex(input)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -781,6 +971,68 @@ mod tests {
         assert_eq!(StyleClass::parse_single("row-span-2"), Ok(StyleClass::RowSpan(2)));
     }
 
+    #[test]
+    fn test_parse_state_variants() {
+        assert_eq!(
+            StyleClass::parse_variant("hover:bg-blue-600"),
+            Ok((Some(StyleState::Hover), StyleClass::BackgroundColor(Color::Blue(600))))
+        );
+        assert_eq!(
+            StyleClass::parse_variant("disabled:opacity-50"),
+            Ok((Some(StyleState::Disabled), StyleClass::Opacity(50)))
+        );
+        assert_eq!(StyleClass::parse_variant("p-4"), Ok((None, StyleClass::Padding(SizeValue::Fixed(4)))));
+        assert!(StyleClass::parse_variant("visited:bg-red-500").is_err());
+        assert!(StyleClass::parse_variant("hover:focus:bg-red-500").is_err());
+        assert!(StyleClass::parse_variant("hover:nonsense").is_err());
+    }
+
+    #[test]
+    fn test_parse_breakpoints() {
+        assert_eq!(
+            StyleClass::parse_responsive("md:flex-row"),
+            Ok((Some(Breakpoint::Md), None, StyleClass::FlexRow))
+        );
+        assert_eq!(
+            StyleClass::parse_responsive("lg:hover:bg-white"),
+            Ok((Some(Breakpoint::Lg), Some(StyleState::Hover), StyleClass::BackgroundColor(Color::White)))
+        );
+        assert_eq!(StyleClass::parse_responsive("hidden"), Ok((None, None, StyleClass::Hidden)));
+        assert!(StyleClass::parse_responsive("2xl:block").is_err());
+        assert!(StyleClass::parse_responsive("hover:md:block").is_err());
+
+        assert!(Breakpoint::Md.matches(768.0));
+        assert!(!Breakpoint::Lg.matches(1023.0));
+    }
+
+    #[test]
+    fn test_parse_arbitrary_values() {
+        assert_eq!(StyleClass::parse_single("w-[320px]"), Ok(StyleClass::Width(SizeValue::Px(320))));
+        assert_eq!(StyleClass::parse_single("gap-[6px]"), Ok(StyleClass::Gap(SizeValue::Px(6))));
+        assert_eq!(StyleClass::parse_single("text-[15px]"), Ok(StyleClass::FontSize(15)));
+        assert_eq!(StyleClass::parse_single("rounded-[10px]"), Ok(StyleClass::RoundedPx(10)));
+        assert_eq!(
+            StyleClass::parse_single("bg-[#1e293b]"),
+            Ok(StyleClass::BackgroundColor(Color::Rgb { r: 0x1e, g: 0x29, b: 0x3b }))
+        );
+        assert_eq!(
+            StyleClass::parse_single("text-[#00000080]"),
+            Ok(StyleClass::TextColor(Color::Rgba { r: 0, g: 0, b: 0, a: 0x80 }))
+        );
+        assert_eq!(SizeValue::Px(320).to_pixels(), 320);
+
+        assert!(StyleClass::parse_single("w-[2rem]").is_err());
+        assert!(StyleClass::parse_single("bg-[red]").is_err());
+        assert!(StyleClass::parse_single("p-[px]").is_err());
+    }
+
+    #[test]
+    fn test_parse_extended_palette() {
+        assert_eq!(StyleClass::parse_single("bg-emerald-950"), Ok(StyleClass::BackgroundColor(Color::Emerald(950))));
+        assert_eq!(StyleClass::parse_single("text-sky-400"), Ok(StyleClass::TextColor(Color::Sky(400))));
+        assert_eq!(StyleClass::parse_single("border-rose-600"), Ok(StyleClass::BorderColor(Color::Rose(600))));
+    }
+
     #[test]
     fn test_parse_grid_position() {
         assert_eq!(StyleClass::parse_single("col-start-2"), Ok(StyleClass::ColStart(2)));
@@ -0,0 +1,423 @@
+// Color representation for the unified styling system
+//
+// Supports semantic colors, the full Tailwind palette (50-950), and custom RGB/RGBA values
+
+use serde::{Deserialize, Serialize};
+
+/// Shades available for every palette family
+const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Tailwind v3 palette, one `0xRRGGBB` per entry in [`SHADES`]
+const PALETTE: [(&str, [u32; 11]); 22] = [
+    ("slate", [0xf8fafc, 0xf1f5f9, 0xe2e8f0, 0xcbd5e1, 0x94a3b8, 0x64748b, 0x475569, 0x334155, 0x1e293b, 0x0f172a, 0x020617]),
+    ("gray", [0xf9fafb, 0xf3f4f6, 0xe5e7eb, 0xd1d5db, 0x9ca3af, 0x6b7280, 0x4b5563, 0x374151, 0x1f2937, 0x111827, 0x030712]),
+    ("zinc", [0xfafafa, 0xf4f4f5, 0xe4e4e7, 0xd4d4d8, 0xa1a1aa, 0x71717a, 0x52525b, 0x3f3f46, 0x27272a, 0x18181b, 0x09090b]),
+    ("neutral", [0xfafafa, 0xf5f5f5, 0xe5e5e5, 0xd4d4d4, 0xa3a3a3, 0x737373, 0x525252, 0x404040, 0x262626, 0x171717, 0x0a0a0a]),
+    ("stone", [0xfafaf9, 0xf5f5f4, 0xe7e5e4, 0xd6d3d1, 0xa8a29e, 0x78716c, 0x57534e, 0x44403c, 0x292524, 0x1c1917, 0x0c0a09]),
+    ("red", [0xfef2f2, 0xfee2e2, 0xfecaca, 0xfca5a5, 0xf87171, 0xef4444, 0xdc2626, 0xb91c1c, 0x991b1b, 0x7f1d1d, 0x450a0a]),
+    ("orange", [0xfff7ed, 0xffedd5, 0xfed7aa, 0xfdba74, 0xfb923c, 0xf97316, 0xea580c, 0xc2410c, 0x9a3412, 0x7c2d12, 0x431407]),
+    ("amber", [0xfffbeb, 0xfef3c7, 0xfde68a, 0xfcd34d, 0xfbbf24, 0xf59e0b, 0xd97706, 0xb45309, 0x92400e, 0x78350f, 0x451a03]),
+    ("yellow", [0xfefce8, 0xfef9c3, 0xfef08a, 0xfde047, 0xfacc15, 0xeab308, 0xca8a04, 0xa16207, 0x854d0e, 0x713f12, 0x422006]),
+    ("lime", [0xf7fee7, 0xecfccb, 0xd9f99d, 0xbef264, 0xa3e635, 0x84cc16, 0x65a30d, 0x4d7c0f, 0x3f6212, 0x365314, 0x1a2e05]),
+    ("green", [0xf0fdf4, 0xdcfce7, 0xbbf7d0, 0x86efac, 0x4ade80, 0x22c55e, 0x16a34a, 0x15803d, 0x166534, 0x14532d, 0x052e16]),
+    ("emerald", [0xecfdf5, 0xd1fae5, 0xa7f3d0, 0x6ee7b7, 0x34d399, 0x10b981, 0x059669, 0x047857, 0x065f46, 0x064e3b, 0x022c22]),
+    ("teal", [0xf0fdfa, 0xccfbf1, 0x99f6e4, 0x5eead4, 0x2dd4bf, 0x14b8a6, 0x0d9488, 0x0f766e, 0x115e59, 0x134e4a, 0x042f2e]),
+    ("cyan", [0xecfeff, 0xcffafe, 0xa5f3fc, 0x67e8f9, 0x22d3ee, 0x06b6d4, 0x0891b2, 0x0e7490, 0x155e75, 0x164e63, 0x083344]),
+    ("sky", [0xf0f9ff, 0xe0f2fe, 0xbae6fd, 0x7dd3fc, 0x38bdf8, 0x0ea5e9, 0x0284c7, 0x0369a1, 0x075985, 0x0c4a6e, 0x082f49]),
+    ("blue", [0xeff6ff, 0xdbeafe, 0xbfdbfe, 0x93c5fd, 0x60a5fa, 0x3b82f6, 0x2563eb, 0x1d4ed8, 0x1e40af, 0x1e3a8a, 0x172554]),
+    ("indigo", [0xeef2ff, 0xe0e7ff, 0xc7d2fe, 0xa5b4fc, 0x818cf8, 0x6366f1, 0x4f46e5, 0x4338ca, 0x3730a3, 0x312e81, 0x1e1b4b]),
+    ("violet", [0xf5f3ff, 0xede9fe, 0xddd6fe, 0xc4b5fd, 0xa78bfa, 0x8b5cf6, 0x7c3aed, 0x6d28d9, 0x5b21b6, 0x4c1d95, 0x2e1065]),
+    ("purple", [0xfaf5ff, 0xf3e8ff, 0xe9d5ff, 0xd8b4fe, 0xc084fc, 0xa855f7, 0x9333ea, 0x7e22ce, 0x6b21a8, 0x581c87, 0x3b0764]),
+    ("fuchsia", [0xfdf4ff, 0xfae8ff, 0xf5d0fe, 0xf0abfc, 0xe879f9, 0xd946ef, 0xc026d3, 0xa21caf, 0x86198f, 0x701a75, 0x4a044e]),
+    ("pink", [0xfdf2f8, 0xfce7f3, 0xfbcfe8, 0xf9a8d4, 0xf472b6, 0xec4899, 0xdb2777, 0xbe185d, 0x9d174d, 0x831843, 0x500724]),
+    ("rose", [0xfff1f2, 0xffe4e6, 0xfecdd3, 0xfda4af, 0xfb7185, 0xf43f5e, 0xe11d48, 0xbe123c, 0x9f1239, 0x881337, 0x4c0519]),
+];
+
+/// Color representation
+///
+/// Serializes as a string: `#rrggbb`/`#rrggbbaa` for custom colors and the
+/// class name (`blue-500`, `primary`) otherwise, which is also what theme files use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Color {
+    // Semantic colors (theme-based)
+    Primary,
+    Secondary,
+    Background,
+    Surface,
+    Error,
+    Warning,
+    Success,
+    Info,
+
+    // Text colors
+    OnPrimary,
+    OnSecondary,
+    OnBackground,
+    OnSurface,
+
+    // Tailwind palette colors, shades 50-950
+    Slate(u16),
+    Gray(u16),
+    Zinc(u16),
+    Neutral(u16),
+    Stone(u16),
+    Red(u16),
+    Orange(u16),
+    Amber(u16),
+    Yellow(u16),
+    Lime(u16),
+    Green(u16),
+    Emerald(u16),
+    Teal(u16),
+    Cyan(u16),
+    Sky(u16),
+    Blue(u16),
+    Indigo(u16),
+    Violet(u16),
+    Purple(u16),
+    Fuchsia(u16),
+    Pink(u16),
+    Rose(u16),
+    White,
+    Black,
+
+    // Custom colors
+    Rgb { r: u8, g: u8, b: u8 },
+    Rgba { r: u8, g: u8, b: u8, a: u8 },
+    Hex(u32), // 0xRRGGBB or 0xRRGGBBAA
+}
+
+impl Color {
+    /// Create a color from a hex string (e.g., "#ffffff" or "#ffffffff")
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim_start_matches('#');
+
+        if hex.len() != 6 && hex.len() != 8 {
+            return Err(format!("Invalid hex color length: {}", hex.len()));
+        }
+
+        let value = u32::from_str_radix(hex, 16)
+            .map_err(|e| format!("Failed to parse hex color: {}", e))?;
+
+        Ok(Self::Hex(value))
+    }
+
+    /// Parse a color from a Tailwind color name (e.g., "red-500", "blue", "white")
+    ///
+    /// Semantic theme tokens ("primary", "on-surface", ...) are accepted too.
+    pub fn from_tailwind(name: &str) -> Result<Self, String> {
+        match name {
+            "white" => Ok(Color::White),
+            "black" => Ok(Color::Black),
+            "primary" => Ok(Color::Primary),
+            "secondary" => Ok(Color::Secondary),
+            "background" => Ok(Color::Background),
+            "surface" => Ok(Color::Surface),
+            "error" => Ok(Color::Error),
+            "warning" => Ok(Color::Warning),
+            "success" => Ok(Color::Success),
+            "info" => Ok(Color::Info),
+            "on-primary" => Ok(Color::OnPrimary),
+            "on-secondary" => Ok(Color::OnSecondary),
+            "on-background" => Ok(Color::OnBackground),
+            "on-surface" => Ok(Color::OnSurface),
+            _ => {
+                // Try to parse "color-shade" format
+                if let Some(pos) = name.find('-') {
+                    let color_name = &name[..pos];
+                    let shade_str = &name[pos + 1..];
+                    let shade: u16 = shade_str.parse()
+                        .map_err(|_| format!("Invalid shade value: {}", shade_str))?;
+                    if !SHADES.contains(&shade) {
+                        return Err(format!("Invalid shade value: {}", shade_str));
+                    }
+
+                    Self::from_family(color_name, shade)
+                        .ok_or_else(|| format!("Unknown color name: {}", color_name))
+                } else {
+                    Err(format!("Invalid color format: {}", name))
+                }
+            }
+        }
+    }
+
+    /// Build a palette color from its family name (`"teal"`, 500)
+    fn from_family(family: &str, shade: u16) -> Option<Self> {
+        let color = match family {
+            "slate" => Color::Slate(shade),
+            "gray" => Color::Gray(shade),
+            "zinc" => Color::Zinc(shade),
+            "neutral" => Color::Neutral(shade),
+            "stone" => Color::Stone(shade),
+            "red" => Color::Red(shade),
+            "orange" => Color::Orange(shade),
+            "amber" => Color::Amber(shade),
+            "yellow" => Color::Yellow(shade),
+            "lime" => Color::Lime(shade),
+            "green" => Color::Green(shade),
+            "emerald" => Color::Emerald(shade),
+            "teal" => Color::Teal(shade),
+            "cyan" => Color::Cyan(shade),
+            "sky" => Color::Sky(shade),
+            "blue" => Color::Blue(shade),
+            "indigo" => Color::Indigo(shade),
+            "violet" => Color::Violet(shade),
+            "purple" => Color::Purple(shade),
+            "fuchsia" => Color::Fuchsia(shade),
+            "pink" => Color::Pink(shade),
+            "rose" => Color::Rose(shade),
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// Family name and shade of a palette color
+    pub fn palette_entry(&self) -> Option<(&'static str, u16)> {
+        let entry = match *self {
+            Color::Slate(s) => ("slate", s),
+            Color::Gray(s) => ("gray", s),
+            Color::Zinc(s) => ("zinc", s),
+            Color::Neutral(s) => ("neutral", s),
+            Color::Stone(s) => ("stone", s),
+            Color::Red(s) => ("red", s),
+            Color::Orange(s) => ("orange", s),
+            Color::Amber(s) => ("amber", s),
+            Color::Yellow(s) => ("yellow", s),
+            Color::Lime(s) => ("lime", s),
+            Color::Green(s) => ("green", s),
+            Color::Emerald(s) => ("emerald", s),
+            Color::Teal(s) => ("teal", s),
+            Color::Cyan(s) => ("cyan", s),
+            Color::Sky(s) => ("sky", s),
+            Color::Blue(s) => ("blue", s),
+            Color::Indigo(s) => ("indigo", s),
+            Color::Violet(s) => ("violet", s),
+            Color::Purple(s) => ("purple", s),
+            Color::Fuchsia(s) => ("fuchsia", s),
+            Color::Pink(s) => ("pink", s),
+            Color::Rose(s) => ("rose", s),
+            _ => return None,
+        };
+        Some(entry)
+    }
+
+    /// `0xRRGGBB` value of a palette color, `None` for other colors or unknown shades
+    pub fn palette_rgb(&self) -> Option<u32> {
+        let (family, shade) = self.palette_entry()?;
+        let index = SHADES.iter().position(|&s| s == shade)?;
+        PALETTE
+            .iter()
+            .find(|(name, _)| *name == family)
+            .map(|(_, values)| values[index])
+    }
+
+    /// Every name `from_tailwind` accepts, for suggesting fixes to typos
+    pub(crate) fn names() -> impl Iterator<Item = String> {
+        let named = [
+            Color::White,
+            Color::Black,
+            Color::Primary,
+            Color::Secondary,
+            Color::Background,
+            Color::Surface,
+            Color::Error,
+            Color::Warning,
+            Color::Success,
+            Color::Info,
+            Color::OnPrimary,
+            Color::OnSecondary,
+            Color::OnBackground,
+            Color::OnSurface,
+        ];
+        let palette = PALETTE.iter().flat_map(|(family, _)| {
+            SHADES.iter().map(move |shade| format!("{}-{}", family, shade))
+        });
+        named.into_iter().map(String::from).chain(palette)
+    }
+
+    /// Parse a `#rrggbb` or `#rrggbbaa` string into an explicit Rgb/Rgba color
+    ///
+    /// Unlike `from_hex`, the alpha channel is kept separate, so the result
+    /// converts unambiguously.
+    pub fn from_hex_rgba(hex: &str) -> Result<Self, String> {
+        let digits = hex.trim_start_matches('#');
+        let value = match Self::from_hex(hex)? {
+            Color::Hex(value) => value,
+            _ => unreachable!("from_hex always returns Color::Hex"),
+        };
+        let byte = |shift: u32| ((value >> shift) & 0xFF) as u8;
+
+        if digits.len() == 8 {
+            Ok(Color::Rgba { r: byte(24), g: byte(16), b: byte(8), a: byte(0) })
+        } else {
+            Ok(Color::Rgb { r: byte(16), g: byte(8), b: byte(0) })
+        }
+    }
+
+    /// Whether this is a semantic token resolved by the active theme
+    pub fn is_semantic(&self) -> bool {
+        matches!(
+            self,
+            Color::Primary
+                | Color::Secondary
+                | Color::Background
+                | Color::Surface
+                | Color::Error
+                | Color::Warning
+                | Color::Success
+                | Color::Info
+                | Color::OnPrimary
+                | Color::OnSecondary
+                | Color::OnBackground
+                | Color::OnSurface
+        )
+    }
+
+    /// Convert to normalized RGBA (0.0-1.0)
+    ///
+    /// Semantic tokens have no fixed value; resolve them with auto-ui's
+    /// `Theme::resolve` first.
+    pub fn to_rgba_normalized(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b) = self.to_rgb_normalized();
+        let a = match self {
+            Color::Rgba { a, .. } => *a as f32 / 255.0,
+            _ => 1.0,
+        };
+        (r, g, b, a)
+    }
+
+    /// Convert to normalized RGB (0.0-1.0)
+    pub fn to_rgb_normalized(&self) -> (f32, f32, f32) {
+        match self {
+            Color::Rgb { r, g, b } => (
+                *r as f32 / 255.0,
+                *g as f32 / 255.0,
+                *b as f32 / 255.0,
+            ),
+            Color::Rgba { r, g, b, .. } => (
+                *r as f32 / 255.0,
+                *g as f32 / 255.0,
+                *b as f32 / 255.0,
+            ),
+            Color::Hex(value) => {
+                let r = ((value >> 16) & 0xFF) as f32 / 255.0;
+                let g = ((value >> 8) & 0xFF) as f32 / 255.0;
+                let b = (value & 0xFF) as f32 / 255.0;
+                (r, g, b)
+            }
+            Color::White => (1.0, 1.0, 1.0),
+            Color::Black => (0.0, 0.0, 0.0),
+            // Palette colors use Tailwind's values; semantic tokens need a theme
+            _ => match self.palette_rgb() {
+                Some(value) => Color::Hex(value).to_rgb_normalized(),
+                None => (0.5, 0.5, 0.5),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, String> {
+        if value.starts_with('#') {
+            Color::from_hex_rgba(&value)
+        } else {
+            Color::from_tailwind(&value)
+        }
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        if let Some((family, shade)) = color.palette_entry() {
+            return format!("{}-{}", family, shade);
+        }
+        match color {
+            Color::Primary => "primary".to_string(),
+            Color::Secondary => "secondary".to_string(),
+            Color::Background => "background".to_string(),
+            Color::Surface => "surface".to_string(),
+            Color::Error => "error".to_string(),
+            Color::Warning => "warning".to_string(),
+            Color::Success => "success".to_string(),
+            Color::Info => "info".to_string(),
+            Color::OnPrimary => "on-primary".to_string(),
+            Color::OnSecondary => "on-secondary".to_string(),
+            Color::OnBackground => "on-background".to_string(),
+            Color::OnSurface => "on-surface".to_string(),
+            Color::White => "white".to_string(),
+            Color::Black => "black".to_string(),
+            Color::Rgb { r, g, b } => format!("#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Rgba { r, g, b, a } => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+            Color::Hex(value) => format!("#{:06x}", value),
+            _ => unreachable!("palette colors are handled above"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_hex() {
+        let color = Color::from_hex("#ffffff").unwrap();
+        assert_eq!(color, Color::Hex(0xffffff));
+
+        let color = Color::from_hex("#000000").unwrap();
+        assert_eq!(color, Color::Hex(0x000000));
+    }
+
+    #[test]
+    fn test_from_tailwind() {
+        let color = Color::from_tailwind("white").unwrap();
+        assert_eq!(color, Color::White);
+
+        let color = Color::from_tailwind("slate-500").unwrap();
+        assert_eq!(color, Color::Slate(500));
+    }
+
+    #[test]
+    fn test_full_palette() {
+        assert_eq!(Color::from_tailwind("teal-950").unwrap(), Color::Teal(950));
+        assert_eq!(Color::from_tailwind("fuchsia-50").unwrap(), Color::Fuchsia(50));
+        assert_eq!(Color::Blue(500).palette_rgb(), Some(0x3b82f6));
+        assert_eq!(Color::Stone(950).palette_rgb(), Some(0x0c0a09));
+        assert_eq!(Color::Rose(500).to_rgb_normalized(), Color::Hex(0xf43f5e).to_rgb_normalized());
+        assert!(Color::from_tailwind("blue-550").is_err());
+        assert!(Color::from_tailwind("mauve-500").is_err());
+    }
+
+    #[test]
+    fn test_semantic_names() {
+        assert_eq!(Color::from_tailwind("primary").unwrap(), Color::Primary);
+        assert_eq!(Color::from_tailwind("on-surface").unwrap(), Color::OnSurface);
+        assert!(Color::OnSurface.is_semantic());
+        assert!(!Color::Slate(500).is_semantic());
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        for color in [
+            Color::Primary,
+            Color::Blue(500),
+            Color::Indigo(950),
+            Color::Rgb { r: 0x1e, g: 0x1e, b: 0x1e },
+            Color::Rgba { r: 0, g: 0, b: 0, a: 0x80 },
+        ] {
+            let text = String::from(color);
+            assert_eq!(Color::try_from(text).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn test_to_rgb_normalized() {
+        let color = Color::Rgb { r: 255, g: 0, b: 0 };
+        let (r, g, b) = color.to_rgb_normalized();
+        assert_eq!(r, 1.0);
+        assert_eq!(g, 0.0);
+        assert_eq!(b, 0.0);
+    }
+}
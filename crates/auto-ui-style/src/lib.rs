@@ -0,0 +1,233 @@
+// auto-ui-style: the Tailwind-style class grammar of AutoUI
+//
+// `StyleClass` is the backend-agnostic IR of a single utility class and
+// `Style` a parsed class string. auto-ui re-exports everything here as
+// `auto_ui::style`; the `style!` macro in auto-ui-macros uses the same parser
+// to check class strings at compile time, so there is only one grammar.
+
+mod class;
+mod color;
+mod parser;
+
+pub use class::{Breakpoint, StyleClass, StyleState, SizeValue};
+pub use color::Color;
+pub use parser::{StyleError, StyleParser};
+
+use std::borrow::Cow;
+
+/// Parsed style collection ready to be applied to backend-specific components
+///
+/// The class lists borrow from static tables when built by `style!`, which
+/// makes such styles usable in `const` and `static` items.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Style {
+    /// Unconditional classes
+    pub classes: Cow<'static, [StyleClass]>,
+    /// State-prefixed classes (`hover:bg-blue-600`), applied on top of `classes`
+    pub variants: Cow<'static, [(StyleState, StyleClass)]>,
+    /// Breakpoint-prefixed classes (`md:flex-row`, `lg:hover:bg-white`)
+    pub responsive: Cow<'static, [(Breakpoint, Option<StyleState>, StyleClass)]>,
+}
+
+impl Style {
+    /// Parse a style string into a Style collection
+    ///
+    /// Classes may carry a `hover:`, `focus:`, `active:` or `disabled:` prefix,
+    /// optionally preceded by a `sm:`/`md:`/`lg:`/`xl:` breakpoint.
+    /// The error points at the first invalid class.
+    pub fn parse(input: &str) -> Result<Self, StyleError> {
+        StyleParser::new().parse_style(input)
+    }
+
+    /// Create an empty style
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Build a style from constant class tables, as expanded by auto-ui's `style!`
+    pub const fn from_static(
+        classes: &'static [StyleClass],
+        variants: &'static [(StyleState, StyleClass)],
+        responsive: &'static [(Breakpoint, Option<StyleState>, StyleClass)],
+    ) -> Self {
+        Self {
+            classes: Cow::Borrowed(classes),
+            variants: Cow::Borrowed(variants),
+            responsive: Cow::Borrowed(responsive),
+        }
+    }
+
+    /// Add a style class
+    pub fn add(mut self, class: StyleClass) -> Self {
+        self.classes.to_mut().push(class);
+        self
+    }
+
+    /// Add a class that only applies in the given state
+    pub fn add_variant(mut self, state: StyleState, class: StyleClass) -> Self {
+        self.variants.to_mut().push((state, class));
+        self
+    }
+
+    /// Whether any class is conditioned on `state`
+    pub fn has_variant(&self, state: StyleState) -> bool {
+        self.variants.iter().any(|(s, _)| *s == state)
+    }
+
+    /// Whether the style has any state variants
+    pub fn is_interactive(&self) -> bool {
+        !self.variants.is_empty()
+    }
+
+    /// Whether any class depends on the window width
+    pub fn is_responsive(&self) -> bool {
+        !self.responsive.is_empty()
+    }
+
+    /// Resolve breakpoint classes for a window of the given width
+    ///
+    /// Matching classes are appended narrowest breakpoint first, so `md:` beats
+    /// the base class and `lg:` beats `md:`. The result has no responsive classes.
+    pub fn at_width(&self, width: f32) -> Style {
+        let mut style = Style {
+            classes: self.classes.clone(),
+            variants: self.variants.clone(),
+            responsive: Cow::Borrowed(&[]),
+        };
+
+        for breakpoint in Breakpoint::ALL.into_iter().filter(|bp| bp.matches(width)) {
+            for (bp, state, class) in self.responsive.iter() {
+                if *bp != breakpoint {
+                    continue;
+                }
+                match state {
+                    Some(state) => style.variants.to_mut().push((*state, class.clone())),
+                    None => style.classes.to_mut().push(class.clone()),
+                }
+            }
+        }
+        style
+    }
+
+    /// Whether the style hides its element at the given window width
+    pub fn is_hidden_at(&self, width: f32) -> bool {
+        self.at_width(width)
+            .classes
+            .iter()
+            .fold(false, |hidden, class| match class {
+                StyleClass::Hidden => true,
+                StyleClass::Block => false,
+                _ => hidden,
+            })
+    }
+
+    /// Flatten into the unconditional style for a set of active states
+    ///
+    /// Variant classes are appended after the base classes in `StyleState::ALL`
+    /// order, so adapters that let later classes win get Tailwind's precedence.
+    pub fn for_states(&self, states: &[StyleState]) -> Style {
+        let mut classes = self.classes.to_vec();
+        for state in StyleState::ALL {
+            if states.contains(&state) {
+                classes.extend(
+                    self.variants
+                        .iter()
+                        .filter(|(s, _)| *s == state)
+                        .map(|(_, class)| class.clone()),
+                );
+            }
+        }
+        Style {
+            classes: classes.into(),
+            ..Style::default()
+        }
+    }
+}
+
+/// Panics on invalid input; use `str::parse` or [`Style::parse`] to handle errors
+impl From<&str> for Style {
+    fn from(input: &str) -> Self {
+        Self::parse(input).unwrap_or_else(|e| panic!("Invalid style string: {}", e))
+    }
+}
+
+impl From<&String> for Style {
+    fn from(input: &String) -> Self {
+        Self::from(input.as_str())
+    }
+}
+
+impl std::str::FromStr for Style {
+    type Err = StyleError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple() {
+        let style = Style::parse("p-4 gap-2 bg-white").unwrap();
+        assert_eq!(style.classes.len(), 3);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let style: Style = "flex items-center".into();
+        assert_eq!(style.classes.len(), 2);
+    }
+
+    #[test]
+    fn test_state_variants() {
+        let style = Style::parse("bg-blue-500 hover:bg-blue-600 active:bg-blue-700 disabled:opacity-50").unwrap();
+        assert_eq!(style.classes, vec![StyleClass::BackgroundColor(Color::Blue(500))]);
+        assert!(style.has_variant(StyleState::Hover));
+        assert!(!style.has_variant(StyleState::Focus));
+
+        // Pressing also hovers; active comes last and wins
+        let pressed = style.for_states(&[StyleState::Active, StyleState::Hover]);
+        assert_eq!(
+            pressed.classes,
+            vec![
+                StyleClass::BackgroundColor(Color::Blue(500)),
+                StyleClass::BackgroundColor(Color::Blue(600)),
+                StyleClass::BackgroundColor(Color::Blue(700)),
+            ]
+        );
+        assert!(!pressed.is_interactive());
+        assert_eq!(style.for_states(&[]).classes, style.classes);
+    }
+
+    #[test]
+    fn test_breakpoints_resolve_by_width() {
+        let style = Style::parse("flex-col md:flex-row lg:gap-8 sm:hover:bg-white").unwrap();
+        assert!(style.is_responsive());
+
+        let narrow = style.at_width(500.0);
+        assert_eq!(narrow.classes, vec![StyleClass::FlexCol]);
+        assert!(!narrow.is_interactive());
+
+        let wide = style.at_width(1100.0);
+        assert_eq!(
+            wide.classes,
+            vec![StyleClass::FlexCol, StyleClass::FlexRow, StyleClass::Gap(SizeValue::Fixed(8))]
+        );
+        assert_eq!(wide.variants, vec![(StyleState::Hover, StyleClass::BackgroundColor(Color::White))]);
+        assert!(!wide.is_responsive());
+    }
+
+    #[test]
+    fn test_hidden_and_block() {
+        let sidebar = Style::parse("hidden md:block w-64").unwrap();
+        let rail = Style::parse("md:hidden").unwrap();
+
+        assert!(sidebar.is_hidden_at(600.0));
+        assert!(!sidebar.is_hidden_at(800.0));
+        assert!(!rail.is_hidden_at(600.0));
+        assert!(rail.is_hidden_at(800.0));
+    }
+}
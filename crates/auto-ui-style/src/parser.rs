@@ -0,0 +1,296 @@
+// StyleParser - Parse Tailwind-style class strings into StyleClass IR
+//
+// This parser takes a space-separated string of Tailwind utility classes
+// and converts them into a Vec<StyleClass> for further processing.
+//
+// Errors point at the offending class by byte span and, when a known class
+// is within a few edits, suggest it (`bg-blu-500` -> `bg-blue-500`).
+
+use crate::{Breakpoint, Color, Style, StyleClass, StyleState};
+use std::fmt;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// A class in a style string that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleError {
+    /// The class as written, including any `md:`/`hover:` prefixes
+    pub class: String,
+    /// Byte range of the class within the parsed string
+    pub span: Range<usize>,
+    /// Why the class was rejected
+    pub message: String,
+    /// Closest known class, if one is only a few edits away
+    pub suggestion: Option<String>,
+}
+
+impl StyleError {
+    fn new(class: &str, span: Range<usize>, message: String) -> Self {
+        Self {
+            class: class.to_string(),
+            span,
+            message,
+            suggestion: suggest(class),
+        }
+    }
+}
+
+impl fmt::Display for StyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid style class `{}` at {}..{}: {}",
+            self.class, self.span.start, self.span.end, self.message
+        )?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean `{}`?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StyleError {}
+
+/// Parser for Tailwind-style utility class strings
+pub struct StyleParser {
+    // For future extensions: caching, custom class definitions, etc.
+}
+
+impl StyleParser {
+    /// Create a new parser
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Parse a space-separated string of style classes
+    ///
+    /// Example: "p-4 gap-2 bg-white flex items-center"
+    pub fn parse(&self, input: &str) -> Result<Vec<StyleClass>, StyleError> {
+        spanned_classes(input)
+            .map(|(class, span)| {
+                StyleClass::parse_single(class).map_err(|message| StyleError::new(class, span, message))
+            })
+            .collect()
+    }
+
+    /// Parse and create a Style object directly
+    ///
+    /// Unlike `parse`, this accepts state variants such as `hover:bg-blue-600`
+    /// and breakpoints such as `md:flex-row`, collected into `Style::variants`
+    /// and `Style::responsive`. Parsing stops at the first invalid class.
+    pub fn parse_style(&self, input: &str) -> Result<Style, StyleError> {
+        let mut style = Style::default();
+        for (class, span) in spanned_classes(input) {
+            let parsed = StyleClass::parse_responsive(class)
+                .map_err(|message| StyleError::new(class, span, message))?;
+            match parsed {
+                (Some(breakpoint), state, class) => style.responsive.to_mut().push((breakpoint, state, class)),
+                (None, Some(state), class) => style.variants.to_mut().push((state, class)),
+                (None, None, class) => style.classes.to_mut().push(class),
+            }
+        }
+        Ok(style)
+    }
+}
+
+/// Whitespace-separated classes with their byte ranges in `input`
+fn spanned_classes(input: &str) -> impl Iterator<Item = (&str, Range<usize>)> {
+    let mut offset = 0;
+    input.split_whitespace().map(move |class| {
+        // Only whitespace separates `offset` from the next class
+        let start = offset + input[offset..].find(class).unwrap_or(0);
+        offset = start + class.len();
+        (class, start..offset)
+    })
+}
+
+/// Suggest a valid spelling of `class`, fixing its prefixes and utility separately
+fn suggest(class: &str) -> Option<String> {
+    let mut parts: Vec<&str> = class.split(':').collect();
+    let utility = parts.pop()?;
+
+    let mut suggestion = String::new();
+    for prefix in parts {
+        let known = Breakpoint::ALL
+            .iter()
+            .map(Breakpoint::prefix)
+            .chain(StyleState::ALL.iter().map(StyleState::prefix));
+        if known.clone().any(|k| k == prefix) {
+            suggestion.push_str(prefix);
+        } else {
+            suggestion.push_str(closest(prefix, known)?);
+        }
+        suggestion.push(':');
+    }
+
+    if StyleClass::parse_single(utility).is_ok() {
+        suggestion.push_str(utility);
+    } else {
+        suggestion.push_str(closest(utility, known_classes().iter().map(String::as_str))?);
+    }
+
+    (suggestion != class).then_some(suggestion)
+}
+
+/// The candidate nearest to `word`, if it is close enough to be a likely typo
+fn closest<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (word.len() / 4).clamp(1, 3);
+    candidates
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Classes offered as suggestions: keywords plus common sizes and every color
+fn known_classes() -> &'static [String] {
+    static KNOWN: OnceLock<Vec<String>> = OnceLock::new();
+    KNOWN.get_or_init(|| {
+        let keywords = [
+            "flex", "flex-1", "flex-row", "flex-col",
+            "items-center", "items-start", "items-end",
+            "justify-center", "justify-between", "justify-start", "justify-end",
+            "rounded", "rounded-sm", "rounded-md", "rounded-lg", "rounded-xl",
+            "rounded-2xl", "rounded-3xl", "rounded-full",
+            "border", "border-0",
+            "text-xs", "text-sm", "text-base", "text-lg", "text-xl", "text-2xl", "text-3xl",
+            "font-bold", "font-medium", "font-normal",
+            "text-center", "text-left", "text-right",
+            "shadow", "shadow-sm", "shadow-md", "shadow-lg", "shadow-xl", "shadow-2xl", "shadow-none",
+            "relative", "absolute",
+            "overflow-auto", "overflow-hidden", "overflow-visible", "overflow-scroll",
+            "overflow-x-auto", "overflow-y-auto",
+            "grid", "hidden", "block",
+        ];
+        let mut known: Vec<String> = keywords.iter().map(|k| k.to_string()).collect();
+
+        let sizes = (0..=12).chain([16, 20, 24, 32, 48, 64]).map(|n| n.to_string());
+        let sizes: Vec<String> = sizes
+            .chain(["full", "auto", "1/2", "1/3", "2/3", "1/4", "3/4"].map(String::from))
+            .collect();
+        for prefix in ["p", "px", "py", "m", "mx", "my", "gap", "w", "h"] {
+            known.extend(sizes.iter().map(|size| format!("{}-{}", prefix, size)));
+        }
+
+        for prefix in ["bg", "text", "border"] {
+            known.extend(Color::names().map(|name| format!("{}-{}", prefix, name)));
+        }
+
+        let ranges: [(&str, std::ops::RangeInclusive<u8>); 6] = [
+            ("grid-cols", 1..=12),
+            ("grid-rows", 1..=6),
+            ("col-span", 1..=12),
+            ("row-span", 1..=6),
+            ("col-start", 1..=7),
+            ("row-start", 1..=7),
+        ];
+        for (prefix, range) in ranges {
+            known.extend(range.map(|n| format!("{}-{}", prefix, n)));
+        }
+        known.extend([0, 25, 50, 75, 100].map(|n| format!("opacity-{}", n)));
+        known.extend([0, 10, 20, 30, 40, 50].map(|n| format!("z-{}", n)));
+        known
+    })
+}
+
+impl Default for StyleParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SizeValue;
+
+    #[test]
+    fn test_parse_multiple_classes() {
+        let parser = StyleParser::new();
+        let classes = parser.parse("p-4 gap-2 bg-white flex").unwrap();
+        assert_eq!(classes.len(), 4);
+        assert_eq!(classes[0], StyleClass::Padding(SizeValue::Fixed(4)));
+        assert_eq!(classes[1], StyleClass::Gap(SizeValue::Fixed(2)));
+    }
+
+    #[test]
+    fn test_parse_empty_string() {
+        let parser = StyleParser::new();
+        let classes = parser.parse("").unwrap();
+        assert_eq!(classes.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_with_extra_whitespace() {
+        let parser = StyleParser::new();
+        let classes = parser.parse("  p-4   gap-2  ").unwrap();
+        assert_eq!(classes.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_invalid_class() {
+        let parser = StyleParser::new();
+        let result = parser.parse("p-4 invalid-class");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_style_object() {
+        let parser = StyleParser::new();
+        let style = parser.parse_style("flex items-center w-full").unwrap();
+        assert_eq!(style.classes.len(), 3);
+    }
+
+    #[test]
+    fn test_error_span_and_suggestion() {
+        let parser = StyleParser::new();
+        let error = parser.parse_style("p-4  bg-blu-500 flex").unwrap_err();
+
+        assert_eq!(error.class, "bg-blu-500");
+        assert_eq!(error.span, 5..15);
+        assert_eq!(error.suggestion.as_deref(), Some("bg-blue-500"));
+        assert!(error.to_string().contains("did you mean `bg-blue-500`?"));
+    }
+
+    #[test]
+    fn test_suggestions_keep_and_fix_prefixes() {
+        let parser = StyleParser::new();
+        let suggestion = |input| parser.parse_style(input).unwrap_err().suggestion;
+
+        assert_eq!(suggestion("md:itms-center"), Some("md:items-center".to_string()));
+        assert_eq!(suggestion("hovr:bg-white"), Some("hover:bg-white".to_string()));
+        assert_eq!(suggestion("flx"), Some("flex".to_string()));
+        assert_eq!(suggestion("completely-unknown"), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("flex", "flex"), 0);
+        assert_eq!(edit_distance("flx", "flex"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_parse_style_with_variants() {
+        let parser = StyleParser::new();
+        let style = parser.parse_style("p-2 hover:bg-blue-600 focus:border-blue-500").unwrap();
+        assert_eq!(style.classes.len(), 1);
+        assert_eq!(style.variants.len(), 2);
+        assert!(parser.parse("hover:bg-blue-600").is_err());
+    }
+}
@@ -0,0 +1,586 @@
+// Terminal runtime state of a component
+//
+// `TuiApp` owns the component and the VTree of its current view, laid out in
+// cell-sized virtual pixels. Input is resolved against that tree: the focused
+// node's handler from `view_to_vtree_with_handlers` turns a key press or
+// click into the component's message. After every message the view is
+// rebuilt, and focus follows the same node ID when it survives.
+
+use crate::input::{key_event, Action};
+use crate::render::{self, Focus};
+use crate::{CELL_HEIGHT, CELL_WIDTH};
+use auto_ui::command::CommandFuture;
+use auto_ui::layout::{LayoutEngine, LayoutResult};
+use auto_ui::subscription::{SubscriptionEvent, WindowSize};
+use auto_ui::view::TabsPosition;
+use auto_ui::vnode::{VNodeId, VNodeProps, VTree};
+use auto_ui::vnode_converter::{view_to_vtree_with_handlers, VNodeHandler, VNodeHandlers};
+use auto_ui::{Command, Component, Theme};
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{Event, MouseButton, MouseEventKind};
+use ratatui::layout::Rect as Area;
+use ratatui::widgets::Widget;
+
+/// A component running in a terminal of a given size
+///
+/// Drives the component from crossterm events and draws it as a ratatui
+/// widget. `run_app` wraps one around a real terminal; tests can use it
+/// directly:
+///
+/// ```ignore
+/// let mut app = TuiApp::new(Counter::default(), 40, 10);
+/// app.handle_event(&Event::Key(KeyEvent::from(KeyCode::Tab)));
+/// app.handle_event(&Event::Key(KeyEvent::from(KeyCode::Enter)));
+/// assert!(app.screen().contains("Count: 1"));
+/// ```
+pub struct TuiApp<C: Component> {
+    component: C,
+    tree: VTree,
+    handlers: VNodeHandlers<C::Msg>,
+    layout: LayoutResult,
+    width: u16,
+    height: u16,
+    /// Interactive nodes in pre-order, the Tab order
+    focusable: Vec<VNodeId>,
+    focus: Option<usize>,
+    /// Highlighted section of a focused accordion
+    section: usize,
+    /// `Command::perform` futures waiting to be spawned
+    tasks: Vec<CommandFuture<C::Msg>>,
+    exit: bool,
+}
+
+impl<C: Component> TuiApp<C> {
+    /// Start `component` in a terminal of `width` x `height` cells
+    pub fn new(component: C, width: u16, height: u16) -> Self {
+        let mut app = Self {
+            component,
+            tree: VTree::new(),
+            handlers: VNodeHandlers::new(),
+            layout: LayoutResult::default(),
+            width,
+            height,
+            focusable: Vec::new(),
+            focus: None,
+            section: 0,
+            tasks: Vec::new(),
+            exit: false,
+        };
+        app.rebuild();
+        app
+    }
+
+    pub fn component(&self) -> &C {
+        &self.component
+    }
+
+    /// VTree of the current view
+    pub fn tree(&self) -> &VTree {
+        &self.tree
+    }
+
+    /// Layout of the current view, in `CELL_WIDTH` x `CELL_HEIGHT` pixels per cell
+    pub fn layout(&self) -> &LayoutResult {
+        &self.layout
+    }
+
+    /// The node that receives key presses
+    pub fn focused(&self) -> Option<VNodeId> {
+        self.focus.map(|index| self.focusable[index])
+    }
+
+    /// Whether the component asked to exit, or the user pressed Ctrl+C
+    pub fn should_exit(&self) -> bool {
+        self.exit
+    }
+
+    /// Futures from `Command::perform` since the last call
+    ///
+    /// Their outputs go back in through `send`.
+    pub fn take_tasks(&mut self) -> Vec<CommandFuture<C::Msg>> {
+        std::mem::take(&mut self.tasks)
+    }
+
+    /// Deliver a message to the component and rebuild the view
+    pub fn send(&mut self, msg: C::Msg) {
+        let command = self.component.update(msg);
+        self.run(command);
+        self.rebuild();
+    }
+
+    /// Deliver a subscription event to the component's current subscription
+    pub fn dispatch(&mut self, event: SubscriptionEvent) {
+        for msg in self.component.subscription().dispatch(event) {
+            self.send(msg);
+        }
+    }
+
+    /// Resize the terminal, re-laying out the view
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.rebuild();
+        self.dispatch(SubscriptionEvent::WindowResized(self.window()));
+    }
+
+    /// Handle a terminal event
+    ///
+    /// Key presses go to keyboard subscriptions and to the focused widget; a
+    /// left click focuses and activates the widget under the mouse.
+    pub fn handle_event(&mut self, event: &Event) {
+        match event {
+            Event::Key(key) => {
+                if let Some(action) = Action::from_key(key) {
+                    self.act(action);
+                }
+                if let Some(key) = key_event(key) {
+                    self.dispatch(SubscriptionEvent::Key(key));
+                }
+            }
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                self.click(mouse.column, mouse.row);
+            }
+            Event::Resize(width, height) => self.resize(*width, *height),
+            _ => {}
+        }
+    }
+
+    /// Text of the screen, one line per row without trailing spaces
+    pub fn screen(&self) -> String {
+        let buffer = self.buffer();
+        let area = buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| {
+                let row: String = (area.left()..area.right()).map(|x| buffer[(x, y)].symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The screen drawn into a fresh buffer of the terminal's size
+    pub fn buffer(&self) -> Buffer {
+        let area = Area::new(0, 0, self.width, self.height);
+        let mut buffer = Buffer::empty(area);
+        self.render(area, &mut buffer);
+        buffer
+    }
+
+    fn window(&self) -> WindowSize {
+        WindowSize::new(
+            f32::from(self.width) * CELL_WIDTH,
+            f32::from(self.height) * CELL_HEIGHT,
+        )
+    }
+
+    fn run(&mut self, command: Command<C::Msg>) {
+        match command {
+            Command::None => {}
+            Command::Batch(commands) => commands.into_iter().for_each(|command| self.run(command)),
+            Command::Perform(future) => self.tasks.push(future),
+            Command::Exit => self.exit = true,
+            Command::SetTheme(theme) => Theme::set_active(theme),
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let focused = self.focused();
+        let window = self.window();
+        WindowSize::set_current(window);
+
+        let (tree, handlers) = view_to_vtree_with_handlers(self.component.view());
+        self.layout = LayoutEngine::new().measure(render::measure).compute(&tree, window);
+        self.focusable = self
+            .layout
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| {
+                let enabled = !matches!(
+                    tree.get(*id).map(|node| &node.props),
+                    Some(VNodeProps::Button { disabled: true, .. } | VNodeProps::Input { disabled: true, .. })
+                );
+                enabled && handlers.contains_key(id)
+            })
+            .collect();
+        self.tree = tree;
+        self.handlers = handlers;
+
+        let last = self.focusable.len().checked_sub(1);
+        self.focus = match focused.and_then(|id| self.focusable.iter().position(|f| *f == id)) {
+            Some(index) => Some(index),
+            None => last.map(|last| self.focus.unwrap_or(0).min(last)),
+        };
+    }
+
+    fn act(&mut self, action: Action) {
+        let count = self.focusable.len();
+        match action {
+            Action::Quit => self.exit = true,
+            Action::FocusNext | Action::FocusPrevious if count > 0 => {
+                let index = self.focus.unwrap_or(0);
+                self.focus = Some(if action == Action::FocusNext {
+                    (index + 1) % count
+                } else {
+                    (index + count - 1) % count
+                });
+                self.section = 0;
+            }
+            _ => {
+                if let Some(msg) = self.focused().and_then(|id| self.respond(id, action)) {
+                    self.send(msg);
+                }
+            }
+        }
+    }
+
+    /// The message a widget produces for a key action
+    fn respond(&mut self, id: VNodeId, action: Action) -> Option<C::Msg> {
+        let props = self.tree.get(id)?.props.clone();
+        let handler = self.handlers.get(&id)?;
+
+        match (&props, handler, action) {
+            (VNodeProps::Input { value, .. }, VNodeHandler::Input(on_change), Action::Type(c)) => {
+                Some(on_change.call(format!("{}{}", value, c)))
+            }
+            (VNodeProps::Input { value, .. }, VNodeHandler::Input(on_change), Action::Backspace) => {
+                let mut value = value.clone();
+                value.pop()?;
+                Some(on_change.call(value))
+            }
+            // Space presses everything but text inputs
+            (_, _, Action::Type(' ')) => self.respond(id, Action::Activate),
+            (
+                VNodeProps::Button { .. } | VNodeProps::Checkbox { .. } | VNodeProps::Radio { .. },
+                VNodeHandler::Message(msg),
+                Action::Activate,
+            ) => Some(msg.clone()),
+            (VNodeProps::Select { options, selected_index }, VNodeHandler::Select(on_select), _) => {
+                let index = match action {
+                    // Enter cycles through the options
+                    Action::Activate if !options.is_empty() => selected_index.map_or(0, |i| (i + 1) % options.len()),
+                    _ => step(*selected_index, options.len(), action)?,
+                };
+                Some(on_select.call(index, &options[index]))
+            }
+            (VNodeProps::RadioGroup { options, selected_index }, VNodeHandler::Select(on_select), _) => {
+                let index = step(*selected_index, options.len(), action)?;
+                Some(on_select.call(index, &options[index]))
+            }
+            (VNodeProps::Slider { min, max, value, step }, VNodeHandler::Slider(on_change), _) => {
+                let step = step.unwrap_or((max - min) / 20.0);
+                let value = match action {
+                    Action::Previous => value - step,
+                    Action::Next => value + step,
+                    _ => return None,
+                };
+                Some(on_change.call(value.clamp(*min, *max)))
+            }
+            (VNodeProps::Tabs { labels, selected, .. }, VNodeHandler::TabsSelect(on_select), _) => {
+                Some(on_select.call(step(Some(*selected), labels.len(), action)?))
+            }
+            (VNodeProps::NavigationRail { items, selected, .. }, VNodeHandler::NavigationRailSelect(on_select), _) => {
+                Some(on_select.call(step(Some(*selected), items.len(), action)?))
+            }
+            (VNodeProps::Accordion { items, .. }, VNodeHandler::AccordionToggle(on_toggle), _) => {
+                let last = items.len().checked_sub(1)?;
+                let section = self.section.min(last);
+                match action {
+                    Action::Activate => Some(on_toggle.call(section, !items[section].expanded)),
+                    Action::Previous => {
+                        self.section = section.saturating_sub(1);
+                        None
+                    }
+                    Action::Next => {
+                        self.section = (section + 1).min(last);
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Focus and activate the widget under a mouse click
+    fn click(&mut self, column: u16, row: u16) {
+        let (x, y) = (
+            (f32::from(column) + 0.5) * CELL_WIDTH,
+            (f32::from(row) + 0.5) * CELL_HEIGHT,
+        );
+
+        // Clicks on a widget's label or contents land on its nearest interactive ancestor
+        let mut target = self.layout.hit_test(x, y);
+        let index = loop {
+            let Some(id) = target else { return };
+            if let Some(index) = self.focusable.iter().position(|f| *f == id) {
+                break index;
+            }
+            target = self.tree.get(id).and_then(|node| node.parent);
+        };
+        let id = self.focusable[index];
+        self.focus = Some(index);
+        self.section = 0;
+
+        let (Some(node), Some(rect)) = (self.tree.get(id), self.layout.get(id)) else {
+            return;
+        };
+        let line = ((y - rect.y) / CELL_HEIGHT).max(0.0) as usize;
+
+        let msg = match (&node.props, self.handlers.get(&id)) {
+            (VNodeProps::RadioGroup { options, .. }, Some(VNodeHandler::Select(on_select))) => {
+                options.get(line).map(|option| on_select.call(line, option))
+            }
+            (VNodeProps::NavigationRail { items, .. }, Some(VNodeHandler::NavigationRailSelect(on_select))) => {
+                (line < items.len()).then(|| on_select.call(line))
+            }
+            (VNodeProps::Slider { min, max, .. }, Some(VNodeHandler::Slider(on_change))) => {
+                let fraction = ((x - rect.x) / rect.width.max(1.0)).clamp(0.0, 1.0);
+                Some(on_change.call(min + (max - min) * fraction))
+            }
+            (VNodeProps::Tabs { labels, position, .. }, Some(VNodeHandler::TabsSelect(on_select))) => {
+                let bar = self.layout.headers(id).first().filter(|bar| bar.contains(x, y));
+                bar.and_then(|bar| match position {
+                    TabsPosition::Top | TabsPosition::Bottom => {
+                        let offset = ((x - bar.x) / CELL_WIDTH) as u16;
+                        render::tab_spans(labels)
+                            .iter()
+                            .position(|(start, width)| (*start..start + width).contains(&offset))
+                    }
+                    TabsPosition::Left | TabsPosition::Right => {
+                        Some(((y - bar.y) / CELL_HEIGHT) as usize).filter(|index| *index < labels.len())
+                    }
+                })
+                .map(|index| on_select.call(index))
+            }
+            (VNodeProps::Accordion { .. }, Some(VNodeHandler::AccordionToggle(_))) => {
+                let Some(section) = self.layout.headers(id).iter().position(|header| header.contains(x, y)) else {
+                    return;
+                };
+                self.section = section;
+                self.respond(id, Action::Activate)
+            }
+            // Inputs only take focus
+            (VNodeProps::Input { .. }, _) => None,
+            _ => self.respond(id, Action::Activate),
+        };
+        if let Some(msg) = msg {
+            self.send(msg);
+        }
+    }
+}
+
+impl<C: Component> Widget for &TuiApp<C> {
+    fn render(self, area: Area, buf: &mut Buffer) {
+        let focus = Focus {
+            id: self.focused(),
+            section: self.section,
+        };
+        render::draw(&self.tree, &self.layout, focus, area, buf);
+    }
+}
+
+/// Index of the previous or next option, if that moves the selection
+fn step(current: Option<usize>, len: usize, action: Action) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let next = match (current, action) {
+        (None, Action::Previous | Action::Next) => 0,
+        (Some(index), Action::Previous) => index.saturating_sub(1),
+        (Some(index), Action::Next) => (index + 1).min(last),
+        _ => return None,
+    };
+    (current != Some(next)).then_some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use auto_ui::View;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+    use ratatui::style::Modifier;
+
+    #[derive(Debug, Default)]
+    struct Form {
+        count: i32,
+        name: String,
+        subscribe: bool,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Msg {
+        Inc,
+        Name(String),
+        Subscribe,
+        Quit,
+    }
+
+    impl Component for Form {
+        type Msg = Msg;
+
+        fn on(&mut self, msg: Msg) {
+            match msg {
+                Msg::Inc => self.count += 1,
+                Msg::Name(name) => self.name = name,
+                Msg::Subscribe => self.subscribe = !self.subscribe,
+                Msg::Quit => {}
+            }
+        }
+
+        fn update(&mut self, msg: Msg) -> Command<Msg> {
+            if let Msg::Quit = msg {
+                return Command::exit();
+            }
+            self.on(msg);
+            Command::none()
+        }
+
+        fn view(&self) -> View<Msg> {
+            View::col()
+                .spacing(0)
+                .child(View::text(format!("Count: {}", self.count)))
+                .child(View::button("+1", Msg::Inc))
+                .child(View::input("Name").value(&self.name).on_change(Msg::Name).build())
+                .child(View::checkbox(self.subscribe, "Subscribe").on_toggle(Msg::Subscribe))
+                .child(View::button("Quit", Msg::Quit))
+                .build()
+        }
+    }
+
+    fn key(app: &mut TuiApp<Form>, code: KeyCode) {
+        app.handle_event(&Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    }
+
+    fn click(app: &mut TuiApp<Form>, column: u16, row: u16) {
+        app.handle_event(&Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+
+    #[test]
+    fn test_renders_into_buffer() {
+        let app = TuiApp::new(Form::default(), 40, 8);
+        let screen = app.screen();
+        let lines: Vec<&str> = screen.lines().collect();
+
+        assert_eq!(lines[0], "Count: 0");
+        assert_eq!(lines[1], "[ +1 ]");
+        assert_eq!(lines[2], "Name");
+        assert_eq!(lines[3], "[ ] Subscribe");
+        assert_eq!(lines[4], "[ Quit ]");
+
+        // The first button has focus
+        let buffer = app.buffer();
+        assert!(buffer[(0, 1)].modifier.contains(Modifier::REVERSED));
+        assert!(buffer[(0, 2)].modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_keyboard_drives_focused_widget() {
+        let mut app = TuiApp::new(Form::default(), 40, 8);
+        key(&mut app, KeyCode::Enter);
+        key(&mut app, KeyCode::Char(' '));
+        assert_eq!(app.component().count, 2);
+
+        key(&mut app, KeyCode::Tab);
+        for c in "Ann".chars() {
+            key(&mut app, KeyCode::Char(c));
+        }
+        key(&mut app, KeyCode::Backspace);
+        assert_eq!(app.component().name, "An");
+        assert!(app.screen().contains("An"));
+
+        key(&mut app, KeyCode::Tab);
+        key(&mut app, KeyCode::Char(' '));
+        assert!(app.component().subscribe);
+        assert!(app.screen().contains("[x] Subscribe"));
+
+        // Focus wraps around
+        key(&mut app, KeyCode::Tab);
+        key(&mut app, KeyCode::Tab);
+        key(&mut app, KeyCode::Enter);
+        assert_eq!(app.component().count, 3);
+    }
+
+    #[test]
+    fn test_mouse_click_activates() {
+        let mut app = TuiApp::new(Form::default(), 40, 8);
+
+        // On the checkbox label, not its mark
+        click(&mut app, 6, 3);
+        assert!(app.component().subscribe);
+        assert_eq!(app.focused(), app.tree().root().map(|root| root.children[3]));
+
+        click(&mut app, 2, 4);
+        assert!(app.should_exit());
+    }
+
+    #[test]
+    fn test_ctrl_c_exits() {
+        let mut app = TuiApp::new(Form::default(), 40, 8);
+        assert!(!app.should_exit());
+        app.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert!(app.should_exit());
+        assert_eq!(app.component().name, "");
+    }
+
+    #[test]
+    fn test_arrow_keys_step_selection() {
+        #[derive(Debug, Default)]
+        struct Pages {
+            page: usize,
+            volume: f32,
+        }
+
+        #[derive(Debug, Clone)]
+        enum PageMsg {
+            Page(usize),
+            Volume(f32),
+        }
+
+        impl Component for Pages {
+            type Msg = PageMsg;
+
+            fn on(&mut self, msg: PageMsg) {
+                match msg {
+                    PageMsg::Page(page) => self.page = page,
+                    PageMsg::Volume(volume) => self.volume = volume,
+                }
+            }
+
+            fn view(&self) -> View<PageMsg> {
+                View::col()
+                    .child(
+                        View::tabs(vec!["One".to_string(), "Two".to_string()])
+                            .contents(vec![View::text("first"), View::text("second")])
+                            .selected(self.page)
+                            .on_select(PageMsg::Page)
+                            .build(),
+                    )
+                    .child(View::slider(0.0..=10.0, self.volume, PageMsg::Volume).step(1.0).build())
+                    .build()
+            }
+        }
+
+        let mut app = TuiApp::new(Pages::default(), 40, 8);
+        assert!(app.screen().starts_with(" One │ Two\nfirst"));
+
+        app.handle_event(&Event::Key(KeyEvent::from(KeyCode::Right)));
+        assert_eq!(app.component().page, 1);
+        assert!(app.screen().contains("second"));
+
+        app.handle_event(&Event::Key(KeyEvent::from(KeyCode::Tab)));
+        app.handle_event(&Event::Key(KeyEvent::from(KeyCode::Right)));
+        assert_eq!(app.component().volume, 1.0);
+
+        // Clicking the first tab label goes back
+        app.handle_event(&Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 2,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }));
+        assert_eq!(app.component().page, 0);
+    }
+}
@@ -0,0 +1,118 @@
+// Terminal input translation
+//
+// crossterm key presses become auto-ui `KeyEvent`s for keyboard
+// subscriptions, and `Action`s for the focused widget.
+
+use auto_ui::subscription::{KeyEvent, Modifiers};
+use ratatui::crossterm::event::{KeyCode, KeyEvent as TermKeyEvent, KeyEventKind, KeyModifiers};
+
+/// What a key press asks of the focused widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    FocusNext,
+    FocusPrevious,
+    /// Enter / Space: press, toggle or expand
+    Activate,
+    /// Left / Up: previous option, smaller value
+    Previous,
+    /// Right / Down: next option, larger value
+    Next,
+    /// Printable character for the focused input
+    Type(char),
+    Backspace,
+    Quit,
+}
+
+impl Action {
+    pub fn from_key(key: &TermKeyEvent) -> Option<Self> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        Some(match key.code {
+            KeyCode::Char('c') if control => Action::Quit,
+            KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => Action::FocusPrevious,
+            KeyCode::Tab => Action::FocusNext,
+            KeyCode::BackTab => Action::FocusPrevious,
+            KeyCode::Enter => Action::Activate,
+            KeyCode::Left | KeyCode::Up => Action::Previous,
+            KeyCode::Right | KeyCode::Down => Action::Next,
+            KeyCode::Backspace => Action::Backspace,
+            KeyCode::Char(c) if !control => Action::Type(c),
+            _ => return None,
+        })
+    }
+}
+
+/// auto-ui's lowercase name for a terminal key
+///
+/// Matches the names the iced and GPUI backends report, so keyboard
+/// subscriptions work unchanged: `"a"`, `"enter"`, `"escape"`, `"space"`,
+/// `"up"`, `"pagedown"`, `"f1"`, ...
+pub fn key_name(code: &KeyCode) -> Option<String> {
+    Some(match code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_lowercase().collect(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Esc => "escape".to_string(),
+        KeyCode::Tab | KeyCode::BackTab => "tab".to_string(),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Insert => "insert".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        KeyCode::F(n) => format!("f{}", n),
+        _ => return None,
+    })
+}
+
+/// The key press as seen by keyboard subscriptions
+pub(crate) fn key_event(key: &TermKeyEvent) -> Option<KeyEvent> {
+    if key.kind == KeyEventKind::Release {
+        return None;
+    }
+    let modifiers = Modifiers {
+        // Shift+Tab arrives as BackTab without the shift flag on some terminals
+        shift: key.modifiers.contains(KeyModifiers::SHIFT) || key.code == KeyCode::BackTab,
+        control: key.modifiers.contains(KeyModifiers::CONTROL),
+        alt: key.modifiers.contains(KeyModifiers::ALT),
+        logo: key.modifiers.contains(KeyModifiers::SUPER),
+    };
+    Some(KeyEvent::new(key_name(&key.code)?, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_name() {
+        assert_eq!(key_name(&KeyCode::Char('A')), Some("a".to_string()));
+        assert_eq!(key_name(&KeyCode::Char(' ')), Some("space".to_string()));
+        assert_eq!(key_name(&KeyCode::Esc), Some("escape".to_string()));
+        assert_eq!(key_name(&KeyCode::F(5)), Some("f5".to_string()));
+        assert_eq!(key_name(&KeyCode::Null), None);
+
+        let back_tab = key_event(&TermKeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE)).unwrap();
+        assert_eq!(back_tab.key, "tab");
+        assert!(back_tab.modifiers.shift);
+    }
+
+    #[test]
+    fn test_actions() {
+        let press = |code, modifiers| Action::from_key(&TermKeyEvent::new(code, modifiers));
+
+        assert_eq!(press(KeyCode::Char('c'), KeyModifiers::CONTROL), Some(Action::Quit));
+        assert_eq!(press(KeyCode::Char('c'), KeyModifiers::NONE), Some(Action::Type('c')));
+        assert_eq!(press(KeyCode::Tab, KeyModifiers::SHIFT), Some(Action::FocusPrevious));
+        assert_eq!(press(KeyCode::Down, KeyModifiers::NONE), Some(Action::Next));
+        assert_eq!(press(KeyCode::Esc, KeyModifiers::NONE), None);
+    }
+}
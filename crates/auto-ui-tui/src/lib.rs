@@ -0,0 +1,131 @@
+// auto-ui-tui: Terminal backend for auto-ui
+//
+// Renders a Component's View to a terminal through ratatui/crossterm and
+// routes keyboard and mouse input back into `Component::update`, so the same
+// components run over SSH as on the desktop.
+//
+// The view is flattened to a VTree and laid out by `auto_ui::layout` in
+// virtual pixels of 8x16 per cell. Tailwind spacing and the sm:/md:/lg:
+// breakpoints keep their desktop meaning that way: an 80-column terminal is
+// 640px wide. Every VNode is then drawn into the cells its rectangle covers.
+//
+// Input:
+// - Tab / Shift+Tab move focus between interactive widgets; a click focuses
+//   and activates the widget under the mouse
+// - Enter / Space press buttons, toggle checkboxes and accordion sections
+// - Arrow keys step sliders, selects, radio groups, tabs and navigation rails
+// - Typing edits the focused input
+// - Ctrl+C quits, like `Command::exit`
+//
+// `TuiApp` holds the whole runtime state and draws into any ratatui Buffer,
+// so components can be tested against an in-memory buffer without a terminal.
+
+mod app;
+mod input;
+mod render;
+mod sources;
+
+pub use app::TuiApp;
+pub use input::key_name;
+
+use auto_ui::Component;
+use ratatui::crossterm::event::{self, DisableMouseCapture, EnableMouseCapture};
+use ratatui::crossterm::execute;
+use ratatui::DefaultTerminal;
+use sources::Sources;
+use std::fmt::Debug;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Width of a terminal cell in layout pixels
+pub const CELL_WIDTH: f32 = 8.0;
+/// Height of a terminal cell in layout pixels
+pub const CELL_HEIGHT: f32 = 16.0;
+
+/// Longest wait for input, so finished `Command::perform` tasks show up promptly
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Run an auto-ui Component in the terminal
+///
+/// Takes over the terminal (raw mode, alternate screen, mouse capture) until
+/// the component returns `Command::exit` or the user presses Ctrl+C.
+///
+/// # Example
+/// ```no_run
+/// use auto_ui::{Component, View};
+/// use auto_ui_tui::run_app;
+///
+/// #[derive(Debug, Default)]
+/// struct MyComponent;
+///
+/// impl Component for MyComponent {
+///     type Msg = ();
+///     fn on(&mut self, _msg: Self::Msg) {}
+///     fn view(&self) -> View<Self::Msg> {
+///         View::text("Hello!")
+///     }
+/// }
+///
+/// fn main() -> auto_ui::AppResult<()> {
+///     run_app::<MyComponent>()
+/// }
+/// ```
+pub fn run_app<C>() -> auto_ui::AppResult<()>
+where
+    C: Component + Default + 'static,
+    C::Msg: Clone + Debug + Send + 'static,
+{
+    let runtime = tokio::runtime::Runtime::new()?;
+    let mut terminal = ratatui::init();
+    execute!(std::io::stdout(), EnableMouseCapture)?;
+
+    let result = event_loop::<C>(&mut terminal, &runtime);
+
+    let _ = execute!(std::io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+    result
+}
+
+fn event_loop<C>(terminal: &mut DefaultTerminal, runtime: &tokio::runtime::Runtime) -> auto_ui::AppResult<()>
+where
+    C: Component + Default + 'static,
+    C::Msg: Clone + Debug + Send + 'static,
+{
+    let size = terminal.size()?;
+    let mut app = TuiApp::new(C::default(), size.width, size.height);
+    let mut sources = Sources::default();
+    let (results, finished) = mpsc::channel();
+
+    loop {
+        // `Command::perform` futures run on tokio; their messages come back here
+        for task in app.take_tasks() {
+            let results = results.clone();
+            runtime.spawn(async move {
+                let _ = results.send(task.await);
+            });
+        }
+        if app.should_exit() {
+            return Ok(());
+        }
+
+        terminal.draw(|frame| frame.render_widget(&app, frame.area()))?;
+
+        let subscription = app.component().subscription();
+        let now = Instant::now();
+        sources.sync(&subscription, now);
+        let timeout = sources
+            .next_deadline()
+            .map(|deadline| deadline.saturating_duration_since(now).min(POLL_INTERVAL))
+            .unwrap_or(POLL_INTERVAL);
+
+        if event::poll(timeout)? {
+            app.handle_event(&event::read()?);
+        }
+        while let Ok(msg) = finished.try_recv() {
+            app.send(msg);
+        }
+        for event in sources.poll(&subscription, Instant::now()) {
+            app.dispatch(event);
+        }
+    }
+}
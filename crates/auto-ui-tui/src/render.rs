@@ -0,0 +1,480 @@
+// Drawing VNodes into terminal cells
+//
+// Layout rectangles are in 8x16 virtual pixels; they are rounded to cells and
+// every rendered node paints its own cells, parents before children. Widgets
+// are drawn with plain characters so they read well on any terminal:
+//
+//   [ Save ]   [x] Remember me   (•) Small   < Pro >   ──●─────   ███░░░
+//
+// Classes that have a terminal equivalent are honoured: background and text
+// colors, `font-bold`/`font-medium`, `border`, `text-center`/`text-right`,
+// and `focus:`/`disabled:` variants.
+
+use crate::{CELL_HEIGHT, CELL_WIDTH};
+use auto_ui::layout::{LayoutResult, Rect};
+use auto_ui::style::{Color, StyleClass, StyleState, Theme};
+use auto_ui::view::{SidebarPosition, TabsPosition};
+use auto_ui::vnode::{VNode, VNodeId, VNodeProps, VTree};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect as Area;
+use ratatui::style::{Color as TermColor, Modifier, Style as TermStyle};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Widget};
+
+/// Width of inputs, sliders and progress bars without an explicit width
+const FIELD_COLS: f32 = 20.0;
+/// Shown by a select with nothing chosen
+const SELECT_PLACEHOLDER: &str = "Select...";
+
+/// Focus state the renderer highlights
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Focus {
+    pub id: Option<VNodeId>,
+    /// Highlighted section of a focused accordion
+    pub section: usize,
+}
+
+/// Cell size of a leaf widget, in layout pixels
+///
+/// Passed to `LayoutEngine::measure`, so layout and drawing agree on every
+/// widget's footprint.
+pub(crate) fn measure(node: &VNode, max_width: Option<f32>) -> (f32, f32) {
+    let size = |cols: f32, rows: f32| (cols * CELL_WIDTH, rows * CELL_HEIGHT);
+    let widest = |texts: &mut dyn Iterator<Item = &str>| texts.map(columns).max().unwrap_or(0) as f32;
+
+    match &node.props {
+        VNodeProps::Text { content } => {
+            let max_cols = max_width.map(|width| (width / CELL_WIDTH).floor().max(1.0) as usize);
+            let lines = wrap(content, max_cols);
+            let width = lines.iter().map(|line| columns(line)).max().unwrap_or(0);
+            size(width as f32, lines.len() as f32)
+        }
+        VNodeProps::Button { label, .. }
+        | VNodeProps::Checkbox { label, .. }
+        | VNodeProps::Radio { label, .. } => size(columns(label) as f32 + 4.0, 1.0),
+        VNodeProps::Input { width, .. } => (
+            width.map(f32::from).unwrap_or(FIELD_COLS * CELL_WIDTH),
+            CELL_HEIGHT,
+        ),
+        VNodeProps::RadioGroup { options, .. } => size(
+            widest(&mut options.iter().map(String::as_str)) + 4.0,
+            options.len() as f32,
+        ),
+        VNodeProps::Select { options, .. } => size(
+            widest(&mut options.iter().map(String::as_str).chain([SELECT_PLACEHOLDER])) + 4.0,
+            1.0,
+        ),
+        VNodeProps::Slider { .. } | VNodeProps::ProgressBar { .. } => size(FIELD_COLS, 1.0),
+        VNodeProps::NavigationRail { items, width, show_labels, .. } => {
+            let labels = if *show_labels {
+                widest(&mut items.iter().map(|item| item.label.as_str())) + 1.0
+            } else {
+                0.0
+            };
+            (width.max((labels + 2.0) * CELL_WIDTH), items.len() as f32 * CELL_HEIGHT)
+        }
+        // Measured for the tab bar: a row of labels, or a column when on the side
+        VNodeProps::Tabs { labels, position, .. } => match position {
+            TabsPosition::Top | TabsPosition::Bottom => {
+                let end = tab_spans(labels).last().map_or(0, |(start, width)| start + width);
+                size(end as f32, 1.0)
+            }
+            TabsPosition::Left | TabsPosition::Right => size(
+                widest(&mut labels.iter().map(String::as_str)) + 2.0,
+                labels.len() as f32,
+            ),
+        },
+        // Measured for one section header
+        VNodeProps::Accordion { .. } => size(0.0, 1.0),
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Columns `(start, width)` of each label in a horizontal tab bar
+///
+/// Labels are padded by a space on each side and separated by `│`.
+pub(crate) fn tab_spans(labels: &[String]) -> Vec<(u16, u16)> {
+    let mut start = 0;
+    labels
+        .iter()
+        .map(|label| {
+            let width = columns(label) as u16 + 2;
+            let span = (start, width);
+            start += width + 1;
+            span
+        })
+        .collect()
+}
+
+/// Round a layout rectangle to the cells it covers inside `area`
+pub(crate) fn to_cells(rect: Rect, area: Area) -> Area {
+    let col = |x: f32| (x / CELL_WIDTH).round() as u16;
+    let row = |y: f32| (y / CELL_HEIGHT).round() as u16;
+    let (x, y) = (col(rect.x), row(rect.y));
+    Area::new(
+        area.x.saturating_add(x),
+        area.y.saturating_add(y),
+        col(rect.right()).saturating_sub(x),
+        row(rect.bottom()).saturating_sub(y),
+    )
+    .intersection(area)
+}
+
+/// Draw every rendered node, parents first
+pub(crate) fn draw(tree: &VTree, layout: &LayoutResult, focus: Focus, area: Area, buf: &mut Buffer) {
+    let theme = Theme::active();
+    let window_width = f32::from(area.width) * CELL_WIDTH;
+
+    for (id, rect) in layout.iter() {
+        let Some(node) = tree.get(id) else { continue };
+        let cells = to_cells(rect, area);
+        let painter = Painter {
+            node,
+            cells,
+            focused: focus.id == Some(id),
+            look: Look::of(node, focus.id == Some(id), is_header_cell(tree, node), &theme, window_width),
+        };
+        painter.paint(layout, area, focus.section, buf);
+    }
+}
+
+/// Terminal rendering of a node's style
+#[derive(Debug, Default)]
+struct Look {
+    text: TermStyle,
+    background: Option<TermColor>,
+    border: Option<TermStyle>,
+    align: Align,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl Look {
+    fn of(node: &VNode, focused: bool, bold: bool, theme: &Theme, window_width: f32) -> Self {
+        let mut look = Look::default();
+        if bold {
+            look.text = look.text.add_modifier(Modifier::BOLD);
+        }
+        if is_disabled(node) {
+            look.text = look.text.add_modifier(Modifier::DIM);
+        }
+        let Some(style) = &node.style else {
+            return look;
+        };
+
+        let mut states = Vec::new();
+        if focused {
+            states.push(StyleState::Focus);
+        }
+        if is_disabled(node) {
+            states.push(StyleState::Disabled);
+        }
+        let style = style.at_width(window_width).for_states(&states);
+
+        let mut border_color = None;
+        for class in style.classes.iter() {
+            match class {
+                StyleClass::BackgroundColor(color) => look.background = Some(term_color(theme, color)),
+                StyleClass::TextColor(color) => look.text = look.text.fg(term_color(theme, color)),
+                StyleClass::FontBold | StyleClass::FontMedium => {
+                    look.text = look.text.add_modifier(Modifier::BOLD)
+                }
+                StyleClass::FontNormal => look.text = look.text.remove_modifier(Modifier::BOLD),
+                StyleClass::Opacity(percent) if *percent < 50 => {
+                    look.text = look.text.add_modifier(Modifier::DIM)
+                }
+                StyleClass::Border => look.border = Some(TermStyle::default()),
+                StyleClass::Border0 => look.border = None,
+                StyleClass::BorderColor(color) => border_color = Some(term_color(theme, color)),
+                StyleClass::TextLeft => look.align = Align::Left,
+                StyleClass::TextCenter => look.align = Align::Center,
+                StyleClass::TextRight => look.align = Align::Right,
+                _ => {}
+            }
+        }
+        if let (Some(border), Some(color)) = (&mut look.border, border_color) {
+            *border = border.fg(color);
+        }
+        look
+    }
+}
+
+struct Painter<'a> {
+    node: &'a VNode,
+    cells: Area,
+    focused: bool,
+    look: Look,
+}
+
+impl Painter<'_> {
+    fn paint(&self, layout: &LayoutResult, area: Area, section: usize, buf: &mut Buffer) {
+        let cells = self.cells;
+        if let Some(background) = self.look.background {
+            buf.set_style(cells, TermStyle::default().bg(background));
+        }
+        if let Some(border) = self.look.border {
+            Block::bordered().border_style(border).render(cells, buf);
+        }
+
+        let text = self.look.text;
+        let active = if self.focused { text.add_modifier(Modifier::REVERSED) } else { text };
+
+        match &self.node.props {
+            VNodeProps::Text { content } => {
+                for (row, line) in wrap(content, Some(usize::from(cells.width))).iter().enumerate() {
+                    self.line(buf, row as u16, line, text);
+                }
+            }
+            VNodeProps::Button { label, .. } => {
+                self.line(buf, 0, &format!("[ {} ]", label), active);
+            }
+            VNodeProps::Input { placeholder, value, password, .. } => {
+                let field = Area { height: cells.height.min(1), ..cells };
+                buf.set_style(field, text.add_modifier(Modifier::UNDERLINED));
+
+                let shown = if *password { "*".repeat(value.chars().count()) } else { value.clone() };
+                let width = usize::from(cells.width);
+                if shown.is_empty() && !self.focused {
+                    buf.set_stringn(cells.x, cells.y, placeholder, width, text.add_modifier(Modifier::DIM));
+                } else {
+                    // Keep the end of long values, and room for the cursor, in view
+                    let skip = (columns(&shown) + 1).saturating_sub(width);
+                    let tail: String = shown.chars().skip(skip).collect();
+                    let end = buf.set_stringn(cells.x, cells.y, &tail, width, text).0;
+                    if self.focused && end < cells.right() {
+                        buf.set_style(Area::new(end, cells.y, 1, 1), active);
+                    }
+                }
+            }
+            VNodeProps::Checkbox { label, is_checked } => {
+                self.mark(buf, 0, if *is_checked { "[x]" } else { "[ ]" }, label, active);
+            }
+            VNodeProps::Radio { label, is_selected } => {
+                self.mark(buf, 0, radio_mark(*is_selected), label, active);
+            }
+            VNodeProps::RadioGroup { options, selected_index } => {
+                for (row, option) in options.iter().enumerate() {
+                    let selected = *selected_index == Some(row);
+                    let mark_style = if selected || selected_index.is_none() && row == 0 { active } else { text };
+                    self.mark(buf, row as u16, radio_mark(selected), option, mark_style);
+                }
+            }
+            VNodeProps::Select { options, selected_index } => {
+                let choice = selected_index
+                    .and_then(|index| options.get(index))
+                    .map_or(SELECT_PLACEHOLDER, String::as_str);
+                self.line(buf, 0, &format!("< {} >", choice), active);
+            }
+            VNodeProps::Slider { min, max, value, .. } => {
+                let width = usize::from(cells.width);
+                let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+                let knob = (fraction.clamp(0.0, 1.0) * width.saturating_sub(1) as f32).round() as usize;
+                let track: String = (0..width).map(|i| if i == knob { '●' } else { '─' }).collect();
+                self.line(buf, 0, &track, text);
+                if width > 0 {
+                    buf.set_style(Area::new(cells.x + knob as u16, cells.y, 1, 1), active);
+                }
+            }
+            VNodeProps::ProgressBar { progress } => {
+                let width = usize::from(cells.width);
+                let filled = (progress.clamp(0.0, 1.0) * width as f32).round() as usize;
+                let bar = "█".repeat(filled) + &"░".repeat(width - filled);
+                self.line(buf, 0, &bar, text);
+            }
+            VNodeProps::NavigationRail { items, selected, show_labels, .. } => {
+                for (row, item) in items.iter().enumerate() {
+                    let mut entry = item.icon.to_string();
+                    if *show_labels {
+                        entry = format!("{} {}", entry, item.label);
+                    }
+                    if let Some(badge) = &item.badge {
+                        entry = format!("{} ({})", entry, badge);
+                    }
+                    let style = if row == *selected { selected_style(text, self.focused) } else { text };
+                    self.line(buf, row as u16, &entry, style);
+                }
+            }
+            VNodeProps::Tabs { labels, selected, position } => {
+                let Some(bar) = layout.headers(self.node.id).first().map(|rect| to_cells(*rect, area)) else {
+                    return;
+                };
+                let style_of = |index: usize| {
+                    if index == *selected { selected_style(text, self.focused) } else { text }
+                };
+                match position {
+                    TabsPosition::Top | TabsPosition::Bottom => {
+                        for (index, (start, width)) in tab_spans(labels).into_iter().enumerate() {
+                            if start > 0 {
+                                buf.set_stringn(bar.x + start - 1, bar.y, "│", 1, text);
+                            }
+                            let label = format!(" {} ", labels[index]);
+                            let room = bar.width.saturating_sub(start);
+                            buf.set_stringn(bar.x + start, bar.y, &label, usize::from(room.min(width)), style_of(index));
+                        }
+                    }
+                    TabsPosition::Left | TabsPosition::Right => {
+                        for (index, label) in labels.iter().enumerate().take(usize::from(bar.height)) {
+                            let row = bar.y + index as u16;
+                            buf.set_stringn(bar.x, row, format!(" {} ", label), usize::from(bar.width), style_of(index));
+                        }
+                    }
+                }
+            }
+            VNodeProps::Accordion { items, .. } => {
+                for (index, (item, header)) in items.iter().zip(layout.headers(self.node.id)).enumerate() {
+                    let header = to_cells(*header, area);
+                    let marker = if item.expanded { '▾' } else { '▸' };
+                    let title = match item.icon {
+                        Some(icon) => format!("{} {} {}", marker, icon, item.title),
+                        None => format!("{} {}", marker, item.title),
+                    };
+                    let style = if self.focused && index == section { active } else { text.add_modifier(Modifier::BOLD) };
+                    buf.set_stringn(header.x, header.y, &title, usize::from(header.width), style);
+                }
+            }
+            VNodeProps::Sidebar { position, .. } if cells.width > 0 => {
+                let edge = match position {
+                    SidebarPosition::Left => cells.right() - 1,
+                    SidebarPosition::Right => cells.x,
+                };
+                for row in cells.top()..cells.bottom() {
+                    buf.set_stringn(edge, row, "│", 1, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// One line of text at `row`, aligned and clipped to the node's cells
+    fn line(&self, buf: &mut Buffer, row: u16, content: &str, style: TermStyle) {
+        let cells = self.cells;
+        if row >= cells.height {
+            return;
+        }
+        let slack = cells.width.saturating_sub(columns(content) as u16);
+        let offset = match self.look.align {
+            Align::Left => 0,
+            Align::Center => slack / 2,
+            Align::Right => slack,
+        };
+        buf.set_stringn(cells.x + offset, cells.y + row, content, usize::from(cells.width - offset), style);
+    }
+
+    /// `mark label`, with only the mark in `mark_style`
+    fn mark(&self, buf: &mut Buffer, row: u16, mark: &str, label: &str, mark_style: TermStyle) {
+        let cells = self.cells;
+        if row >= cells.height {
+            return;
+        }
+        let width = usize::from(cells.width);
+        let (end, _) = buf.set_stringn(cells.x, cells.y + row, mark, width, mark_style);
+        let room = width.saturating_sub(usize::from(end - cells.x));
+        buf.set_stringn(end, cells.y + row, format!(" {}", label), room, self.look.text);
+    }
+}
+
+/// Selected entry of a tab bar or navigation rail
+fn selected_style(text: TermStyle, focused: bool) -> TermStyle {
+    if focused {
+        text.add_modifier(Modifier::REVERSED)
+    } else {
+        text.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    }
+}
+
+fn radio_mark(selected: bool) -> &'static str {
+    if selected {
+        "(•)"
+    } else {
+        "( )"
+    }
+}
+
+fn is_disabled(node: &VNode) -> bool {
+    matches!(
+        node.props,
+        VNodeProps::Button { disabled: true, .. } | VNodeProps::Input { disabled: true, .. }
+    )
+}
+
+/// Whether the node is one of its table's header cells
+fn is_header_cell(tree: &VTree, node: &VNode) -> bool {
+    let Some(parent) = node.parent.and_then(|id| tree.get(id)) else {
+        return false;
+    };
+    match parent.props {
+        VNodeProps::Table { headers, .. } => parent
+            .children
+            .iter()
+            .position(|child| *child == node.id)
+            .is_some_and(|index| index < headers),
+        _ => false,
+    }
+}
+
+fn term_color(theme: &Theme, color: &Color) -> TermColor {
+    let (r, g, b, _) = theme.rgba(color);
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    TermColor::Rgb(channel(r), channel(g), channel(b))
+}
+
+/// Display width of a string in cells
+fn columns(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Split text into lines of at most `max_cols` cells, breaking anywhere
+fn wrap(text: &str, max_cols: Option<usize>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for source in text.split('\n') {
+        let Some(max) = max_cols.filter(|max| *max > 0) else {
+            lines.push(source.to_string());
+            continue;
+        };
+
+        let mut line = String::new();
+        for c in source.chars() {
+            let mut buf = [0; 4];
+            if !line.is_empty() && columns(&line) + columns(c.encode_utf8(&mut buf)) > max {
+                lines.push(std::mem::take(&mut line));
+            }
+            line.push(c);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_breaks_at_width() {
+        assert_eq!(wrap("abcdef", Some(4)), vec!["abcd", "ef"]);
+        assert_eq!(wrap("ab\ncd", None), vec!["ab", "cd"]);
+        assert_eq!(wrap("", Some(3)), vec![""]);
+    }
+
+    #[test]
+    fn test_to_cells_rounds_edges() {
+        let area = Area::new(0, 0, 80, 24);
+        let cells = to_cells(Rect::new(4.0, 26.0, 40.0, 16.0), area);
+        assert_eq!(cells, Area::new(1, 2, 5, 1));
+
+        let clipped = to_cells(Rect::new(600.0, 0.0, 100.0, 16.0), area);
+        assert_eq!(clipped.right(), 80);
+    }
+
+    #[test]
+    fn test_tab_spans() {
+        let labels = vec!["One".to_string(), "Three".to_string()];
+        assert_eq!(tab_spans(&labels), vec![(0, 5), (6, 7)]);
+    }
+}
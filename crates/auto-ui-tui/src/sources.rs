@@ -0,0 +1,125 @@
+// Subscription sources for the terminal event loop
+//
+// Timers and file watchers live as long as the component keeps declaring
+// them, identified by `SourceKey`. Events are addressed by the source's
+// index in the current subscription, as `Subscription::dispatch` expects.
+// Keyboard and resize sources need no state: the app dispatches those from
+// terminal events directly.
+
+use auto_ui::subscription::{watch_path, Source, SourceKey, Subscription, SubscriptionEvent};
+use notify::RecommendedWatcher;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+#[derive(Default)]
+pub(crate) struct Sources {
+    /// Next firing time per timer interval
+    timers: HashMap<Duration, Instant>,
+    watchers: HashMap<PathBuf, (RecommendedWatcher, UnboundedReceiver<PathBuf>)>,
+}
+
+impl Sources {
+    /// Start newly declared sources and stop the ones no longer declared
+    pub fn sync<M: 'static>(&mut self, subscription: &Subscription<M>, now: Instant) {
+        let keys = subscription.keys();
+
+        self.timers
+            .retain(|interval, _| keys.contains(&SourceKey::Every(*interval)));
+        self.watchers
+            .retain(|path, _| keys.contains(&SourceKey::FileChanged(path.clone())));
+
+        for key in keys {
+            match key {
+                SourceKey::Every(interval) => {
+                    self.timers.entry(interval).or_insert(now + interval);
+                }
+                SourceKey::FileChanged(path) if !self.watchers.contains_key(&path) => {
+                    // A file that can't be watched (yet) is retried on the next sync
+                    if let Ok(watch) = watch_path(&path) {
+                        self.watchers.insert(path, watch);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// When the earliest timer fires
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers.values().min().copied()
+    }
+
+    /// Events that are due, in source order
+    pub fn poll<M: 'static>(&mut self, subscription: &Subscription<M>, now: Instant) -> Vec<SubscriptionEvent> {
+        let due: Vec<Duration> = self
+            .timers
+            .iter()
+            .filter(|(_, next)| **next <= now)
+            .map(|(interval, _)| *interval)
+            .collect();
+        let changed: HashMap<PathBuf, usize> = self
+            .watchers
+            .iter_mut()
+            .map(|(path, (_, changes))| {
+                let mut count = 0;
+                while changes.try_recv().is_ok() {
+                    count += 1;
+                }
+                (path.clone(), count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        let mut events = Vec::new();
+        for (index, source) in subscription.sources().iter().enumerate() {
+            match source {
+                Source::Every { interval, .. } if due.contains(interval) => {
+                    events.push(SubscriptionEvent::Tick { index, at: now });
+                }
+                Source::FileChanged { path, .. } if changed.contains_key(path) => {
+                    events.push(SubscriptionEvent::FileChanged {
+                        index,
+                        path: path.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // Skip missed ticks instead of firing them in a burst
+        for interval in due {
+            self.timers.insert(interval, now + interval);
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use auto_ui::subscription::{every, keyboard};
+
+    #[test]
+    fn test_timers_fire_by_index() {
+        let subscription: Subscription<u8> = Subscription::batch([
+            keyboard().map(|_| 0),
+            every(Duration::from_millis(100)).map(|_| 1),
+        ]);
+        let start = Instant::now();
+        let mut sources = Sources::default();
+        sources.sync(&subscription, start);
+
+        assert!(sources.poll(&subscription, start).is_empty());
+        assert_eq!(sources.next_deadline(), Some(start + Duration::from_millis(100)));
+
+        let later = start + Duration::from_millis(350);
+        let events = sources.poll(&subscription, later);
+        assert!(matches!(events.as_slice(), [SubscriptionEvent::Tick { index: 1, .. }]));
+        assert_eq!(sources.next_deadline(), Some(later + Duration::from_millis(100)));
+
+        sources.sync(&Subscription::<u8>::none(), later);
+        assert_eq!(sources.next_deadline(), None);
+    }
+}
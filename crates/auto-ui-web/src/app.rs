@@ -0,0 +1,667 @@
+// Server-side state of a component shown in the browser
+//
+// `WebApp` keeps the component and the VTree the browser currently shows.
+// After every message the new view is diffed against that tree; the patches
+// are applied to it (keeping node IDs stable, as `vnode_diff` guarantees) and
+// translated into `DomOp`s addressed by `data-id`.
+//
+// Browser events carry those same IDs. The handlers from
+// `view_to_vtree_with_handlers` are keyed by the fresh tree's IDs, so they
+// are re-keyed onto the patched tree, which has the same shape.
+
+use crate::html::{self, Shape};
+use crate::style::{self, ClassRule, StyleMode, LAYERS};
+use auto_ui::command::CommandFuture;
+use auto_ui::vnode::{VNodeId, VNodeProps, VTree};
+use auto_ui::vnode_converter::{view_to_vtree_with_handlers, VNodeHandler, VNodeHandlers};
+use auto_ui::vnode_diff::Patch;
+use auto_ui::{Command, Component, Theme};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Tailwind's Play CDN build, which styles classes as they appear in the DOM
+const TAILWIND_CDN: &str = "https://cdn.tailwindcss.com";
+
+/// Browser script applying `DomOp`s and reporting `ClientEvent`s
+const CLIENT_JS: &str = include_str!("client.js");
+
+/// An event from the browser, addressed by `data-id`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ClientEvent {
+    /// Button press, checkbox or radio toggle
+    Click { id: u64 },
+    /// New text of an input, or value of a slider
+    Input { id: u64, value: String },
+    /// Option of a select or radio group, tab, rail item, or accordion section
+    Select { id: u64, index: usize },
+}
+
+impl ClientEvent {
+    fn id(&self) -> VNodeId {
+        match self {
+            ClientEvent::Click { id } | ClientEvent::Input { id, .. } | ClientEvent::Select { id, .. } => {
+                VNodeId::new(*id)
+            }
+        }
+    }
+}
+
+/// A DOM update for connected browsers
+///
+/// Elements are addressed by `data-id`; `html` is the outer HTML of a node.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum DomOp {
+    /// Replace the whole page content
+    Root { html: String },
+    /// Insert an element into `parent`, before `before` or at the end
+    Insert { parent: u64, before: Option<u64>, html: String },
+    Remove { id: u64 },
+    Move { parent: u64, id: u64, before: Option<u64> },
+    Replace { id: u64, html: String },
+    /// New content of a text element
+    Text { id: u64, content: String },
+    /// New attributes of an element; `None` removes the attribute
+    Attrs { id: u64, attrs: Vec<(String, Option<String>)> },
+    /// Rules for classes that appeared since the page was built
+    Css { layer: String, rules: String },
+    /// The theme changed: reload the page
+    Reload,
+}
+
+/// A component served to the browser
+pub struct WebApp<C: Component> {
+    component: C,
+    /// The tree the browser shows, with patched IDs
+    tree: VTree,
+    handlers: VNodeHandlers<C::Msg>,
+    mode: StyleMode,
+    theme: Arc<Theme>,
+    /// Every CSS rule sent so far, in order
+    rules: Vec<ClassRule>,
+    known: HashSet<String>,
+    tasks: Vec<CommandFuture<C::Msg>>,
+    exit: bool,
+}
+
+impl<C: Component> WebApp<C> {
+    pub fn new(component: C, mode: StyleMode) -> Self {
+        let (tree, handlers) = view_to_vtree_with_handlers(component.view());
+        let mut app = Self {
+            component,
+            tree,
+            handlers,
+            mode,
+            theme: Theme::active(),
+            rules: Vec::new(),
+            known: HashSet::new(),
+            tasks: Vec::new(),
+            exit: false,
+        };
+        app.new_rules();
+        app
+    }
+
+    pub fn component(&self) -> &C {
+        &self.component
+    }
+
+    /// The tree the browser shows; its IDs are the `data-id`s of the page
+    pub fn tree(&self) -> &VTree {
+        &self.tree
+    }
+
+    /// Whether the component returned `Command::exit`
+    pub fn should_exit(&self) -> bool {
+        self.exit
+    }
+
+    /// Futures from `Command::perform` since the last call
+    ///
+    /// Their outputs go back in through `send`.
+    pub fn take_tasks(&mut self) -> Vec<CommandFuture<C::Msg>> {
+        std::mem::take(&mut self.tasks)
+    }
+
+    /// The full HTML document for a newly connecting browser
+    pub fn page(&self) -> String {
+        let title = std::any::type_name::<C>().rsplit("::").next().unwrap_or("auto-ui");
+        let mut head = format!(
+            "<meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+<title>{}</title><style id=\"auto-ui-base\">{}</style>",
+            html::escape(title),
+            html::base_css(&self.theme)
+        );
+        match self.mode {
+            StyleMode::Css => {
+                for (layer, name) in LAYERS.iter().enumerate() {
+                    head.push_str(&format!("<style data-layer=\"{}\">{}</style>", name, self.layer_css(layer)));
+                }
+            }
+            StyleMode::Tailwind => head.push_str(&format!("<script src=\"{}\"></script>", TAILWIND_CDN)),
+        }
+
+        format!(
+            "<!DOCTYPE html><html><head>{}</head><body><div id=\"app\">{}</div><script>{}</script></body></html>",
+            head,
+            self.body(),
+            CLIENT_JS
+        )
+    }
+
+    /// HTML of the current view
+    pub fn body(&self) -> String {
+        self.tree
+            .root()
+            .map(|root| html::render(&self.tree, root.id, &self.theme))
+            .unwrap_or_default()
+    }
+
+    /// Ops bringing a page built from any earlier state up to date
+    pub fn snapshot(&self) -> Vec<DomOp> {
+        let mut ops = vec![DomOp::Root { html: self.body() }];
+        if self.mode == StyleMode::Css {
+            ops.extend(LAYERS.iter().enumerate().filter_map(|(layer, name)| {
+                let rules = self.layer_css(layer);
+                (!rules.is_empty()).then(|| DomOp::Css {
+                    layer: name.to_string(),
+                    rules,
+                })
+            }));
+        }
+        ops
+    }
+
+    fn layer_css(&self, layer: usize) -> String {
+        self.rules
+            .iter()
+            .filter(|rule| rule.layer == layer)
+            .map(|rule| rule.css.as_str())
+            .collect()
+    }
+
+    /// Handle a browser event, returning the DOM updates for every browser
+    ///
+    /// Events for unknown or handler-less nodes are ignored. Inputs and
+    /// selects are controlled: when the component keeps their old value, the
+    /// returned ops put it back in the browser that changed it.
+    pub fn handle(&mut self, event: &ClientEvent) -> Vec<DomOp> {
+        let Some(msg) = self.message(event) else {
+            return Vec::new();
+        };
+        let id = event.id();
+        let mut ops = self.send(msg);
+
+        let touched = ops.iter().any(|op| op_target(op) == Some(id.as_u64()));
+        if !touched && !matches!(event, ClientEvent::Click { .. }) {
+            if let Some(node) = self.tree.get(id) {
+                ops.push(match html::shape(node) {
+                    Shape::Leaf if matches!(node.props, VNodeProps::Input { .. } | VNodeProps::Slider { .. }) => {
+                        attrs_op(node, &self.theme)
+                    }
+                    _ => DomOp::Replace {
+                        id: id.as_u64(),
+                        html: html::render(&self.tree, id, &self.theme),
+                    },
+                });
+            }
+        }
+        ops
+    }
+
+    /// Deliver a message to the component, returning the DOM updates
+    pub fn send(&mut self, msg: C::Msg) -> Vec<DomOp> {
+        let command = self.component.update(msg);
+        self.run(command);
+        if !Arc::ptr_eq(&self.theme, &Theme::active()) {
+            self.theme = Theme::active();
+            self.rebuild();
+            return vec![DomOp::Reload];
+        }
+        self.refresh()
+    }
+
+    fn run(&mut self, command: Command<C::Msg>) {
+        match command {
+            Command::None => {}
+            Command::Batch(commands) => commands.into_iter().for_each(|command| self.run(command)),
+            Command::Perform(future) => self.tasks.push(future),
+            Command::Exit => self.exit = true,
+            Command::SetTheme(theme) => Theme::set_active(theme),
+        }
+    }
+
+    fn message(&self, event: &ClientEvent) -> Option<C::Msg> {
+        let id = event.id();
+        let props = &self.tree.get(id)?.props;
+        let handler = self.handlers.get(&id)?;
+
+        match (event, handler, props) {
+            (_, _, VNodeProps::Button { disabled: true, .. } | VNodeProps::Input { disabled: true, .. }) => None,
+            (ClientEvent::Click { .. }, VNodeHandler::Message(msg), _) => Some(msg.clone()),
+            (ClientEvent::Input { value, .. }, VNodeHandler::Input(on_change), _) => Some(on_change.call(value.clone())),
+            (ClientEvent::Input { value, .. }, VNodeHandler::Slider(on_change), _) => {
+                value.parse().ok().map(|value| on_change.call(value))
+            }
+            (
+                ClientEvent::Select { index, .. },
+                VNodeHandler::Select(on_select),
+                VNodeProps::Select { options, .. } | VNodeProps::RadioGroup { options, .. },
+            ) => options.get(*index).map(|option| on_select.call(*index, option)),
+            (ClientEvent::Select { index, .. }, VNodeHandler::TabsSelect(on_select), VNodeProps::Tabs { labels, .. }) => {
+                (*index < labels.len()).then(|| on_select.call(*index))
+            }
+            (
+                ClientEvent::Select { index, .. },
+                VNodeHandler::NavigationRailSelect(on_select),
+                VNodeProps::NavigationRail { items, .. },
+            ) => (*index < items.len()).then(|| on_select.call(*index)),
+            (
+                ClientEvent::Select { index, .. },
+                VNodeHandler::AccordionToggle(on_toggle),
+                VNodeProps::Accordion { items, .. },
+            ) => items.get(*index).map(|item| on_toggle.call(*index, !item.expanded)),
+            _ => None,
+        }
+    }
+
+    /// Bring the tree up to date with the view, returning the DOM updates
+    fn refresh(&mut self) -> Vec<DomOp> {
+        let (fresh, handlers) = view_to_vtree_with_handlers(self.component.view());
+        let patches = VTree::diff(&self.tree, &fresh);
+        let old = self.tree.clone();
+
+        let mut ops = match self.tree.apply(patches.clone()) {
+            Ok(()) => Translator::new(&old, &self.tree, &self.theme).translate(&patches),
+            Err(e) => {
+                // Same fallback as the GPUI renderer: start over from the fresh tree
+                eprintln!("⚠️ Failed to apply VTree patches, re-rendering: {}", e);
+                self.tree = fresh.clone();
+                vec![DomOp::Root { html: self.body() }]
+            }
+        };
+
+        let ids = align(&fresh, &self.tree);
+        self.handlers = handlers
+            .into_iter()
+            .filter_map(|(id, handler)| Some((*ids.get(&id)?, handler)))
+            .collect();
+        ops.extend(self.new_rules());
+        ops
+    }
+
+    /// Start over from the current view, e.g. after a theme change
+    fn rebuild(&mut self) {
+        let (tree, handlers) = view_to_vtree_with_handlers(self.component.view());
+        self.tree = tree;
+        self.handlers = handlers;
+        self.rules.clear();
+        self.known.clear();
+        self.new_rules();
+    }
+
+    /// CSS for classes of the current tree that no browser has yet
+    fn new_rules(&mut self) -> Vec<DomOp> {
+        if self.mode != StyleMode::Css {
+            return Vec::new();
+        }
+
+        let mut layers = vec![String::new(); LAYERS.len()];
+        for node in self.tree.nodes() {
+            let Some(node_style) = &node.style else { continue };
+            for rule in style::rules(node_style, &self.theme) {
+                if self.known.insert(rule.name.clone()) {
+                    layers[rule.layer].push_str(&rule.css);
+                    self.rules.push(rule);
+                }
+            }
+        }
+
+        layers
+            .into_iter()
+            .zip(LAYERS)
+            .filter(|(rules, _)| !rules.is_empty())
+            .map(|(rules, layer)| DomOp::Css {
+                layer: layer.to_string(),
+                rules,
+            })
+            .collect()
+    }
+}
+
+/// Translates tree patches into DOM operations
+///
+/// Changes inside composite widgets (lists, tables, tabs, accordions) can't
+/// be applied element by element; those widgets are re-rendered whole, once,
+/// after the other operations.
+struct Translator<'a> {
+    old: &'a VTree,
+    new: &'a VTree,
+    theme: &'a Theme,
+    ops: Vec<(Target, DomOp)>,
+    dirty: Vec<VNodeId>,
+}
+
+/// The node an operation changes, and which tree it belongs to
+#[derive(Clone, Copy)]
+enum Target {
+    Old(VNodeId),
+    New(VNodeId),
+    Page,
+}
+
+impl<'a> Translator<'a> {
+    fn new(old: &'a VTree, new: &'a VTree, theme: &'a Theme) -> Self {
+        Self {
+            old,
+            new,
+            theme,
+            ops: Vec::new(),
+            dirty: Vec::new(),
+        }
+    }
+
+    fn translate(mut self, patches: &[Patch]) -> Vec<DomOp> {
+        for patch in patches {
+            self.patch(patch);
+        }
+
+        // Only the outermost dirty widgets need re-rendering
+        let dirty: HashSet<VNodeId> = self.dirty.iter().copied().collect();
+        let outermost: Vec<VNodeId> = self
+            .dirty
+            .iter()
+            .copied()
+            .filter(|id| !self.within(self.new, self.new.get(*id).and_then(|node| node.parent), &dirty))
+            .fold(Vec::new(), |mut ids, id| {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+                ids
+            });
+
+        let mut ops: Vec<DomOp> = std::mem::take(&mut self.ops)
+            .into_iter()
+            .filter(|(target, _)| match *target {
+                Target::Old(id) => !self.within(self.old, Some(id), &dirty),
+                Target::New(id) => !self.within(self.new, Some(id), &dirty),
+                Target::Page => true,
+            })
+            .map(|(_, op)| op)
+            .collect();
+        ops.extend(outermost.into_iter().map(|id| DomOp::Replace {
+            id: id.as_u64(),
+            html: html::render(self.new, id, self.theme),
+        }));
+        ops
+    }
+
+    fn patch(&mut self, patch: &Patch) {
+        match patch {
+            Patch::Insert { parent: None, .. } => {
+                let html = self
+                    .new
+                    .root()
+                    .map(|root| html::render(self.new, root.id, self.theme))
+                    .unwrap_or_default();
+                self.push(Target::Page, DomOp::Root { html });
+            }
+            Patch::Insert { parent: Some(parent), before, subtree } => {
+                let Some(root) = subtree.root() else { return };
+                if self.is_composite(*parent) {
+                    self.dirty.push(*parent);
+                } else {
+                    let op = DomOp::Insert {
+                        parent: parent.as_u64(),
+                        before: before.map(|id| id.as_u64()),
+                        html: html::render(self.new, root.id, self.theme),
+                    };
+                    self.push(Target::New(*parent), op);
+                }
+            }
+            Patch::Remove { id } => match self.old.get(*id).and_then(|node| node.parent) {
+                None => self.push(Target::Page, DomOp::Root { html: String::new() }),
+                Some(parent) if self.is_composite(parent) => self.dirty.push(parent),
+                Some(_) => self.push(Target::Old(*id), DomOp::Remove { id: id.as_u64() }),
+            },
+            Patch::Move { parent, id, before } => {
+                if self.is_composite(*parent) {
+                    self.dirty.push(*parent);
+                } else {
+                    let op = DomOp::Move {
+                        parent: parent.as_u64(),
+                        id: id.as_u64(),
+                        before: before.map(|id| id.as_u64()),
+                    };
+                    self.push(Target::New(*parent), op);
+                }
+            }
+            Patch::Replace { id, .. } => {
+                let html = html::render(self.new, *id, self.theme);
+                self.push(Target::New(*id), DomOp::Replace { id: id.as_u64(), html });
+            }
+            Patch::PropsChanged { id, .. } => {
+                let Some(node) = self.new.get(*id) else { return };
+                match html::shape(node) {
+                    Shape::Composite => self.dirty.push(*id),
+                    // Attribute updates keep focus and caret in text inputs
+                    Shape::Flat => self.push(Target::New(*id), attrs_op(node, self.theme)),
+                    Shape::Leaf
+                        if matches!(
+                            node.props,
+                            VNodeProps::Input { .. } | VNodeProps::Slider { .. } | VNodeProps::ProgressBar { .. }
+                        ) =>
+                    {
+                        self.push(Target::New(*id), attrs_op(node, self.theme))
+                    }
+                    Shape::Leaf => {
+                        let html = html::render(self.new, *id, self.theme);
+                        self.push(Target::New(*id), DomOp::Replace { id: id.as_u64(), html });
+                    }
+                }
+            }
+            Patch::TextChanged { id, content } => {
+                let op = DomOp::Text {
+                    id: id.as_u64(),
+                    content: content.clone(),
+                };
+                self.push(Target::New(*id), op);
+            }
+            Patch::StyleChanged { id, .. } => {
+                if let Some(node) = self.new.get(*id) {
+                    self.push(Target::New(*id), attrs_op(node, self.theme));
+                }
+            }
+            // Events are delegated by element kind, not bound per node
+            Patch::EventChanged { .. } => {}
+        }
+    }
+
+    fn push(&mut self, target: Target, op: DomOp) {
+        self.ops.push((target, op));
+    }
+
+    fn is_composite(&self, id: VNodeId) -> bool {
+        self.new
+            .get(id)
+            .or_else(|| self.old.get(id))
+            .is_some_and(|node| html::shape(node) == Shape::Composite)
+    }
+
+    /// Whether `id` or one of its ancestors in `tree` is in `set`
+    fn within(&self, tree: &VTree, mut id: Option<VNodeId>, set: &HashSet<VNodeId>) -> bool {
+        while let Some(current) = id {
+            if set.contains(&current) {
+                return true;
+            }
+            id = tree.get(current).and_then(|node| node.parent);
+        }
+        false
+    }
+}
+
+fn attrs_op(node: &auto_ui::vnode::VNode, theme: &Theme) -> DomOp {
+    DomOp::Attrs {
+        id: node.id.as_u64(),
+        attrs: html::attributes(node, theme)
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    }
+}
+
+/// The element an operation changes
+fn op_target(op: &DomOp) -> Option<u64> {
+    match op {
+        DomOp::Remove { id } | DomOp::Replace { id, .. } | DomOp::Text { id, .. } | DomOp::Attrs { id, .. } => {
+            Some(*id)
+        }
+        DomOp::Insert { parent, .. } | DomOp::Move { parent, .. } => Some(*parent),
+        DomOp::Root { .. } | DomOp::Css { .. } | DomOp::Reload => None,
+    }
+}
+
+/// Map node IDs of `from` onto a tree of the same shape
+fn align(from: &VTree, to: &VTree) -> HashMap<VNodeId, VNodeId> {
+    let mut ids = HashMap::new();
+    let mut pending: Vec<(VNodeId, VNodeId)> = from.root().zip(to.root()).map(|(a, b)| (a.id, b.id)).into_iter().collect();
+    while let Some((a, b)) = pending.pop() {
+        ids.insert(a, b);
+        if let (Some(a), Some(b)) = (from.get(a), to.get(b)) {
+            pending.extend(a.children.iter().copied().zip(b.children.iter().copied()));
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use auto_ui::vnode::VNodeKind;
+    use auto_ui::View;
+
+    #[derive(Debug, Default)]
+    struct Todos {
+        items: Vec<String>,
+        draft: String,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Msg {
+        Draft(String),
+        Add,
+    }
+
+    impl Component for Todos {
+        type Msg = Msg;
+
+        fn on(&mut self, msg: Msg) {
+            match msg {
+                // Drafts are capped at eight characters
+                Msg::Draft(draft) if draft.len() <= 8 => self.draft = draft,
+                Msg::Draft(_) => {}
+                Msg::Add => self.items.push(std::mem::take(&mut self.draft)),
+            }
+        }
+
+        fn view(&self) -> View<Msg> {
+            View::col()
+                .child(View::input("New").value(&self.draft).on_change(Msg::Draft).build())
+                .child(View::button("Add", Msg::Add))
+                .child(View::text(format!("{} items", self.items.len())))
+                .child(View::col().children(self.items.iter().map(|item| View::text_styled(item, "text-green-600"))).build())
+                .child(View::list(self.items.iter().map(View::text).collect()).build())
+                .build()
+        }
+    }
+
+    fn find(app: &WebApp<Todos>, kind: VNodeKind) -> u64 {
+        app.tree().nodes().iter().find(|node| node.kind == kind).unwrap().id.as_u64()
+    }
+
+    fn columns(app: &WebApp<Todos>) -> Vec<u64> {
+        let root = app.tree().root().unwrap();
+        let inner = root.children.iter().find(|id| app.tree().get(**id).unwrap().kind == VNodeKind::Column);
+        vec![root.id.as_u64(), inner.unwrap().as_u64()]
+    }
+
+    #[test]
+    fn test_page_embeds_view_and_client() {
+        let app = WebApp::new(Todos::default(), StyleMode::Css);
+        let page = app.page();
+        assert!(page.contains("<button"));
+        assert!(page.contains(">0 items</span>"));
+        assert!(page.contains("new WebSocket"));
+        assert!(page.contains("<style data-layer=\"md\">"));
+
+        let tailwind = WebApp::new(Todos::default(), StyleMode::Tailwind).page();
+        assert!(tailwind.contains(TAILWIND_CDN));
+        assert!(!tailwind.contains("<style data-layer"));
+    }
+
+    #[test]
+    fn test_controlled_input() {
+        let mut app = WebApp::new(Todos::default(), StyleMode::Css);
+        let input = find(&app, VNodeKind::Input);
+
+        let ops = app.handle(&ClientEvent::Input { id: input, value: "milk".into() });
+        assert_eq!(app.component().draft, "milk");
+        let [DomOp::Attrs { id, attrs }] = &ops[..] else { panic!("{:?}", ops) };
+        assert_eq!(*id, input);
+        assert!(attrs.contains(&("value".to_string(), Some("milk".to_string()))));
+
+        // A rejected edit puts the component's value back
+        let ops = app.handle(&ClientEvent::Input { id: input, value: "milk and eggs".into() });
+        assert_eq!(app.component().draft, "milk");
+        let [DomOp::Attrs { attrs, .. }] = &ops[..] else { panic!("{:?}", ops) };
+        assert!(attrs.contains(&("value".to_string(), Some("milk".to_string()))));
+    }
+
+    #[test]
+    fn test_click_patches_dom() {
+        let mut app = WebApp::new(Todos::default(), StyleMode::Css);
+        let input = find(&app, VNodeKind::Input);
+        let button = find(&app, VNodeKind::Button);
+        let list = find(&app, VNodeKind::List);
+        let [_, inner] = columns(&app)[..] else { unreachable!() };
+
+        app.handle(&ClientEvent::Input { id: input, value: "milk".into() });
+        let ops = app.handle(&ClientEvent::Click { id: button });
+
+        assert!(ops.iter().any(|op| matches!(op, DomOp::Text { content, .. } if content == "1 items")));
+        assert!(ops.iter().any(|op| matches!(op, DomOp::Attrs { id, .. } if *id == input)));
+        assert!(ops.iter().any(
+            |op| matches!(op, DomOp::Insert { parent, html, .. } if *parent == inner && html.contains(">milk</span>"))
+        ));
+        // List items can't be patched one by one
+        assert!(ops.iter().any(|op| matches!(op, DomOp::Replace { id, html } if *id == list && html.contains("<li"))));
+        assert!(ops.iter().any(|op| matches!(op, DomOp::Css { layer, rules } if layer == "base" && rules.contains(".text-green-600"))));
+
+        // Node IDs stay stable, so events keep reaching the same handlers
+        assert_eq!(find(&app, VNodeKind::Button), button);
+        app.handle(&ClientEvent::Input { id: input, value: "eggs".into() });
+        let ops = app.handle(&ClientEvent::Click { id: button });
+        assert_eq!(app.component().items, ["milk", "eggs"]);
+        assert!(!ops.iter().any(|op| matches!(op, DomOp::Css { .. })));
+    }
+
+    #[test]
+    fn test_unknown_events_are_ignored() {
+        let mut app = WebApp::new(Todos::default(), StyleMode::Css);
+        let button = find(&app, VNodeKind::Button);
+
+        assert!(app.handle(&ClientEvent::Click { id: 9999 }).is_empty());
+        assert!(app.handle(&ClientEvent::Select { id: button, index: 0 }).is_empty());
+        assert!(app.component().items.is_empty());
+    }
+
+    #[test]
+    fn test_client_event_wire_format() {
+        let event: ClientEvent = serde_json::from_str(r#"{"type":"input","id":3,"value":"hi"}"#).unwrap();
+        assert_eq!(event, ClientEvent::Input { id: 3, value: "hi".into() });
+        let op = serde_json::to_string(&DomOp::Remove { id: 3 }).unwrap();
+        assert_eq!(op, r#"{"op":"remove","id":3}"#);
+    }
+}
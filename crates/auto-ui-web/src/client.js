@@ -0,0 +1,110 @@
+// auto-ui web client: applies DomOps from the server and reports events.
+// Elements are addressed by data-id; see app.rs for both message formats.
+(() => {
+  const socket = new WebSocket(`ws://${location.host}/ws`);
+  const send = (event) => {
+    if (socket.readyState === WebSocket.OPEN) socket.send(JSON.stringify(event));
+  };
+
+  const byId = (id) => (id == null ? null : document.querySelector(`[data-id="${id}"]`));
+  const fragment = (html) => {
+    const template = document.createElement("template");
+    template.innerHTML = html;
+    return template.content.firstElementChild;
+  };
+  const owner = (element) => element.closest("[data-id]");
+  const idOf = (element) => Number(element.dataset.id);
+
+  const apply = (op) => {
+    switch (op.op) {
+      case "root":
+        document.getElementById("app").innerHTML = op.html;
+        break;
+      case "insert":
+        byId(op.parent)?.insertBefore(fragment(op.html), byId(op.before));
+        break;
+      case "remove":
+        byId(op.id)?.remove();
+        break;
+      case "move":
+        byId(op.parent)?.insertBefore(byId(op.id), byId(op.before));
+        break;
+      case "replace":
+        byId(op.id)?.replaceWith(fragment(op.html));
+        break;
+      case "text":
+        if (byId(op.id)) byId(op.id).textContent = op.content;
+        break;
+      case "attrs": {
+        const element = byId(op.id);
+        if (!element) break;
+        for (const [name, value] of op.attrs) {
+          if (value === null) element.removeAttribute(name);
+          else element.setAttribute(name, value);
+          // The attribute is only the default; the live value is a property
+          if (name === "value" && element.value !== value) element.value = value;
+        }
+        break;
+      }
+      case "css":
+        document.querySelector(`style[data-layer="${op.layer}"]`)?.append(op.rules);
+        break;
+      case "reload":
+        location.reload();
+        break;
+    }
+  };
+
+  socket.addEventListener("message", (message) => JSON.parse(message.data).forEach(apply));
+
+  document.addEventListener("click", (event) => {
+    const target = event.target;
+    const element = owner(target);
+    if (!element) return;
+
+    switch (element.dataset.kind) {
+      case "button":
+        send({ type: "click", id: idOf(element) });
+        break;
+      case "checkbox":
+      case "radio":
+        // The server decides the new state; only react to the input's own click
+        if (target.tagName !== "INPUT") return;
+        event.preventDefault();
+        send({ type: "click", id: idOf(element) });
+        break;
+      case "radio-group":
+        if (target.tagName !== "INPUT") return;
+        event.preventDefault();
+        send({ type: "select", id: idOf(element), index: Number(target.value) });
+        break;
+      case "tabs":
+      case "navigation-rail": {
+        const item = target.closest("[data-index]");
+        if (item && owner(item) === element) send({ type: "select", id: idOf(element), index: Number(item.dataset.index) });
+        break;
+      }
+      case "accordion": {
+        const summary = target.closest("summary");
+        if (!summary || owner(summary) !== element) return;
+        event.preventDefault();
+        send({ type: "select", id: idOf(element), index: Number(summary.dataset.index) });
+        break;
+      }
+    }
+  });
+
+  document.addEventListener("input", (event) => {
+    const element = event.target;
+    if (element.dataset.kind === "input" || element.dataset.kind === "slider") {
+      send({ type: "input", id: idOf(element), value: element.value });
+    }
+  });
+
+  document.addEventListener("change", (event) => {
+    const element = event.target;
+    if (element.dataset.kind === "select" && element.value !== "") {
+      send({ type: "select", id: idOf(element), index: Number(element.value) });
+    }
+  });
+})();
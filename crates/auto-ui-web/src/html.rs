@@ -0,0 +1,500 @@
+// Server-side rendering of VTrees to semantic HTML
+//
+// Each VNode becomes one element carrying `data-id` (its VNodeId) and
+// `data-kind`, so patches and browser events can address it. Widgets map to
+// their native HTML counterparts: <button>, <input type="range">, <select>,
+// <progress>, <details> for accordion sections, role="tab" buttons, <table>.
+//
+// Kind defaults (a column is a vertical flexbox, ...) live in a base stylesheet
+// wrapped in `:where()`, which has zero specificity: any utility class on the
+// element overrides them, whichever stylesheet defines it.
+
+use crate::style::{class_names, css_color};
+use auto_ui::style::Theme;
+use auto_ui::view::{SidebarPosition, TabsPosition};
+use auto_ui::vnode::{VNode, VNodeId, VNodeKind, VNodeProps, VTree};
+use std::fmt::Write;
+
+/// How a node's changes reach the DOM
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Shape {
+    /// Children are the element's direct DOM children: inserts, moves and
+    /// removals apply as-is, prop changes are attribute updates
+    Flat,
+    /// No children: prop changes re-render the element
+    Leaf,
+    /// Children sit in wrappers (<li>, <td>, tab panels, <details>): any
+    /// change re-renders the whole subtree
+    Composite,
+}
+
+pub(crate) fn shape(node: &VNode) -> Shape {
+    match node.kind {
+        VNodeKind::Column
+        | VNodeKind::Row
+        | VNodeKind::Container
+        | VNodeKind::Center
+        | VNodeKind::Scrollable
+        | VNodeKind::Sidebar => Shape::Flat,
+        VNodeKind::List | VNodeKind::Table | VNodeKind::Accordion | VNodeKind::Tabs => Shape::Composite,
+        _ => Shape::Leaf,
+    }
+}
+
+/// Outer HTML of a node and its subtree
+pub(crate) fn render(tree: &VTree, id: VNodeId, theme: &Theme) -> String {
+    let mut out = String::new();
+    if let Some(node) = tree.get(id) {
+        Renderer { tree, theme, out: &mut out }.node(node);
+    }
+    out
+}
+
+/// Attributes of a node's own element
+///
+/// `None` values are absent boolean attributes; they are sent too, so a
+/// patch can remove `disabled` or `checked`.
+pub(crate) fn attributes(node: &VNode, theme: &Theme) -> Vec<(&'static str, Option<String>)> {
+    let mut classes = Vec::new();
+    if let Some(style) = &node.style {
+        classes = class_names(style, theme);
+    }
+
+    let mut attrs = vec![
+        ("data-id", Some(node.id.as_u64().to_string())),
+        ("data-kind", Some(kind_name(node).to_string())),
+        ("class", (!classes.is_empty()).then(|| classes.join(" "))),
+        ("style", inline_style(node)),
+    ];
+    let flag = |on: bool| on.then(String::new);
+
+    match &node.props {
+        VNodeProps::Button { disabled, .. } => {
+            attrs.push(("type", Some("button".to_string())));
+            attrs.push(("disabled", flag(*disabled)));
+        }
+        VNodeProps::Input { placeholder, value, password, disabled, .. } => {
+            attrs.push(("type", Some(if *password { "password" } else { "text" }.to_string())));
+            attrs.push(("placeholder", Some(placeholder.clone())));
+            attrs.push(("value", Some(value.clone())));
+            attrs.push(("disabled", flag(*disabled)));
+        }
+        VNodeProps::Slider { min, max, value, step } => {
+            attrs.push(("type", Some("range".to_string())));
+            attrs.push(("min", Some(min.to_string())));
+            attrs.push(("max", Some(max.to_string())));
+            attrs.push(("step", Some(step.map_or("any".to_string(), |step| step.to_string()))));
+            attrs.push(("value", Some(value.to_string())));
+        }
+        VNodeProps::ProgressBar { progress } => {
+            attrs.push(("value", Some(progress.clamp(0.0, 1.0).to_string())));
+            attrs.push(("max", Some("1".to_string())));
+        }
+        VNodeProps::Sidebar { position, .. } => {
+            let position = match position {
+                SidebarPosition::Left => "left",
+                SidebarPosition::Right => "right",
+            };
+            attrs.push(("data-position", Some(position.to_string())));
+        }
+        VNodeProps::Tabs { position, .. } => {
+            attrs.push(("data-position", Some(tabs_position(*position).to_string())));
+        }
+        VNodeProps::Empty => attrs.push(("hidden", Some(String::new()))),
+        _ => {}
+    }
+    attrs
+}
+
+/// Stylesheet with the defaults of every kind of element
+pub(crate) fn base_css(theme: &Theme) -> String {
+    let colors = &theme.colors;
+    let color = |color| css_color(&color, theme);
+    format!(
+        "body{{margin:0;font-family:system-ui,sans-serif;background:{background};color:{text}}}\
+#app{{display:flex;flex-direction:column;min-height:100vh}}\
+:where([data-id]){{box-sizing:border-box;border:0 solid {border}}}\
+:where([data-kind=column]){{display:flex;flex-direction:column;align-items:flex-start}}\
+:where([data-kind=row]){{display:flex;flex-direction:row;align-items:flex-start}}\
+:where([data-kind=center]){{display:flex;align-items:center;justify-content:center}}\
+:where([data-kind=scrollable]){{overflow:auto}}\
+:where([data-kind=text]){{white-space:pre-wrap}}\
+:where([data-kind=list]){{display:flex;flex-direction:column;list-style:none;margin:0;padding:0}}\
+:where([data-kind=radio-group]){{display:flex;flex-direction:column;margin:0;padding:0}}\
+:where([data-kind=sidebar]){{flex-shrink:0}}\
+:where([data-kind=sidebar][data-position=right]){{margin-left:auto}}\
+:where([data-kind=tabs],[data-kind=accordion]){{width:100%}}\
+:where([data-kind=tabs]){{display:flex;flex-direction:column}}\
+:where([data-kind=tabs][data-position=bottom]){{flex-direction:column-reverse}}\
+:where([data-kind=tabs][data-position=left]){{flex-direction:row}}\
+:where([data-kind=tabs][data-position=right]){{flex-direction:row-reverse}}\
+:where([role=tablist]){{display:flex;gap:4px}}\
+:where([data-position=left]>[role=tablist],[data-position=right]>[role=tablist]){{flex-direction:column}}\
+:where([role=tab][aria-selected=true]){{font-weight:700;box-shadow:inset 0 -2px {primary}}}\
+:where([data-kind=navigation-rail]){{display:flex;flex-direction:column}}\
+:where([data-kind=navigation-rail] [aria-current=page]){{font-weight:700;color:{primary}}}",
+        background = color(colors.background),
+        text = color(colors.on_background),
+        border = color(colors.border),
+        primary = color(colors.primary),
+    )
+}
+
+/// Escape text for HTML content and attribute values
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct Renderer<'a> {
+    tree: &'a VTree,
+    theme: &'a Theme,
+    out: &'a mut String,
+}
+
+impl Renderer<'_> {
+    fn node(&mut self, node: &VNode) {
+        let tag = match node.kind {
+            VNodeKind::Text => "span",
+            VNodeKind::Button => "button",
+            VNodeKind::Input | VNodeKind::Slider => "input",
+            VNodeKind::Checkbox | VNodeKind::Radio => "label",
+            VNodeKind::RadioGroup => "fieldset",
+            VNodeKind::Select => "select",
+            VNodeKind::List => "ul",
+            VNodeKind::Table => "table",
+            VNodeKind::ProgressBar => "progress",
+            VNodeKind::Sidebar => "aside",
+            VNodeKind::NavigationRail => "nav",
+            _ => "div",
+        };
+        self.open(tag, node);
+
+        match &node.props {
+            VNodeProps::Text { content } => self.text(content),
+            VNodeProps::Button { label, .. } => self.text(label),
+            // Void elements
+            VNodeProps::Input { .. } | VNodeProps::Slider { .. } => return,
+            VNodeProps::Checkbox { label, is_checked: checked }
+            | VNodeProps::Radio { label, is_selected: checked } => {
+                let kind = if node.kind == VNodeKind::Checkbox { "checkbox" } else { "radio" };
+                let checked = if *checked { " checked" } else { "" };
+                write!(self.out, "<input type=\"{}\"{}> <span>", kind, checked).unwrap();
+                self.text(label);
+                self.out.push_str("</span>");
+            }
+            VNodeProps::RadioGroup { options, selected_index } => {
+                for (index, option) in options.iter().enumerate() {
+                    let checked = if *selected_index == Some(index) { " checked" } else { "" };
+                    write!(
+                        self.out,
+                        "<label><input type=\"radio\" name=\"group-{}\" value=\"{}\"{}> <span>",
+                        node.id.as_u64(), index, checked
+                    )
+                    .unwrap();
+                    self.text(option);
+                    self.out.push_str("</span></label>");
+                }
+            }
+            VNodeProps::Select { options, selected_index } => {
+                if selected_index.is_none() {
+                    self.out.push_str("<option value=\"\" selected disabled>Select...</option>");
+                }
+                for (index, option) in options.iter().enumerate() {
+                    let selected = if *selected_index == Some(index) { " selected" } else { "" };
+                    write!(self.out, "<option value=\"{}\"{}>", index, selected).unwrap();
+                    self.text(option);
+                    self.out.push_str("</option>");
+                }
+            }
+            VNodeProps::List { .. } => {
+                for child in &node.children {
+                    self.out.push_str("<li>");
+                    self.child(*child);
+                    self.out.push_str("</li>");
+                }
+            }
+            VNodeProps::Table { headers, rows, .. } => {
+                let (header_cells, body_cells) = node.children.split_at((*headers).min(node.children.len()));
+                if !header_cells.is_empty() {
+                    self.out.push_str("<thead><tr>");
+                    self.cells("th", header_cells);
+                    self.out.push_str("</tr></thead>");
+                }
+                self.out.push_str("<tbody>");
+                let mut rest = body_cells;
+                for count in rows {
+                    let (row, next) = rest.split_at((*count).min(rest.len()));
+                    self.out.push_str("<tr>");
+                    self.cells("td", row);
+                    self.out.push_str("</tr>");
+                    rest = next;
+                }
+                self.out.push_str("</tbody>");
+            }
+            VNodeProps::Accordion { items, .. } => {
+                let mut rest = node.children.as_slice();
+                for (index, item) in items.iter().enumerate() {
+                    let (children, next) = rest.split_at(item.children.min(rest.len()));
+                    let open = if item.expanded { " open" } else { "" };
+                    write!(self.out, "<details{}><summary data-index=\"{}\">", open, index).unwrap();
+                    if let Some(icon) = item.icon {
+                        write!(self.out, "{} ", icon).unwrap();
+                    }
+                    self.text(&item.title);
+                    self.out.push_str("</summary>");
+                    for child in children {
+                        self.child(*child);
+                    }
+                    self.out.push_str("</details>");
+                    rest = next;
+                }
+            }
+            VNodeProps::Tabs { labels, selected, .. } => {
+                self.out.push_str("<div role=\"tablist\">");
+                for (index, label) in labels.iter().enumerate() {
+                    write!(
+                        self.out,
+                        "<button type=\"button\" role=\"tab\" data-index=\"{}\" aria-selected=\"{}\">",
+                        index,
+                        index == *selected
+                    )
+                    .unwrap();
+                    self.text(label);
+                    self.out.push_str("</button>");
+                }
+                self.out.push_str("</div>");
+                for (index, child) in node.children.iter().enumerate() {
+                    let hidden = if index == *selected { "" } else { " hidden" };
+                    write!(self.out, "<div role=\"tabpanel\"{}>", hidden).unwrap();
+                    self.child(*child);
+                    self.out.push_str("</div>");
+                }
+            }
+            VNodeProps::NavigationRail { items, selected, show_labels, .. } => {
+                for (index, item) in items.iter().enumerate() {
+                    let current = if index == *selected { " aria-current=\"page\"" } else { "" };
+                    write!(
+                        self.out,
+                        "<button type=\"button\" data-index=\"{}\"{}><span>{}</span>",
+                        index, current, item.icon
+                    )
+                    .unwrap();
+                    if *show_labels {
+                        self.out.push_str(" <span>");
+                        self.text(&item.label);
+                        self.out.push_str("</span>");
+                    }
+                    if let Some(badge) = &item.badge {
+                        self.out.push_str(" <span data-badge>");
+                        self.text(badge);
+                        self.out.push_str("</span>");
+                    }
+                    self.out.push_str("</button>");
+                }
+            }
+            _ => {
+                for child in &node.children {
+                    self.child(*child);
+                }
+            }
+        }
+        write!(self.out, "</{}>", tag).unwrap();
+    }
+
+    fn open(&mut self, tag: &str, node: &VNode) {
+        write!(self.out, "<{}", tag).unwrap();
+        for (name, value) in attributes(node, self.theme) {
+            match value {
+                Some(value) if value.is_empty() && name != "value" && name != "placeholder" => {
+                    write!(self.out, " {}", name).unwrap()
+                }
+                Some(value) => write!(self.out, " {}=\"{}\"", name, escape(&value)).unwrap(),
+                None => {}
+            }
+        }
+        self.out.push('>');
+    }
+
+    fn child(&mut self, id: VNodeId) {
+        if let Some(child) = self.tree.get(id) {
+            self.node(child);
+        }
+    }
+
+    fn cells(&mut self, tag: &str, cells: &[VNodeId]) {
+        for cell in cells {
+            write!(self.out, "<{}>", tag).unwrap();
+            self.child(*cell);
+            write!(self.out, "</{}>", tag).unwrap();
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        self.out.push_str(&escape(text));
+    }
+}
+
+/// `data-kind` of a node: its kind in kebab case
+fn kind_name(node: &VNode) -> &'static str {
+    match node.kind {
+        VNodeKind::Column => "column",
+        VNodeKind::Row => "row",
+        VNodeKind::Container => "container",
+        VNodeKind::Scrollable => "scrollable",
+        VNodeKind::Center => "center",
+        // View::Empty is an empty text node
+        VNodeKind::Text if matches!(node.props, VNodeProps::Empty) => "empty",
+        VNodeKind::Text => "text",
+        VNodeKind::Button => "button",
+        VNodeKind::Input => "input",
+        VNodeKind::Checkbox => "checkbox",
+        VNodeKind::Radio => "radio",
+        VNodeKind::RadioGroup => "radio-group",
+        VNodeKind::Select => "select",
+        VNodeKind::List => "list",
+        VNodeKind::Table => "table",
+        VNodeKind::Slider => "slider",
+        VNodeKind::ProgressBar => "progress-bar",
+        VNodeKind::Accordion => "accordion",
+        VNodeKind::Sidebar => "sidebar",
+        VNodeKind::Tabs => "tabs",
+        VNodeKind::NavigationRail => "navigation-rail",
+    }
+}
+
+fn tabs_position(position: TabsPosition) -> &'static str {
+    match position {
+        TabsPosition::Top => "top",
+        TabsPosition::Bottom => "bottom",
+        TabsPosition::Left => "left",
+        TabsPosition::Right => "right",
+    }
+}
+
+/// Inline CSS for props that have no class equivalent
+///
+/// Spacing, padding and sizes from the builder API only apply to nodes
+/// without a style, as in the native backends; widths of sidebars, rails and
+/// inputs always do.
+fn inline_style(node: &VNode) -> Option<String> {
+    let legacy = node.style.is_none();
+    let mut css = String::new();
+    let mut px = |property: &str, value: f32| {
+        if value > 0.0 {
+            write!(css, "{}:{}px;", property, value).unwrap();
+        }
+    };
+
+    match &node.props {
+        VNodeProps::Layout { spacing, padding } if legacy => {
+            px("gap", f32::from(*spacing));
+            px("padding", f32::from(*padding));
+        }
+        VNodeProps::Container { padding, width, height, center_x, center_y } if legacy => {
+            px("padding", f32::from(*padding));
+            px("width", width.map_or(0.0, f32::from));
+            px("height", height.map_or(0.0, f32::from));
+            if *center_x || *center_y {
+                css.push_str("display:flex;");
+            }
+            if *center_x {
+                css.push_str("justify-content:center;width:100%;");
+            }
+            if *center_y {
+                css.push_str("align-items:center;");
+            }
+        }
+        VNodeProps::Scrollable { width, height } if legacy => {
+            px("width", width.map_or(0.0, f32::from));
+            px("height", height.map_or(0.0, f32::from));
+        }
+        VNodeProps::List { spacing } if legacy => px("gap", f32::from(*spacing)),
+        VNodeProps::Table { spacing, col_spacing, .. } if legacy => {
+            write!(css, "border-spacing:{}px {}px;", col_spacing, spacing).unwrap();
+        }
+        VNodeProps::Input { width, .. } => px("width", width.map_or(0.0, f32::from)),
+        VNodeProps::Sidebar { width, .. } | VNodeProps::NavigationRail { width, .. } => px("width", *width),
+        _ => {}
+    }
+    (!css.is_empty()).then_some(css)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use auto_ui::view::AccordionItem;
+    use auto_ui::vnode_converter::view_to_vtree;
+    use auto_ui::View;
+
+    fn root_html(view: View<()>) -> String {
+        let tree = view_to_vtree(view);
+        render(&tree, tree.root().unwrap().id, &Theme::light())
+    }
+
+    #[test]
+    fn test_semantic_elements() {
+        let html = root_html(
+            View::col()
+                .spacing(8)
+                .child(View::text("a < b"))
+                .child(View::button("Save", ()))
+                .child(View::input("Name").value("Ann").build())
+                .child(View::checkbox(true, "Remember"))
+                .build(),
+        );
+
+        assert!(html.starts_with("<div data-id=\""), "{}", html);
+        assert!(html.contains("data-kind=\"column\" style=\"gap:8px;\">"));
+        assert!(html.contains("data-kind=\"text\">a &lt; b</span>"));
+        assert!(html.contains("data-kind=\"button\" type=\"button\">Save</button>"));
+        assert!(html.contains("type=\"text\" placeholder=\"Name\" value=\"Ann\">"));
+        assert!(html.contains("<input type=\"checkbox\" checked> <span>Remember</span></label>"));
+    }
+
+    #[test]
+    fn test_style_replaces_legacy_spacing() {
+        let html = root_html(View::col().spacing(8).style("gap-2 md:flex-row").child(View::text("x")).build());
+        assert!(html.contains("class=\"gap-2 md:flex-row\">"));
+        assert!(!html.contains("style="));
+    }
+
+    #[test]
+    fn test_composite_widgets() {
+        let html = root_html(
+            View::col()
+                .child(
+                    View::tabs(vec!["One".to_string(), "Two".to_string()])
+                        .contents(vec![View::text("first"), View::text("second")])
+                        .selected(1)
+                        .build(),
+                )
+                .child(
+                    View::accordion()
+                        .items(vec![AccordionItem::new("More").with_children(vec![View::text("hidden")])])
+                        .build(),
+                )
+                .build(),
+        );
+
+        assert!(html.contains("role=\"tab\" data-index=\"0\" aria-selected=\"false\">One</button>"));
+        assert!(html.contains("<div role=\"tabpanel\" hidden><span"));
+        assert!(html.contains("<details><summary data-index=\"0\">More</summary><span"));
+    }
+
+    #[test]
+    fn test_base_css_uses_theme() {
+        let theme = Theme::dark();
+        let css = base_css(&theme);
+        assert!(css.contains(&format!("background:{}", css_color(&theme.colors.background, &theme))));
+    }
+}
@@ -0,0 +1,74 @@
+// auto-ui-web: Web backend for auto-ui
+//
+// Serves a Component to the browser from a local process. The view is
+// flattened to a VTree and server-rendered to semantic HTML (buttons are
+// <button>, selects are <select>, tabs are role="tab", ...), with every
+// element tagged by its VNode ID in `data-id`.
+//
+// The component stays alive in this process. A small script in the page
+// opens a WebSocket back to it, reports clicks, input and select changes as
+// `ClientEvent`s, and applies the `DomOp`s that come back: the VTree diff of
+// each update, translated into element-level DOM changes.
+//
+// Styles:
+// - `StyleMode::Css` generates one atomic CSS rule per class from the active
+//   Theme, so pages work offline; responsive and state variants become
+//   @media and :hover/:focus rules
+// - `StyleMode::Tailwind` emits the same literal Tailwind class names and
+//   lets the Tailwind Play CDN style them
+//
+// Subscriptions are not driven yet; `Command::perform` tasks are.
+
+mod app;
+mod html;
+mod server;
+mod style;
+
+pub use app::{ClientEvent, DomOp, WebApp};
+pub use server::serve;
+pub use style::StyleMode;
+
+use auto_ui::Component;
+use tokio::net::TcpListener;
+
+/// Address `run_app` serves on; only reachable from this machine
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8600";
+
+/// Run an auto-ui Component as a local web app
+///
+/// Serves the component on [`DEFAULT_ADDR`] until it returns `Command::exit`.
+/// Open the printed URL in a browser; every open tab shows the same state.
+///
+/// # Example
+/// ```no_run
+/// use auto_ui::{Component, View};
+/// use auto_ui_web::run_app;
+///
+/// #[derive(Debug, Default)]
+/// struct MyComponent;
+///
+/// impl Component for MyComponent {
+///     type Msg = ();
+///     fn on(&mut self, _msg: Self::Msg) {}
+///     fn view(&self) -> View<Self::Msg> {
+///         View::text("Hello!")
+///     }
+/// }
+///
+/// fn main() -> auto_ui::AppResult<()> {
+///     run_app::<MyComponent>()
+/// }
+/// ```
+pub fn run_app<C>() -> auto_ui::AppResult<()>
+where
+    C: Component + Default + Send + 'static,
+    C::Msg: Send,
+{
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = TcpListener::bind(DEFAULT_ADDR).await?;
+        println!("🌐 Serving on http://{}", listener.local_addr()?);
+        serve(listener, WebApp::new(C::default(), StyleMode::default())).await?;
+        Ok(())
+    })
+}
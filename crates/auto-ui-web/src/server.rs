@@ -0,0 +1,414 @@
+// Local HTTP and WebSocket server around a WebApp
+//
+// Routes:
+// - `GET /`       the page, rendered from the current view
+// - `GET /ws`     WebSocket: `ClientEvent`s in, arrays of `DomOp`s out
+// - `POST /event` one `ClientEvent` as JSON; responds with the `DomOp`s
+//
+// Every route answers 403 unless the Host is a loopback name with the bound
+// port and any Origin matches it. Checking the Origin alone is not enough:
+// after DNS rebinding a page on `evil.example:8600` sends that name as both.
+//
+// Every connected browser receives every update, so several tabs stay in
+// sync with the one component. `POST /event` makes the app scriptable with
+// any HTTP client, e.g. `curl -d '{"type":"click","id":2}' -H 'content-type:
+// application/json' localhost:8600/event`.
+
+use crate::app::{ClientEvent, DomOp, WebApp};
+use auto_ui::command::CommandFuture;
+use auto_ui::Component;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, watch};
+
+/// Updates a slow browser may fall behind by before it is resynchronized
+const UPDATE_BACKLOG: usize = 64;
+
+/// Host names the server answers to; any other name may have been rebound to it
+const LOOPBACK_HOSTS: [&str; 3] = ["localhost", "127.0.0.1", "[::1]"];
+
+struct Shared<C: Component> {
+    app: Mutex<WebApp<C>>,
+    /// Serialized `DomOp` batches for every connected socket
+    updates: broadcast::Sender<String>,
+    exit: watch::Sender<bool>,
+}
+
+impl<C> Shared<C>
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    fn app(&self) -> MutexGuard<'_, WebApp<C>> {
+        self.app.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Run `change` on the app, then publish its DOM updates and start its tasks
+    fn update(self: &Arc<Self>, change: impl FnOnce(&mut WebApp<C>) -> Vec<DomOp>) -> Vec<DomOp> {
+        let (ops, tasks, exit) = {
+            let mut app = self.app();
+            let ops = change(&mut app);
+            (ops, app.take_tasks(), app.should_exit())
+        };
+        self.publish(&ops, tasks, exit);
+        ops
+    }
+
+    // Kept apart from the generic `update`: its tasks call back into `update`
+    fn publish(self: &Arc<Self>, ops: &[DomOp], tasks: Vec<CommandFuture<C::Msg>>, exit: bool) {
+        if !ops.is_empty() {
+            if let Ok(json) = serde_json::to_string(ops) {
+                // No receivers just means no browser is connected
+                let _ = self.updates.send(json);
+            }
+        }
+        for task in tasks {
+            let shared = Arc::clone(self);
+            tokio::spawn(async move {
+                let msg = task.await;
+                shared.update(|app| app.send(msg));
+            });
+        }
+        if exit {
+            self.exit.send_replace(true);
+        }
+    }
+}
+
+/// Serve `app` on `listener` until the component returns `Command::exit`
+pub async fn serve<C>(listener: TcpListener, app: WebApp<C>) -> std::io::Result<()>
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    let port = listener.local_addr()?.port();
+    let (exit, mut exited) = watch::channel(false);
+    let shared = Arc::new(Shared {
+        app: Mutex::new(app),
+        updates: broadcast::channel(UPDATE_BACKLOG).0,
+        exit,
+    });
+
+    let router = Router::new()
+        .route("/", get(page::<C>))
+        .route("/ws", get(socket::<C>))
+        .route("/event", post(event::<C>))
+        .layer(middleware::from_fn_with_state(port, local_only))
+        .with_state(shared);
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async move {
+            let _ = exited.wait_for(|exit| *exit).await;
+        })
+        .await
+}
+
+async fn page<C>(State(shared): State<Arc<Shared<C>>>) -> Html<String>
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    Html(shared.app().page())
+}
+
+async fn event<C>(State(shared): State<Arc<Shared<C>>>, Json(event): Json<ClientEvent>) -> Json<Vec<DomOp>>
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    Json(shared.update(|app| app.handle(&event)))
+}
+
+async fn socket<C>(State(shared): State<Arc<Shared<C>>>, upgrade: WebSocketUpgrade) -> Response
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    upgrade.on_upgrade(move |socket| connection(socket, shared))
+}
+
+/// Refuse requests that do not come from a page or client on this machine
+///
+/// WebSockets and form posts are exempt from the same-origin policy: without
+/// this any site open in the browser could drive the app.
+async fn local_only(State(port): State<u16>, request: Request, next: Next) -> Response {
+    if !is_local(request.headers(), port) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    next.run(request).await
+}
+
+/// Whether the Host is a loopback name on `port` and any Origin names that host
+///
+/// Browsers send an Origin with every WebSocket handshake and cross-site post;
+/// clients that send none are not pages and only need a loopback Host.
+fn is_local(headers: &HeaderMap, port: u16) -> bool {
+    let Some(host) = headers.get(header::HOST).and_then(|host| host.to_str().ok()) else {
+        return false;
+    };
+    if !is_loopback_host(host, port) {
+        return false;
+    }
+    let Some(origin) = headers.get(header::ORIGIN) else {
+        return true;
+    };
+    origin
+        .to_str()
+        .ok()
+        .and_then(|origin| origin.strip_prefix("http://").or_else(|| origin.strip_prefix("https://")))
+        .is_some_and(|origin| origin.eq_ignore_ascii_case(host))
+}
+
+/// Whether `host` (a Host header value) is a loopback name with port `port`
+fn is_loopback_host(host: &str, port: u16) -> bool {
+    let (name, host_port) = match host.rsplit_once(':') {
+        Some((name, host_port)) if !host.ends_with(']') => (name, host_port.parse().ok()),
+        // No port: the scheme default
+        _ => (host, Some(80)),
+    };
+    host_port == Some(port) && LOOPBACK_HOSTS.iter().any(|loopback| name.eq_ignore_ascii_case(loopback))
+}
+
+async fn connection<C>(mut socket: WebSocket, shared: Arc<Shared<C>>)
+where
+    C: Component + Send + 'static,
+    C::Msg: Send,
+{
+    let mut updates = shared.updates.subscribe();
+    let mut exit = shared.exit.subscribe();
+
+    // The page may predate this connection: start from the current state
+    let snapshot = || serde_json::to_string(&shared.app().snapshot()).unwrap_or_default();
+    if socket.send(Message::Text(snapshot().into())).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    // Malformed events are dropped like events for unknown nodes
+                    if let Ok(event) = serde_json::from_str::<ClientEvent>(&text) {
+                        shared.update(|app| app.handle(&event));
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+            update = updates.recv() => {
+                let json = match update {
+                    Ok(json) => json,
+                    Err(broadcast::error::RecvError::Lagged(_)) => snapshot(),
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if socket.send(Message::Text(json.into())).await.is_err() {
+                    break;
+                }
+            }
+            // The only change ever sent is the exit
+            _ = exit.changed() => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StyleMode;
+    use auto_ui::{Command, View};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::tungstenite;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+    #[derive(Debug, Default)]
+    struct Counter {
+        count: i32,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Msg {
+        Inc,
+        Quit,
+    }
+
+    impl Component for Counter {
+        type Msg = Msg;
+
+        fn on(&mut self, msg: Msg) {
+            if let Msg::Inc = msg {
+                self.count += 1;
+            }
+        }
+
+        fn update(&mut self, msg: Msg) -> Command<Msg> {
+            if let Msg::Quit = msg {
+                return Command::exit();
+            }
+            self.on(msg);
+            Command::none()
+        }
+
+        fn view(&self) -> View<Msg> {
+            View::col()
+                .child(View::text(format!("Count: {}", self.count)))
+                .child(View::button("+1", Msg::Inc))
+                .child(View::button("Quit", Msg::Quit))
+                .build()
+        }
+    }
+
+    /// Start a server on a free port, returning its address
+    async fn start() -> (String, tokio::task::JoinHandle<std::io::Result<()>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let app = WebApp::new(Counter::default(), StyleMode::Css);
+        (addr, tokio::spawn(serve(listener, app)))
+    }
+
+    /// A bare HTTP/1.1 exchange, returning the response body
+    async fn request(addr: &str, head: &str, body: &str) -> String {
+        let response = exchange(addr, addr, head, body).await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        response.split_once("\r\n\r\n").unwrap().1.to_string()
+    }
+
+    /// A bare HTTP/1.1 exchange with the given Host, returning the whole response
+    async fn exchange(addr: &str, host: &str, head: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "{}\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            head,
+            host,
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    fn ids(html: &str) -> Vec<u64> {
+        html.split("data-id=\"")
+            .skip(1)
+            .map(|rest| rest.split('"').next().unwrap().parse().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_page_and_http_events() {
+        let (addr, server) = start().await;
+
+        let page = request(&addr, "GET / HTTP/1.1", "").await;
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page.contains(">Count: 0</span>"));
+        let body = page.split("<div id=\"app\">").nth(1).unwrap().split("<script>").next().unwrap();
+        let [_, text, plus, quit] = ids(body)[..] else { panic!("{}", page) };
+
+        let click = format!(r#"{{"type":"click","id":{}}}"#, plus);
+        let ops = request(&addr, "POST /event HTTP/1.1", &click).await;
+        assert_eq!(ops, format!(r#"[{{"op":"text","id":{},"content":"Count: 1"}}]"#, text));
+
+        let quit = format!(r#"{{"type":"click","id":{}}}"#, quit);
+        request(&addr, "POST /event HTTP/1.1", &quit).await;
+        server.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_pushes_patches() {
+        let (addr, server) = start().await;
+        let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{}/ws", addr)).await.unwrap();
+
+        let next = |message: Option<Result<tungstenite::Message, _>>| {
+            let text = message.unwrap().unwrap().into_text().unwrap();
+            serde_json::from_str::<serde_json::Value>(&text).unwrap()
+        };
+        let snapshot = next(socket.next().await);
+        assert_eq!(snapshot[0]["op"], "root");
+        let body = snapshot[0]["html"].as_str().unwrap().to_string();
+        let [_, text, plus, _] = ids(&body)[..] else { panic!("{}", body) };
+
+        // An event from another client reaches this socket too
+        request(&addr, "POST /event HTTP/1.1", &format!(r#"{{"type":"click","id":{}}}"#, plus)).await;
+        let pushed = next(socket.next().await);
+        assert_eq!(pushed[0]["id"], text);
+        assert_eq!(pushed[0]["content"], "Count: 1");
+
+        let click = format!(r#"{{"type":"click","id":{}}}"#, plus);
+        socket.send(tungstenite::Message::text(click)).await.unwrap();
+        assert_eq!(next(socket.next().await)[0]["content"], "Count: 2");
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_refuses_foreign_origin() {
+        let (addr, server) = start().await;
+        let handshake = |origin: String| {
+            let mut request = format!("ws://{}/ws", addr).into_client_request().unwrap();
+            request.headers_mut().insert("Origin", origin.parse().unwrap());
+            tokio_tungstenite::connect_async(request)
+        };
+
+        match handshake("http://evil.example".to_string()).await {
+            Err(tungstenite::Error::Http(response)) => assert_eq!(response.status(), 403),
+            Err(e) => panic!("Expected 403, got {}", e),
+            Ok(_) => panic!("Handshake from a foreign origin was accepted"),
+        }
+
+        // The served page itself may connect
+        assert!(handshake(format!("http://{}", addr)).await.is_ok());
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_refuses_rebound_host() {
+        let (addr, server) = start().await;
+        let port = addr.rsplit_once(':').unwrap().1;
+        let rebound = format!("evil.example:{}", port);
+
+        // After DNS rebinding the attacker's page sends its own name as Origin and Host
+        let mut request = format!("ws://{}/ws", addr).into_client_request().unwrap();
+        request.headers_mut().insert("Host", rebound.parse().unwrap());
+        request.headers_mut().insert("Origin", format!("http://{}", rebound).parse().unwrap());
+        match tokio_tungstenite::connect_async(request).await {
+            Err(tungstenite::Error::Http(response)) => assert_eq!(response.status(), 403),
+            Err(e) => panic!("Expected 403, got {}", e),
+            Ok(_) => panic!("Handshake with a rebound host was accepted"),
+        }
+
+        for head in ["GET / HTTP/1.1", "POST /event HTTP/1.1"] {
+            let response = exchange(&addr, &rebound, head, r#"{"type":"click","id":1}"#).await;
+            assert!(response.starts_with("HTTP/1.1 403"), "{}", response);
+        }
+
+        // Loopback names other than the one connected to are fine
+        let localhost = format!("localhost:{}", port);
+        let response = exchange(&addr, &localhost, "GET / HTTP/1.1", "").await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+
+        server.abort();
+    }
+
+    #[test]
+    fn test_is_loopback_host() {
+        assert!(is_loopback_host("127.0.0.1:8600", 8600));
+        assert!(is_loopback_host("LOCALHOST:8600", 8600));
+        assert!(is_loopback_host("[::1]:8600", 8600));
+        assert!(is_loopback_host("localhost", 80));
+        assert!(!is_loopback_host("[::1]", 8600));
+        assert!(!is_loopback_host("localhost:8601", 8600));
+        assert!(!is_loopback_host("evil.example:8600", 8600));
+        assert!(!is_loopback_host("localhost.evil.example:8600", 8600));
+    }
+}
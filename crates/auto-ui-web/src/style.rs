@@ -0,0 +1,362 @@
+// Style classes as HTML class names and CSS rules
+//
+// Every element carries its classes as Tailwind names (`p-4`, `hover:bg-blue-600`,
+// `md:flex-row`). What makes them take effect depends on the `StyleMode`:
+// Tailwind itself, or atomic rules generated here from the same `StyleClass`
+// values, so a page works offline with no CSS toolchain.
+
+use auto_ui::style::{Breakpoint, Color, SizeValue, Style, StyleClass, StyleState, Theme};
+
+/// How class names become visual styles in the browser
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StyleMode {
+    /// Generate a CSS rule for every class in use (works offline)
+    #[default]
+    Css,
+    /// Leave the classes to Tailwind, loaded from its CDN build
+    Tailwind,
+}
+
+/// Stylesheet layers, in cascade order
+///
+/// State variants must beat plain classes and wider breakpoints must beat
+/// narrower ones, so rules go to separate `<style>` elements by layer rather
+/// than in the order classes first appear.
+pub(crate) const LAYERS: [&str; 6] = ["base", "state", "sm", "md", "lg", "xl"];
+
+/// A class as it appears in the `class` attribute, with its CSS rule
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ClassRule {
+    pub name: String,
+    /// Index into `LAYERS`
+    pub layer: usize,
+    pub css: String,
+}
+
+/// Tailwind names of a style's classes, prefixed by state and breakpoint
+pub(crate) fn class_names(style: &Style, theme: &Theme) -> Vec<String> {
+    rules(style, theme).into_iter().map(|rule| rule.name).collect()
+}
+
+/// Every class of a style with the rule that implements it
+pub(crate) fn rules(style: &Style, theme: &Theme) -> Vec<ClassRule> {
+    let plain = style.classes.iter().map(|class| (None, None, class));
+    let states = style.variants.iter().map(|(state, class)| (None, Some(*state), class));
+    let responsive = style
+        .responsive
+        .iter()
+        .map(|(breakpoint, state, class)| (Some(*breakpoint), *state, class));
+
+    plain
+        .chain(states)
+        .chain(responsive)
+        .map(|(breakpoint, state, class)| rule(breakpoint, state, class, theme))
+        .collect()
+}
+
+fn rule(breakpoint: Option<Breakpoint>, state: Option<StyleState>, class: &StyleClass, theme: &Theme) -> ClassRule {
+    let mut name = class_name(class, theme);
+    if let Some(state) = state {
+        name = format!("{}:{}", state.prefix(), name);
+    }
+    if let Some(breakpoint) = breakpoint {
+        name = format!("{}:{}", breakpoint.prefix(), name);
+    }
+
+    let pseudo = match state {
+        Some(StyleState::Hover) => ":hover",
+        Some(StyleState::Focus) => ":focus",
+        Some(StyleState::Active) => ":active",
+        Some(StyleState::Disabled) => ":disabled",
+        None => "",
+    };
+    let mut css = format!(".{}{}{{{}}}", escape(&name), pseudo, declarations(class, theme));
+    if let Some(breakpoint) = breakpoint {
+        css = format!("@media (min-width: {}px){{{}}}", breakpoint.min_width(), css);
+    }
+
+    let layer = match breakpoint {
+        Some(breakpoint) => LAYERS.iter().position(|layer| *layer == breakpoint.prefix()).unwrap_or(0),
+        None if state.is_some() => 1,
+        None => 0,
+    };
+    ClassRule { name, layer, css }
+}
+
+/// Tailwind name of a class
+///
+/// Semantic colors (`bg-primary`) have no Tailwind counterpart and become
+/// arbitrary values with the theme's color, `bg-[#3b82f6]`.
+pub(crate) fn class_name(class: &StyleClass, theme: &Theme) -> String {
+    let color = |color: &Color| {
+        let value = if color.is_semantic() {
+            css_color(color, theme)
+        } else {
+            String::from(*color)
+        };
+        // Custom colors are written as arbitrary values: `bg-[#1e293b]`
+        if value.starts_with('#') || value.starts_with("rgba") {
+            format!("[{}]", value)
+        } else {
+            value
+        }
+    };
+
+    match class {
+        StyleClass::Padding(size) => format!("p-{}", size_name(size)),
+        StyleClass::PaddingX(size) => format!("px-{}", size_name(size)),
+        StyleClass::PaddingY(size) => format!("py-{}", size_name(size)),
+        StyleClass::Margin(size) => format!("m-{}", size_name(size)),
+        StyleClass::MarginX(size) => format!("mx-{}", size_name(size)),
+        StyleClass::MarginY(size) => format!("my-{}", size_name(size)),
+        StyleClass::Gap(size) => format!("gap-{}", size_name(size)),
+        StyleClass::BackgroundColor(c) => format!("bg-{}", color(c)),
+        StyleClass::TextColor(c) => format!("text-{}", color(c)),
+        StyleClass::Flex => "flex".to_string(),
+        StyleClass::Flex1 => "flex-1".to_string(),
+        StyleClass::FlexRow => "flex-row".to_string(),
+        StyleClass::FlexCol => "flex-col".to_string(),
+        StyleClass::ItemsCenter => "items-center".to_string(),
+        StyleClass::ItemsStart => "items-start".to_string(),
+        StyleClass::ItemsEnd => "items-end".to_string(),
+        StyleClass::JustifyCenter => "justify-center".to_string(),
+        StyleClass::JustifyBetween => "justify-between".to_string(),
+        StyleClass::JustifyStart => "justify-start".to_string(),
+        StyleClass::JustifyEnd => "justify-end".to_string(),
+        StyleClass::Width(size) => format!("w-{}", size_name(size)),
+        StyleClass::Height(size) => format!("h-{}", size_name(size)),
+        StyleClass::Rounded => "rounded".to_string(),
+        StyleClass::RoundedSm => "rounded-sm".to_string(),
+        StyleClass::RoundedMd => "rounded-md".to_string(),
+        StyleClass::RoundedLg => "rounded-lg".to_string(),
+        StyleClass::RoundedXl => "rounded-xl".to_string(),
+        StyleClass::Rounded2Xl => "rounded-2xl".to_string(),
+        StyleClass::Rounded3Xl => "rounded-3xl".to_string(),
+        StyleClass::RoundedFull => "rounded-full".to_string(),
+        StyleClass::RoundedPx(px) => format!("rounded-[{}px]", px),
+        StyleClass::Border => "border".to_string(),
+        StyleClass::Border0 => "border-0".to_string(),
+        StyleClass::BorderColor(c) => format!("border-{}", color(c)),
+        StyleClass::TextXs => "text-xs".to_string(),
+        StyleClass::TextSm => "text-sm".to_string(),
+        StyleClass::TextBase => "text-base".to_string(),
+        StyleClass::TextLg => "text-lg".to_string(),
+        StyleClass::TextXl => "text-xl".to_string(),
+        StyleClass::Text2Xl => "text-2xl".to_string(),
+        StyleClass::Text3Xl => "text-3xl".to_string(),
+        StyleClass::FontSize(px) => format!("text-[{}px]", px),
+        StyleClass::FontBold => "font-bold".to_string(),
+        StyleClass::FontMedium => "font-medium".to_string(),
+        StyleClass::FontNormal => "font-normal".to_string(),
+        StyleClass::TextCenter => "text-center".to_string(),
+        StyleClass::TextLeft => "text-left".to_string(),
+        StyleClass::TextRight => "text-right".to_string(),
+        StyleClass::Shadow => "shadow".to_string(),
+        StyleClass::ShadowSm => "shadow-sm".to_string(),
+        StyleClass::ShadowMd => "shadow-md".to_string(),
+        StyleClass::ShadowLg => "shadow-lg".to_string(),
+        StyleClass::ShadowXl => "shadow-xl".to_string(),
+        StyleClass::Shadow2Xl => "shadow-2xl".to_string(),
+        StyleClass::ShadowNone => "shadow-none".to_string(),
+        StyleClass::Opacity(value) => format!("opacity-{}", value),
+        StyleClass::Relative => "relative".to_string(),
+        StyleClass::Absolute => "absolute".to_string(),
+        StyleClass::ZIndex(value) => format!("z-{}", value),
+        StyleClass::OverflowAuto => "overflow-auto".to_string(),
+        StyleClass::OverflowHidden => "overflow-hidden".to_string(),
+        StyleClass::OverflowVisible => "overflow-visible".to_string(),
+        StyleClass::OverflowScroll => "overflow-scroll".to_string(),
+        StyleClass::OverflowXAuto => "overflow-x-auto".to_string(),
+        StyleClass::OverflowYAuto => "overflow-y-auto".to_string(),
+        StyleClass::Grid => "grid".to_string(),
+        StyleClass::GridCols(n) => format!("grid-cols-{}", n),
+        StyleClass::GridRows(n) => format!("grid-rows-{}", n),
+        StyleClass::ColSpan(n) => format!("col-span-{}", n),
+        StyleClass::RowSpan(n) => format!("row-span-{}", n),
+        StyleClass::ColStart(n) => format!("col-start-{}", n),
+        StyleClass::RowStart(n) => format!("row-start-{}", n),
+        StyleClass::Hidden => "hidden".to_string(),
+        StyleClass::Block => "block".to_string(),
+    }
+}
+
+fn size_name(size: &SizeValue) -> String {
+    match size {
+        SizeValue::Full => "full".to_string(),
+        SizeValue::Half => "1/2".to_string(),
+        SizeValue::Third => "1/3".to_string(),
+        SizeValue::TwoThirds => "2/3".to_string(),
+        SizeValue::Quarter => "1/4".to_string(),
+        SizeValue::ThreeQuarters => "3/4".to_string(),
+        SizeValue::Auto => "auto".to_string(),
+        SizeValue::Fixed(units) => units.to_string(),
+        SizeValue::Px(px) => format!("[{}px]", px),
+    }
+}
+
+/// CSS declarations implementing a class under `theme`
+fn declarations(class: &StyleClass, theme: &Theme) -> String {
+    let size = |size: &SizeValue| css_size(size, theme);
+    let radius = |px: f32| format!("border-radius:{}px", px);
+    let font_size = |px: f32| format!("font-size:{}px", px);
+
+    match class {
+        StyleClass::Padding(s) => format!("padding:{}", size(s)),
+        StyleClass::PaddingX(s) => format!("padding-left:{0};padding-right:{0}", size(s)),
+        StyleClass::PaddingY(s) => format!("padding-top:{0};padding-bottom:{0}", size(s)),
+        StyleClass::Margin(s) => format!("margin:{}", size(s)),
+        StyleClass::MarginX(s) => format!("margin-left:{0};margin-right:{0}", size(s)),
+        StyleClass::MarginY(s) => format!("margin-top:{0};margin-bottom:{0}", size(s)),
+        StyleClass::Gap(s) => format!("gap:{}", size(s)),
+        StyleClass::BackgroundColor(c) => format!("background-color:{}", css_color(c, theme)),
+        StyleClass::TextColor(c) => format!("color:{}", css_color(c, theme)),
+        StyleClass::Flex => "display:flex".to_string(),
+        StyleClass::Flex1 => "flex:1 1 0%".to_string(),
+        StyleClass::FlexRow => "flex-direction:row".to_string(),
+        StyleClass::FlexCol => "flex-direction:column".to_string(),
+        StyleClass::ItemsCenter => "align-items:center".to_string(),
+        StyleClass::ItemsStart => "align-items:flex-start".to_string(),
+        StyleClass::ItemsEnd => "align-items:flex-end".to_string(),
+        StyleClass::JustifyCenter => "justify-content:center".to_string(),
+        StyleClass::JustifyBetween => "justify-content:space-between".to_string(),
+        StyleClass::JustifyStart => "justify-content:flex-start".to_string(),
+        StyleClass::JustifyEnd => "justify-content:flex-end".to_string(),
+        StyleClass::Width(s) => format!("width:{}", size(s)),
+        StyleClass::Height(s) => format!("height:{}", size(s)),
+        StyleClass::Rounded | StyleClass::RoundedMd => radius(theme.radii.md),
+        StyleClass::RoundedSm => radius(theme.radii.sm),
+        StyleClass::RoundedLg => radius(theme.radii.lg),
+        StyleClass::RoundedXl => radius(theme.radii.xl),
+        StyleClass::Rounded2Xl => radius(theme.radii.xxl),
+        StyleClass::Rounded3Xl => radius(theme.radii.xxxl),
+        StyleClass::RoundedFull => radius(theme.radii.full),
+        StyleClass::RoundedPx(px) => radius(f32::from(*px)),
+        StyleClass::Border => "border-width:1px;border-style:solid".to_string(),
+        StyleClass::Border0 => "border-width:0".to_string(),
+        StyleClass::BorderColor(c) => format!("border-color:{}", css_color(c, theme)),
+        StyleClass::TextXs => font_size(theme.font_sizes.xs),
+        StyleClass::TextSm => font_size(theme.font_sizes.sm),
+        StyleClass::TextBase => font_size(theme.font_sizes.base),
+        StyleClass::TextLg => font_size(theme.font_sizes.lg),
+        StyleClass::TextXl => font_size(theme.font_sizes.xl),
+        StyleClass::Text2Xl => font_size(theme.font_sizes.xxl),
+        StyleClass::Text3Xl => font_size(theme.font_sizes.xxxl),
+        StyleClass::FontSize(px) => font_size(f32::from(*px)),
+        StyleClass::FontBold => "font-weight:700".to_string(),
+        StyleClass::FontMedium => "font-weight:500".to_string(),
+        StyleClass::FontNormal => "font-weight:400".to_string(),
+        StyleClass::TextCenter => "text-align:center".to_string(),
+        StyleClass::TextLeft => "text-align:left".to_string(),
+        StyleClass::TextRight => "text-align:right".to_string(),
+        // Tailwind's shadow scale
+        StyleClass::ShadowSm => "box-shadow:0 1px 2px 0 rgb(0 0 0 / 0.05)".to_string(),
+        StyleClass::Shadow => {
+            "box-shadow:0 1px 3px 0 rgb(0 0 0 / 0.1),0 1px 2px -1px rgb(0 0 0 / 0.1)".to_string()
+        }
+        StyleClass::ShadowMd => {
+            "box-shadow:0 4px 6px -1px rgb(0 0 0 / 0.1),0 2px 4px -2px rgb(0 0 0 / 0.1)".to_string()
+        }
+        StyleClass::ShadowLg => {
+            "box-shadow:0 10px 15px -3px rgb(0 0 0 / 0.1),0 4px 6px -4px rgb(0 0 0 / 0.1)".to_string()
+        }
+        StyleClass::ShadowXl => {
+            "box-shadow:0 20px 25px -5px rgb(0 0 0 / 0.1),0 8px 10px -6px rgb(0 0 0 / 0.1)".to_string()
+        }
+        StyleClass::Shadow2Xl => "box-shadow:0 25px 50px -12px rgb(0 0 0 / 0.25)".to_string(),
+        StyleClass::ShadowNone => "box-shadow:none".to_string(),
+        StyleClass::Opacity(value) => format!("opacity:{}", f32::from(*value) / 100.0),
+        StyleClass::Relative => "position:relative".to_string(),
+        StyleClass::Absolute => "position:absolute".to_string(),
+        StyleClass::ZIndex(value) => format!("z-index:{}", value),
+        StyleClass::OverflowAuto => "overflow:auto".to_string(),
+        StyleClass::OverflowHidden => "overflow:hidden".to_string(),
+        StyleClass::OverflowVisible => "overflow:visible".to_string(),
+        StyleClass::OverflowScroll => "overflow:scroll".to_string(),
+        StyleClass::OverflowXAuto => "overflow-x:auto".to_string(),
+        StyleClass::OverflowYAuto => "overflow-y:auto".to_string(),
+        StyleClass::Grid => "display:grid".to_string(),
+        StyleClass::GridCols(n) => format!("grid-template-columns:repeat({},minmax(0,1fr))", n),
+        StyleClass::GridRows(n) => format!("grid-template-rows:repeat({},minmax(0,1fr))", n),
+        StyleClass::ColSpan(n) => format!("grid-column:span {0} / span {0}", n),
+        StyleClass::RowSpan(n) => format!("grid-row:span {0} / span {0}", n),
+        StyleClass::ColStart(n) => format!("grid-column-start:{}", n),
+        StyleClass::RowStart(n) => format!("grid-row-start:{}", n),
+        StyleClass::Hidden => "display:none".to_string(),
+        StyleClass::Block => "display:block".to_string(),
+    }
+}
+
+/// CSS length of a size value
+pub(crate) fn css_size(size: &SizeValue, theme: &Theme) -> String {
+    match size {
+        SizeValue::Full => "100%".to_string(),
+        SizeValue::Half => "50%".to_string(),
+        SizeValue::Third => "33.333333%".to_string(),
+        SizeValue::TwoThirds => "66.666667%".to_string(),
+        SizeValue::Quarter => "25%".to_string(),
+        SizeValue::ThreeQuarters => "75%".to_string(),
+        SizeValue::Auto => "auto".to_string(),
+        SizeValue::Fixed(_) | SizeValue::Px(_) => format!("{}px", theme.spacing(size)),
+    }
+}
+
+/// CSS color value, with semantic tokens resolved by the theme
+pub(crate) fn css_color(color: &Color, theme: &Theme) -> String {
+    let (r, g, b, a) = theme.rgba(color);
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    if a < 1.0 {
+        format!("rgba({},{},{},{})", channel(r), channel(g), channel(b), a)
+    } else {
+        format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+    }
+}
+
+/// Escape a class name for use in a CSS selector (`md:w-1/2` -> `md\:w-1\/2`)
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if !(c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_class_names_round_trip() {
+        let theme = Theme::light();
+        let source = "p-4 w-1/2 bg-blue-500 text-[15px] rounded-[10px] bg-[#1e293b] hover:bg-white md:flex-row lg:hover:text-red-600";
+        let style = Style::parse(source).unwrap();
+
+        let names = class_names(&style, &theme);
+        assert_eq!(names.join(" "), source);
+        assert_eq!(Style::parse(&names.join(" ")).unwrap(), style);
+    }
+
+    #[test]
+    fn test_semantic_colors_become_arbitrary_values() {
+        let theme = Theme::light();
+        let expected = format!("bg-[{}]", css_color(&theme.colors.primary, &theme));
+        let style = Style::parse("bg-primary").unwrap();
+        assert_eq!(class_names(&style, &theme), vec![expected]);
+    }
+
+    #[test]
+    fn test_css_rules() {
+        let theme = Theme::light();
+        let style = Style::parse("p-4 w-1/2 hover:bg-white md:flex-row").unwrap();
+        let rules = rules(&style, &theme);
+
+        assert_eq!(rules[0].css, ".p-4{padding:16px}");
+        assert_eq!(rules[1].css, ".w-1\\/2{width:50%}");
+        assert_eq!(rules[2].css, ".hover\\:bg-white:hover{background-color:#ffffff}");
+        assert_eq!(rules[2].layer, 1);
+        assert_eq!(rules[3].css, "@media (min-width: 768px){.md\\:flex-row{flex-direction:row}}");
+        assert_eq!(LAYERS[rules[3].layer], "md");
+    }
+}
@@ -59,7 +59,7 @@ impl Component for AllComponents {
             .child(
                 View::input("Enter text...")
                     .value(self.input_value.clone())
-                    .on_change(Msg::InputChanged("dummy".to_string()))
+                    .on_change(Msg::InputChanged)
                     .build(),
             )
             // Display input value
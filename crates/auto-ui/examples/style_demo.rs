@@ -31,7 +31,7 @@ fn main() {
 
     let style = Style::parse(counter_style).unwrap();
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::Padding(size) => println!("  ✅ Padding: {:?}", size),
             StyleClass::Gap(size) => println!("  ✅ Gap: {:?}", size),
@@ -53,7 +53,7 @@ fn main() {
     // Note: px and py are not in L1, but p-4 works
     let style = Style::parse("p-2 bg-white text-slate-500 rounded w-full").unwrap();
     println!("解析结果 (L1 简化版): {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         println!("  ✅ {:?}", class);
     }
     println!();
@@ -65,7 +65,7 @@ fn main() {
 
     let style = Style::parse(layout_style).unwrap();
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         println!("  ✅ {:?}", class);
     }
     println!();
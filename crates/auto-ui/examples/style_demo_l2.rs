@@ -18,7 +18,7 @@ fn main() {
     let style = Style::parse("px-4 py-2 bg-white rounded").unwrap();
     println!("输入: \"px-4 py-2 bg-white rounded\"\n");
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::PaddingX(size) => println!("  ✅ PaddingX: {:?}", size),
             StyleClass::PaddingY(size) => println!("  ✅ PaddingY: {:?}", size),
@@ -32,7 +32,7 @@ fn main() {
     let style = Style::parse("m-4 mx-2 my-2 bg-gray-100").unwrap();
     println!("输入: \"m-4 mx-2 my-2 bg-gray-100\"\n");
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::Margin(size) => println!("  ✅ Margin: {:?} (GPUI only)", size),
             StyleClass::MarginX(size) => println!("  ✅ MarginX: {:?} (GPUI only)", size),
@@ -47,7 +47,7 @@ fn main() {
     let style = Style::parse("flex items-center justify-between").unwrap();
     println!("输入: \"flex items-center justify-between\"\n");
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         println!("  ✅ {:?}", class);
     }
     println!();
@@ -56,7 +56,7 @@ fn main() {
     let style = Style::parse("flex-1 bg-white").unwrap();
     println!("输入: \"flex-1 bg-white\"\n");
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::Flex1 => println!("  ✅ Flex1 (grow to fill space)"),
             _ => println!("  ✅ {:?}", class),
@@ -91,7 +91,7 @@ fn main() {
     let style = Style::parse("font-bold text-center").unwrap();
     println!("输入: \"font-bold text-center\"\n");
     println!("解析结果: {} 个样式类", style.classes.len());
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::FontBold => println!("  ✅ FontBold"),
             StyleClass::TextCenter => println!("  ✅ TextCenter"),
@@ -172,7 +172,7 @@ fn main() {
 
     println!("Main: \"{}\"", main_style);
     let style = Style::parse(main_style).unwrap();
-    for class in &style.classes {
+    for class in style.classes.iter() {
         match class {
             StyleClass::Flex1 => println!("  ✅ Flex1 (main content fills remaining space)"),
             _ => println!("  ✅ {:?}", class),
@@ -5,12 +5,15 @@
 //
 // Usage:
 //   cargo run --package auto-ui -- file input.at [output.rs]
+//   cargo run --package auto-ui -- file input.at Counter.vue --target vue
+//   cargo run --package auto-ui -- file input.at Counter.kt --target compose
+//   cargo run --package auto-ui -- file input.at Counter.ets --target arkts
 //   cargo run --package auto-ui -- run input.at -b gpui
 //   cargo run --package auto-ui -- batch --input ./src --output ./gen
 //   cargo run --package auto-ui -- watch --input ./src --output ./gen
 
 use anyhow::Result;
-use auto_ui::trans::transpile_file;
+use auto_ui::trans::{transpile_file, transpile_file_to, Target};
 use clap::{Parser, Subcommand};
 use console::style;
 use miette::Report;
@@ -39,19 +42,23 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Transpile a single .at file to Rust code
+    /// Transpile a single .at file to Rust (or Vue, Compose, ArkTS) code
     File {
         /// Input .at file to transpile
         #[arg(value_name = "INPUT")]
         input: PathBuf,
 
-        /// Output Rust file (writes to stdout if not specified)
+        /// Output file (writes to stdout if not specified)
         #[arg(value_name = "OUTPUT")]
         output: Option<PathBuf>,
 
         /// Check syntax without writing output
         #[arg(long)]
         check: bool,
+
+        /// Code to generate: rust, vue, compose or arkts
+        #[arg(short, long, default_value = "rust")]
+        target: Target,
     },
 
     /// Transpile all .at files in a directory
@@ -118,8 +125,8 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::File { input, output, check } => {
-            run_file(&input, output.as_ref(), check)
+        Commands::File { input, output, check, target } => {
+            run_file(&input, output.as_ref(), check, target)
         }
         Commands::Batch { input, output, jobs, preserve_structure } => {
             run_batch(&input, &output, jobs, preserve_structure)
@@ -137,10 +144,13 @@ fn main() -> Result<()> {
 }
 
 /// Transpile a single .at file
-fn run_file(input: &PathBuf, output: Option<&PathBuf>, check: bool) -> Result<()> {
+fn run_file(input: &PathBuf, output: Option<&PathBuf>, check: bool, target: Target) -> Result<()> {
     println!("{}", style("Auto UI Transpiler").cyan().bold());
     println!("{}", style("==================").cyan().bold());
     println!("Input: {}", style(input.display()));
+    if target != Target::Rust {
+        println!("Target: {}", style(target));
+    }
     if let Some(out) = output {
         println!("Output: {}", style(out.display()));
     }
@@ -159,7 +169,7 @@ fn run_file(input: &PathBuf, output: Option<&PathBuf>, check: bool) -> Result<()
         .map_err(|e| anyhow::anyhow!("{}: {}", style("Failed to read file").red(), e))?;
 
     // Transpile the file
-    let rust_code = transpile_file(input, None)
+    let code = transpile_file_to(input, target)
         .map_err(|e| {
             // Create miette-compatible error with source code and location
             let transpile_error = TranspileError::with_extracted_offset(
@@ -193,16 +203,22 @@ fn run_file(input: &PathBuf, output: Option<&PathBuf>, check: bool) -> Result<()
         }
 
         // Write to output file
-        fs::write(output_path, &rust_code)
+        fs::write(output_path, &code)
             .map_err(|e| anyhow::anyhow!("{}: {}", style("Failed to write output file").red(), e))?;
         println!("{} {} {}", style("✓").green(), style("Generated").green(), style(output_path.display()));
         println!("  Time: {:?}", elapsed);
     } else {
         // Print to stdout
-        println!("{}", style("// Generated Rust Code").dim());
-        println!("{}", style("=====================").dim());
+        let header = match target {
+            Target::Rust => "// Generated Rust Code",
+            Target::Vue => "<!-- Generated Vue Component -->",
+            Target::Compose => "// Generated Compose Code",
+            Target::ArkTs => "// Generated ArkTS Component",
+        };
+        println!("{}", style(header).dim());
+        println!("{}", style("=".repeat(header.len())).dim());
         println!();
-        println!("{}", rust_code);
+        println!("{}", code);
     }
 
     Ok(())
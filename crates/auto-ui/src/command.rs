@@ -0,0 +1,215 @@
+// Command abstraction - backend-agnostic side effects
+//
+// A Command is returned from `Component::update` and describes work the runtime
+// should perform after the state mutation: run an async task and feed its result
+// back as a message, exit the application, or nothing at all.
+//
+// Backends translate commands into their native primitives:
+// - iced: `iced::Task`
+// - gpui: `cx.spawn` / `cx.quit`
+//
+// `SetTheme` is applied by the backend itself: it swaps the active Theme and the
+// next render picks it up.
+
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::style::Theme;
+
+/// Boxed future producing a message, as stored in `Command::Perform`
+pub type CommandFuture<M> = Pin<Box<dyn Future<Output = M> + Send + 'static>>;
+
+/// Side effect requested by a component
+///
+/// # Example
+/// ```ignore
+/// fn update(&mut self, msg: Msg) -> Command<Msg> {
+///     match msg {
+///         Msg::Load => Command::perform(fetch_data(), Msg::Loaded),
+///         Msg::Loaded(data) => {
+///             self.data = data;
+///             Command::none()
+///         }
+///         Msg::Quit => Command::exit(),
+///     }
+/// }
+/// ```
+pub enum Command<M> {
+    /// No side effect
+    None,
+    /// Run several commands concurrently
+    Batch(Vec<Command<M>>),
+    /// Run a future and deliver its output as a message
+    Perform(CommandFuture<M>),
+    /// Close the application
+    Exit,
+    /// Switch the active theme at runtime
+    SetTheme(Theme),
+}
+
+impl<M> Command<M> {
+    /// Create an empty command
+    pub fn none() -> Self {
+        Command::None
+    }
+
+    /// Combine several commands into one
+    pub fn batch(commands: impl IntoIterator<Item = Command<M>>) -> Self {
+        Command::Batch(commands.into_iter().collect())
+    }
+
+    /// Run `future` and map its output into a message with `map`
+    pub fn perform<T, F>(future: F, map: impl FnOnce(T) -> M + Send + 'static) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+        M: 'static,
+    {
+        Command::Perform(Box::pin(async move { map(future.await) }))
+    }
+
+    /// Request the application to exit
+    pub fn exit() -> Self {
+        Command::Exit
+    }
+
+    /// Switch the active theme, e.g. in response to a "dark mode" toggle
+    ///
+    /// ```ignore
+    /// Msg::ToggleDark(on) => Command::set_theme(if on { Theme::dark() } else { Theme::light() }),
+    /// ```
+    pub fn set_theme(theme: Theme) -> Self {
+        Command::SetTheme(theme)
+    }
+
+    /// Transform the message this command produces
+    ///
+    /// Lets a parent forward a child component's command under its own message type.
+    pub fn map<N: 'static>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> Command<N>
+    where
+        M: 'static,
+    {
+        let f: Arc<dyn Fn(M) -> N + Send + Sync> = Arc::new(f);
+        self.map_with(&f)
+    }
+
+    fn map_with<N: 'static>(self, f: &Arc<dyn Fn(M) -> N + Send + Sync>) -> Command<N>
+    where
+        M: 'static,
+    {
+        match self {
+            Command::None => Command::None,
+            Command::Batch(commands) => {
+                Command::Batch(commands.into_iter().map(|command| command.map_with(f)).collect())
+            }
+            Command::Perform(future) => {
+                let f = f.clone();
+                Command::Perform(Box::pin(async move { f(future.await) }))
+            }
+            Command::Exit => Command::Exit,
+            Command::SetTheme(theme) => Command::SetTheme(theme),
+        }
+    }
+
+    /// Check whether this command does nothing
+    pub fn is_none(&self) -> bool {
+        match self {
+            Command::None => true,
+            Command::Batch(commands) => commands.iter().all(Command::is_none),
+            _ => false,
+        }
+    }
+}
+
+impl<M> Default for Command<M> {
+    fn default() -> Self {
+        Command::None
+    }
+}
+
+impl<M> Debug for Command<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::None => write!(f, "Command::None"),
+            Command::Batch(commands) => f.debug_tuple("Command::Batch").field(commands).finish(),
+            Command::Perform(_) => write!(f, "Command::Perform(<future>)"),
+            Command::Exit => write!(f, "Command::Exit"),
+            Command::SetTheme(theme) => write!(f, "Command::SetTheme({})", theme.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestMsg {
+        Loaded(i32),
+    }
+
+    #[test]
+    fn test_none_and_batch() {
+        let cmd: Command<TestMsg> = Command::none();
+        assert!(cmd.is_none());
+
+        let cmd: Command<TestMsg> = Command::batch(vec![Command::none(), Command::none()]);
+        assert!(cmd.is_none());
+
+        let cmd: Command<TestMsg> = Command::batch(vec![Command::none(), Command::exit()]);
+        assert!(!cmd.is_none());
+    }
+
+    #[test]
+    fn test_perform_maps_output() {
+        let cmd = Command::perform(async { 21 }, |v| TestMsg::Loaded(v * 2));
+
+        let future = match cmd {
+            Command::Perform(future) => future,
+            other => panic!("Expected Command::Perform, got {:?}", other),
+        };
+
+        let msg = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future);
+        assert_eq!(msg, TestMsg::Loaded(42));
+    }
+
+    #[test]
+    fn test_map_wraps_output() {
+        #[derive(Debug, PartialEq)]
+        enum Parent {
+            Child(TestMsg),
+        }
+
+        let cmd = Command::batch(vec![
+            Command::perform(async { 1 }, TestMsg::Loaded),
+            Command::exit(),
+        ])
+        .map(Parent::Child);
+
+        let mut commands = match cmd {
+            Command::Batch(commands) => commands,
+            other => panic!("Expected Command::Batch, got {:?}", other),
+        };
+        assert!(matches!(commands.pop(), Some(Command::Exit)));
+
+        let future = match commands.pop() {
+            Some(Command::Perform(future)) => future,
+            other => panic!("Expected Command::Perform, got {:?}", other),
+        };
+        let msg = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future);
+        assert_eq!(msg, Parent::Child(TestMsg::Loaded(1)));
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let cmd: Command<TestMsg> = Command::batch(vec![Command::exit()]);
+        assert_eq!(format!("{:?}", cmd), "Command::Batch([Command::Exit])");
+    }
+}
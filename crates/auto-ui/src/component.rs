@@ -1,5 +1,7 @@
 // Component abstraction - improved version aligned with Auto language
 
+use crate::command::Command;
+use crate::subscription::Subscription;
 use crate::view::View;
 use std::fmt::Debug;
 
@@ -38,10 +40,42 @@ pub trait Component: Sized + Debug {
     /// Handle messages - Auto's equivalent of `fn on(ev Msg)`
     ///
     /// This is where state mutations happen based on incoming messages.
+    /// Components that need side effects also override `update`.
     fn on(&mut self, msg: Self::Msg);
 
+    /// Handle messages and return a side effect for the runtime to execute
+    ///
+    /// The default implementation delegates to `on` and returns `Command::none()`,
+    /// so existing components keep working unchanged.
+    ///
+    /// ```ignore
+    /// fn update(&mut self, msg: Msg) -> Command<Msg> {
+    ///     match msg {
+    ///         Msg::Refresh => Command::perform(load_items(), Msg::Loaded),
+    ///         Msg::Loaded(items) => {
+    ///             self.items = items;
+    ///             Command::none()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+        self.on(msg);
+        Command::none()
+    }
+
+    /// Declare external event sources (timers, keyboard, window, files)
+    ///
+    /// Called again after every update, so the returned sources may depend on state.
+    /// Backends keep a source running as long as it stays declared.
+    fn subscription(&self) -> Subscription<Self::Msg> {
+        Subscription::none()
+    }
+
     /// Render the view - Auto's equivalent of `fn view() View`
     ///
     /// Returns the abstract view tree that will be adapted to specific backends.
+    /// Backends call it again when the window is resized, so layouts can branch
+    /// on `WindowSize::current()` as well as use `md:`-style breakpoint classes.
     fn view(&self) -> View<Self::Msg>;
 }
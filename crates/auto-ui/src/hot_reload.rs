@@ -5,10 +5,12 @@
 
 use auto_val::Node;
 use crate::node_converter::{convert_node, ConversionError};
+use std::fmt;
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
+use std::sync::{Arc, Mutex, RwLock};
 use notify::{RecommendedWatcher, RecursiveMode, Event, EventKind, Watcher};
 use thiserror::Error;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 /// Errors that can occur during hot-reload operations
 #[derive(Debug, Error)]
@@ -23,12 +25,112 @@ pub enum HotReloadError {
     Conversion(#[from] ConversionError),
 
     #[error("Parse error: {0}")]
-    Parse(String),
+    Parse(ParseError),
+
+    #[error("Lock error: {0}")]
+    Lock(String),
 
     #[error("File not found: {0}")]
     FileNotFound(PathBuf),
 }
 
+/// Position in an .at source file (1-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourceLocation {
+    /// Convert a byte offset into a line/column pair
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let offset = offset.min(source.len());
+        let before = &source[..floor_char_boundary(source, offset)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        Self { line, column }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Error produced while parsing or evaluating an .at file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// Where the error occurred, when the parser reported a position
+    pub location: Option<SourceLocation>,
+}
+
+impl ParseError {
+    /// Create an error without a source position
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Build an error from an auto-lang diagnostic
+    ///
+    /// The position comes from the diagnostic's primary label (or its first
+    /// label), falling back to related diagnostics when it has none; the byte
+    /// offset is resolved against `source` into a line and column.
+    #[cfg(feature = "interpreter")]
+    pub fn from_diagnostic(source: &str, diagnostic: &dyn miette::Diagnostic) -> Self {
+        Self {
+            message: diagnostic.to_string(),
+            location: diagnostic_offset(diagnostic).map(|offset| SourceLocation::from_offset(source, offset)),
+        }
+    }
+}
+
+/// Byte offset of the label a diagnostic points at
+#[cfg(feature = "interpreter")]
+fn diagnostic_offset(diagnostic: &dyn miette::Diagnostic) -> Option<usize> {
+    let labels: Vec<_> = diagnostic.labels().map(|labels| labels.collect()).unwrap_or_default();
+    labels
+        .iter()
+        .find(|label| label.primary())
+        .or_else(|| labels.first())
+        .map(|label| label.offset())
+        .or_else(|| diagnostic.related()?.find_map(diagnostic_offset))
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error from the last load, reload or view conversion of a component
+#[derive(Debug, Clone, Error)]
+pub enum ComponentError {
+    #[error("Parse error: {0}")]
+    Parse(ParseError),
+
+    #[error("Conversion error: {0}")]
+    Conversion(ConversionError),
+}
+
+/// Round `offset` down to the nearest char boundary
+fn floor_char_boundary(source: &str, mut offset: usize) -> usize {
+    while offset > 0 && !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
 /// Result type for hot-reload operations
 pub type HotReloadResult<T> = Result<T, HotReloadError>;
 
@@ -48,10 +150,12 @@ pub type HotReloadResult<T> = Result<T, HotReloadError>;
 pub struct HotReloadComponent {
     /// Path to the .at file
     path: PathBuf,
+    /// Source of the last successful parse
+    source: RwLock<String>,
     /// Last parsed UI node
     node: RwLock<Node>,
-    /// Last conversion error (if any)
-    error: RwLock<Option<ConversionError>>,
+    /// Last parse or conversion error (if any)
+    error: RwLock<Option<ComponentError>>,
 }
 
 impl HotReloadComponent {
@@ -75,6 +179,7 @@ impl HotReloadComponent {
 
         Ok(Self {
             path: path.to_path_buf(),
+            source: RwLock::new(content),
             node: RwLock::new(node),
             error: RwLock::new(None),
         })
@@ -93,16 +198,26 @@ impl HotReloadComponent {
         let content = std::fs::read_to_string(&self.path)
             .map_err(|_| HotReloadError::FileNotFound(self.path.clone()))?;
 
-        // Parse the content
-        let node = Self::parse_content(&content)?;
+        // Unchanged source (e.g. a metadata-only write) needs no re-evaluation
+        if *self.source.read().map_err(lock_error)? == content && !self.has_error() {
+            return Ok(false);
+        }
+
+        // Parse the content, keeping the previous node on failure
+        let node = match Self::parse_content(&content) {
+            Ok(node) => node,
+            Err(HotReloadError::Parse(e)) => {
+                self.set_error(Some(ComponentError::Parse(e.clone())));
+                return Err(HotReloadError::Parse(e));
+            }
+            Err(e) => return Err(e),
+        };
 
-        // Update the node
-        let mut node_guard = self.node.write().unwrap();
-        *node_guard = node;
+        *self.node.write().map_err(lock_error)? = node;
+        *self.source.write().map_err(lock_error)? = content;
 
         // Clear any previous error
-        let mut error_guard = self.error.write().unwrap();
-        *error_guard = None;
+        self.set_error(None);
 
         Ok(true)
     }
@@ -113,19 +228,33 @@ impl HotReloadComponent {
     /// * `Ok(view)` - Successfully converted View
     /// * `Err(...)` - Conversion failed
     pub fn view(&self) -> HotReloadResult<crate::View<String>> {
-        let node = self.node.read().map_err(|e| {
-            HotReloadError::Parse(format!("RwLock poisoned: {:?}", e))
-        })?;
-        let view = convert_node(&*node)?;
-        Ok(view)
+        let node = self.node.read().map_err(lock_error)?;
+        match convert_node(&node) {
+            Ok(view) => Ok(view),
+            Err(e) => {
+                // Style errors are reported at their position in the .at file
+                let e = e.locate(&self.source.read().map_err(lock_error)?);
+                self.set_error(Some(ComponentError::Conversion(e.clone())));
+                Err(e.into())
+            }
+        }
     }
 
-    /// Get the last reload error (if any)
-    pub fn error(&self) -> Option<ConversionError> {
+    /// Get the last reload or conversion error (if any)
+    ///
+    /// Parse errors carry the line and column reported by the parser, style
+    /// errors the position of the offending class.
+    pub fn error(&self) -> Option<ComponentError> {
         let error_guard = self.error.read().ok()?;
         error_guard.clone()
     }
 
+    fn set_error(&self, error: Option<ComponentError>) {
+        if let Ok(mut guard) = self.error.write() {
+            *guard = error;
+        }
+    }
+
     /// Check if there's currently an error
     pub fn has_error(&self) -> bool {
         self.error().is_some()
@@ -136,24 +265,66 @@ impl HotReloadComponent {
         &self.path
     }
 
-    /// Parse AutoLang content and extract the UI node
-    ///
-    /// This is a simplified parser that extracts the first Node from the content.
-    /// A production version would use the full auto-lang parser.
+    /// Parse and evaluate AutoLang content, returning the resulting UI node
+    #[cfg(feature = "interpreter")]
+    fn parse_content(content: &str) -> HotReloadResult<Node> {
+        use auto_lang::interp::Interpreter;
+        use auto_val::Value;
+
+        let mut interpreter = Interpreter::new();
+        interpreter.interpret(content).map_err(|e| {
+            HotReloadError::Parse(ParseError::from_diagnostic(content, &e))
+        })?;
+
+        match &interpreter.result {
+            Value::Node(node) => Ok(node.clone()),
+            other => Err(HotReloadError::Parse(ParseError::new(format!(
+                "expected the file to evaluate to a UI node, got {:?}",
+                other
+            )))),
+        }
+    }
+
+    /// Without the interpreter there is nothing to evaluate .at files with
+    #[cfg(not(feature = "interpreter"))]
     fn parse_content(_content: &str) -> HotReloadResult<Node> {
-        // For now, return a simple test node
-        // In production, this would use the full parser
-        Ok(Node::new("text").with_arg("Hot-reload test"))
+        Err(HotReloadError::Parse(ParseError::new(
+            "hot-reload requires the `interpreter` feature",
+        )))
+    }
+}
+
+fn lock_error<T>(e: std::sync::PoisonError<T>) -> HotReloadError {
+    HotReloadError::Lock(format!("RwLock poisoned: {}", e))
+}
+
+/// Change to an .at file reported by [`UIWatcher`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UIChange {
+    Created(PathBuf),
+    Modified(PathBuf),
+    Removed(PathBuf),
+}
+
+impl UIChange {
+    /// Path of the changed file
+    pub fn path(&self) -> &Path {
+        match self {
+            UIChange::Created(path) | UIChange::Modified(path) | UIChange::Removed(path) => path,
+        }
     }
 }
 
 /// File watcher for .at files
 ///
-/// Watches a directory for changes to .at files and triggers reloads.
+/// Watches a directory for changes to .at files and delivers them to every
+/// channel obtained from [`UIWatcher::subscribe`].
 pub struct UIWatcher {
     watcher: Option<RecommendedWatcher>,
     /// Path being watched
     watch_path: PathBuf,
+    /// Subscribers receiving change notifications
+    subscribers: Arc<Mutex<Vec<UnboundedSender<UIChange>>>>,
 }
 
 impl UIWatcher {
@@ -168,9 +339,30 @@ impl UIWatcher {
         Ok(Self {
             watcher: None,
             watch_path: PathBuf::new(),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
         })
     }
 
+    /// Subscribe to .at file changes
+    ///
+    /// Subscribing may happen before or after [`UIWatcher::watch`]. Dropping the
+    /// receiver unsubscribes.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut changes = watcher.subscribe();
+    /// while let Some(change) = changes.recv().await {
+    ///     component.reload()?;
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> UnboundedReceiver<UIChange> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(tx);
+        }
+        rx
+    }
+
     /// Watch a directory for .at file changes
     ///
     /// # Arguments
@@ -179,9 +371,10 @@ impl UIWatcher {
         self.watch_path = path.as_ref().to_path_buf();
 
         // Create the actual watcher with proper handler
-        let mut watcher = notify::recommended_watcher(|res| match res {
+        let subscribers = self.subscribers.clone();
+        let mut watcher = notify::recommended_watcher(move |res| match res {
             Ok(event) => {
-                Self::handle_event(event);
+                Self::handle_event(event, &subscribers);
             }
             Err(e) => {
                 eprintln!("Watch error: {:?}", e);
@@ -197,20 +390,34 @@ impl UIWatcher {
     }
 
     /// Internal event handler for file changes
-    fn handle_event(event: Event) {
-        match event.kind {
-            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => {
-                // Trigger reload
-                if let Some(path) = event.paths.first() {
-                    if path.extension().and_then(|s| s.to_str()) == Some("at") {
-                        // File changed - would trigger callback here
-                        println!("File changed: {:?}", path);
-                    }
-                }
-            }
-            _ => {}
+    fn handle_event(event: Event, subscribers: &Mutex<Vec<UnboundedSender<UIChange>>>) {
+        let changes = Self::changes_for(&event);
+        if changes.is_empty() {
+            return;
+        }
+
+        if let Ok(mut subscribers) = subscribers.lock() {
+            // Drop subscribers whose receiver is gone
+            subscribers.retain(|tx| changes.iter().all(|change| tx.send(change.clone()).is_ok()));
         }
     }
+
+    /// Translate a notify event into .at file changes
+    fn changes_for(event: &Event) -> Vec<UIChange> {
+        let make: fn(PathBuf) -> UIChange = match event.kind {
+            EventKind::Create(_) => UIChange::Created,
+            EventKind::Modify(_) => UIChange::Modified,
+            EventKind::Remove(_) => UIChange::Removed,
+            _ => return Vec::new(),
+        };
+
+        event
+            .paths
+            .iter()
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("at"))
+            .map(|path| make(path.clone()))
+            .collect()
+    }
 }
 
 /// Message wrapper for hot-reload components
@@ -247,12 +454,28 @@ pub fn hot_reloadable_app<F>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use notify::event::{CreateKind, ModifyKind};
 
+    #[cfg(feature = "interpreter")]
     #[test]
     fn test_hot_reload_component_load() {
-        // This test requires actual .at files to exist
-        // For now, just verify the API compiles
-        assert!(true);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hello.at");
+        std::fs::write(&path, "col {\n    text(\"Hello\") {}\n}\n").unwrap();
+
+        let component = HotReloadComponent::load(&path).unwrap();
+        assert_eq!(component.path(), path.as_path());
+        assert!(component.view().is_ok());
+        assert!(!component.has_error());
+
+        // A syntax error on reload is reported with its position and keeps the old node
+        std::fs::write(&path, "col {\n    text(\n").unwrap();
+        match component.reload() {
+            Err(HotReloadError::Parse(e)) => assert!(e.location.is_some(), "no position in {:?}", e),
+            other => panic!("Expected parse error, got {:?}", other),
+        }
+        assert!(component.has_error());
+        assert!(component.view().is_ok());
     }
 
     #[test]
@@ -262,4 +485,97 @@ mod tests {
         // Just verify it compiles - actual file watching depends on OS
         let _ = result;
     }
+
+    #[test]
+    fn test_source_location_from_offset() {
+        let source = "widget Counter {\n    count int\n    bad {\n}";
+        let offset = source.find("bad").unwrap();
+
+        let location = SourceLocation::from_offset(source, offset);
+        assert_eq!(location, SourceLocation { line: 3, column: 5 });
+        assert_eq!(SourceLocation::from_offset(source, 0), SourceLocation { line: 1, column: 1 });
+    }
+
+    #[cfg(feature = "interpreter")]
+    #[derive(Debug, Error, miette::Diagnostic)]
+    #[error("unexpected token")]
+    struct SyntaxError {
+        #[label("here")]
+        span: miette::SourceSpan,
+    }
+
+    #[cfg(feature = "interpreter")]
+    #[test]
+    fn test_parse_error_from_diagnostic() {
+        let source = "col {\n  text(\n}";
+        let error = ParseError::from_diagnostic(source, &SyntaxError { span: (14, 1).into() });
+
+        assert_eq!(error.location, Some(SourceLocation { line: 3, column: 1 }));
+        assert_eq!(error.to_string(), "3:1: unexpected token");
+
+        #[derive(Debug, Error, miette::Diagnostic)]
+        #[error("eval failed")]
+        struct EvalError;
+
+        let error = ParseError::from_diagnostic(source, &EvalError);
+        assert_eq!(error.location, None);
+        assert_eq!(error.to_string(), "eval failed");
+
+        // Grouped errors point at their first related diagnostic
+        #[derive(Debug, Error, miette::Diagnostic)]
+        #[error("2 errors")]
+        struct Errors {
+            #[related]
+            errors: Vec<SyntaxError>,
+        }
+
+        let errors = Errors { errors: vec![SyntaxError { span: (8, 4).into() }, SyntaxError { span: (14, 1).into() }] };
+        let error = ParseError::from_diagnostic(source, &errors);
+        assert_eq!(error.location, Some(SourceLocation { line: 2, column: 3 }));
+    }
+
+    #[test]
+    fn test_changes_for_filters_at_files() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("ui/counter.at"))
+            .add_path(PathBuf::from("ui/notes.txt"));
+        assert_eq!(
+            UIWatcher::changes_for(&event),
+            vec![UIChange::Modified(PathBuf::from("ui/counter.at"))]
+        );
+
+        let event = Event::new(EventKind::Access(notify::event::AccessKind::Any))
+            .add_path(PathBuf::from("ui/counter.at"));
+        assert!(UIWatcher::changes_for(&event).is_empty());
+    }
+
+    #[test]
+    fn test_handle_event_delivers_to_subscribers() {
+        let watcher = UIWatcher::new().unwrap();
+        let mut first = watcher.subscribe();
+        let second = watcher.subscribe();
+        drop(second);
+
+        let event = Event::new(EventKind::Create(CreateKind::File))
+            .add_path(PathBuf::from("ui/app.at"));
+        UIWatcher::handle_event(event, &watcher.subscribers);
+
+        assert_eq!(first.try_recv().unwrap(), UIChange::Created(PathBuf::from("ui/app.at")));
+        // The dropped subscriber was removed
+        assert_eq!(watcher.subscribers.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "interpreter")]
+    #[test]
+    fn test_reload_reports_parse_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.at");
+        std::fs::write(&path, "col {\n    text(\n").unwrap();
+
+        match HotReloadComponent::load(&path) {
+            Err(HotReloadError::Parse(_)) => {}
+            Err(e) => panic!("Expected parse error, got {:?}", e),
+            Ok(_) => panic!("Expected parse error"),
+        }
+    }
 }
@@ -18,10 +18,15 @@
 //! GPUI 渲染
 //! ```
 
+use auto_lang::ast::{Stmt, Type};
 use auto_lang::interp::Interpreter;
+use auto_lang::{Parser, Universe};
 use auto_val::{Node, Value};
-use std::path::Path;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
 
 // 结果类型别名
 pub type Result<T> = std::result::Result<T, BridgeError>;
@@ -37,6 +42,12 @@ pub enum BridgeError {
 
     #[error("Lock error: {0}")]
     Lock(String),
+
+    #[error("Widget not found: {0}")]
+    WidgetNotFound(String),
+
+    #[error("Invalid event: {0}")]
+    InvalidEvent(String),
 }
 
 /// 动态消息（保留类型信息）
@@ -61,6 +72,9 @@ pub struct InterpreterBridge {
     /// Widget 实例状态（widget_name → state）
     widget_states: HashMap<String, WidgetState>,
 
+    /// 最近一次加载的 Widget 类型声明（widget_name → decl），用于热重载时比对字段
+    widget_decls: HashMap<String, WidgetDecl>,
+
     /// 是否启用热重载
     hot_reload: bool,
 }
@@ -71,6 +85,9 @@ pub struct WidgetState {
     /// 字段值
     pub fields: HashMap<String, Value>,
 
+    /// `on()` 方法接收的消息枚举名（未限定的事件名会加上此前缀）
+    pub msg_type: String,
+
     /// 缓存的视图节点
     pub cached_node: Option<Node>,
 
@@ -78,12 +95,31 @@ pub struct WidgetState {
     pub view_dirty: bool,
 }
 
+impl WidgetState {
+    /// 以初始字段值创建状态，消息枚举默认为 `Msg`
+    pub fn new(fields: HashMap<String, Value>) -> Self {
+        Self {
+            fields,
+            msg_type: "Msg".to_string(),
+            cached_node: None,
+            view_dirty: true,
+        }
+    }
+
+    /// 设置消息枚举名
+    pub fn with_msg_type(mut self, msg_type: impl Into<String>) -> Self {
+        self.msg_type = msg_type.into();
+        self
+    }
+}
+
 impl InterpreterBridge {
     /// 创建新的解释器桥梁
     pub fn new() -> Self {
         Self {
             interpreter: Interpreter::new(),
             widget_states: HashMap::new(),
+            widget_decls: HashMap::new(),
             hot_reload: true,
         }
     }
@@ -95,26 +131,145 @@ impl InterpreterBridge {
     }
 
     /// 解释并执行 Auto 代码
+    ///
+    /// 同时记录代码中的 Widget 类型声明，供 [`reload`](Self::reload) 比对字段，
+    /// 并为还没有实例的 Widget 创建实例，使事件能派发到它们的 `on()` 方法。
     pub fn interpret(&mut self, code: &str) -> Result<()> {
+        self.eval(code)?;
+        self.widget_decls = parse_widget_decls(code);
+        self.register_declared_widgets();
+        Ok(())
+    }
+
+    /// 为声明了但还没有实例的 Widget 创建实例，字段取声明默认值
+    ///
+    /// 已有实例保持不变，热重载时由 [`migrate_states`](Self::migrate_states) 迁移。
+    fn register_declared_widgets(&mut self) {
+        let mut decls: Vec<WidgetDecl> = self
+            .widget_decls
+            .values()
+            .filter(|decl| !self.widget_states.contains_key(&decl.name))
+            .cloned()
+            .collect();
+        decls.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for decl in decls {
+            let state = WidgetState::new(self.declared_defaults(&decl))
+                .with_msg_type(decl.msg_type.clone());
+            self.widget_states.insert(decl.name, state);
+        }
+    }
+
+    /// 执行代码（不更新类型声明）
+    fn eval(&mut self, code: &str) -> Result<()> {
         self.interpreter.interpret(code)
             .map_err(|e| BridgeError::AutoLang(e.to_string()))?;
         Ok(())
     }
 
+    /// 执行内部生成的脚本并取回它的结果
+    ///
+    /// 脚本在子作用域中执行，`__event_target` 等临时绑定不会留在用户代码的全局作用域里。
+    /// 解释器的 `result` 保存着主视图，执行后恢复原值。
+    fn eval_script(&mut self, script: &str) -> Result<Value> {
+        let main_result = self.interpreter.result.clone();
+        self.interpreter.scope.borrow_mut().enter_scope();
+        let outcome = self.eval(script);
+        self.interpreter.scope.borrow_mut().exit_scope();
+        let value = std::mem::replace(&mut self.interpreter.result, main_result);
+        outcome.map(|_| value)
+    }
+
+    /// 注册 Widget 实例状态
+    ///
+    /// `widget_name` 是 Widget 的类型名（如 `Counter`），事件派发时用它构造实例。
+    pub fn register_widget(&mut self, widget_name: impl Into<String>, state: WidgetState) {
+        self.widget_states.insert(widget_name.into(), state);
+    }
+
+    /// 获取 Widget 实例状态
+    pub fn widget_state(&self, widget_name: &str) -> Option<&WidgetState> {
+        self.widget_states.get(widget_name)
+    }
+
+    /// 主 Widget 的类型名
+    ///
+    /// 有 App 声明时是 App，否则是代码中唯一的 Widget；都没有时返回 `None`。
+    pub fn main_widget(&self) -> Option<&str> {
+        if let Some(app) = self.widget_decls.values().find(|decl| decl.is_app) {
+            return Some(&app.name);
+        }
+        if self.widget_decls.len() == 1 {
+            return self.widget_decls.keys().next().map(String::as_str);
+        }
+        None
+    }
+
+    /// 解释器的符号表，作为 `convert_node_dynamic` 元数据的一部分
+    pub fn universe(&self) -> Rc<RefCell<Universe>> {
+        self.interpreter.scope.clone()
+    }
+
+    /// App 的 `theme` 字段值
+    ///
+    /// 值是预设名（`"dark"`）或主题文件路径，交给 `Theme::select` 解析。
+    /// 代码中没有 App 声明、或字段无法求值为字符串时返回 `None`。
+    pub fn app_theme(&mut self) -> Option<String> {
+        let app = self.widget_decls.values().find(|decl| decl.is_app)?.name.clone();
+        check_ident(&app).ok()?;
+
+        let script = format!("mut {target} = {app}()\n{target}.theme\n", target = EVENT_TARGET, app = app);
+        match self.eval_script(&script).ok()? {
+            Value::Str(theme) => Some(theme.to_string()),
+            Value::OwnedStr(theme) => Some(theme.to_string()),
+            _ => None,
+        }
+    }
+
     /// 获取主 Widget 的视图节点
     ///
-    /// 此方法会：
-    /// 1. 调用 `main()` 函数
-    /// 2. 或者查找主 Widget 的 `view()` 方法
-    /// 3. 返回求值后的 Node
+    /// 有主 Widget（见 [`main_widget`](Self::main_widget)）时用它当前的字段值调用 `view()`，
+    /// 结果缓存到视图再次变脏为止；否则返回代码顶层求值得到的节点。
     pub fn get_main_view(&mut self) -> Result<Node> {
-        // 临时：返回解释器的结果
-        if let Value::Node(node) = &self.interpreter.result {
-            Ok(node.clone())
-        } else {
-            // 创建一个默认的空节点
-            Ok(Node::new("div"))
+        let widget_name = match self.main_widget() {
+            Some(name) => name.to_string(),
+            None => {
+                return match &self.interpreter.result {
+                    Value::Node(node) => Ok(node.clone()),
+                    // 创建一个默认的空节点
+                    _ => Ok(Node::new("div")),
+                };
+            }
+        };
+
+        let state = self
+            .widget_states
+            .get(&widget_name)
+            .ok_or_else(|| BridgeError::WidgetNotFound(widget_name.clone()))?;
+
+        if !state.view_dirty {
+            if let Some(node) = &state.cached_node {
+                return Ok(node.clone());
+            }
+        }
+
+        let script = view_script(&widget_name, state)?;
+        let node = match self.eval_script(&script)? {
+            Value::Node(node) => node,
+            _ => {
+                return Err(BridgeError::AutoLang(format!(
+                    "{}.view() 没有返回视图节点",
+                    widget_name
+                )))
+            }
+        };
+
+        if let Some(state) = self.widget_states.get_mut(&widget_name) {
+            state.cached_node = Some(node.clone());
+            state.view_dirty = false;
         }
+
+        Ok(node)
     }
 
     /// 处理事件消息
@@ -132,62 +287,174 @@ impl InterpreterBridge {
     }
 
     /// 处理字符串事件
+    ///
+    /// 支持两种格式：
+    /// - `"Widget.Event"`：前缀是已注册的 Widget 名
+    /// - `"Event"` 或 `"Msg.Event"`：发送给主 Widget，没有主 Widget 时发送给唯一注册的 Widget
+    ///
+    /// 无法确定目标 Widget 的事件会被忽略。
     fn handle_string_event(&mut self, event: &str) -> Result<()> {
         // 解析 "widget.event" 格式
-        if let Some(dot_pos) = event.find('.') {
-            let widget_name = &event[..dot_pos];
-            let event_name = &event[dot_pos + 1..];
-            self.handle_typed_event(widget_name, event_name, &[])?;
-        } else {
-            // 尝试调用默认 Widget 的 on() 方法
-            // TODO: 实现默认 Widget 查找
+        if let Some((widget_name, event_name)) = event.split_once('.') {
+            if self.widget_states.contains_key(widget_name) {
+                return self.handle_typed_event(widget_name, event_name, &[]);
+            }
+        }
+
+        // 默认 Widget：主 Widget 或唯一注册的 Widget
+        let widget_name = match self.main_widget() {
+            Some(name) => Some(name.to_string()),
+            None if self.widget_states.len() == 1 => self.widget_states.keys().next().cloned(),
+            None => None,
+        };
+        match widget_name {
+            Some(widget_name) => self.handle_typed_event(&widget_name, event, &[]),
+            None => Ok(()),
         }
-        Ok(())
     }
 
     /// 处理类型化事件
-    fn handle_typed_event(&mut self, widget_name: &str, _event_name: &str, _args: &[Value]) -> Result<()> {
+    ///
+    /// 用当前字段值构造 Widget 实例，通过解释器调用它的 `on()` 方法，
+    /// 再把调用后的字段值写回 `WidgetState` 并标记视图为脏。
+    fn handle_typed_event(&mut self, widget_name: &str, event_name: &str, args: &[Value]) -> Result<()> {
         // 查找 Widget 状态
+        let state = self
+            .widget_states
+            .get(widget_name)
+            .ok_or_else(|| BridgeError::WidgetNotFound(widget_name.to_string()))?;
+
+        let (script, field_names) = event_script(widget_name, state, event_name, args)?;
+
+        let result = self.eval_script(&script)?;
+
+        // 脚本最后一个表达式是调用后的字段值数组
+        let values = match &result {
+            Value::Array(values) => values.iter().cloned().collect::<Vec<_>>(),
+            _ if field_names.is_empty() => Vec::new(),
+            _ => {
+                return Err(BridgeError::AutoLang(format!(
+                    "{}.on() 没有返回字段值",
+                    widget_name
+                )))
+            }
+        };
+
         if let Some(state) = self.widget_states.get_mut(widget_name) {
+            for (name, value) in field_names.into_iter().zip(values) {
+                state.fields.insert(name, value);
+            }
+
             // 标记视图为脏（需要重建）
+            state.cached_node = None;
             state.view_dirty = true;
         }
 
-        // 调用 Widget 的 on() 方法
-        // TODO: 实现通过解释器调用 on() 方法
-        // let widget = self.interpreter.scope.borrow().get_val(widget_name);
-        // call_method(widget, "on", &[Value::Str(event_name.into())]);
-
         Ok(())
     }
 
     /// 重新加载代码（热重载）
-    pub fn reload(&mut self, code: &str) -> Result<()> {
-        // 保存旧状态（用于状态迁移）
+    ///
+    /// 比对新旧 Widget 类型声明后迁移已有实例状态：
+    /// - 名称和类型都不变的字段保留当前值
+    /// - 新声明中删除的字段被丢弃
+    /// - 类型改变的字段和新增字段使用声明的默认值
+    ///
+    /// 返回的 [`MigrationReport`] 列出被丢弃和被重置的字段。
+    pub fn reload(&mut self, code: &str) -> Result<MigrationReport> {
+        // 保存旧状态和旧声明（用于状态迁移）
         let old_states = self.widget_states.clone();
+        let old_decls = std::mem::take(&mut self.widget_decls);
 
         // 重新解释代码
-        self.interpret(code)?;
+        if let Err(e) = self.interpret(code) {
+            self.widget_decls = old_decls;
+            return Err(e);
+        }
 
         // 迁移状态
-        self.migrate_states(old_states);
-
-        Ok(())
+        Ok(self.migrate_states(old_states, &old_decls))
     }
 
     /// 状态迁移
-    fn migrate_states(&mut self, old_states: HashMap<String, WidgetState>) {
-        for (name, old_state) in old_states {
-            if let Some(new_state) = self.widget_states.get_mut(&name) {
-                // 迁移兼容的字段
-                for (field_name, field_value) in old_state.fields {
-                    // 只保留类型相同的字段
-                    if new_state.fields.contains_key(&field_name) {
-                        new_state.fields.insert(field_name.clone(), field_value);
+    fn migrate_states(
+        &mut self,
+        old_states: HashMap<String, WidgetState>,
+        old_decls: &HashMap<String, WidgetDecl>,
+    ) -> MigrationReport {
+        let mut report = MigrationReport::default();
+
+        let mut names: Vec<String> = old_states.keys().cloned().collect();
+        names.sort();
+
+        for name in names {
+            let old_state = &old_states[&name];
+
+            let new_decl = match self.widget_decls.get(&name) {
+                Some(decl) => decl.clone(),
+                None if self.widget_decls.is_empty() => {
+                    // 没有可比对的声明：按字段名保留
+                    if let Some(new_state) = self.widget_states.get_mut(&name) {
+                        for (field_name, field_value) in &old_state.fields {
+                            if new_state.fields.contains_key(field_name) {
+                                new_state.fields.insert(field_name.clone(), field_value.clone());
+                            }
+                        }
                     }
+                    continue;
+                }
+                None => {
+                    // Widget 声明已被删除
+                    self.widget_states.remove(&name);
+                    report.removed_widgets.push(name);
+                    continue;
+                }
+            };
+
+            let defaults = self.declared_defaults(&new_decl);
+            let mut new_state = self
+                .widget_states
+                .remove(&name)
+                .unwrap_or_else(|| old_state.clone());
+            new_state.fields = migrate_fields(
+                old_state,
+                old_decls.get(&name),
+                &new_decl,
+                &defaults,
+                &mut report,
+            );
+            new_state.msg_type = new_decl.msg_type.clone();
+            new_state.cached_node = None;
+            new_state.view_dirty = true;
+            self.widget_states.insert(name, new_state);
+        }
+
+        report
+    }
+
+    /// 求出 Widget 各字段的声明默认值
+    ///
+    /// 先让解释器构造一个无参实例并读回字段；失败时退回到类型的零值。
+    fn declared_defaults(&mut self, decl: &WidgetDecl) -> HashMap<String, Value> {
+        let mut defaults = HashMap::new();
+
+        if let Some(script) = defaults_script(decl) {
+            if let Ok(Value::Array(values)) = self.eval_script(&script) {
+                for ((field, _), value) in decl.fields.iter().zip(values.iter()) {
+                    defaults.insert(field.clone(), value.clone());
+                }
+            }
+        }
+
+        for (field, ty) in &decl.fields {
+            if !defaults.contains_key(field) {
+                if let Some(value) = zero_value(ty) {
+                    defaults.insert(field.clone(), value);
                 }
             }
         }
+
+        defaults
     }
 
     /// 启用热重载
@@ -201,6 +468,396 @@ impl InterpreterBridge {
     }
 }
 
+/// 实例在事件脚本中使用的变量名
+const EVENT_TARGET: &str = "__event_target";
+
+/// Widget 类型声明（热重载时比对字段用）
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetDecl {
+    /// Widget 类型名
+    pub name: String,
+    /// 字段名和类型名，按声明顺序
+    pub fields: Vec<(String, String)>,
+    /// 是否是 App（`type X is App`）
+    pub is_app: bool,
+    /// `on()` 参数的类型名（没有 `on()` 时为 `Msg`）
+    pub msg_type: String,
+}
+
+impl WidgetDecl {
+    fn field_type(&self, field: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, ty)| ty.as_str())
+    }
+}
+
+/// 热重载时某个字段的变化
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldMigration {
+    /// Widget 名称
+    pub widget: String,
+    /// 字段名
+    pub field: String,
+    /// 旧类型名（新增字段为 `None`）
+    pub old_type: Option<String>,
+    /// 新类型名（被丢弃的字段为 `None`）
+    pub new_type: Option<String>,
+}
+
+impl fmt::Display for FieldMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.widget, self.field)?;
+        match (&self.old_type, &self.new_type) {
+            (Some(old), Some(new)) => write!(f, " ({} → {})", old, new),
+            (None, Some(new)) => write!(f, " (new {})", new),
+            (Some(old), None) => write!(f, " ({})", old),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// 热重载状态迁移报告
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    /// 新声明中已删除、值被丢弃的字段
+    pub dropped: Vec<FieldMigration>,
+    /// 类型改变或新增、值被重置为默认值的字段
+    pub reset: Vec<FieldMigration>,
+    /// 声明已被删除的 Widget
+    pub removed_widgets: Vec<String>,
+}
+
+impl MigrationReport {
+    /// 是否所有状态都原样保留
+    pub fn is_clean(&self) -> bool {
+        self.dropped.is_empty() && self.reset.is_empty() && self.removed_widgets.is_empty()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for field in &self.dropped {
+            writeln!(f, "dropped: {}", field)?;
+        }
+        for field in &self.reset {
+            writeln!(f, "reset: {}", field)?;
+        }
+        for widget in &self.removed_widgets {
+            writeln!(f, "removed widget: {}", widget)?;
+        }
+        Ok(())
+    }
+}
+
+/// 收集代码中的 Widget 类型声明
+///
+/// 与转译器一致：带 `Widget` spec 或 `view` 方法的类型视为 Widget。解析失败时返回空表。
+fn parse_widget_decls(code: &str) -> HashMap<String, WidgetDecl> {
+    let universe = Rc::new(RefCell::new(Universe::new()));
+    let mut parser = Parser::new(code, universe);
+    let ast = match parser.parse() {
+        Ok(ast) => ast,
+        Err(_) => return HashMap::new(),
+    };
+
+    ast.stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::TypeDecl(decl)
+                if decl.specs.iter().any(|spec| spec.as_str() == "Widget")
+                    || decl.methods.iter().any(|method| method.name == "view") =>
+            {
+                let fields = decl
+                    .members
+                    .iter()
+                    .map(|member| (member.name.to_string(), type_name(&member.ty)))
+                    .collect();
+                let name = decl.name.to_string();
+                let is_app = decl.specs.iter().any(|spec| spec.as_str() == "App");
+                let msg_type = decl
+                    .methods
+                    .iter()
+                    .find(|method| method.name == "on")
+                    .and_then(|method| method.params.first())
+                    .map(|param| type_name(&param.ty))
+                    .unwrap_or_else(|| "Msg".to_string());
+                Some((name.clone(), WidgetDecl { name, fields, is_app, msg_type }))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// 类型名（用于比较字段类型是否改变）
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Int => "int".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::Float => "float".to_string(),
+        Type::Double => "double".to_string(),
+        Type::Str(_) => "str".to_string(),
+        Type::User(user) => user.name.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// 类型的零值（解释器无法给出默认值时使用）
+fn zero_value(ty: &str) -> Option<Value> {
+    match ty {
+        "int" => Some(Value::Int(0)),
+        "bool" => Some(Value::Bool(false)),
+        "float" => Some(Value::Float(0.0)),
+        "double" => Some(Value::Double(0.0)),
+        "str" => Some(Value::Str("".into())),
+        _ => None,
+    }
+}
+
+/// 按新旧声明迁移单个 Widget 的字段
+fn migrate_fields(
+    old_state: &WidgetState,
+    old_decl: Option<&WidgetDecl>,
+    new_decl: &WidgetDecl,
+    defaults: &HashMap<String, Value>,
+    report: &mut MigrationReport,
+) -> HashMap<String, Value> {
+    let mut fields = HashMap::new();
+
+    for (field, new_type) in &new_decl.fields {
+        let old_type = match old_decl {
+            Some(decl) => decl.field_type(field).map(str::to_string),
+            // 没有旧声明时，旧状态中存在即视为类型未变
+            None => old_state.fields.get(field).map(|_| new_type.clone()),
+        };
+
+        match (old_state.fields.get(field), &old_type) {
+            (Some(value), Some(old_type)) if old_type == new_type => {
+                fields.insert(field.clone(), value.clone());
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some(value) = defaults.get(field) {
+            fields.insert(field.clone(), value.clone());
+        }
+        report.reset.push(FieldMigration {
+            widget: new_decl.name.clone(),
+            field: field.clone(),
+            old_type,
+            new_type: Some(new_type.clone()),
+        });
+    }
+
+    let mut removed: Vec<&String> = old_state
+        .fields
+        .keys()
+        .filter(|field| new_decl.field_type(field).is_none())
+        .collect();
+    removed.sort();
+    for field in removed {
+        report.dropped.push(FieldMigration {
+            widget: new_decl.name.clone(),
+            field: field.clone(),
+            old_type: old_decl.and_then(|decl| decl.field_type(field)).map(str::to_string),
+            new_type: None,
+        });
+    }
+
+    fields
+}
+
+/// 生成读取声明默认值的脚本
+///
+/// ```text
+/// mut __event_target = Counter()
+/// [__event_target.count]
+/// ```
+fn defaults_script(decl: &WidgetDecl) -> Option<String> {
+    check_ident(&decl.name).ok()?;
+    for (field, _) in &decl.fields {
+        check_ident(field).ok()?;
+    }
+
+    let readback = decl
+        .fields
+        .iter()
+        .map(|(field, _)| format!("{}.{}", EVENT_TARGET, field))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!(
+        "mut {target} = {widget}()\n[{readback}]\n",
+        target = EVENT_TARGET,
+        widget = decl.name,
+        readback = readback,
+    ))
+}
+
+/// 生成派发事件的 Auto 脚本
+///
+/// 返回脚本和按顺序读回的字段名。例如 `Counter { count: 5 }` 收到 `Inc`：
+///
+/// ```text
+/// mut __event_target = Counter(count: 5)
+/// __event_target.on(Msg.Inc)
+/// [__event_target.count]
+/// ```
+///
+/// `on()` 接收 `str` 时事件名本身就是消息，作为字符串字面量传入。
+fn event_script(
+    widget_name: &str,
+    state: &WidgetState,
+    event_name: &str,
+    args: &[Value],
+) -> Result<(String, Vec<String>)> {
+    let (construct, field_names) = instance_script(widget_name, state)?;
+
+    let message = if state.msg_type == "str" {
+        string_literal(event_name)
+    } else {
+        // 未限定的事件名加上消息枚举前缀
+        let event = if event_name.contains('.') {
+            event_name.to_string()
+        } else {
+            format!("{}.{}", state.msg_type, event_name)
+        };
+        for part in event.split('.') {
+            check_ident(part)?;
+        }
+
+        if args.is_empty() {
+            event
+        } else {
+            let args = args.iter().map(value_literal).collect::<Result<Vec<_>>>()?;
+            format!("{}({})", event, args.join(", "))
+        }
+    };
+
+    let readback = field_names
+        .iter()
+        .map(|name| format!("{}.{}", EVENT_TARGET, name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let script = format!(
+        "{construct}{target}.on({message})\n[{readback}]\n",
+        construct = construct,
+        target = EVENT_TARGET,
+        message = message,
+        readback = readback,
+    );
+
+    Ok((script, field_names))
+}
+
+/// 生成渲染视图的 Auto 脚本
+///
+/// ```text
+/// mut __event_target = Counter(count: 5)
+/// __event_target.view()
+/// ```
+fn view_script(widget_name: &str, state: &WidgetState) -> Result<String> {
+    let (construct, _) = instance_script(widget_name, state)?;
+    Ok(format!("{}{}.view()\n", construct, EVENT_TARGET))
+}
+
+/// 生成用当前字段值构造实例的语句，并返回按顺序排列的字段名
+fn instance_script(widget_name: &str, state: &WidgetState) -> Result<(String, Vec<String>)> {
+    check_ident(widget_name)?;
+
+    let mut field_names: Vec<String> = state.fields.keys().cloned().collect();
+    field_names.sort();
+
+    let mut init = Vec::with_capacity(field_names.len());
+    for name in &field_names {
+        check_ident(name)?;
+        init.push(format!("{}: {}", name, value_literal(&state.fields[name])?));
+    }
+
+    let construct = format!(
+        "mut {target} = {widget}({init})\n",
+        target = EVENT_TARGET,
+        widget = widget_name,
+        init = init.join(", "),
+    );
+
+    Ok((construct, field_names))
+}
+
+/// 把值写成 Auto 字面量
+fn value_literal(value: &Value) -> Result<String> {
+    match value {
+        Value::Int(i) => Ok(i.to_string()),
+        Value::Uint(u) => Ok(u.to_string()),
+        Value::USize(u) => Ok(u.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Float(f) | Value::Double(f) => float_literal(*f),
+        Value::Str(s) => Ok(string_literal(&s.to_string())),
+        Value::OwnedStr(s) => Ok(string_literal(s.as_str())),
+        Value::Array(values) => {
+            let items = values.iter().map(value_literal).collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", items.join(", ")))
+        }
+        other => Err(BridgeError::InvalidEvent(format!(
+            "无法作为 Auto 字面量传递的值: {:?}",
+            other
+        ))),
+    }
+}
+
+/// 把浮点数写成 Auto 字面量
+///
+/// 整数值保留 `.0`，避免被解释器读成 int；NaN 和无穷大没有字面量形式。
+fn float_literal(value: f64) -> Result<String> {
+    if !value.is_finite() {
+        return Err(BridgeError::InvalidEvent(format!(
+            "无法作为 Auto 字面量传递的浮点数: {}",
+            value
+        )));
+    }
+    let literal = value.to_string();
+    if literal.contains('.') {
+        Ok(literal)
+    } else {
+        Ok(format!("{}.0", literal))
+    }
+}
+
+/// 把字符串写成 Auto 字符串字面量
+///
+/// 只转义 Auto 词法支持的 `\\`、`\"`、`\n`、`\r`、`\t`，其余字符原样写入。
+fn string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// 校验标识符，防止事件名被拼接成任意代码
+fn check_ident(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BridgeError::InvalidEvent(format!("无效的标识符: {:?}", name)))
+    }
+}
+
 impl Default for InterpreterBridge {
     fn default() -> Self {
         Self::new()
@@ -223,4 +880,350 @@ mod tests {
         // Test that default bridge works
         let _ = bridge.get_main_view();
     }
+
+    fn counter_state(count: i32) -> WidgetState {
+        let mut fields = HashMap::new();
+        fields.insert("count".to_string(), Value::Int(count));
+        WidgetState::new(fields)
+    }
+
+    #[test]
+    fn test_event_script() {
+        let (script, fields) = event_script("Counter", &counter_state(5), "Inc", &[]).unwrap();
+
+        assert_eq!(fields, vec!["count".to_string()]);
+        assert_eq!(
+            script,
+            "mut __event_target = Counter(count: 5)\n__event_target.on(Msg.Inc)\n[__event_target.count]\n"
+        );
+    }
+
+    #[test]
+    fn test_event_script_with_args() {
+        let state = counter_state(0).with_msg_type("CounterMsg");
+        let (script, _) = event_script(
+            "Counter",
+            &state,
+            "Set",
+            &[Value::Int(3), Value::Bool(true)],
+        )
+        .unwrap();
+        assert!(script.contains("__event_target.on(CounterMsg.Set(3, true))"));
+
+        // 已限定的事件名不再加前缀
+        let (script, _) = event_script("Counter", &state, "Other.Reset", &[]).unwrap();
+        assert!(script.contains("__event_target.on(Other.Reset)"));
+    }
+
+    #[test]
+    fn test_event_script_float_and_string_args() {
+        let mut fields = HashMap::new();
+        fields.insert("volume".to_string(), Value::Float(0.5));
+        fields.insert("title".to_string(), Value::Str("say \"hi\"\n\\ café".into()));
+        let state = WidgetState::new(fields);
+
+        let (script, fields) = event_script("Player", &state, "Seek", &[Value::Float(2.0)]).unwrap();
+        assert_eq!(fields, vec!["title".to_string(), "volume".to_string()]);
+        assert_eq!(
+            script,
+            "mut __event_target = Player(title: \"say \\\"hi\\\"\\n\\\\ café\", volume: 0.5)\n\
+             __event_target.on(Msg.Seek(2.0))\n\
+             [__event_target.title, __event_target.volume]\n"
+        );
+
+        assert_eq!(value_literal(&Value::Float(0.0000001)).unwrap(), "0.0000001");
+        assert!(value_literal(&Value::Float(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_event_script_str_message() {
+        let state = counter_state(0).with_msg_type("str");
+        let (script, _) = event_script("Counter", &state, "inc", &[]).unwrap();
+        assert!(script.contains("__event_target.on(\"inc\")"));
+
+        assert_eq!(
+            view_script("Counter", &state).unwrap(),
+            "mut __event_target = Counter(count: 0)\n__event_target.view()\n"
+        );
+    }
+
+    #[test]
+    fn test_event_script_rejects_invalid_names() {
+        let state = counter_state(0);
+        assert!(event_script("Counter", &state, "Inc); exit(", &[]).is_err());
+        assert!(event_script("Counter()", &state, "Inc", &[]).is_err());
+    }
+
+    #[test]
+    fn test_typed_event_unknown_widget() {
+        let mut bridge = InterpreterBridge::new();
+        let result = bridge.handle_message(DynamicMessage::Typed {
+            widget_name: "Missing".to_string(),
+            event_name: "Inc".to_string(),
+            args: vec![],
+        });
+        assert!(matches!(result, Err(BridgeError::WidgetNotFound(name)) if name == "Missing"));
+
+        // 无法确定目标的字符串事件被忽略
+        assert!(bridge.handle_message(DynamicMessage::String("clicked".to_string())).is_ok());
+    }
+
+    const COUNTER: &str = r#"
+type Counter is Widget {
+    count int = 0
+
+    fn view() {
+        col {
+            label("Count: {self.count}") {}
+            button("Increment") {
+                onclick: "inc"
+            }
+        }
+    }
+
+    fn on(ev str) {
+        if ev == "inc" {
+            count = count + 1
+        }
+    }
+}
+"#;
+
+    /// 主视图第一行文本
+    fn first_label(bridge: &mut InterpreterBridge) -> String {
+        let node = bridge.get_main_view().unwrap();
+        match crate::node_converter::convert_node_dynamic(&node, None).unwrap() {
+            crate::view::View::Column { children, .. } => match children.first() {
+                Some(crate::view::View::Text { content, .. }) => content.clone(),
+                other => panic!("Expected label, got {:?}", other),
+            },
+            other => panic!("Expected column, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_file_dispatches_typed_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("counter.at");
+        std::fs::write(&path, COUNTER).unwrap();
+
+        let mut bridge = InterpreterBridge::new();
+        bridge.load_file(&path).unwrap();
+        assert_eq!(bridge.main_widget(), Some("Counter"));
+        let state = bridge.widget_state("Counter").unwrap();
+        assert_eq!(state.msg_type, "str");
+        assert!(matches!(state.fields.get("count"), Some(Value::Int(0))));
+        assert_eq!(first_label(&mut bridge), "Count: 0");
+
+        bridge
+            .handle_message(DynamicMessage::Typed {
+                widget_name: "Counter".to_string(),
+                event_name: "inc".to_string(),
+                args: vec![],
+            })
+            .unwrap();
+        assert!(bridge.widget_state("Counter").unwrap().view_dirty);
+        assert_eq!(first_label(&mut bridge), "Count: 1");
+        assert!(!bridge.widget_state("Counter").unwrap().view_dirty);
+
+        // 按钮的字符串事件发送给主 Widget
+        bridge.handle_message(DynamicMessage::String("inc".to_string())).unwrap();
+        assert_eq!(first_label(&mut bridge), "Count: 2");
+    }
+
+    #[test]
+    fn test_event_scripts_do_not_leak_bindings() {
+        let mut bridge = InterpreterBridge::new();
+        bridge.interpret(COUNTER).unwrap();
+        bridge.handle_message(DynamicMessage::String("inc".to_string())).unwrap();
+        bridge.get_main_view().unwrap();
+
+        // 临时实例只存在于脚本的子作用域
+        let leaked = bridge.eval(&format!("{}.count", EVENT_TARGET)).is_ok()
+            && matches!(bridge.interpreter.result, Value::Int(_));
+        assert!(!leaked);
+    }
+
+    fn decl(name: &str, fields: &[(&str, &str)]) -> WidgetDecl {
+        WidgetDecl {
+            name: name.to_string(),
+            fields: fields
+                .iter()
+                .map(|(field, ty)| (field.to_string(), ty.to_string()))
+                .collect(),
+            is_app: false,
+            msg_type: "Msg".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_fields() {
+        let mut fields = HashMap::new();
+        fields.insert("count".to_string(), Value::Int(7));
+        fields.insert("step".to_string(), Value::Int(2));
+        fields.insert("label".to_string(), Value::Int(1));
+        let old_state = WidgetState::new(fields);
+
+        let old_decl = decl("Counter", &[("count", "int"), ("step", "int"), ("label", "int")]);
+        let new_decl = decl("Counter", &[("count", "int"), ("label", "bool"), ("enabled", "bool")]);
+
+        let mut defaults = HashMap::new();
+        defaults.insert("enabled".to_string(), Value::Bool(true));
+        defaults.insert("label".to_string(), Value::Bool(false));
+
+        let mut report = MigrationReport::default();
+        let migrated = migrate_fields(&old_state, Some(&old_decl), &new_decl, &defaults, &mut report);
+
+        // 兼容字段保留，其余使用默认值
+        assert!(matches!(migrated.get("count"), Some(Value::Int(7))));
+        assert!(matches!(migrated.get("label"), Some(Value::Bool(false))));
+        assert!(matches!(migrated.get("enabled"), Some(Value::Bool(true))));
+        assert!(!migrated.contains_key("step"));
+
+        assert_eq!(
+            report.dropped,
+            vec![FieldMigration {
+                widget: "Counter".to_string(),
+                field: "step".to_string(),
+                old_type: Some("int".to_string()),
+                new_type: None,
+            }]
+        );
+        assert_eq!(report.reset.len(), 2);
+        assert_eq!(report.reset[0].field, "label");
+        assert_eq!(report.reset[0].old_type.as_deref(), Some("int"));
+        assert_eq!(report.reset[1].field, "enabled");
+        assert_eq!(report.reset[1].old_type, None);
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.to_string(),
+            "dropped: Counter.step (int)\nreset: Counter.label (int → bool)\nreset: Counter.enabled (new bool)\n"
+        );
+    }
+
+    #[test]
+    fn test_migrate_fields_unchanged_is_clean() {
+        let old_state = counter_state(3);
+        let counter = decl("Counter", &[("count", "int")]);
+
+        let mut report = MigrationReport::default();
+        let migrated = migrate_fields(&old_state, Some(&counter), &counter, &HashMap::new(), &mut report);
+
+        assert!(matches!(migrated.get("count"), Some(Value::Int(3))));
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_migrate_fields_falls_back_to_zero_values() {
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Value::Int(1));
+        let old_state = WidgetState::new(fields);
+
+        let old_decl = decl("Form", &[("name", "int")]);
+        let new_decl = decl("Form", &[("name", "str"), ("ratio", "float"), ("done", "bool")]);
+
+        // 解释器中没有 Form 类型，默认值全部来自零值
+        let mut bridge = InterpreterBridge::new();
+        let defaults = bridge.declared_defaults(&new_decl);
+
+        let mut report = MigrationReport::default();
+        let migrated = migrate_fields(&old_state, Some(&old_decl), &new_decl, &defaults, &mut report);
+
+        assert!(matches!(migrated.get("name"), Some(Value::Str(s)) if s.to_string().is_empty()));
+        assert!(matches!(migrated.get("ratio"), Some(Value::Float(f)) if *f == 0.0));
+        assert!(matches!(migrated.get("done"), Some(Value::Bool(false))));
+        assert_eq!(report.reset.len(), 3);
+    }
+
+    #[test]
+    fn test_reload_migrates_loaded_widget() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("form.at");
+        std::fs::write(
+            &path,
+            r#"
+type Form is Widget {
+    count int = 0
+    note str = ""
+
+    fn view() {
+        col {
+            label("Count: {self.count}") {}
+        }
+    }
+
+    fn on(ev str) {
+        if ev == "inc" {
+            count = count + 1
+        }
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let mut bridge = InterpreterBridge::new();
+        bridge.load_file(&path).unwrap();
+        bridge.handle_message(DynamicMessage::String("inc".to_string())).unwrap();
+
+        // count 保留，note 被删除，step 是新字段
+        let report = bridge
+            .reload(
+                r#"
+type Form is Widget {
+    count int = 0
+    step int = 5
+
+    fn view() {
+        col {
+            label("Count: {self.count} by {self.step}") {}
+        }
+    }
+
+    fn on(ev str) {
+        if ev == "inc" {
+            count = count + step
+        }
+    }
+}
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            report,
+            MigrationReport {
+                dropped: vec![FieldMigration {
+                    widget: "Form".to_string(),
+                    field: "note".to_string(),
+                    old_type: Some("str".to_string()),
+                    new_type: None,
+                }],
+                reset: vec![FieldMigration {
+                    widget: "Form".to_string(),
+                    field: "step".to_string(),
+                    old_type: None,
+                    new_type: Some("int".to_string()),
+                }],
+                removed_widgets: vec![],
+            }
+        );
+
+        let state = bridge.widget_state("Form").unwrap();
+        assert!(matches!(state.fields.get("count"), Some(Value::Int(1))));
+        assert!(matches!(state.fields.get("step"), Some(Value::Int(5))));
+        assert!(!state.fields.contains_key("note"));
+        assert_eq!(first_label(&mut bridge), "Count: 1 by 5");
+    }
+
+    #[test]
+    fn test_defaults_script() {
+        let script = defaults_script(&decl("Form", &[("name", "str"), ("age", "int")])).unwrap();
+        assert_eq!(
+            script,
+            "mut __event_target = Form()\n[__event_target.name, __event_target.age]\n"
+        );
+
+        assert!(defaults_script(&decl("Form", &[("bad name", "str")])).is_none());
+    }
 }
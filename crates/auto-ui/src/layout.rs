@@ -0,0 +1,872 @@
+// Layout - backend-independent box layout for a VTree
+//
+// Computes a rectangle for every rendered VNode with a flexbox/grid engine
+// (taffy), using the same Tailwind classes the backend adapters read:
+// `flex`, `flex-col`, `gap-*`, `p-*`, `m-*`, `w-*`, `h-*`, `items-*`,
+// `justify-*`, `grid`, `grid-cols-*`, `col-span-*`, `absolute`, `overflow-*`,
+// `hidden`, ...
+//
+// iced and GPUI lay widgets out themselves; this pass exists for renderers
+// that have no layout engine of their own (terminal, HTML canvas) and for
+// headless tests that want to assert where things end up.
+//
+// As in the backends, a style replaces the legacy spacing/padding/width/height
+// props of layout widgets. Like iced, rows and columns keep children at their
+// natural cross-axis size; `w-full`/`h-full` makes a child fill it.
+// Leaf widgets are measured with a font-size based estimate; renderers with
+// real metrics pass their own measure function.
+
+use crate::style::{SizeValue, Style, StyleClass, Theme};
+use crate::subscription::WindowSize;
+use crate::view::{SidebarPosition, TabsPosition};
+use crate::vnode::{VNode, VNodeId, VNodeKind, VNodeProps, VTree};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use taffy::prelude::{
+    auto, evenly_sized_tracks, length, line, percent, span, zero, AlignItems, AvailableSpace, Dimension,
+    Display, FlexDirection, GridPlacement, JustifyContent, LengthPercentage, LengthPercentageAuto, NodeId,
+    Position, TaffyTree,
+};
+use taffy::Overflow;
+
+/// Average glyph advance as a fraction of the font size
+const CHAR_WIDTH: f32 = 0.5;
+/// Line height as a multiple of the font size
+const LINE_HEIGHT: f32 = 1.25;
+/// Horizontal/vertical padding of buttons, inputs and selects (iced defaults)
+const PAD_X: f32 = 10.0;
+const PAD_Y: f32 = 5.0;
+/// Space between a checkbox/radio mark and its label
+const MARK_GAP: f32 = 8.0;
+/// Width of widgets that fill their row in iced (inputs, sliders, progress bars)
+const FIELD_WIDTH: f32 = 200.0;
+
+/// Position and size of a laid-out node, in window coordinates
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    /// Whether the point lies inside (right and bottom edges excluded)
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{} at ({}, {})", self.width, self.height, self.x, self.y)
+    }
+}
+
+/// Rectangles of the rendered nodes of a VTree
+///
+/// Nodes that aren't on screen — hidden by a `hidden` class, unselected tabs,
+/// collapsed accordion sections — have no rectangle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutResult {
+    /// Rendered nodes in tree pre-order
+    rects: Vec<(VNodeId, Rect)>,
+    index: HashMap<VNodeId, usize>,
+    /// Tab bars and accordion headers, which have no VNode of their own
+    headers: HashMap<VNodeId, Vec<Rect>>,
+}
+
+impl LayoutResult {
+    /// Rectangle of a node, if it is rendered
+    pub fn get(&self, id: VNodeId) -> Option<Rect> {
+        self.index.get(&id).map(|&i| self.rects[i].1)
+    }
+
+    /// Rendered nodes and their rectangles, parents before children
+    pub fn iter(&self) -> impl Iterator<Item = (VNodeId, Rect)> + '_ {
+        self.rects.iter().copied()
+    }
+
+    /// Tab bar of a `Tabs` node, or the section headers of an `Accordion`
+    pub fn headers(&self, id: VNodeId) -> &[Rect] {
+        self.headers.get(&id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Topmost node under a point: the deepest one, later siblings winning
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<VNodeId> {
+        self.rects
+            .iter()
+            .rev()
+            .find(|(_, rect)| rect.contains(x, y))
+            .map(|(id, _)| *id)
+    }
+
+    fn push(&mut self, id: VNodeId, rect: Rect) {
+        self.index.insert(id, self.rects.len());
+        self.rects.push((id, rect));
+    }
+}
+
+/// Measures a leaf widget: `(node, max width) -> (width, height)`
+///
+/// Also called with `Tabs`/`Accordion` nodes to size their headers.
+pub type MeasureFn = dyn Fn(&VNode, Option<f32>) -> (f32, f32);
+
+/// Lays out VTrees against a theme and a text measure
+///
+/// # Example
+/// ```ignore
+/// let tree = view_to_vtree(app.view());
+/// let layout = LayoutEngine::new().compute(&tree, WindowSize::new(800.0, 600.0));
+///
+/// let root = tree.root().unwrap();
+/// assert_eq!(layout.get(root.id).unwrap().width, 800.0);
+/// ```
+pub struct LayoutEngine {
+    theme: Arc<Theme>,
+    measure: Option<Box<MeasureFn>>,
+}
+
+impl Default for LayoutEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayoutEngine {
+    /// Engine using the active theme and the built-in size estimate
+    pub fn new() -> Self {
+        Self {
+            theme: Theme::active(),
+            measure: None,
+        }
+    }
+
+    /// Resolve spacing and font sizes through another theme
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Arc::new(theme);
+        self
+    }
+
+    /// Replace the built-in size estimate of leaf widgets
+    ///
+    /// A terminal renderer, for example, measures text in cells.
+    pub fn measure(mut self, measure: impl Fn(&VNode, Option<f32>) -> (f32, f32) + 'static) -> Self {
+        self.measure = Some(Box::new(measure));
+        self
+    }
+
+    /// Lay out `tree` in a window of the given size
+    ///
+    /// The root fills the window; responsive classes resolve at its width.
+    pub fn compute(&self, tree: &VTree, window: WindowSize) -> LayoutResult {
+        let Some(root) = tree.root() else {
+            return LayoutResult::default();
+        };
+
+        let mut builder = Builder {
+            engine: self,
+            tree,
+            width: window.width,
+            taffy: TaffyTree::new(),
+            vnodes: HashMap::new(),
+            headers: HashMap::new(),
+        };
+        let Some(root_id) = builder.build(root) else {
+            return LayoutResult::default();
+        };
+
+        let mut root_style = builder.taffy.style(root_id).cloned().unwrap_or_default();
+        if root_style.size.width == Dimension::auto() {
+            root_style.size.width = length(window.width);
+        }
+        if root_style.size.height == Dimension::auto() {
+            root_style.size.height = length(window.height);
+        }
+        let _ = builder.taffy.set_style(root_id, root_style);
+
+        let available = taffy::Size {
+            width: AvailableSpace::Definite(window.width),
+            height: AvailableSpace::Definite(window.height),
+        };
+        let computed = builder.taffy.compute_layout_with_measure(
+            root_id,
+            available,
+            |known, available, _, id, _| {
+                let Some(node) = id.and_then(|id| tree.get(*id)) else {
+                    return taffy::Size::ZERO;
+                };
+                let max_width = known.width.or(match available.width {
+                    AvailableSpace::Definite(width) => Some(width),
+                    _ => None,
+                });
+                let (width, height) = self.measure_node(node, max_width);
+                taffy::Size {
+                    width: known.width.unwrap_or(width),
+                    height: known.height.unwrap_or(height),
+                }
+            },
+        );
+
+        let mut result = LayoutResult::default();
+        if computed.is_ok() {
+            builder.collect(root_id, 0.0, 0.0, &mut result);
+        }
+        result
+    }
+
+    fn measure_node(&self, node: &VNode, max_width: Option<f32>) -> (f32, f32) {
+        match &self.measure {
+            Some(measure) => measure(node, max_width),
+            None => estimate_size(node, &self.theme, max_width),
+        }
+    }
+}
+
+/// Lay out a VTree with the active theme and the built-in size estimate
+pub fn compute_layout(tree: &VTree, window: WindowSize) -> LayoutResult {
+    LayoutEngine::new().compute(tree, window)
+}
+
+/// Built-in size estimate of a leaf widget
+///
+/// Text is `0.5em` per character and `1.25em` per line, wrapping at
+/// `max_width`; widget chrome follows iced's default paddings. `Tabs` and
+/// `Accordion` nodes are measured for their tab bar and section headers.
+pub fn estimate_size(node: &VNode, theme: &Theme, max_width: Option<f32>) -> (f32, f32) {
+    let font = node
+        .style
+        .as_ref()
+        .and_then(|style| font_size(style, theme))
+        .unwrap_or(theme.font_sizes.base);
+    let line_height = font * LINE_HEIGHT;
+    let text = |text: &str| text_size(text, font, None);
+
+    match &node.props {
+        VNodeProps::Text { content } => text_size(content, font, max_width),
+        VNodeProps::Button { label, .. } => {
+            let (width, height) = text(label);
+            (width + 2.0 * PAD_X, height + 2.0 * PAD_Y)
+        }
+        VNodeProps::Input { width, .. } => (
+            width.map(f32::from).unwrap_or(FIELD_WIDTH),
+            line_height + 2.0 * PAD_Y,
+        ),
+        VNodeProps::Checkbox { label, .. } | VNodeProps::Radio { label, .. } => {
+            let (width, height) = text(label);
+            (line_height + MARK_GAP + width, height.max(line_height))
+        }
+        VNodeProps::RadioGroup { options, .. } => {
+            let widest = options.iter().map(|o| text(o).0).fold(0.0, f32::max);
+            let gaps = options.len().saturating_sub(1) as f32 * MARK_GAP;
+            (line_height + MARK_GAP + widest, options.len() as f32 * line_height + gaps)
+        }
+        VNodeProps::Select { options, .. } => {
+            let widest = options.iter().map(|o| text(o).0).fold(0.0, f32::max);
+            (widest + line_height + 2.0 * PAD_X, line_height + 2.0 * PAD_Y)
+        }
+        VNodeProps::Slider { .. } => (FIELD_WIDTH, line_height),
+        VNodeProps::ProgressBar { .. } => (FIELD_WIDTH, line_height / 2.0),
+        VNodeProps::NavigationRail { items, width, show_labels, .. } => {
+            let item_height = if *show_labels { 3.0 } else { 2.0 } * line_height;
+            (*width, items.len() as f32 * item_height)
+        }
+        // Their tab bar / section headers
+        VNodeProps::Tabs { .. } | VNodeProps::Accordion { .. } => {
+            (FIELD_WIDTH / 2.0, line_height + 2.0 * PAD_Y)
+        }
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Size of a text block, wrapping lines longer than `max_width`
+fn text_size(text: &str, font: f32, max_width: Option<f32>) -> (f32, f32) {
+    let advance = font * CHAR_WIDTH;
+    let mut width: f32 = 0.0;
+    let mut lines = 0.0;
+
+    for text_line in text.split('\n') {
+        let line_width = text_line.chars().count() as f32 * advance;
+        match max_width {
+            Some(max) if max > 0.0 && line_width > max => {
+                width = width.max(max);
+                lines += (line_width / max).ceil();
+            }
+            _ => {
+                width = width.max(line_width);
+                lines += 1.0;
+            }
+        }
+    }
+
+    (width, lines * font * LINE_HEIGHT)
+}
+
+/// Font size set by a `text-*` class
+fn font_size(style: &Style, theme: &Theme) -> Option<f32> {
+    let sizes = &theme.font_sizes;
+    style.classes.iter().rev().find_map(|class| match class {
+        StyleClass::TextXs => Some(sizes.xs),
+        StyleClass::TextSm => Some(sizes.sm),
+        StyleClass::TextBase => Some(sizes.base),
+        StyleClass::TextLg => Some(sizes.lg),
+        StyleClass::TextXl => Some(sizes.xl),
+        StyleClass::Text2Xl => Some(sizes.xxl),
+        StyleClass::Text3Xl => Some(sizes.xxxl),
+        StyleClass::FontSize(px) => Some(f32::from(*px)),
+        _ => None,
+    })
+}
+
+/// Mirrors a VTree into a taffy tree
+struct Builder<'a> {
+    engine: &'a LayoutEngine,
+    tree: &'a VTree,
+    width: f32,
+    taffy: TaffyTree<VNodeId>,
+    /// taffy node → VNode
+    vnodes: HashMap<NodeId, VNodeId>,
+    /// Tab bar / accordion header nodes → the VNode they belong to
+    headers: HashMap<NodeId, VNodeId>,
+}
+
+impl Builder<'_> {
+    /// Build the subtree of a node, or `None` if it isn't rendered
+    fn build(&mut self, node: &VNode) -> Option<NodeId> {
+        let style = match &node.style {
+            Some(style) if style.is_hidden_at(self.width) => return None,
+            Some(style) => Some(style.at_width(self.width)),
+            None => None,
+        };
+
+        let mut layout = self.widget_style(node, style.is_none());
+        if let Some(style) = &style {
+            apply_classes(&mut layout, style, &self.engine.theme);
+        }
+        if let VNodeProps::Container { center_x, center_y, .. } = node.props {
+            // iced centers by filling the axis and aligning the child
+            if center_x {
+                layout.size.width = percent(1.0);
+                layout.align_items = Some(AlignItems::Center);
+            }
+            if center_y {
+                layout.size.height = percent(1.0);
+                layout.justify_content = Some(JustifyContent::Center);
+            }
+        }
+
+        let id = if is_measured(node) {
+            self.taffy.new_leaf_with_context(layout, node.id)
+        } else {
+            let children = self.children(node);
+            self.taffy.new_with_children(layout, &children)
+        }
+        .ok()?;
+
+        self.vnodes.insert(id, node.id);
+        Some(id)
+    }
+
+    fn children(&mut self, node: &VNode) -> Vec<NodeId> {
+        let tree = self.tree;
+        let children = tree.children(node.id).unwrap_or_default();
+
+        match &node.props {
+            VNodeProps::Accordion { items, .. } => {
+                let mut built = Vec::new();
+                let mut contents = children.into_iter();
+                for item in items {
+                    built.extend(self.header(node.id, false));
+                    for child in contents.by_ref().take(item.children) {
+                        if item.expanded {
+                            built.extend(self.build(child));
+                        }
+                    }
+                }
+                built
+            }
+            VNodeProps::Tabs { selected, position, .. } => {
+                let vertical = matches!(position, TabsPosition::Left | TabsPosition::Right);
+                let bar = self.header(node.id, vertical);
+                let content = children.get(*selected).and_then(|child| self.build(child));
+                match position {
+                    TabsPosition::Top | TabsPosition::Left => bar.into_iter().chain(content).collect(),
+                    TabsPosition::Bottom | TabsPosition::Right => content.into_iter().chain(bar).collect(),
+                }
+            }
+            VNodeProps::Table { headers, rows, .. } => {
+                let cells = std::iter::once(*headers).chain(rows.iter().copied());
+                let positions = cells
+                    .enumerate()
+                    .flat_map(|(row, count)| (0..count).map(move |column| (row, column)));
+
+                let mut built = Vec::new();
+                for (child, (row, column)) in children.into_iter().zip(positions) {
+                    let Some(cell) = self.build(child) else { continue };
+                    if let Some(mut style) = self.taffy.style(cell).ok().cloned() {
+                        style.grid_row = grid_line(row);
+                        style.grid_column = grid_line(column);
+                        let _ = self.taffy.set_style(cell, style);
+                    }
+                    built.push(cell);
+                }
+                built
+            }
+            _ => children.into_iter().filter_map(|child| self.build(child)).collect(),
+        }
+    }
+
+    /// Strip for a tab bar or accordion header
+    ///
+    /// It spans its owner and is measured as the owner itself across the other
+    /// axis, so renderers control its thickness through the measure function.
+    fn header(&mut self, owner: VNodeId, vertical: bool) -> Option<NodeId> {
+        let size = if vertical {
+            taffy::Size { width: auto(), height: percent(1.0) }
+        } else {
+            taffy::Size { width: percent(1.0), height: auto() }
+        };
+        let style = taffy::Style {
+            size,
+            flex_shrink: 0.0,
+            ..Default::default()
+        };
+        let id = self.taffy.new_leaf_with_context(style, owner).ok()?;
+        self.headers.insert(id, owner);
+        Some(id)
+    }
+
+    /// Layout implied by the widget kind and its props
+    ///
+    /// `legacy` is false when a style replaces the spacing/padding/size props.
+    fn widget_style(&self, node: &VNode, legacy: bool) -> taffy::Style {
+        let mut style = taffy::Style {
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Start),
+            flex_shrink: 0.0,
+            ..Default::default()
+        };
+
+        match &node.props {
+            VNodeProps::Layout { spacing, padding } => {
+                if node.kind == VNodeKind::Row {
+                    style.flex_direction = FlexDirection::Row;
+                }
+                if legacy {
+                    style.gap = both(length(f32::from(*spacing)));
+                    style.padding = uniform(length(f32::from(*padding)));
+                }
+            }
+            VNodeProps::Container { padding, width, height, .. } if legacy => {
+                style.padding = uniform(length(f32::from(*padding)));
+                style.size = fixed_size(*width, *height);
+            }
+            VNodeProps::Scrollable { width, height } => {
+                style.overflow.y = Overflow::Scroll;
+                if legacy {
+                    style.size = fixed_size(*width, *height);
+                }
+            }
+            VNodeProps::List { spacing } if legacy => {
+                style.gap = both(length(f32::from(*spacing)));
+            }
+            VNodeProps::Table { spacing, col_spacing, headers, rows } => {
+                let columns = rows.iter().copied().chain([*headers]).max().unwrap_or(0);
+                style.display = Display::Grid;
+                style.grid_template_columns = evenly_sized_tracks(columns as u16);
+                style.gap = taffy::Size {
+                    width: length(f32::from(*col_spacing)),
+                    height: length(f32::from(*spacing)),
+                };
+            }
+            VNodeProps::Sidebar { width, position, .. } => {
+                style.size.width = length(*width);
+                if *position == SidebarPosition::Right {
+                    style.margin.left = LengthPercentageAuto::auto();
+                }
+            }
+            // Tab bars and accordion headers span the available width
+            VNodeProps::Tabs { position, .. } => {
+                style.size.width = percent(1.0);
+                if matches!(position, TabsPosition::Left | TabsPosition::Right) {
+                    style.flex_direction = FlexDirection::Row;
+                }
+            }
+            VNodeProps::Accordion { .. } => style.size.width = percent(1.0),
+            _ => {}
+        }
+
+        style
+    }
+
+    /// Record window-space rectangles of the rendered VNodes
+    fn collect(&self, id: NodeId, parent_x: f32, parent_y: f32, result: &mut LayoutResult) {
+        let Ok(layout) = self.taffy.layout(id) else { return };
+        let x = parent_x + layout.location.x;
+        let y = parent_y + layout.location.y;
+
+        let rect = Rect::new(x, y, layout.size.width, layout.size.height);
+        if let Some(vnode) = self.vnodes.get(&id) {
+            result.push(*vnode, rect);
+        } else if let Some(owner) = self.headers.get(&id) {
+            result.headers.entry(*owner).or_default().push(rect);
+        }
+        for child in self.taffy.children(id).unwrap_or_default() {
+            self.collect(child, x, y, result);
+        }
+    }
+}
+
+/// Leaf widgets sized by the measure function
+fn is_measured(node: &VNode) -> bool {
+    !matches!(
+        node.props,
+        VNodeProps::Layout { .. }
+            | VNodeProps::Container { .. }
+            | VNodeProps::Scrollable { .. }
+            | VNodeProps::List { .. }
+            | VNodeProps::Table { .. }
+            | VNodeProps::Accordion { .. }
+            | VNodeProps::Sidebar { .. }
+            | VNodeProps::Tabs { .. }
+    )
+}
+
+/// Overlay Tailwind layout classes on a widget's default layout
+fn apply_classes(layout: &mut taffy::Style, style: &Style, theme: &Theme) {
+    let mut col_start = None;
+    let mut col_span = None;
+    let mut row_start = None;
+    let mut row_span = None;
+
+    for class in style.classes.iter() {
+        match class {
+            StyleClass::Padding(size) => layout.padding = uniform(padding(size, theme)),
+            StyleClass::PaddingX(size) => {
+                layout.padding.left = padding(size, theme);
+                layout.padding.right = padding(size, theme);
+            }
+            StyleClass::PaddingY(size) => {
+                layout.padding.top = padding(size, theme);
+                layout.padding.bottom = padding(size, theme);
+            }
+            StyleClass::Margin(size) => layout.margin = uniform(margin(size, theme)),
+            StyleClass::MarginX(size) => {
+                layout.margin.left = margin(size, theme);
+                layout.margin.right = margin(size, theme);
+            }
+            StyleClass::MarginY(size) => {
+                layout.margin.top = margin(size, theme);
+                layout.margin.bottom = margin(size, theme);
+            }
+            StyleClass::Gap(size) => layout.gap = both(padding(size, theme)),
+
+            StyleClass::Flex => layout.display = Display::Flex,
+            StyleClass::FlexRow => layout.flex_direction = FlexDirection::Row,
+            StyleClass::FlexCol => layout.flex_direction = FlexDirection::Column,
+            StyleClass::Flex1 => {
+                layout.flex_grow = 1.0;
+                layout.flex_shrink = 1.0;
+                layout.flex_basis = percent(0.0);
+            }
+            StyleClass::ItemsStart => layout.align_items = Some(AlignItems::Start),
+            StyleClass::ItemsCenter => layout.align_items = Some(AlignItems::Center),
+            StyleClass::ItemsEnd => layout.align_items = Some(AlignItems::End),
+            StyleClass::JustifyStart => layout.justify_content = Some(JustifyContent::Start),
+            StyleClass::JustifyCenter => layout.justify_content = Some(JustifyContent::Center),
+            StyleClass::JustifyEnd => layout.justify_content = Some(JustifyContent::End),
+            StyleClass::JustifyBetween => layout.justify_content = Some(JustifyContent::SpaceBetween),
+
+            StyleClass::Width(size) => layout.size.width = dimension(size, theme),
+            StyleClass::Height(size) => layout.size.height = dimension(size, theme),
+
+            StyleClass::Border => layout.border = uniform(length(1.0)),
+            StyleClass::Border0 => layout.border = uniform(zero()),
+
+            StyleClass::Relative => layout.position = Position::Relative,
+            StyleClass::Absolute => layout.position = Position::Absolute,
+
+            StyleClass::OverflowAuto | StyleClass::OverflowScroll => {
+                layout.overflow.x = Overflow::Scroll;
+                layout.overflow.y = Overflow::Scroll;
+            }
+            StyleClass::OverflowHidden => {
+                layout.overflow.x = Overflow::Hidden;
+                layout.overflow.y = Overflow::Hidden;
+            }
+            StyleClass::OverflowVisible => {
+                layout.overflow.x = Overflow::Visible;
+                layout.overflow.y = Overflow::Visible;
+            }
+            StyleClass::OverflowXAuto => layout.overflow.x = Overflow::Scroll,
+            StyleClass::OverflowYAuto => layout.overflow.y = Overflow::Scroll,
+
+            StyleClass::Grid => layout.display = Display::Grid,
+            StyleClass::GridCols(n) => layout.grid_template_columns = evenly_sized_tracks(u16::from(*n)),
+            StyleClass::GridRows(n) => layout.grid_template_rows = evenly_sized_tracks(u16::from(*n)),
+            StyleClass::ColStart(n) => col_start = Some(*n),
+            StyleClass::ColSpan(n) => col_span = Some(*n),
+            StyleClass::RowStart(n) => row_start = Some(*n),
+            StyleClass::RowSpan(n) => row_span = Some(*n),
+
+            _ => {}
+        }
+    }
+
+    if col_start.is_some() || col_span.is_some() {
+        layout.grid_column = placement(col_start, col_span);
+    }
+    if row_start.is_some() || row_span.is_some() {
+        layout.grid_row = placement(row_start, row_span);
+    }
+}
+
+/// `col-start-N` / `col-span-N` as a grid line pair
+fn placement(start: Option<u8>, span_of: Option<u8>) -> taffy::Line<GridPlacement> {
+    let spanning = span_of.map(|n| span(u16::from(n))).unwrap_or(GridPlacement::Auto);
+    match start {
+        Some(n) => taffy::Line { start: line(i16::from(n)), end: spanning },
+        None => taffy::Line { start: spanning, end: GridPlacement::Auto },
+    }
+}
+
+/// Single grid track at a zero-based index
+fn grid_line(index: usize) -> taffy::Line<GridPlacement> {
+    taffy::Line {
+        start: line(index as i16 + 1),
+        end: span(1),
+    }
+}
+
+fn uniform<T: Copy>(value: T) -> taffy::Rect<T> {
+    taffy::Rect { left: value, right: value, top: value, bottom: value }
+}
+
+fn both<T: Copy>(value: T) -> taffy::Size<T> {
+    taffy::Size { width: value, height: value }
+}
+
+fn fixed_size(width: Option<u16>, height: Option<u16>) -> taffy::Size<Dimension> {
+    let fixed = |px: Option<u16>| px.map(|px| length(f32::from(px))).unwrap_or(auto());
+    taffy::Size { width: fixed(width), height: fixed(height) }
+}
+
+/// Fraction of the parent for `w-full`, `w-1/2`, ...
+fn fraction(size: &SizeValue) -> Option<f32> {
+    match size {
+        SizeValue::Full => Some(1.0),
+        SizeValue::Half => Some(0.5),
+        SizeValue::Third => Some(1.0 / 3.0),
+        SizeValue::TwoThirds => Some(2.0 / 3.0),
+        SizeValue::Quarter => Some(0.25),
+        SizeValue::ThreeQuarters => Some(0.75),
+        SizeValue::Auto | SizeValue::Fixed(_) | SizeValue::Px(_) => None,
+    }
+}
+
+fn dimension(size: &SizeValue, theme: &Theme) -> Dimension {
+    match (size, fraction(size)) {
+        (SizeValue::Auto, _) => auto(),
+        (_, Some(fraction)) => percent(fraction),
+        _ => length(theme.spacing(size)),
+    }
+}
+
+fn padding(size: &SizeValue, theme: &Theme) -> LengthPercentage {
+    match fraction(size) {
+        Some(fraction) => percent(fraction),
+        None => length(theme.spacing(size)),
+    }
+}
+
+fn margin(size: &SizeValue, theme: &Theme) -> LengthPercentageAuto {
+    match (size, fraction(size)) {
+        (SizeValue::Auto, _) => auto(),
+        (_, Some(fraction)) => percent(fraction),
+        _ => length(theme.spacing(size)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{AccordionItem, View};
+    use crate::vnode_converter::view_to_vtree;
+
+    fn layout(view: View<()>, width: f32, height: f32) -> (VTree, LayoutResult) {
+        let tree = view_to_vtree(view);
+        let result = LayoutEngine::new()
+            .theme(Theme::light())
+            .compute(&tree, WindowSize::new(width, height));
+        (tree, result)
+    }
+
+    /// Rectangles of the root's children, in order
+    fn child_rects(tree: &VTree, result: &LayoutResult) -> Vec<Rect> {
+        let root = tree.root().unwrap();
+        root.children.iter().filter_map(|id| result.get(*id)).collect()
+    }
+
+    #[test]
+    fn test_column_stacks_children_with_spacing() {
+        let view = View::col()
+            .spacing(10)
+            .padding(20)
+            .child(View::text("Hello"))
+            .child(View::button("OK", ()))
+            .build();
+        let (tree, result) = layout(view, 800.0, 600.0);
+
+        let root = result.get(tree.root().unwrap().id).unwrap();
+        assert_eq!(root, Rect::new(0.0, 0.0, 800.0, 600.0));
+
+        let rects = child_rects(&tree, &result);
+        assert_eq!(rects[0], Rect::new(20.0, 20.0, 40.0, 20.0));
+        // Button: 2 chars + 10px padding each side, 20px line + 5px each side
+        assert_eq!(rects[1], Rect::new(20.0, 50.0, 36.0, 30.0));
+    }
+
+    #[test]
+    fn test_style_classes_drive_flex_layout() {
+        let view = View::row()
+            .style("flex-row gap-4 p-2 w-full justify-between")
+            .child(View::text("Left"))
+            .child(View::text_styled("Right", "flex-1"))
+            .child(View::text_styled("End", "w-[100px]"))
+            .build();
+        let (tree, result) = layout(view, 400.0, 300.0);
+
+        let rects = child_rects(&tree, &result);
+        assert_eq!(rects[0].x, 8.0);
+        assert_eq!(rects[2].width, 100.0);
+        assert_eq!(rects[2].right(), 392.0);
+        // flex-1 takes the space left between its siblings and the 16px gaps
+        assert_eq!(rects[1].x, rects[0].right() + 16.0);
+        assert_eq!(rects[1].right() + 16.0, rects[2].x);
+    }
+
+    #[test]
+    fn test_grid_columns_and_spans() {
+        let view = View::col()
+            .style("grid grid-cols-3 gap-2 w-[300px]")
+            .child(View::text_styled("a", "col-span-2"))
+            .child(View::text("b"))
+            .child(View::text_styled("c", "col-start-2"))
+            .build();
+        let (tree, result) = layout(view, 800.0, 600.0);
+
+        let rects = child_rects(&tree, &result);
+        // three 1fr tracks of (300 - 2 * 8) / 3 ≈ 94.67px
+        assert!((rects[0].width - (2.0 * 284.0 / 3.0 + 8.0)).abs() < 1.0);
+        assert!((rects[1].x - (2.0 * 284.0 / 3.0 + 16.0)).abs() < 1.0);
+        assert!(rects[2].y > rects[0].y);
+        assert!((rects[2].x - (284.0 / 3.0 + 8.0)).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_hidden_and_responsive_nodes() {
+        let view = View::col()
+            .child(View::text_styled("mobile", "md:hidden"))
+            .child(View::text_styled("desktop", "hidden md:block"))
+            .build();
+
+        let (tree, narrow) = layout(view.clone(), 500.0, 400.0);
+        let [mobile, desktop] = [tree.root().unwrap().children[0], tree.root().unwrap().children[1]];
+        assert!(narrow.get(mobile).is_some());
+        assert!(narrow.get(desktop).is_none());
+
+        let (_, wide) = layout(view, 1024.0, 400.0);
+        assert!(wide.get(mobile).is_none());
+        assert_eq!(wide.get(desktop).unwrap().y, 0.0);
+    }
+
+    #[test]
+    fn test_text_wraps_and_custom_measure() {
+        let long = "x".repeat(30);
+        let view = View::col().style("w-[100px]").child(View::text(long)).build();
+        let (tree, result) = layout(view.clone(), 800.0, 600.0);
+        let text = child_rects(&tree, &result)[0];
+        assert_eq!(text.height, 60.0);
+
+        // A terminal measures in cells: one per char, one row per line
+        let tree = view_to_vtree(view);
+        let cells = LayoutEngine::new()
+            .measure(|node, _| match &node.props {
+                VNodeProps::Text { content } => (content.chars().count() as f32, 1.0),
+                _ => (0.0, 0.0),
+            })
+            .compute(&tree, WindowSize::new(80.0, 24.0));
+        let root = tree.root().unwrap();
+        assert_eq!(cells.get(root.children[0]).unwrap().height, 1.0);
+    }
+
+    #[test]
+    fn test_tab_bar_and_accordion_headers() {
+        let view = View::col()
+            .child(
+                View::tabs(vec!["One".to_string(), "Two".to_string()])
+                    .contents(vec![View::text("first"), View::text("second")])
+                    .selected(1)
+                    .build(),
+            )
+            .build();
+        let (tree, result) = layout(view, 400.0, 300.0);
+
+        let tabs = tree.root().unwrap().children[0];
+        let contents = &tree.get(tabs).unwrap().children;
+        assert!(result.get(contents[0]).is_none());
+        // The 30px bar (20px line + 5px padding each side) sits above the selected tab
+        assert_eq!(result.headers(tabs), &[Rect::new(0.0, 0.0, 400.0, 30.0)]);
+        assert_eq!(result.get(contents[1]).unwrap().y, 30.0);
+
+        let accordion = View::<()>::accordion()
+            .items(vec![
+                AccordionItem::new("A").with_expanded(true).with_children(vec![View::text("inside")]),
+                AccordionItem::new("B").with_children(vec![View::text("collapsed")]),
+            ])
+            .build();
+        let (tree, result) = layout(accordion, 400.0, 300.0);
+
+        let root = tree.root().unwrap();
+        let headers = result.headers(root.id);
+        assert_eq!(headers.len(), 2);
+        assert_eq!(result.get(root.children[0]).unwrap().y, headers[0].bottom());
+        assert_eq!(headers[1].y, 50.0);
+        assert!(result.get(root.children[1]).is_none());
+    }
+
+    #[test]
+    fn test_hit_test_finds_deepest_node() {
+        let view = View::col()
+            .padding(10)
+            .child(View::button("Save", ()))
+            .build();
+        let (tree, result) = layout(view, 200.0, 100.0);
+        let root = tree.root().unwrap();
+
+        assert_eq!(result.hit_test(15.0, 15.0), Some(root.children[0]));
+        assert_eq!(result.hit_test(150.0, 80.0), Some(root.id));
+        assert_eq!(result.hit_test(250.0, 15.0), None);
+    }
+}
@@ -4,12 +4,17 @@
 // This crate provides the abstraction layer that can be adapted to multiple backends
 // (iced, gpui, vue.js, etc.) through a unified Component trait and View system.
 
+// Lets the `style!` macro's `::auto_ui::...` paths resolve inside this crate too
+extern crate self as auto_ui;
+
 pub mod prelude {
     pub use crate::app::{App, AppResult};
     pub use crate::component::Component;
+    pub use crate::command::Command;
+    pub use crate::subscription::{Subscription, KeyEvent, Modifiers, WindowSize};
     pub use crate::view::{
         View, ViewBuilder, ViewContainerBuilder, ViewScrollableBuilder, ViewListBuilder,
-        ViewInputBuilder, ViewTableBuilder, ViewSliderBuilder, SelectCallback,
+        ViewInputBuilder, ViewTableBuilder, ViewSliderBuilder, SelectCallback, SliderCallback, InputCallback,
         // Plan 010: Unified Navigation Components
         AccordionBuilder, SidebarBuilder, TabsBuilder, NavigationRailBuilder,
         AccordionItem, NavigationRailItem, SidebarPosition, TabsPosition,
@@ -17,19 +22,25 @@ pub mod prelude {
     };
     // Plan 012: VNode types
     pub use crate::vnode::{VNodeId, VNodeKind, VNode, VNodeProps, VTree, VTreeStats};
-    pub use crate::vnode_converter::view_to_vtree;
+    pub use crate::vnode_converter::{view_to_vtree, vtree_to_view};
+    pub use crate::vnode_diff::Patch;
 }
 
 pub mod app;
 pub mod component;
+pub mod command; // Side effects returned from Component::update
+pub mod subscription; // External event sources (timers, keyboard, window, files)
 pub mod view;
 pub mod style; // Unified styling system
 pub mod node_converter; // AutoLang Node → View converter
 pub mod hot_reload; // Hot-reload support for .at files
+pub mod testing; // Headless harness for driving components in unit tests
+pub mod layout; // Backend-independent flexbox/grid layout of VTrees
 
 // Plan 012: VNode architecture - flattened view representation
 pub mod vnode;
 pub mod vnode_converter;
+pub mod vnode_diff; // Keyed VTree diffing and patch application
 
 // Dynamic interpreter module (Plan 011)
 #[cfg(feature = "interpreter")]
@@ -48,21 +59,29 @@ pub mod trans;
 // Re-export core types for convenience
 pub use app::{App, AppResult};
 pub use component::Component;
+pub use command::Command;
+pub use subscription::Subscription;
 pub use view::{
     View, ViewBuilder, ViewContainerBuilder, ViewScrollableBuilder, ViewListBuilder,
-    ViewInputBuilder, ViewTableBuilder, ViewSliderBuilder, SelectCallback,
+    ViewInputBuilder, ViewTableBuilder, ViewSliderBuilder, SelectCallback, SliderCallback, InputCallback,
     // Plan 010: Unified Navigation Components
     AccordionBuilder, SidebarBuilder, TabsBuilder, NavigationRailBuilder,
     AccordionItem, NavigationRailItem, SidebarPosition, TabsPosition,
     AccordionToggleCallback, TabsSelectCallback, NavigationRailSelectCallback,
 };
-pub use style::Style; // Re-export Style for backend adapters
+pub use style::{Style, Theme}; // Re-export for backend adapters
+pub use auto_ui_macros::style; // Compile-time checked `style!("p-4 bg-white")`
+pub use testing::{HarnessError, TestHarness};
+pub use layout::{compute_layout, LayoutEngine, LayoutResult, Rect};
 
 // Re-export AutoLang integration types
 pub use node_converter::{convert_node, ConversionError, ConversionResult};
 
 // Re-export hot-reload types
-pub use hot_reload::{HotReloadComponent, UIWatcher, HotReloadError, HotReloadResult};
+pub use hot_reload::{
+    ComponentError, HotReloadComponent, HotReloadError, HotReloadResult, ParseError, SourceLocation,
+    UIChange, UIWatcher,
+};
 
 // Note: widget.rs is kept for backward compatibility but may be deprecated
 // The new design uses Component trait directly instead of Widget trait
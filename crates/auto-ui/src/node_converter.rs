@@ -5,7 +5,8 @@
 
 use auto_val::{Value, Node};
 use crate::view::{View, SelectCallback};
-use crate::style::Style;
+use crate::style::{Style, StyleError};
+use crate::hot_reload::SourceLocation;
 
 // 导出动态消息类型（当 interpreter feature 启用时）
 #[cfg(feature = "interpreter")]
@@ -27,6 +28,33 @@ pub enum ConversionError {
     },
     /// Message type is required for this node (e.g., Button needs onclick)
     MessageRequired { kind: String },
+    /// Invalid class in a `style` property
+    InvalidStyle {
+        kind: String,
+        /// The whole style string the class came from
+        style: String,
+        error: StyleError,
+        /// Position of the class in the .at source, once resolved by `locate`
+        location: Option<SourceLocation>,
+    },
+}
+
+impl ConversionError {
+    /// Resolve where a style error sits in the .at source it was converted from
+    ///
+    /// Nodes carry no source positions, so the style string is looked up in
+    /// `source` (first occurrence). Other errors are returned unchanged.
+    pub fn locate(self, source: &str) -> Self {
+        match self {
+            ConversionError::InvalidStyle { kind, style, error, location: None } => {
+                let location = source
+                    .find(&style)
+                    .map(|start| SourceLocation::from_offset(source, start + error.span.start));
+                ConversionError::InvalidStyle { kind, style, error, location }
+            }
+            other => other,
+        }
+    }
 }
 
 impl std::fmt::Display for ConversionError {
@@ -44,6 +72,10 @@ impl std::fmt::Display for ConversionError {
             ConversionError::MessageRequired { kind } => {
                 write!(f, "Message type required for node kind '{}' (runtime interpretation not yet supported)", kind)
             }
+            ConversionError::InvalidStyle { kind, error, location, .. } => match location {
+                Some(location) => write!(f, "{}: Invalid style on node kind '{}': {}", location, kind, error),
+                None => write!(f, "Invalid style on node kind '{}': {}", kind, error),
+            },
         }
     }
 }
@@ -92,6 +124,7 @@ pub fn convert_node(node: &Node) -> ConversionResult<View<String>> {
         "input" => convert_input(node),
         "checkbox" => convert_checkbox(node),
         "radio" => convert_radio(node),
+        "radio_group" => convert_radio_group(node),
         "select" => convert_select(node),
         "list" => convert_list(node),
         "table" => convert_table(node),
@@ -276,16 +309,18 @@ fn convert_button(node: &Node) -> ConversionResult<View<String>> {
             prop: "onclick".to_string(),
         })?;
 
+    let disabled = extract_prop_bool(node, "disabled").unwrap_or(false);
     let style = extract_style(node)?;
 
     if let Some(style) = style {
         Ok(View::Button {
             label,
             onclick, // Store as string message ID
+            disabled,
             style: Some(style),
         })
     } else {
-        Ok(View::button(label, onclick))
+        Ok(View::button(label, onclick).disabled(disabled))
     }
 }
 
@@ -296,15 +331,17 @@ fn convert_input(node: &Node) -> ConversionResult<View<String>> {
     let on_change = extract_prop_opt_str(node, "on_change");
     let width = extract_prop_opt_u16(node, "width");
     let password = extract_prop_bool(node, "password").unwrap_or(false);
+    let disabled = extract_prop_bool(node, "disabled").unwrap_or(false);
     let style = extract_style(node)?;
 
-    let mut builder = View::input(placeholder).value(value);
+    let mut builder = View::input(placeholder).value(value).disabled(disabled);
 
     if password {
         builder = builder.password();
     }
     if let Some(msg) = on_change {
-        builder = builder.on_change(msg);
+        // String messages name the event only; the typed text is not forwarded
+        builder = builder.on_change(move |_text| msg.clone());
     }
     if let Some(w) = width {
         builder = builder.width(w);
@@ -358,6 +395,31 @@ fn convert_radio(node: &Node) -> ConversionResult<View<String>> {
     Ok(view)
 }
 
+/// Convert RadioGroup node: `radio_group { options: ["S", "M", "L"], selected_index: 0, on_select: "size" }`
+fn convert_radio_group(node: &Node) -> ConversionResult<View<String>> {
+    let options = extract_prop_str_array(node, "options").unwrap_or_default();
+    let selected_index = extract_prop_opt_usize(node, "selected_index");
+    let on_select = extract_prop_opt_str(node, "on_select");
+    let style = extract_style(node)?;
+
+    let mut view = View::radio_group(options);
+
+    if let Some(idx) = selected_index {
+        view = view.selected(idx);
+    }
+    if let Some(msg) = on_select {
+        // Same limitation as select: string messages cannot carry the chosen index
+        view = view.on_choose(move |_index, _value| msg.clone());
+    }
+    if let Some(style) = style {
+        if let View::RadioGroup { style: s, .. } = &mut view {
+            *s = Some(style);
+        }
+    }
+
+    Ok(view)
+}
+
 /// Convert Select node: `select { options: ["A", "B"], selected_index: 0, on_select: "change" }`
 fn convert_select(node: &Node) -> ConversionResult<View<String>> {
     let options = extract_prop_str_array(node, "options").unwrap_or_default();
@@ -609,11 +671,11 @@ fn extract_style(node: &Node) -> ConversionResult<Option<Style>> {
         // Parse style string using Plan 005's Style::parse
         Style::parse(&s)
             .map(Some)
-            .map_err(|e| ConversionError::InvalidPropType {
+            .map_err(|error| ConversionError::InvalidStyle {
                 kind: node.name.to_string(),
-                prop: "style".to_string(),
-                expected: "valid Tailwind CSS class string".to_string(),
-                got: format!("invalid: {}", e),
+                style: s,
+                error,
+                location: None,
             })
     } else {
         Ok(None)
@@ -731,6 +793,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_radio_group() {
+        let node = Node::new("radio_group")
+            .with_prop("selected_index", 2u32)
+            .with_prop("on_select", "pick-size");
+
+        let view = convert_node(&node).unwrap();
+
+        match view {
+            View::RadioGroup { selected_index, on_select, .. } => {
+                assert_eq!(selected_index, Some(2));
+                assert_eq!(on_select.unwrap().call(0, "S"), "pick-size");
+            }
+            _ => panic!("Expected View::RadioGroup"),
+        }
+    }
+
     #[test]
     fn test_unknown_kind() {
         let node = Node::new("unknown_widget");
@@ -744,6 +823,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_invalid_style_is_located() {
+        let node = Node::new("text")
+            .with_arg("Hi")
+            .with_prop("style", "p-4 bg-blu-500");
+        let source = "col {\n    text(\"Hi\") { style: \"p-4 bg-blu-500\" }\n}\n";
+
+        let error = convert_node(&node).unwrap_err().locate(source);
+        match &error {
+            ConversionError::InvalidStyle { kind, error, location, .. } => {
+                assert_eq!(kind, "text");
+                assert_eq!(error.suggestion.as_deref(), Some("bg-blue-500"));
+                assert_eq!(*location, Some(SourceLocation { line: 2, column: 30 }));
+            }
+            other => panic!("Expected InvalidStyle error, got {:?}", other),
+        }
+        assert!(error.to_string().starts_with("2:30: Invalid style on node kind 'text'"));
+    }
+
     #[test]
     fn test_button_missing_onclick() {
         let node = Node::new("button").with_arg("Click");
@@ -841,6 +939,7 @@ pub fn convert_node_dynamic(
         "input" => convert_input_dynamic(node, metadata),
         "checkbox" => convert_checkbox_dynamic(node, metadata),
         "radio" => convert_radio_dynamic(node, metadata),
+        "radio_group" => convert_radio_group_dynamic(node, metadata),
         "select" => convert_select_dynamic(node, metadata),
         "list" => convert_list_dynamic(node, metadata),
         "table" => convert_table_dynamic(node, metadata),
@@ -1034,8 +1133,9 @@ fn convert_button_dynamic(
     let label = extract_main_arg_str(node)
         .unwrap_or_else(|| String::from("Button"));
     let onclick = extract_event_handler(node, "onclick", metadata.map(|(name, _)|name))?;
+    let disabled = extract_prop_bool(node, "disabled").unwrap_or(false);
     let style = extract_style(node)?;
-    Ok(View::Button { label, onclick, style })
+    Ok(View::Button { label, onclick, disabled, style })
 }
 
 #[cfg(feature = "interpreter")]
@@ -1044,13 +1144,29 @@ fn convert_input_dynamic(
     metadata: Option<(&str, &auto_lang::Universe)>,
 ) -> ConversionResult<View<DynamicMessage>> {
     let placeholder = extract_main_arg_str(node).unwrap_or_default();
-    let on_change = extract_event_handler(node, "onchange", metadata.map(|(name, _)|name))?;
+    let event = extract_prop_str(node, "onchange")
+        .ok_or_else(|| ConversionError::MissingProp {
+            kind: node.name.to_string(),
+            prop: "onchange".to_string(),
+        })?;
+    let widget_name = metadata.map(|(name, _)| name.to_string());
+
+    // 已知所属 Widget 时发送带输入文本的类型化事件，否则退回字符串事件
+    let on_change = crate::view::InputCallback::new(move |text: String| match &widget_name {
+        Some(widget_name) => DynamicMessage::Typed {
+            widget_name: widget_name.clone(),
+            event_name: event.clone(),
+            args: vec![Value::Str(text.into())],
+        },
+        None => DynamicMessage::String(event.clone()),
+    });
     let value = extract_prop_str(node, "value").unwrap_or_default();
     let width = extract_prop_u32(node, "width");  // Option<u32>
     let width = width.map(|w| w as u16);  // Option<u16>
     let password = extract_prop_bool(node, "password").unwrap_or(false);
+    let disabled = extract_prop_bool(node, "disabled").unwrap_or(false);
     let style = extract_style(node)?;
-    Ok(View::Input { placeholder, value, on_change: Some(on_change), width, password, style })
+    Ok(View::Input { placeholder, value, on_change: Some(on_change), width, password, disabled, style })
 }
 
 #[cfg(feature = "interpreter")]
@@ -1081,6 +1197,32 @@ fn convert_radio_dynamic(
     Ok(View::Radio { label, is_selected, on_select, style })
 }
 
+#[cfg(feature = "interpreter")]
+fn convert_radio_group_dynamic(
+    node: &Node,
+    metadata: Option<(&str, &auto_lang::Universe)>,
+) -> ConversionResult<View<DynamicMessage>> {
+    let options = extract_children_strings(node)?;
+    let selected_index = extract_prop_usize(node, "selected");
+    let widget_name = metadata.map(|(name, _)| name.to_string());
+
+    // 已知所属 Widget 时发送带索引的类型化事件，否则退回字符串事件
+    let on_select = extract_prop_str(node, "onselect")
+        .map(|event_str| {
+            SelectCallback::new(move |index: usize, _selected: &str| match &widget_name {
+                Some(widget_name) => DynamicMessage::Typed {
+                    widget_name: widget_name.clone(),
+                    event_name: event_str.clone(),
+                    args: vec![Value::USize(index)],
+                },
+                None => DynamicMessage::String(event_str.clone()),
+            })
+        });
+
+    let style = extract_style(node)?;
+    Ok(View::RadioGroup { options, selected_index, on_select, style })
+}
+
 #[cfg(feature = "interpreter")]
 fn convert_select_dynamic(
     node: &Node,
@@ -1208,6 +1350,38 @@ mod tests_dynamic {
         }
     }
 
+    #[test]
+    fn test_convert_input_dynamic_carries_text() {
+        let node = Node::new("input")
+            .with_arg("Name")
+            .with_prop("onchange", "Rename");
+        let universe = auto_lang::Universe::new();
+
+        let view = convert_node_dynamic(&node, Some(("Form", &universe))).unwrap();
+        let on_change = match view {
+            View::Input { on_change: Some(on_change), .. } => on_change,
+            _ => panic!("Expected View::Input with on_change"),
+        };
+
+        match on_change.call("Ada") {
+            DynamicMessage::Typed { widget_name, event_name, args } => {
+                assert_eq!(widget_name, "Form");
+                assert_eq!(event_name, "Rename");
+                assert!(matches!(args.as_slice(), [Value::Str(text)] if text.to_string() == "Ada"));
+            }
+            other => panic!("Expected typed message, got {:?}", other),
+        }
+
+        // 没有 Widget 信息时退回字符串事件
+        let view = convert_node_dynamic(&node, None).unwrap();
+        match view {
+            View::Input { on_change: Some(on_change), .. } => {
+                assert!(matches!(on_change.call("Ada"), DynamicMessage::String(event) if event == "Rename"));
+            }
+            _ => panic!("Expected View::Input with on_change"),
+        }
+    }
+
     #[test]
     fn test_convert_column_dynamic() {
         let node = Node::new("col")
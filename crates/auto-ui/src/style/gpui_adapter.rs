@@ -3,7 +3,8 @@
 // This adapter translates the unified StyleClass IR into GPUI-specific
 // builder method calls for styling components.
 
-use crate::style::{Style, StyleClass, SizeValue, Color};
+use crate::style::{Color, SizeValue, Style, StyleClass, StyleState, Theme};
+use crate::subscription::WindowSize;
 use gpui_lib as gpui;
 
 /// Apply a Style to a GPUI component builder
@@ -43,6 +44,7 @@ pub struct GpuiStyle {
     // Border Radius (L1 + L2)
     pub rounded: bool,
     pub rounded_size: Option<GpuiRoundedSize>,
+    pub border_radius: Option<f32>, // rounded_size resolved through the theme
 
     // Border (L2)
     pub border: bool,
@@ -75,6 +77,9 @@ pub struct GpuiStyle {
     pub row_span: Option<u8>,
     pub col_start: Option<u8>,
     pub row_start: Option<u8>,
+
+    // Visibility
+    pub hidden: bool,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -125,6 +130,7 @@ pub enum GpuiFontSize {
     Xl,   // 20px
     Xxl,  // 24px
     X3xl, // 30px
+    Px(f32), // text-[15px]
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -162,8 +168,15 @@ pub enum GpuiSize {
 }
 
 impl GpuiStyle {
-    /// Convert a Style to GpuiStyle
+    /// Convert a Style to GpuiStyle using the active theme and window width
     pub fn from_style(style: &Style) -> Self {
+        Self::from_style_with_theme(&style.at_width(WindowSize::current().width), &Theme::active())
+    }
+
+    /// Convert a Style to GpuiStyle, resolving tokens through `theme`
+    ///
+    /// Breakpoint classes are skipped; resolve them first with `Style::at_width`.
+    pub fn from_style_with_theme(style: &Style, theme: &Theme) -> Self {
         let mut gpui_style = GpuiStyle {
             padding: None,
             padding_x: None,
@@ -183,6 +196,7 @@ impl GpuiStyle {
             text_color: None,
             rounded: false,
             rounded_size: None,
+            border_radius: None,
             border: false,
             border_width: None,
             border_color: None,
@@ -204,47 +218,79 @@ impl GpuiStyle {
             row_span: None,
             col_start: None,
             row_start: None,
+            hidden: false,
         };
 
-        for class in &style.classes {
-            gpui_style.apply_class(class);
+        for class in style.classes.iter() {
+            gpui_style.apply_class(class, theme);
+        }
+
+        // A bare `border` uses the theme's border color
+        if gpui_style.border && gpui_style.border_color.is_none() {
+            gpui_style.border_color = Some(convert_color(&theme.colors.border, theme));
         }
 
         gpui_style
     }
 
+    /// Convert only the classes of one state variant using the active theme and window width
+    pub fn from_variant(style: &Style, state: StyleState) -> Option<Self> {
+        Self::from_variant_with_theme(&style.at_width(WindowSize::current().width), state, &Theme::active())
+    }
+
+    /// Convert only the classes of one state variant (`hover:bg-blue-600` → bg)
+    ///
+    /// GPUI refines the base style itself in `.hover()`/`.active()`/`.focus()`,
+    /// so only the delta is needed. Returns `None` if the state has no classes.
+    pub fn from_variant_with_theme(style: &Style, state: StyleState, theme: &Theme) -> Option<Self> {
+        if !style.has_variant(state) {
+            return None;
+        }
+
+        let delta = Style {
+            classes: style
+                .variants
+                .iter()
+                .filter(|(s, _)| *s == state)
+                .map(|(_, class)| class.clone())
+                .collect(),
+            ..Style::default()
+        };
+        Some(Self::from_style_with_theme(&delta, theme))
+    }
+
     /// Apply a single StyleClass to this GpuiStyle
-    fn apply_class(&mut self, class: &StyleClass) {
+    fn apply_class(&mut self, class: &StyleClass, theme: &Theme) {
         match class {
             // ========== Spacing (L1 + L2) ==========
             StyleClass::Padding(size) => {
-                self.padding = Some(GpuiPadding::Uniform(size.to_pixels() as f32));
+                self.padding = Some(GpuiPadding::Uniform(theme.spacing(size)));
             }
             StyleClass::PaddingX(size) => {
-                self.padding_x = Some(size.to_pixels() as f32);
+                self.padding_x = Some(theme.spacing(size));
             }
             StyleClass::PaddingY(size) => {
-                self.padding_y = Some(size.to_pixels() as f32);
+                self.padding_y = Some(theme.spacing(size));
             }
             StyleClass::Margin(size) => {
-                self.margin = Some(size.to_pixels() as f32);
+                self.margin = Some(theme.spacing(size));
             }
             StyleClass::MarginX(size) => {
-                self.margin_x = Some(size.to_pixels() as f32);
+                self.margin_x = Some(theme.spacing(size));
             }
             StyleClass::MarginY(size) => {
-                self.margin_y = Some(size.to_pixels() as f32);
+                self.margin_y = Some(theme.spacing(size));
             }
             StyleClass::Gap(size) => {
-                self.gap = Some(size.to_pixels() as f32);
+                self.gap = Some(theme.spacing(size));
             }
 
             // ========== Colors (L1) ==========
             StyleClass::BackgroundColor(color) => {
-                self.background_color = Some(convert_color(color));
+                self.background_color = Some(convert_color(color, theme));
             }
             StyleClass::TextColor(color) => {
-                self.text_color = Some(convert_color(color));
+                self.text_color = Some(convert_color(color, theme));
             }
 
             // ========== Layout (L1 + L2) ==========
@@ -287,44 +333,56 @@ impl GpuiStyle {
 
             // ========== Sizing (L1) ==========
             StyleClass::Width(size) => {
-                self.width = Some(convert_size(&size));
+                self.width = Some(convert_size(size, theme));
             }
             StyleClass::Height(size) => {
-                self.height = Some(convert_size(&size));
+                self.height = Some(convert_size(size, theme));
             }
 
             // ========== Border Radius (L1 + L2) ==========
             StyleClass::Rounded => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Md);
+                self.border_radius = Some(theme.radii.md);
             }
             StyleClass::RoundedSm => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Sm);
+                self.border_radius = Some(theme.radii.sm);
             }
             StyleClass::RoundedMd => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Md);
+                self.border_radius = Some(theme.radii.md);
             }
             StyleClass::RoundedLg => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Lg);
+                self.border_radius = Some(theme.radii.lg);
             }
             StyleClass::RoundedXl => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Xl);
+                self.border_radius = Some(theme.radii.xl);
             }
             StyleClass::Rounded2Xl => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Xxl);
+                self.border_radius = Some(theme.radii.xxl);
             }
             StyleClass::Rounded3Xl => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Xxl);
+                self.border_radius = Some(theme.radii.xxxl);
             }
             StyleClass::RoundedFull => {
                 self.rounded = true;
                 self.rounded_size = Some(GpuiRoundedSize::Full);
+                self.border_radius = Some(theme.radii.full);
+            }
+            StyleClass::RoundedPx(px) => {
+                self.rounded = true;
+                self.border_radius = Some(*px as f32);
             }
 
             // ========== Border (L2) ==========
@@ -338,7 +396,7 @@ impl GpuiStyle {
             }
             StyleClass::BorderColor(color) => {
                 self.border = true;
-                self.border_color = Some(convert_color(color));
+                self.border_color = Some(convert_color(color, theme));
             }
 
             // ========== Typography (L2) ==========
@@ -363,6 +421,9 @@ impl GpuiStyle {
             StyleClass::Text3Xl => {
                 self.font_size = Some(GpuiFontSize::X3xl);
             }
+            StyleClass::FontSize(px) => {
+                self.font_size = Some(GpuiFontSize::Px(*px as f32));
+            }
             StyleClass::FontBold => {
                 self.font_weight = Some(GpuiFontWeight::Bold);
             }
@@ -474,53 +535,31 @@ impl GpuiStyle {
             StyleClass::RowStart(start) => {
                 self.row_start = Some(*start);
             }
+
+            // ========== Visibility ==========
+            StyleClass::Hidden => {
+                self.hidden = true;
+            }
+            StyleClass::Block => {
+                self.hidden = false;
+            }
         }
     }
 }
 
 /// Convert a SizeValue to GpuiSize
-fn convert_size(size: &SizeValue) -> GpuiSize {
+fn convert_size(size: &SizeValue, theme: &Theme) -> GpuiSize {
     match size {
         SizeValue::Full => GpuiSize::Full,
-        SizeValue::Fixed(units) => GpuiSize::Fixed((*units * 4) as f32),
+        SizeValue::Fixed(_) | SizeValue::Px(_) => GpuiSize::Fixed(theme.spacing(size)),
         _ => GpuiSize::Full, // Default to full for other variants
     }
 }
 
-/// Convert a Color to gpui::Rgba
-fn convert_color(color: &Color) -> gpui::Rgba {
-    match color {
-        Color::Rgb { r, g, b } => {
-            gpui::Rgba { r: *r as f32 / 255.0, g: *g as f32 / 255.0, b: *b as f32 / 255.0, a: 1.0 }
-        }
-        Color::Rgba { r, g, b, a } => {
-            gpui::Rgba { r: *r as f32 / 255.0, g: *g as f32 / 255.0, b: *b as f32 / 255.0, a: *a as f32 / 255.0 }
-        }
-        Color::White => gpui::Rgba { r: 1.0, g: 1.0, b: 1.0, a: 1.0 },
-        Color::Black => gpui::Rgba { r: 0.0, g: 0.0, b: 0.0, a: 1.0 },
-        Color::Slate(shade) | Color::Gray(shade) | Color::Zinc(shade) | Color::Neutral(shade) => {
-            // Grayscale colors
-            let value = 1.0 - (*shade as f32 / 900.0);
-            gpui::Rgba { r: value, g: value, b: value, a: 1.0 }
-        }
-        Color::Red(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            gpui::Rgba { r: intensity, g: 0.0, b: 0.0, a: 1.0 }
-        }
-        Color::Blue(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            gpui::Rgba { r: 0.0, g: 0.0, b: intensity, a: 1.0 }
-        }
-        Color::Green(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            gpui::Rgba { r: 0.0, g: intensity, b: 0.0, a: 1.0 }
-        }
-        Color::Yellow(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            gpui::Rgba { r: intensity, g: intensity, b: 0.0, a: 1.0 }
-        }
-        _ => gpui::Rgba { r: 0.5, g: 0.5, b: 0.5, a: 1.0 }, // Default gray (semantic colors)
-    }
+/// Convert a Color to gpui::Rgba, resolving semantic tokens through the theme
+fn convert_color(color: &Color, theme: &Theme) -> gpui::Rgba {
+    let (r, g, b, a) = theme.rgba(color);
+    gpui::Rgba { r, g, b, a }
 }
 
 #[cfg(test)]
@@ -536,9 +575,23 @@ mod tests {
         assert_eq!(gpui_style.padding, Some(GpuiPadding::Uniform(16.0)));
     }
 
+    #[test]
+    fn test_variant_delta() {
+        let style = Style::parse("p-4 bg-white hover:bg-black active:opacity-75").unwrap();
+        let theme = Theme::light();
+
+        let hover = GpuiStyle::from_variant_with_theme(&style, StyleState::Hover, &theme).unwrap();
+        assert_eq!(hover.background_color, Some(convert_color(&Color::Black, &theme)));
+        assert_eq!(hover.padding, None);
+
+        let active = GpuiStyle::from_variant_with_theme(&style, StyleState::Active, &theme).unwrap();
+        assert_eq!(active.opacity, Some(0.75));
+        assert!(GpuiStyle::from_variant_with_theme(&style, StyleState::Focus, &theme).is_none());
+    }
+
     #[test]
     fn test_convert_color() {
-        let white = convert_color(&Color::White);
+        let white = convert_color(&Color::White, &Theme::light());
         assert_eq!(white.r, 1.0);
         assert_eq!(white.g, 1.0);
         assert_eq!(white.b, 1.0);
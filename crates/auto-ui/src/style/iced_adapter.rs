@@ -3,7 +3,8 @@
 // This adapter translates the unified StyleClass IR into Iced-specific
 // style objects for styling components.
 
-use crate::style::{Style, StyleClass, SizeValue, Color};
+use crate::style::{Color, SizeValue, Style, StyleClass, StyleState, Theme, ThemeFontSizes};
+use crate::subscription::WindowSize;
 
 /// Iced style representation
 ///
@@ -67,6 +68,13 @@ pub struct IcedStyle {
     pub row_span: Option<u8>,       // Not supported by Iced
     pub col_start: Option<u8>,      // Not supported by Iced
     pub row_start: Option<u8>,      // Not supported by Iced
+
+    // Visibility
+    pub hidden: bool,
+
+    // Theme values needed after conversion
+    font_sizes: ThemeFontSizes,
+    default_border_color: iced::Color,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -96,7 +104,22 @@ pub enum IcedOverflow {
 #[derive(Clone, Copy, PartialEq)]
 pub enum IcedSize {
     Full,
+    Shrink,
     Fixed(f32),
+    /// Share of the free space in twelfths (`w-1/2` is 6, `w-1/3` is 4)
+    Portion(u16),
+}
+
+impl IcedSize {
+    /// Convert to an iced Length
+    pub fn to_length(self) -> iced::Length {
+        match self {
+            IcedSize::Full => iced::Length::Fill,
+            IcedSize::Shrink => iced::Length::Shrink,
+            IcedSize::Fixed(px) => iced::Length::Fixed(px),
+            IcedSize::Portion(twelfths) => iced::Length::FillPortion(twelfths),
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -108,6 +131,23 @@ pub enum IcedFontSize {
     Xl,   // 20px
     Xxl,  // 24px
     X3xl, // 30px
+    Px(f32), // text-[15px]
+}
+
+impl IcedFontSize {
+    /// Font size in pixels under the given theme scale
+    pub fn to_pixels(self, sizes: &ThemeFontSizes) -> f32 {
+        match self {
+            IcedFontSize::Xs => sizes.xs,
+            IcedFontSize::Sm => sizes.sm,
+            IcedFontSize::Base => sizes.base,
+            IcedFontSize::Lg => sizes.lg,
+            IcedFontSize::Xl => sizes.xl,
+            IcedFontSize::Xxl => sizes.xxl,
+            IcedFontSize::X3xl => sizes.xxxl,
+            IcedFontSize::Px(px) => px,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -125,8 +165,15 @@ pub enum IcedTextAlign {
 }
 
 impl IcedStyle {
-    /// Convert a Style to IcedStyle
+    /// Convert a Style to IcedStyle using the active theme and window width
     pub fn from_style(style: &Style) -> Self {
+        Self::from_style_with_theme(&style.at_width(WindowSize::current().width), &Theme::active())
+    }
+
+    /// Convert a Style to IcedStyle, resolving tokens through `theme`
+    ///
+    /// Breakpoint classes are skipped; resolve them first with `Style::at_width`.
+    pub fn from_style_with_theme(style: &Style, theme: &Theme) -> Self {
         let mut iced_style = IcedStyle {
             padding: None,
             padding_x: None,
@@ -162,92 +209,99 @@ impl IcedStyle {
             row_span: None,     // Not supported by Iced
             col_start: None,    // Not supported by Iced
             row_start: None,    // Not supported by Iced
+            hidden: false,
+            font_sizes: theme.font_sizes,
+            default_border_color: convert_color(&theme.colors.border, theme),
         };
 
-        for class in &style.classes {
-            iced_style.apply_class(class);
+        for class in style.classes.iter() {
+            iced_style.apply_class(class, theme);
         }
 
         iced_style
     }
 
     /// Apply a single StyleClass to this IcedStyle
-    fn apply_class(&mut self, class: &StyleClass) {
+    fn apply_class(&mut self, class: &StyleClass, theme: &Theme) {
         match class {
             // ========== Spacing (L1 + L2) ==========
             StyleClass::Padding(size) => {
-                self.padding = Some(size.to_pixels() as f32);
+                self.padding = Some(theme.spacing(size));
             }
             StyleClass::PaddingX(size) => {
-                self.padding_x = Some(size.to_pixels() as f32);
+                self.padding_x = Some(theme.spacing(size));
             }
             StyleClass::PaddingY(size) => {
-                self.padding_y = Some(size.to_pixels() as f32);
+                self.padding_y = Some(theme.spacing(size));
             }
             StyleClass::Margin(size) => {
                 // Iced doesn't support margin - store but will be ignored
-                self.margin = Some(size.to_pixels() as f32);
+                self.margin = Some(theme.spacing(size));
             }
             StyleClass::MarginX(size) => {
                 // Iced doesn't support margin - store but will be ignored
-                self.margin_x = Some(size.to_pixels() as f32);
+                self.margin_x = Some(theme.spacing(size));
             }
             StyleClass::MarginY(size) => {
                 // Iced doesn't support margin - store but will be ignored
-                self.margin_y = Some(size.to_pixels() as f32);
+                self.margin_y = Some(theme.spacing(size));
             }
             StyleClass::Gap(size) => {
-                self.gap = Some(size.to_pixels() as f32);
+                self.gap = Some(theme.spacing(size));
             }
 
             // ========== Colors (L1) ==========
             StyleClass::BackgroundColor(color) => {
-                self.background_color = Some(convert_color(color));
+                self.background_color = Some(convert_color(color, theme));
             }
             StyleClass::TextColor(color) => {
-                self.text_color = Some(convert_color(color));
+                self.text_color = Some(convert_color(color, theme));
             }
 
             // ========== Sizing (L1) ==========
             StyleClass::Width(size) => {
-                self.width = Some(convert_size(size));
+                self.width = Some(convert_size(size, theme));
             }
             StyleClass::Height(size) => {
-                self.height = Some(convert_size(size));
+                self.height = Some(convert_size(size, theme));
             }
 
             // ========== Border Radius (L1 + L2) ==========
             StyleClass::Rounded => {
                 self.rounded = true;
-                self.border_radius = Some(4.0);
+                self.border_radius = Some(theme.radii.md);
             }
             StyleClass::RoundedSm => {
                 self.rounded = true;
-                self.border_radius = Some(2.0);
+                self.border_radius = Some(theme.radii.sm);
             }
             StyleClass::RoundedMd => {
                 self.rounded = true;
-                self.border_radius = Some(4.0);
+                self.border_radius = Some(theme.radii.md);
             }
             StyleClass::RoundedLg => {
                 self.rounded = true;
-                self.border_radius = Some(8.0);
+                self.border_radius = Some(theme.radii.lg);
             }
             StyleClass::RoundedXl => {
                 self.rounded = true;
-                self.border_radius = Some(12.0);
+                self.border_radius = Some(theme.radii.xl);
             }
             StyleClass::Rounded2Xl => {
                 self.rounded = true;
-                self.border_radius = Some(16.0);
+                self.border_radius = Some(theme.radii.xxl);
             }
             StyleClass::Rounded3Xl => {
                 self.rounded = true;
-                self.border_radius = Some(24.0);
+                self.border_radius = Some(theme.radii.xxxl);
             }
             StyleClass::RoundedFull => {
                 self.rounded = true;
-                self.border_radius = Some(9999.0); // Effectively full
+                self.border_radius = Some(theme.radii.full); // Effectively full
+            }
+            StyleClass::RoundedPx(px) => {
+                self.rounded = true;
+                self.border_radius = Some(*px as f32);
             }
 
             // ========== Border (L2) ==========
@@ -261,7 +315,7 @@ impl IcedStyle {
             }
             StyleClass::BorderColor(color) => {
                 self.border = true;
-                self.border_color = Some(convert_color(color));
+                self.border_color = Some(convert_color(color, theme));
             }
 
             // ========== Typography (L2) ==========
@@ -286,6 +340,9 @@ impl IcedStyle {
             StyleClass::Text3Xl => {
                 self.font_size = Some(IcedFontSize::X3xl);
             }
+            StyleClass::FontSize(px) => {
+                self.font_size = Some(IcedFontSize::Px(*px as f32));
+            }
             StyleClass::FontBold => {
                 self.font_weight = Some(IcedFontWeight::Bold);
             }
@@ -405,6 +462,14 @@ impl IcedStyle {
                 self.row_start = Some(*start);
             }
 
+            // ========== Visibility ==========
+            StyleClass::Hidden => {
+                self.hidden = true;
+            }
+            StyleClass::Block => {
+                self.hidden = false;
+            }
+
             // ========== Layout styles ==========
             // Layout-related styles (flex, items-center, etc.) are handled differently in Iced
             // They're applied through layout methods rather than style objects
@@ -414,53 +479,293 @@ impl IcedStyle {
         }
     }
 
-    // Note: Iced 0.14+ uses theme-based styling
-    // Style conversion methods are deprecated
-}
+    // ========== Conversions to iced values ==========
+    // Iced 0.14+ styles widgets through closures returning a per-widget Style,
+    // so these helpers produce plain values the backend can capture in them.
 
-/// Convert a SizeValue to IcedSize
-fn convert_size(size: &SizeValue) -> IcedSize {
-    match size {
-        SizeValue::Full => IcedSize::Full,
-        SizeValue::Fixed(units) => IcedSize::Fixed(*units as f32),
-        _ => IcedSize::Full, // Default to full for other variants
+    /// Padding, with px-*/py-* overriding p-* on their axis
+    pub fn padding(&self) -> Option<iced::Padding> {
+        if self.padding.is_none() && self.padding_x.is_none() && self.padding_y.is_none() {
+            return None;
+        }
+
+        let base = self.padding.unwrap_or(0.0);
+        let x = self.padding_x.unwrap_or(base);
+        let y = self.padding_y.unwrap_or(base);
+
+        Some(iced::Padding {
+            top: y,
+            right: x,
+            bottom: y,
+            left: x,
+        })
+    }
+
+    /// Width as an iced Length
+    pub fn width_length(&self) -> Option<iced::Length> {
+        self.width.map(IcedSize::to_length)
     }
-}
 
-/// Convert a Color to iced::Color
-fn convert_color(color: &Color) -> iced::Color {
-    match color {
-        Color::Rgb { r, g, b } => {
-            iced::Color::from_rgb(*r as f32 / 255.0, *g as f32 / 255.0, *b as f32 / 255.0)
+    /// Height as an iced Length
+    pub fn height_length(&self) -> Option<iced::Length> {
+        self.height.map(IcedSize::to_length)
+    }
+
+    /// Text size in pixels
+    pub fn text_size(&self) -> Option<f32> {
+        self.font_size.map(|size| size.to_pixels(&self.font_sizes))
+    }
+
+    /// Default font with the requested weight
+    pub fn font(&self) -> Option<iced::Font> {
+        self.font_weight.map(|weight| iced::Font {
+            weight: match weight {
+                IcedFontWeight::Normal => iced::font::Weight::Normal,
+                IcedFontWeight::Medium => iced::font::Weight::Medium,
+                IcedFontWeight::Bold => iced::font::Weight::Bold,
+            },
+            ..iced::Font::DEFAULT
+        })
+    }
+
+    /// Horizontal text alignment
+    pub fn text_alignment(&self) -> Option<iced::alignment::Horizontal> {
+        self.text_align.map(|align| match align {
+            IcedTextAlign::Left => iced::alignment::Horizontal::Left,
+            IcedTextAlign::Center => iced::alignment::Horizontal::Center,
+            IcedTextAlign::Right => iced::alignment::Horizontal::Right,
+        })
+    }
+
+    /// Text color with opacity applied
+    pub fn text_color(&self) -> Option<iced::Color> {
+        self.text_color.map(|color| self.faded(color))
+    }
+
+    /// Background with opacity applied
+    pub fn background(&self) -> Option<iced::Background> {
+        self.background_color
+            .map(|color| iced::Background::Color(self.faded(color)))
+    }
+
+    /// Border from border, border-color and rounded-* classes
+    pub fn border(&self) -> iced::Border {
+        let default_color = if self.border {
+            self.default_border_color
+        } else {
+            iced::Color::TRANSPARENT
+        };
+
+        iced::Border {
+            color: self.faded(self.border_color.unwrap_or(default_color)),
+            width: self.border_width.unwrap_or(if self.border { 1.0 } else { 0.0 }),
+            radius: self.border_radius.unwrap_or(0.0).into(),
         }
-        Color::Rgba { r, g, b, a } => {
-            iced::Color::from_rgba(*r as f32 / 255.0, *g as f32 / 255.0, *b as f32 / 255.0, *a as f32 / 255.0)
+    }
+
+    /// Drop shadow approximating Tailwind's shadow-* scale
+    pub fn shadow(&self) -> iced::Shadow {
+        let (offset, blur) = match self.shadow_size {
+            Some(IcedShadowSize::Sm) => (1.0, 2.0),
+            Some(IcedShadowSize::Md) => (4.0, 6.0),
+            Some(IcedShadowSize::Lg) => (10.0, 15.0),
+            Some(IcedShadowSize::Xl) => (20.0, 25.0),
+            Some(IcedShadowSize::Xxl) => (25.0, 50.0),
+            Some(IcedShadowSize::None) | None => return iced::Shadow::default(),
+        };
+
+        iced::Shadow {
+            color: iced::Color::from_rgba(0.0, 0.0, 0.0, 0.15),
+            offset: iced::Vector::new(0.0, offset),
+            blur_radius: blur,
         }
-        Color::White => iced::Color::WHITE,
-        Color::Black => iced::Color::BLACK,
-        Color::Slate(shade) | Color::Gray(shade) | Color::Zinc(shade) | Color::Neutral(shade) => {
-            // Grayscale colors
-            let value = 1.0 - (*shade as f32 / 900.0);
-            iced::Color::from_rgb(value, value, value)
+    }
+
+    /// Whether any class needs a styled container to be visible
+    pub fn has_decoration(&self) -> bool {
+        self.background_color.is_some()
+            || self.text_color.is_some()
+            || self.border
+            || self.border_radius.is_some()
+            || self.shadow
+    }
+
+    /// Whether any box-model class (padding, size, decoration) is present
+    pub fn has_box(&self) -> bool {
+        self.padding().is_some()
+            || self.width.is_some()
+            || self.height.is_some()
+            || self.has_decoration()
+    }
+
+    /// Container appearance for background, border, radius and shadow
+    pub fn container_style(&self) -> iced::widget::container::Style {
+        iced::widget::container::Style {
+            text_color: self.text_color(),
+            background: self.background(),
+            border: self.border(),
+            shadow: self.shadow(),
+            ..Default::default()
         }
-        Color::Red(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            iced::Color::from_rgb(intensity, 0.0, 0.0)
+    }
+
+    /// Button appearance layered over the theme's style for the current status
+    pub fn button_style(&self, base: iced::widget::button::Style) -> iced::widget::button::Style {
+        iced::widget::button::Style {
+            background: self.background().or(base.background),
+            text_color: self.text_color().unwrap_or(base.text_color),
+            border: if self.border || self.border_radius.is_some() {
+                self.border()
+            } else {
+                base.border
+            },
+            shadow: if self.shadow { self.shadow() } else { base.shadow },
+            ..base
         }
-        Color::Blue(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            iced::Color::from_rgb(0.0, 0.0, intensity)
+    }
+
+    /// Text input appearance layered over the theme's style for the current status
+    pub fn text_input_style(
+        &self,
+        base: iced::widget::text_input::Style,
+    ) -> iced::widget::text_input::Style {
+        iced::widget::text_input::Style {
+            background: self.background().unwrap_or(base.background),
+            // Inputs always have a border, so classes only adjust its parts
+            border: iced::Border {
+                color: match self.border_color {
+                    Some(color) => self.faded(color),
+                    None if self.border => self.default_border_color,
+                    None => base.border.color,
+                },
+                width: self.border_width.unwrap_or(base.border.width),
+                radius: self.border_radius.map(Into::into).unwrap_or(base.border.radius),
+            },
+            value: self.text_color().unwrap_or(base.value),
+            ..base
         }
-        Color::Green(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            iced::Color::from_rgb(0.0, intensity, 0.0)
+    }
+
+    fn faded(&self, color: iced::Color) -> iced::Color {
+        match self.opacity {
+            Some(opacity) => iced::Color {
+                a: color.a * opacity,
+                ..color
+            },
+            None => color,
         }
-        Color::Yellow(shade) => {
-            let intensity = 1.0 - (*shade as f32 / 900.0);
-            iced::Color::from_rgb(intensity, intensity, 0.0)
+    }
+}
+
+/// Appearances of one Style in each widget status
+///
+/// Iced reports hover/press/focus through the `status` argument of a widget's
+/// style closure, so every `hover:`/`active:`/`focus:`/`disabled:` combination
+/// is converted up front and picked per status.
+pub struct IcedStateStyle {
+    pub base: IcedStyle,
+    pub hovered: IcedStyle,
+    pub pressed: IcedStyle,
+    pub focused: IcedStyle,
+    pub focused_hovered: IcedStyle,
+    pub disabled: IcedStyle,
+}
+
+impl IcedStateStyle {
+    /// Convert a Style using the active theme and window width
+    pub fn from_style(style: &Style) -> Self {
+        Self::from_style_with_theme(&style.at_width(WindowSize::current().width), &Theme::active())
+    }
+
+    /// Convert a Style, resolving tokens through `theme`
+    pub fn from_style_with_theme(style: &Style, theme: &Theme) -> Self {
+        use StyleState::*;
+        let convert = |states: &[StyleState]| {
+            IcedStyle::from_style_with_theme(&style.for_states(states), theme)
+        };
+
+        Self {
+            base: convert(&[]),
+            hovered: convert(&[Hover]),
+            pressed: convert(&[Hover, Active]),
+            focused: convert(&[Focus]),
+            focused_hovered: convert(&[Hover, Focus]),
+            disabled: convert(&[Disabled]),
         }
-        _ => iced::Color::from_rgb(0.5, 0.5, 0.5), // Default gray (semantic colors)
     }
+
+    /// Button appearance for `status`
+    pub fn button_style(
+        &self,
+        base: iced::widget::button::Style,
+        status: iced::widget::button::Status,
+    ) -> iced::widget::button::Style {
+        use iced::widget::button::Status;
+        let style = match status {
+            Status::Active => &self.base,
+            Status::Hovered => &self.hovered,
+            Status::Pressed => &self.pressed,
+            Status::Disabled => &self.disabled,
+        };
+        style.button_style(base)
+    }
+
+    /// Text input appearance for `status`
+    pub fn text_input_style(
+        &self,
+        base: iced::widget::text_input::Style,
+        status: iced::widget::text_input::Status,
+    ) -> iced::widget::text_input::Style {
+        use iced::widget::text_input::Status;
+        let style = match status {
+            Status::Active => &self.base,
+            Status::Hovered => &self.hovered,
+            Status::Focused { is_hovered: false } => &self.focused,
+            Status::Focused { is_hovered: true } => &self.focused_hovered,
+            Status::Disabled => &self.disabled,
+        };
+        style.text_input_style(base)
+    }
+}
+
+/// Native iced theme built from a unified Theme's semantic colors
+pub fn to_iced_theme(theme: &Theme) -> iced::Theme {
+    let color = |color: &Color| convert_color(color, theme);
+    iced::Theme::custom(
+        theme.name.clone(),
+        iced::theme::Palette {
+            background: color(&theme.colors.background),
+            text: color(&theme.colors.on_background),
+            primary: color(&theme.colors.primary),
+            success: color(&theme.colors.success),
+            warning: color(&theme.colors.warning),
+            danger: color(&theme.colors.error),
+        },
+    )
+}
+
+/// Convert a SizeValue to IcedSize
+///
+/// iced has no percentage lengths, so fractions become fill portions out of
+/// twelve. They match GPUI's relative sizes when the fractions of siblings
+/// add up to one (`w-1/3` next to `w-2/3`); a lone `w-1/2` fills its parent.
+fn convert_size(size: &SizeValue, theme: &Theme) -> IcedSize {
+    match size {
+        SizeValue::Full => IcedSize::Full,
+        SizeValue::Auto => IcedSize::Shrink,
+        SizeValue::Fixed(_) | SizeValue::Px(_) => IcedSize::Fixed(theme.spacing(size)),
+        SizeValue::Half => IcedSize::Portion(6),
+        SizeValue::Third => IcedSize::Portion(4),
+        SizeValue::TwoThirds => IcedSize::Portion(8),
+        SizeValue::Quarter => IcedSize::Portion(3),
+        SizeValue::ThreeQuarters => IcedSize::Portion(9),
+    }
+}
+
+/// Convert a Color to iced::Color, resolving semantic tokens through the theme
+fn convert_color(color: &Color, theme: &Theme) -> iced::Color {
+    let (r, g, b, a) = theme.rgba(color);
+    iced::Color::from_rgba(r, g, b, a)
 }
 
 #[cfg(test)]
@@ -477,9 +782,101 @@ mod tests {
 
     #[test]
     fn test_convert_color() {
-        let white = convert_color(&Color::White);
+        let white = convert_color(&Color::White, &Theme::light());
         assert_eq!(white.r, 1.0);
         assert_eq!(white.g, 1.0);
         assert_eq!(white.b, 1.0);
     }
+
+    #[test]
+    fn test_box_conversions() {
+        let style = Style::parse("px-2 py-1 w-64 h-full").unwrap();
+        let iced_style = IcedStyle::from_style(&style);
+
+        let padding = iced_style.padding().unwrap();
+        assert_eq!(padding.left, 8.0);
+        assert_eq!(padding.top, 4.0);
+        assert_eq!(iced_style.width_length(), Some(iced::Length::Fixed(256.0)));
+        assert_eq!(iced_style.height_length(), Some(iced::Length::Fill));
+        assert!(!iced_style.has_decoration());
+    }
+
+    #[test]
+    fn test_fraction_sizes() {
+        let portion = |classes: &str| IcedStyle::from_style(&Style::parse(classes).unwrap()).width_length();
+
+        assert_eq!(portion("w-1/2"), Some(iced::Length::FillPortion(6)));
+        assert_eq!(portion("w-1/3"), Some(iced::Length::FillPortion(4)));
+        assert_eq!(portion("w-2/3"), Some(iced::Length::FillPortion(8)));
+        assert_eq!(portion("w-1/4"), Some(iced::Length::FillPortion(3)));
+        assert_eq!(portion("w-3/4"), Some(iced::Length::FillPortion(9)));
+        assert_eq!(portion("w-auto"), Some(iced::Length::Shrink));
+
+        let height = IcedStyle::from_style(&Style::parse("h-1/2").unwrap()).height_length();
+        assert_eq!(height, Some(iced::Length::FillPortion(6)));
+    }
+
+    #[test]
+    fn test_container_style() {
+        let style = Style::parse("bg-white border rounded-lg shadow-md text-lg font-bold").unwrap();
+        let iced_style = IcedStyle::from_style(&style);
+        let container = iced_style.container_style();
+
+        assert_eq!(container.background, Some(iced::Background::Color(iced::Color::WHITE)));
+        assert_eq!(container.border.width, 1.0);
+        assert_eq!(container.border.radius, iced::border::Radius::from(8.0));
+        assert_eq!(container.shadow.blur_radius, 6.0);
+        assert_eq!(iced_style.text_size(), Some(18.0));
+        assert_eq!(iced_style.font().unwrap().weight, iced::font::Weight::Bold);
+    }
+
+    #[test]
+    fn test_state_styles_follow_status() {
+        use iced::widget::button::{self, Status};
+
+        let style = Style::parse("bg-white hover:bg-black active:bg-red-500 disabled:opacity-50").unwrap();
+        let states = IcedStateStyle::from_style_with_theme(&style, &Theme::light());
+        let theme = iced::Theme::Light;
+        let background = |status| states.button_style(button::primary(&theme, status), status).background;
+
+        assert_eq!(background(Status::Active), Some(iced::Background::Color(iced::Color::WHITE)));
+        assert_eq!(background(Status::Hovered), Some(iced::Background::Color(iced::Color::BLACK)));
+        assert_ne!(background(Status::Pressed), background(Status::Hovered));
+        assert_eq!(states.disabled.opacity, Some(0.5));
+    }
+
+    #[test]
+    fn test_breakpoint_visibility() {
+        let style = Style::parse("hidden md:block md:p-2").unwrap();
+        let narrow = IcedStyle::from_style_with_theme(&style.at_width(600.0), &Theme::light());
+        let wide = IcedStyle::from_style_with_theme(&style.at_width(900.0), &Theme::light());
+
+        assert!(narrow.hidden);
+        assert_eq!(narrow.padding, None);
+        assert!(!wide.hidden);
+        assert_eq!(wide.padding, Some(8.0));
+    }
+
+    #[test]
+    fn test_arbitrary_values() {
+        let style = Style::parse("w-[320px] p-[10px] text-[15px] rounded-[6px] bg-[#1e293b]").unwrap();
+        let iced_style = IcedStyle::from_style_with_theme(&style, &Theme::light());
+
+        assert_eq!(iced_style.width_length(), Some(iced::Length::Fixed(320.0)));
+        assert_eq!(iced_style.padding, Some(10.0));
+        assert_eq!(iced_style.text_size(), Some(15.0));
+        assert_eq!(iced_style.border().radius, iced::border::Radius::from(6.0));
+        assert_eq!(iced_style.background_color, Some(iced::Color::from_rgb8(0x1e, 0x29, 0x3b)));
+    }
+
+    #[test]
+    fn test_semantic_colors_follow_theme() {
+        let style = Style::parse("bg-background text-on-background").unwrap();
+        let light = IcedStyle::from_style_with_theme(&style, &Theme::light());
+        let dark = IcedStyle::from_style_with_theme(&style, &Theme::dark());
+
+        assert_eq!(light.background_color, Some(iced::Color::from_rgb8(0xf3, 0xf3, 0xf3)));
+        assert_eq!(dark.background_color, Some(iced::Color::from_rgb8(0x1e, 0x1e, 0x1e)));
+        assert_eq!(dark.text_color, Some(iced::Color::WHITE));
+    }
 }
@@ -3,13 +3,11 @@
 // This module provides a Tailwind CSS-inspired utility class system that works across
 // multiple backends (GPUI, Iced, etc.) through a unified intermediate representation.
 
-mod class;
-mod color;
-mod parser;
+mod theme;
 
-pub use class::{StyleClass, SizeValue};
-pub use color::Color;
-pub use parser::StyleParser;
+// The class grammar and `Style` live in auto-ui-style, shared with the style! macro
+pub use auto_ui_style::{Breakpoint, Color, SizeValue, Style, StyleClass, StyleError, StyleParser, StyleState};
+pub use theme::{Theme, ThemeColors, ThemeError, ThemeFontSizes, ThemeRadii};
 
 // Backend adapters (only compile when the respective backend is enabled)
 #[cfg(feature = "gpui")]
@@ -21,51 +19,23 @@ pub use gpui_adapter::GpuiStyle; // Re-export for backend adapters
 #[cfg(feature = "iced")]
 pub mod iced_adapter;
 
-/// Parsed style collection ready to be applied to backend-specific components
-#[derive(Debug, Clone, Default)]
-pub struct Style {
-    pub classes: Vec<StyleClass>,
-}
-
-impl Style {
-    /// Parse a style string into a Style collection
-    pub fn parse(input: &str) -> Result<Self, String> {
-        let parser = StyleParser::new();
-        let classes = parser.parse(input)?;
-        Ok(Self { classes })
-    }
-
-    /// Create an empty style
-    pub fn empty() -> Self {
-        Self::default()
-    }
-
-    /// Add a style class
-    pub fn add(mut self, class: StyleClass) -> Self {
-        self.classes.push(class);
-        self
-    }
-}
-
-impl From<&str> for Style {
-    fn from(input: &str) -> Self {
-        Self::parse(input).expect("Failed to parse style string")
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const CARD: Style = crate::style!("p-4 bg-white md:hover:bg-gray-100");
+
     #[test]
-    fn test_parse_simple() {
-        let style = Style::parse("p-4 gap-2 bg-white").unwrap();
-        assert_eq!(style.classes.len(), 3);
+    fn test_style_macro_matches_runtime_parse() {
+        let expanded = crate::style!("p-4 w-[320px] bg-teal-950 text-[#1e293b] md:hover:bg-white disabled:opacity-50");
+        let parsed = Style::parse("p-4 w-[320px] bg-teal-950 text-[#1e293b] md:hover:bg-white disabled:opacity-50");
+
+        assert_eq!(expanded, parsed.unwrap());
     }
 
     #[test]
-    fn test_from_str() {
-        let style: Style = "flex items-center".into();
-        assert_eq!(style.classes.len(), 2);
+    fn test_style_macro_in_const() {
+        assert_eq!(CARD, Style::parse("p-4 bg-white md:hover:bg-gray-100").unwrap());
+        assert!(CARD.is_responsive());
     }
 }
@@ -0,0 +1,371 @@
+// Theme - concrete values for semantic style tokens
+//
+// Semantic colors (`bg-primary`, `text-on-surface`), border radii, the spacing
+// scale and font sizes are resolved through a Theme. Backend adapters read the
+// active theme when converting a Style, so switching it (e.g. light → dark)
+// restyles every view without touching style strings.
+//
+// Themes ship as presets (`light`, `dark`) or load from JSON/TOML files.
+// Every field is optional in a file; missing ones fall back to the light preset.
+
+use super::{Color, SizeValue};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
+use thiserror::Error;
+
+/// Errors raised while loading a theme
+#[derive(Debug, Error)]
+pub enum ThemeError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid JSON theme: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid TOML theme: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Unknown theme: {0}")]
+    Unknown(String),
+}
+
+/// Concrete values for semantic tokens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    /// Whether this is a dark theme (backends pick matching native widget styles)
+    pub dark: bool,
+    pub colors: ThemeColors,
+    pub radii: ThemeRadii,
+    /// Pixels per Tailwind spacing unit (`p-4` = 4 units)
+    pub spacing_unit: f32,
+    pub font_sizes: ThemeFontSizes,
+}
+
+/// Semantic color palette
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeColors {
+    pub primary: Color,
+    pub secondary: Color,
+    pub background: Color,
+    pub surface: Color,
+    pub error: Color,
+    pub warning: Color,
+    pub success: Color,
+    pub info: Color,
+    pub on_primary: Color,
+    pub on_secondary: Color,
+    pub on_background: Color,
+    pub on_surface: Color,
+    /// Color of `border` without an explicit border color
+    pub border: Color,
+}
+
+/// Border radii for `rounded-*` classes, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeRadii {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+    #[serde(rename = "2xl")]
+    pub xxl: f32,
+    #[serde(rename = "3xl")]
+    pub xxxl: f32,
+    pub full: f32,
+}
+
+/// Font sizes for `text-*` classes, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeFontSizes {
+    pub xs: f32,
+    pub sm: f32,
+    pub base: f32,
+    pub lg: f32,
+    pub xl: f32,
+    #[serde(rename = "2xl")]
+    pub xxl: f32,
+    #[serde(rename = "3xl")]
+    pub xxxl: f32,
+}
+
+const fn rgb(hex: u32) -> Color {
+    Color::Rgb {
+        r: (hex >> 16) as u8,
+        g: (hex >> 8) as u8,
+        b: hex as u8,
+    }
+}
+
+impl Default for ThemeColors {
+    fn default() -> Self {
+        Theme::light().colors
+    }
+}
+
+impl Default for ThemeRadii {
+    fn default() -> Self {
+        Self {
+            sm: 2.0,
+            md: 4.0,
+            lg: 8.0,
+            xl: 12.0,
+            xxl: 16.0,
+            xxxl: 24.0,
+            full: 9999.0,
+        }
+    }
+}
+
+impl Default for ThemeFontSizes {
+    fn default() -> Self {
+        Self {
+            xs: 12.0,
+            sm: 14.0,
+            base: 16.0,
+            lg: 18.0,
+            xl: 20.0,
+            xxl: 24.0,
+            xxxl: 30.0,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme {
+    /// Light preset (also used for the `"default"` theme name)
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            dark: false,
+            colors: ThemeColors {
+                primary: rgb(0x3b82f6),
+                secondary: rgb(0x64748b),
+                background: rgb(0xf3f3f3),
+                surface: rgb(0xffffff),
+                error: rgb(0xef4444),
+                warning: rgb(0xf59e0b),
+                success: rgb(0x22c55e),
+                info: rgb(0x0ea5e9),
+                on_primary: rgb(0xffffff),
+                on_secondary: rgb(0xffffff),
+                on_background: rgb(0x000000),
+                on_surface: rgb(0x0f172a),
+                border: rgb(0xe5e7eb),
+            },
+            radii: ThemeRadii::default(),
+            spacing_unit: 4.0,
+            font_sizes: ThemeFontSizes::default(),
+        }
+    }
+
+    /// Dark preset
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            dark: true,
+            colors: ThemeColors {
+                primary: rgb(0x60a5fa),
+                secondary: rgb(0x94a3b8),
+                background: rgb(0x1e1e1e),
+                surface: rgb(0x27272a),
+                error: rgb(0xf87171),
+                warning: rgb(0xfbbf24),
+                success: rgb(0x4ade80),
+                info: rgb(0x38bdf8),
+                on_primary: rgb(0x0f172a),
+                on_secondary: rgb(0x0f172a),
+                on_background: rgb(0xffffff),
+                on_surface: rgb(0xf4f4f5),
+                border: rgb(0x3f3f46),
+            },
+            radii: ThemeRadii::default(),
+            spacing_unit: 4.0,
+            font_sizes: ThemeFontSizes::default(),
+        }
+    }
+
+    /// Look up a preset by name
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" | "light" => Some(Self::light()),
+            "dark" => Some(Self::dark()),
+            _ => None,
+        }
+    }
+
+    /// Parse a theme from JSON
+    pub fn from_json(source: &str) -> Result<Self, ThemeError> {
+        Ok(serde_json::from_str(source)?)
+    }
+
+    /// Parse a theme from TOML
+    pub fn from_toml(source: &str) -> Result<Self, ThemeError> {
+        Ok(toml::from_str(source)?)
+    }
+
+    /// Load a theme file, choosing the format by extension (`.toml`, otherwise JSON)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            _ => Self::from_json(&source),
+        }
+    }
+
+    /// Resolve the value of an App's `theme` field
+    ///
+    /// Accepts a preset name or a path to a `.json`/`.toml` theme file.
+    /// Relative paths are looked up next to `base_dir` (usually the .at file's folder).
+    pub fn select(spec: &str, base_dir: Option<&Path>) -> Result<Self, ThemeError> {
+        if let Some(theme) = Self::preset(spec) {
+            return Ok(theme);
+        }
+
+        let path = Path::new(spec);
+        if !matches!(path.extension().and_then(|ext| ext.to_str()), Some("json" | "toml")) {
+            return Err(ThemeError::Unknown(spec.to_string()));
+        }
+
+        match base_dir {
+            Some(dir) if path.is_relative() => Self::load(dir.join(path)),
+            _ => Self::load(path),
+        }
+    }
+
+    /// Replace a semantic token with this theme's concrete color
+    ///
+    /// Palette and custom colors are returned unchanged.
+    pub fn resolve(&self, color: Color) -> Color {
+        let colors = &self.colors;
+        match color {
+            Color::Primary => colors.primary,
+            Color::Secondary => colors.secondary,
+            Color::Background => colors.background,
+            Color::Surface => colors.surface,
+            Color::Error => colors.error,
+            Color::Warning => colors.warning,
+            Color::Success => colors.success,
+            Color::Info => colors.info,
+            Color::OnPrimary => colors.on_primary,
+            Color::OnSecondary => colors.on_secondary,
+            Color::OnBackground => colors.on_background,
+            Color::OnSurface => colors.on_surface,
+            other => other,
+        }
+    }
+
+    /// Normalized RGBA of a color under this theme
+    pub fn rgba(&self, color: &Color) -> (f32, f32, f32, f32) {
+        self.resolve(*color).to_rgba_normalized()
+    }
+
+    /// Pixel value of a spacing size (`p-4`, `gap-2`, `w-64`)
+    ///
+    /// Arbitrary values (`w-[320px]`) are exact and ignore the spacing unit.
+    pub fn spacing(&self, size: &SizeValue) -> f32 {
+        match size {
+            SizeValue::Fixed(units) => *units as f32 * self.spacing_unit,
+            SizeValue::Px(px) => *px as f32,
+            _ => 0.0,
+        }
+    }
+
+    /// The active theme used by the style adapters
+    pub fn active() -> Arc<Theme> {
+        active_slot()
+            .read()
+            .map(|theme| theme.clone())
+            .unwrap_or_else(|poisoned| poisoned.into_inner().clone())
+    }
+
+    /// Switch the active theme
+    ///
+    /// Views pick it up on their next render; components usually do this by
+    /// returning [`Command::set_theme`](crate::Command::set_theme) from `update`.
+    pub fn set_active(theme: Theme) {
+        let theme = Arc::new(theme);
+        match active_slot().write() {
+            Ok(mut active) => *active = theme,
+            Err(poisoned) => *poisoned.into_inner() = theme,
+        }
+    }
+}
+
+fn active_slot() -> &'static RwLock<Arc<Theme>> {
+    static ACTIVE: OnceLock<RwLock<Arc<Theme>>> = OnceLock::new();
+    ACTIVE.get_or_init(|| RwLock::new(Arc::new(Theme::light())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_resolve_semantic_colors() {
+        let light = Theme::light();
+        let dark = Theme::dark();
+
+        assert_eq!(light.resolve(Color::Background), rgb(0xf3f3f3));
+        assert_eq!(dark.resolve(Color::Background), rgb(0x1e1e1e));
+        assert_eq!(dark.resolve(Color::Blue(500)), Color::Blue(500));
+        assert!(Theme::preset("default").is_some());
+        assert!(Theme::preset("solarized").is_none());
+    }
+
+    #[test]
+    fn test_partial_json_falls_back_to_light() {
+        let theme = Theme::from_json(
+            r##"{ "name": "brand", "colors": { "primary": "#ff0000" }, "radii": { "2xl": 20 } }"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.name, "brand");
+        assert_eq!(theme.colors.primary, Color::Rgb { r: 255, g: 0, b: 0 });
+        assert_eq!(theme.colors.surface, Theme::light().colors.surface);
+        assert_eq!(theme.radii.xxl, 20.0);
+        assert_eq!(theme.spacing_unit, 4.0);
+    }
+
+    #[test]
+    fn test_toml_theme() {
+        let theme = Theme::from_toml(
+            r##"
+            name = "midnight"
+            dark = true
+            spacing_unit = 5.0
+
+            [colors]
+            background = "#000000"
+            primary = "blue-500"
+
+            [font_sizes]
+            base = 15.0
+            "##,
+        )
+        .unwrap();
+
+        assert!(theme.dark);
+        assert_eq!(theme.colors.primary, Color::Blue(500));
+        assert_eq!(theme.font_sizes.base, 15.0);
+        assert_eq!(theme.spacing(&SizeValue::Fixed(2)), 10.0);
+        assert_eq!(theme.spacing(&SizeValue::Px(6)), 6.0);
+    }
+
+    #[test]
+    fn test_select_rejects_unknown_names() {
+        assert!(matches!(Theme::select("neon", None), Err(ThemeError::Unknown(_))));
+        assert_eq!(Theme::select("dark", None).unwrap(), Theme::dark());
+    }
+}
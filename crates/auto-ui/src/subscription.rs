@@ -0,0 +1,483 @@
+// Subscription abstraction - events that don't come from a widget
+//
+// A Subscription is returned from `Component::subscription` and declares which
+// external event sources the component listens to: timers, global key presses,
+// window resizes, window close requests and file changes.
+//
+// Backends only need to produce `SubscriptionEvent`s for the declared sources;
+// `Subscription::dispatch` turns them back into component messages. Sources are
+// identified by their position in `sources()`, so backends can keep timers and
+// watchers alive across updates as long as the declared sources don't change.
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::cell::Cell;
+use std::fmt::{self, Debug};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+type Mapper<T, M> = Arc<dyn Fn(T) -> Option<M> + Send + Sync>;
+
+/// Keyboard modifiers held during a key press
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// Command on macOS, Windows key elsewhere
+    pub logo: bool,
+}
+
+/// A global key press
+///
+/// Key names are lowercase and backend-independent: `"a"`, `"1"`, `"enter"`,
+/// `"escape"`, `"space"`, `"tab"`, `"backspace"`, `"delete"`, `"up"`, `"down"`,
+/// `"left"`, `"right"`, `"home"`, `"end"`, `"pageup"`, `"pagedown"`, `"f1"`…
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub key: String,
+    pub modifiers: Modifiers,
+}
+
+impl KeyEvent {
+    pub fn new(key: impl Into<String>, modifiers: Modifiers) -> Self {
+        Self {
+            key: key.into(),
+            modifiers,
+        }
+    }
+}
+
+/// Logical size of the application window
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl WindowSize {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+
+    /// Size of the window currently being rendered
+    ///
+    /// Backends keep this up to date on resize, so `Component::view` can
+    /// branch on it directly (e.g. swap a Sidebar for a NavigationRail) and
+    /// `sm:`/`md:`/`lg:`/`xl:` style classes resolve against it.
+    pub fn current() -> WindowSize {
+        CURRENT_WINDOW_SIZE.with(Cell::get)
+    }
+
+    /// Record the window size; called by backends, or by tests to simulate a resize
+    ///
+    /// Rendering happens on the UI thread, so the size is tracked per thread.
+    pub fn set_current(size: WindowSize) {
+        CURRENT_WINDOW_SIZE.with(|current| current.set(size));
+    }
+}
+
+thread_local! {
+    // Until a backend reports the real size, assume iced's default window
+    static CURRENT_WINDOW_SIZE: Cell<WindowSize> = const { Cell::new(WindowSize { width: 1024.0, height: 768.0 }) };
+}
+
+/// Raw event produced by a backend for a declared source
+#[derive(Clone, Debug)]
+pub enum SubscriptionEvent {
+    /// Timer at `sources()[index]` fired
+    Tick { index: usize, at: Instant },
+    /// A key was pressed anywhere in the window
+    Key(KeyEvent),
+    /// The window was resized
+    WindowResized(WindowSize),
+    /// The user asked to close the window
+    WindowCloseRequested,
+    /// File watched by `sources()[index]` changed
+    FileChanged { index: usize, path: PathBuf },
+}
+
+/// Identity of a source, used by backends to decide whether to restart it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceKey {
+    Every(Duration),
+    Keyboard,
+    WindowResized,
+    WindowCloseRequested,
+    FileChanged(PathBuf),
+}
+
+/// A single event source together with its message mapping
+pub enum Source<M> {
+    Every { interval: Duration, map: Mapper<Instant, M> },
+    Keyboard { map: Mapper<KeyEvent, M> },
+    WindowResized { map: Mapper<WindowSize, M> },
+    WindowCloseRequested { map: Mapper<(), M> },
+    FileChanged { path: PathBuf, map: Mapper<PathBuf, M> },
+}
+
+impl<M: 'static> Source<M> {
+    /// Get the identity of this source
+    pub fn key(&self) -> SourceKey {
+        match self {
+            Source::Every { interval, .. } => SourceKey::Every(*interval),
+            Source::Keyboard { .. } => SourceKey::Keyboard,
+            Source::WindowResized { .. } => SourceKey::WindowResized,
+            Source::WindowCloseRequested { .. } => SourceKey::WindowCloseRequested,
+            Source::FileChanged { path, .. } => SourceKey::FileChanged(path.clone()),
+        }
+    }
+
+    fn filter_map<N: 'static>(self, f: Arc<dyn Fn(M) -> Option<N> + Send + Sync>) -> Source<N> {
+        match self {
+            Source::Every { interval, map } => Source::Every {
+                interval,
+                map: compose(map, f),
+            },
+            Source::Keyboard { map } => Source::Keyboard { map: compose(map, f) },
+            Source::WindowResized { map } => Source::WindowResized { map: compose(map, f) },
+            Source::WindowCloseRequested { map } => Source::WindowCloseRequested { map: compose(map, f) },
+            Source::FileChanged { path, map } => Source::FileChanged {
+                path,
+                map: compose(map, f),
+            },
+        }
+    }
+}
+
+fn compose<T: 'static, M: 'static, N: 'static>(
+    inner: Mapper<T, M>,
+    outer: Arc<dyn Fn(M) -> Option<N> + Send + Sync>,
+) -> Mapper<T, N> {
+    Arc::new(move |value| inner(value).and_then(&*outer))
+}
+
+impl<M> Debug for Source<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Every { interval, .. } => write!(f, "Every({:?})", interval),
+            Source::Keyboard { .. } => write!(f, "Keyboard"),
+            Source::WindowResized { .. } => write!(f, "WindowResized"),
+            Source::WindowCloseRequested { .. } => write!(f, "WindowCloseRequested"),
+            Source::FileChanged { path, .. } => write!(f, "FileChanged({:?})", path),
+        }
+    }
+}
+
+/// Set of external event sources a component listens to
+///
+/// # Example
+/// ```ignore
+/// use auto_ui::subscription::{self, Subscription};
+///
+/// fn subscription(&self) -> Subscription<Msg> {
+///     Subscription::batch([
+///         subscription::every(Duration::from_secs(1)).map(|_| Msg::Tick),
+///         subscription::keyboard().filter_map(|key| match key.key.as_str() {
+///             "escape" => Some(Msg::Cancel),
+///             _ => None,
+///         }),
+///     ])
+/// }
+/// ```
+pub struct Subscription<M> {
+    sources: Vec<Source<M>>,
+}
+
+impl<M: 'static> Subscription<M> {
+    /// Create a subscription that listens to nothing
+    pub fn none() -> Self {
+        Self { sources: Vec::new() }
+    }
+
+    /// Combine several subscriptions into one
+    pub fn batch(subscriptions: impl IntoIterator<Item = Subscription<M>>) -> Self {
+        Self {
+            sources: subscriptions
+                .into_iter()
+                .flat_map(|subscription| subscription.sources)
+                .collect(),
+        }
+    }
+
+    /// Transform every produced message
+    pub fn map<N: 'static>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> Subscription<N> {
+        self.filter_map(move |msg| Some(f(msg)))
+    }
+
+    /// Transform produced messages, dropping those mapped to `None`
+    pub fn filter_map<N: 'static>(
+        self,
+        f: impl Fn(M) -> Option<N> + Send + Sync + 'static,
+    ) -> Subscription<N> {
+        let f: Arc<dyn Fn(M) -> Option<N> + Send + Sync> = Arc::new(f);
+        Subscription {
+            sources: self
+                .sources
+                .into_iter()
+                .map(|source| source.filter_map(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Get the declared sources
+    pub fn sources(&self) -> &[Source<M>] {
+        &self.sources
+    }
+
+    /// Get the identities of the declared sources, in order
+    pub fn keys(&self) -> Vec<SourceKey> {
+        self.sources.iter().map(Source::key).collect()
+    }
+
+    /// Check whether this subscription has no sources
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Check whether the component handles close requests itself
+    ///
+    /// Backends keep the window open on a close request while this holds; the
+    /// component closes it by returning `Command::exit`.
+    pub fn handles_close_requests(&self) -> bool {
+        self.sources
+            .iter()
+            .any(|source| matches!(source, Source::WindowCloseRequested { .. }))
+    }
+
+    /// Turn a backend event into the messages it produces
+    pub fn dispatch(&self, event: SubscriptionEvent) -> Vec<M> {
+        match event {
+            SubscriptionEvent::Tick { index, at } => match self.sources.get(index) {
+                Some(Source::Every { map, .. }) => map(at).into_iter().collect(),
+                _ => Vec::new(),
+            },
+            SubscriptionEvent::FileChanged { index, path } => match self.sources.get(index) {
+                Some(Source::FileChanged { map, .. }) => map(path).into_iter().collect(),
+                _ => Vec::new(),
+            },
+            SubscriptionEvent::Key(key) => self
+                .sources
+                .iter()
+                .filter_map(|source| match source {
+                    Source::Keyboard { map } => map(key.clone()),
+                    _ => None,
+                })
+                .collect(),
+            SubscriptionEvent::WindowResized(size) => self
+                .sources
+                .iter()
+                .filter_map(|source| match source {
+                    Source::WindowResized { map } => map(size),
+                    _ => None,
+                })
+                .collect(),
+            SubscriptionEvent::WindowCloseRequested => self
+                .sources
+                .iter()
+                .filter_map(|source| match source {
+                    Source::WindowCloseRequested { map } => map(()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<M: 'static> Default for Subscription<M> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<M> Debug for Subscription<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Subscription").field(&self.sources).finish()
+    }
+}
+
+// ============================================================================
+// Built-in sources
+// ============================================================================
+
+/// Fire periodically with the current instant
+pub fn every(interval: Duration) -> Subscription<Instant> {
+    Subscription {
+        sources: vec![Source::Every {
+            interval,
+            map: Arc::new(Some::<Instant>),
+        }],
+    }
+}
+
+/// Receive every key press in the window
+pub fn keyboard() -> Subscription<KeyEvent> {
+    Subscription {
+        sources: vec![Source::Keyboard { map: Arc::new(Some::<KeyEvent>) }],
+    }
+}
+
+/// Receive the new window size whenever the window is resized
+pub fn window_resized() -> Subscription<WindowSize> {
+    Subscription {
+        sources: vec![Source::WindowResized { map: Arc::new(Some::<WindowSize>) }],
+    }
+}
+
+/// Receive the user's requests to close the window
+///
+/// While subscribed the window stays open when the user tries to close it,
+/// e.g. to ask about unsaved changes; return `Command::exit` to close it.
+pub fn window_close_requested() -> Subscription<()> {
+    Subscription {
+        sources: vec![Source::WindowCloseRequested { map: Arc::new(Some::<()>) }],
+    }
+}
+
+/// Receive the path whenever the file at `path` is modified
+pub fn file_changed(path: impl Into<PathBuf>) -> Subscription<PathBuf> {
+    Subscription {
+        sources: vec![Source::FileChanged {
+            path: path.into(),
+            map: Arc::new(Some::<PathBuf>),
+        }],
+    }
+}
+
+/// Watch a file for `file_changed` sources
+///
+/// Shared by the backends: the returned receiver is runtime-agnostic and yields
+/// the watched path on every create or modify event. Dropping the watcher stops
+/// the notifications.
+pub fn watch_path(path: &Path) -> notify::Result<(RecommendedWatcher, UnboundedReceiver<PathBuf>)> {
+    let (tx, rx) = unbounded_channel();
+    let watched = path.to_path_buf();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            if event.kind.is_modify() || event.kind.is_create() {
+                let _ = tx.send(watched.clone());
+            }
+        }
+    })?;
+    watcher.watch(path, RecursiveMode::NonRecursive)?;
+
+    Ok((watcher, rx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestMsg {
+        Tick,
+        Escape,
+        Resized(f32, f32),
+        Reload(PathBuf),
+        Close,
+    }
+
+    fn test_subscription() -> Subscription<TestMsg> {
+        Subscription::batch([
+            every(Duration::from_millis(100)).map(|_| TestMsg::Tick),
+            keyboard().filter_map(|key| match key.key.as_str() {
+                "escape" => Some(TestMsg::Escape),
+                _ => None,
+            }),
+            window_resized().map(|size| TestMsg::Resized(size.width, size.height)),
+            file_changed("ui.at").map(TestMsg::Reload),
+            window_close_requested().map(|_| TestMsg::Close),
+        ])
+    }
+
+    #[test]
+    fn test_keys() {
+        let subscription = test_subscription();
+        assert_eq!(
+            subscription.keys(),
+            vec![
+                SourceKey::Every(Duration::from_millis(100)),
+                SourceKey::Keyboard,
+                SourceKey::WindowResized,
+                SourceKey::FileChanged(PathBuf::from("ui.at")),
+                SourceKey::WindowCloseRequested,
+            ]
+        );
+        assert!(Subscription::<TestMsg>::none().is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_close_request() {
+        let subscription = test_subscription();
+        assert!(subscription.handles_close_requests());
+        assert_eq!(
+            subscription.dispatch(SubscriptionEvent::WindowCloseRequested),
+            vec![TestMsg::Close]
+        );
+
+        let ticks = every(Duration::from_secs(1)).map(|_| TestMsg::Tick);
+        assert!(!ticks.handles_close_requests());
+        assert!(ticks.dispatch(SubscriptionEvent::WindowCloseRequested).is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_tick() {
+        let subscription = test_subscription();
+        let msgs = subscription.dispatch(SubscriptionEvent::Tick {
+            index: 0,
+            at: Instant::now(),
+        });
+        assert_eq!(msgs, vec![TestMsg::Tick]);
+
+        // Index pointing at a non-timer source produces nothing
+        let msgs = subscription.dispatch(SubscriptionEvent::Tick {
+            index: 1,
+            at: Instant::now(),
+        });
+        assert!(msgs.is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_keyboard_filter() {
+        let subscription = test_subscription();
+
+        let msgs = subscription.dispatch(SubscriptionEvent::Key(KeyEvent::new(
+            "escape",
+            Modifiers::default(),
+        )));
+        assert_eq!(msgs, vec![TestMsg::Escape]);
+
+        let msgs = subscription.dispatch(SubscriptionEvent::Key(KeyEvent::new(
+            "a",
+            Modifiers::default(),
+        )));
+        assert!(msgs.is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_resize_and_file() {
+        let subscription = test_subscription();
+
+        let msgs = subscription.dispatch(SubscriptionEvent::WindowResized(WindowSize::new(
+            1024.0, 768.0,
+        )));
+        assert_eq!(msgs, vec![TestMsg::Resized(1024.0, 768.0)]);
+
+        let msgs = subscription.dispatch(SubscriptionEvent::FileChanged {
+            index: 3,
+            path: PathBuf::from("ui.at"),
+        });
+        assert_eq!(msgs, vec![TestMsg::Reload(PathBuf::from("ui.at"))]);
+    }
+
+    #[test]
+    fn test_current_window_size_is_per_thread() {
+        WindowSize::set_current(WindowSize::new(400.0, 800.0));
+        assert_eq!(WindowSize::current(), WindowSize::new(400.0, 800.0));
+
+        let other = std::thread::spawn(WindowSize::current).join().unwrap();
+        assert_eq!(other, WindowSize::new(1024.0, 768.0));
+    }
+}
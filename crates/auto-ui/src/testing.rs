@@ -0,0 +1,661 @@
+// Headless test harness - drive Components without a window
+//
+// TestHarness renders a Component to its abstract View tree and simulates user
+// interaction by finding widgets by their visible text and dispatching the
+// messages stored on them (onclick, on_toggle, SelectCallback, ...) into
+// `Component::update`. The view is re-rendered after every message, so
+// assertions always see the current tree.
+//
+// No backend is involved: iced and GPUI only ever receive the same View tree.
+// Views hidden by a `hidden` class at the current window width are skipped,
+// just like the backends skip them. Widget positions come from the shared
+// layout pass (`layout`, `rect_of`), computed at the current window size.
+
+use crate::command::Command;
+use crate::component::Component;
+use crate::layout::{compute_layout, LayoutResult, Rect};
+use crate::subscription::WindowSize;
+use crate::view::View;
+use crate::vnode::{VNode, VNodeProps, VTree};
+use crate::vnode_converter::view_to_vtree;
+use std::fmt::{Debug, Write};
+use thiserror::Error;
+
+/// Errors raised when a simulated interaction can't find its target
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum HarnessError {
+    #[error("no {kind} labelled {label:?} in the view")]
+    NotFound { kind: &'static str, label: String },
+
+    #[error("{kind} {label:?} has no handler")]
+    NoHandler { kind: &'static str, label: String },
+
+    #[error("{kind} {label:?} is disabled")]
+    Disabled { kind: &'static str, label: String },
+}
+
+/// Drives a Component headlessly for unit tests
+///
+/// # Example
+/// ```ignore
+/// let mut harness = TestHarness::new(Counter::default());
+///
+/// harness.click("+")?;
+/// harness.click("+")?;
+///
+/// assert!(harness.has_text("Count: 2"));
+/// assert_eq!(harness.component().count, 2);
+/// ```
+pub struct TestHarness<C: Component> {
+    component: C,
+    view: View<C::Msg>,
+    commands: Vec<Command<C::Msg>>,
+}
+
+impl<C: Component> TestHarness<C> {
+    /// Wrap a component and render its initial view
+    pub fn new(component: C) -> Self {
+        let view = component.view();
+        Self {
+            component,
+            view,
+            commands: Vec::new(),
+        }
+    }
+
+    /// The component under test
+    pub fn component(&self) -> &C {
+        &self.component
+    }
+
+    /// Mutate the component directly, then re-render
+    pub fn with_component(&mut self, f: impl FnOnce(&mut C)) {
+        f(&mut self.component);
+        self.render();
+    }
+
+    /// Simulate a window resize, then re-render
+    ///
+    /// Updates `WindowSize::current` for this thread, so both responsive
+    /// classes and size checks in `Component::view` see the new size.
+    pub fn resize(&mut self, width: f32, height: f32) {
+        WindowSize::set_current(WindowSize::new(width, height));
+        self.render();
+    }
+
+    /// The view as of the last render
+    pub fn view(&self) -> &View<C::Msg> {
+        &self.view
+    }
+
+    /// Consume the harness and return the component
+    pub fn into_component(self) -> C {
+        self.component
+    }
+
+    /// Deliver a message as if the runtime had, then re-render
+    ///
+    /// Commands returned from `update` are not executed; they are queued and
+    /// can be inspected with `take_commands`.
+    pub fn send(&mut self, msg: C::Msg) {
+        let command = self.component.update(msg);
+        if !matches!(command, Command::None) {
+            self.commands.push(command);
+        }
+        self.render();
+    }
+
+    /// Commands returned by `update` since the last call
+    pub fn take_commands(&mut self) -> Vec<Command<C::Msg>> {
+        std::mem::take(&mut self.commands)
+    }
+
+    /// Click a button, radio, tab, navigation rail item or accordion header
+    pub fn click(&mut self, label: &str) -> Result<(), HarnessError> {
+        self.ensure_enabled(label)?;
+        let found = find_map(&self.view, &mut |view| match view {
+            View::Button { label: l, onclick, .. } if l == label => {
+                Some(("button", Some(onclick.clone())))
+            }
+            View::Radio { label: l, on_select, .. } if l == label => {
+                Some(("radio", on_select.clone()))
+            }
+            View::Tabs { labels, on_select, .. } => {
+                let index = labels.iter().position(|l| l == label)?;
+                Some(("tab", on_select.as_ref().map(|cb| cb.call(index))))
+            }
+            View::NavigationRail { items, on_select, .. } => {
+                let index = items.iter().position(|item| item.label == label)?;
+                Some(("navigation item", on_select.as_ref().map(|cb| cb.call(index))))
+            }
+            View::Accordion { items, on_toggle, .. } => {
+                let index = items.iter().position(|item| item.title == label)?;
+                let expanded = !items[index].expanded;
+                Some(("accordion item", on_toggle.as_ref().map(|cb| cb.call(index, expanded))))
+            }
+            _ => None,
+        });
+
+        self.dispatch(found, "clickable", label)
+    }
+
+    /// Replace the text of the input with the given placeholder
+    pub fn type_text(&mut self, placeholder: &str, text: &str) -> Result<(), HarnessError> {
+        self.ensure_enabled(placeholder)?;
+        let found = find_map(&self.view, &mut |view| match view {
+            View::Input { placeholder: p, on_change, .. } if p == placeholder => {
+                Some(("input", on_change.as_ref().map(|cb| cb.call(text))))
+            }
+            _ => None,
+        });
+
+        self.dispatch(found, "input", placeholder)
+    }
+
+    /// Toggle the checkbox with the given label
+    pub fn toggle(&mut self, label: &str) -> Result<(), HarnessError> {
+        let found = find_map(&self.view, &mut |view| match view {
+            View::Checkbox { label: l, on_toggle, .. } if l == label => {
+                Some(("checkbox", on_toggle.clone()))
+            }
+            _ => None,
+        });
+
+        self.dispatch(found, "checkbox", label)
+    }
+
+    /// Pick an option from the first select or radio group offering it
+    pub fn select(&mut self, option: &str) -> Result<(), HarnessError> {
+        let found = find_map(&self.view, &mut |view| match view {
+            View::Select { options, on_select, .. } => {
+                let index = options.iter().position(|o| o == option)?;
+                Some(("select option", on_select.as_ref().map(|cb| cb.call(index, option))))
+            }
+            View::RadioGroup { options, on_select, .. } => {
+                let index = options.iter().position(|o| o == option)?;
+                Some(("radio option", on_select.as_ref().map(|cb| cb.call(index, option))))
+            }
+            _ => None,
+        });
+
+        self.dispatch(found, "option", option)
+    }
+
+    /// Move the nth slider (in tree order) to `value`
+    pub fn slide(&mut self, nth: usize, value: f32) -> Result<(), HarnessError> {
+        let mut seen = 0;
+        let found = find_map(&self.view, &mut |view| match view {
+            View::Slider { on_change, .. } => {
+                seen += 1;
+                (seen == nth + 1).then(|| ("slider", Some(on_change.call(value))))
+            }
+            _ => None,
+        });
+
+        self.dispatch(found, "slider", &nth.to_string())
+    }
+
+    /// All visible text in tree order: text views plus widget labels
+    pub fn texts(&self) -> Vec<String> {
+        let mut texts = Vec::new();
+        visit(&self.view, &mut |view| match view {
+            View::Text { content, .. } => texts.push(content.clone()),
+            View::Button { label, .. }
+            | View::Checkbox { label, .. }
+            | View::Radio { label, .. } => texts.push(label.clone()),
+            _ => {}
+        });
+        texts
+    }
+
+    /// Whether some text view or label reads exactly `text`
+    pub fn has_text(&self, text: &str) -> bool {
+        self.texts().iter().any(|t| t == text)
+    }
+
+    /// Current value of the input with the given placeholder
+    pub fn input_value(&self, placeholder: &str) -> Option<String> {
+        let mut value = None;
+        visit(&self.view, &mut |view| {
+            if let View::Input { placeholder: p, value: v, .. } = view {
+                if p == placeholder && value.is_none() {
+                    value = Some(v.clone());
+                }
+            }
+        });
+        value
+    }
+
+    /// Indented text rendering of the current view, for snapshot assertions
+    pub fn dump(&self) -> String {
+        dump_view(&self.view)
+    }
+
+    /// The current view as a VTree; its ids are the ones `layout` reports
+    pub fn vtree(&self) -> VTree {
+        view_to_vtree(self.view.clone())
+    }
+
+    /// Layout of the current view in a window of `WindowSize::current()`
+    pub fn layout(&self) -> LayoutResult {
+        compute_layout(&self.vtree(), WindowSize::current())
+    }
+
+    /// Where the first rendered text, label or input placeholder reading
+    /// `label` ends up on screen
+    pub fn rect_of(&self, label: &str) -> Option<Rect> {
+        let tree = self.vtree();
+        let layout = compute_layout(&tree, WindowSize::current());
+        let found = layout
+            .iter()
+            .find(|(id, _)| tree.get(*id).and_then(node_text) == Some(label));
+        found.map(|(_, rect)| rect)
+    }
+
+    fn render(&mut self) {
+        self.view = self.component.view();
+    }
+
+    /// Disabled buttons and inputs swallow interaction, like they do on screen
+    fn ensure_enabled(&self, label: &str) -> Result<(), HarnessError> {
+        let disabled = find_map(&self.view, &mut |view| match view {
+            View::Button { label: l, disabled: true, .. } if l == label => Some("button"),
+            View::Input { placeholder: p, disabled: true, .. } if p == label => Some("input"),
+            _ => None,
+        });
+
+        match disabled {
+            Some(kind) => Err(HarnessError::Disabled {
+                kind,
+                label: label.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn dispatch(
+        &mut self,
+        found: Option<(&'static str, Option<C::Msg>)>,
+        kind: &'static str,
+        label: &str,
+    ) -> Result<(), HarnessError> {
+        match found {
+            Some((_, Some(msg))) => {
+                self.send(msg);
+                Ok(())
+            }
+            Some((kind, None)) => Err(HarnessError::NoHandler {
+                kind,
+                label: label.to_string(),
+            }),
+            None => Err(HarnessError::NotFound {
+                kind,
+                label: label.to_string(),
+            }),
+        }
+    }
+}
+
+/// Render a view tree as indented text, one widget per line
+///
+/// Only what a user could see or interact with is included; styles and
+/// callbacks are left out so snapshots stay stable across styling changes.
+pub fn dump_view<M: Clone + Debug>(view: &View<M>) -> String {
+    let mut out = String::new();
+    dump_node(view, 0, &mut out);
+    out
+}
+
+fn dump_node<M: Clone + Debug>(view: &View<M>, depth: usize, out: &mut String) {
+    if view.is_hidden() {
+        return;
+    }
+
+    let indent = "  ".repeat(depth);
+    let mut line = |text: String| {
+        let _ = writeln!(out, "{}{}", indent, text);
+    };
+
+    match view {
+        View::Empty => line("Empty".to_string()),
+        View::Text { content, .. } => line(format!("Text {:?}", content)),
+        View::Button { label, disabled, .. } => {
+            line(format!("Button {:?}{}", label, if *disabled { " (disabled)" } else { "" }))
+        }
+        View::Row { .. } => line("Row".to_string()),
+        View::Column { .. } => line("Column".to_string()),
+        View::Input { placeholder, value, password, disabled, .. } => {
+            let shown = if *password { "*".repeat(value.chars().count()) } else { value.clone() };
+            let state = if *disabled { " (disabled)" } else { "" };
+            line(format!("Input {:?} = {:?}{}", placeholder, shown, state))
+        }
+        View::Checkbox { is_checked, label, .. } => {
+            line(format!("Checkbox [{}] {:?}", if *is_checked { "x" } else { " " }, label))
+        }
+        View::Container { .. } => line("Container".to_string()),
+        View::Scrollable { .. } => line("Scrollable".to_string()),
+        View::Radio { label, is_selected, .. } => {
+            line(format!("Radio ({}) {:?}", if *is_selected { "*" } else { " " }, label))
+        }
+        View::RadioGroup { options, selected_index, .. } => {
+            line(format!("RadioGroup {}", choices(options, *selected_index)))
+        }
+        View::Select { options, selected_index, .. } => {
+            line(format!("Select {}", choices(options, *selected_index)))
+        }
+        View::List { .. } => line("List".to_string()),
+        View::Table { .. } => line("Table".to_string()),
+        View::Slider { min, max, value, .. } => line(format!("Slider {} [{}..{}]", value, min, max)),
+        View::ProgressBar { progress, .. } => line(format!("ProgressBar {:.0}%", progress * 100.0)),
+        View::Accordion { .. } => line("Accordion".to_string()),
+        View::Sidebar { .. } => line("Sidebar".to_string()),
+        View::Tabs { labels, selected, .. } => {
+            line(format!("Tabs {}", choices(labels, Some(*selected))))
+        }
+        View::NavigationRail { items, selected, .. } => {
+            let labels: Vec<String> = items.iter().map(|item| item.label.clone()).collect();
+            line(format!("NavigationRail {}", choices(&labels, Some(*selected))))
+        }
+        View::Keyed { key, .. } => line(format!("Keyed {:?}", key)),
+    }
+
+    match view {
+        View::Table { headers, rows, .. } => {
+            for (i, cells) in std::iter::once(headers).chain(rows).enumerate() {
+                let _ = writeln!(out, "{}  {}", indent, if i == 0 { "Header" } else { "Row" });
+                for cell in cells {
+                    dump_node(cell, depth + 2, out);
+                }
+            }
+        }
+        View::Accordion { items, .. } => {
+            for item in items {
+                let marker = if item.expanded { "v" } else { ">" };
+                let _ = writeln!(out, "{}  {} {:?}", indent, marker, item.title);
+                if item.expanded {
+                    for child in &item.children {
+                        dump_node(child, depth + 2, out);
+                    }
+                }
+            }
+        }
+        _ => {
+            for child in children(view) {
+                dump_node(child, depth + 1, out);
+            }
+        }
+    }
+}
+
+/// `["a", *"b"]` with the selected entry starred
+fn choices(options: &[String], selected: Option<usize>) -> String {
+    let entries: Vec<String> = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            if Some(i) == selected {
+                format!("*{:?}", option)
+            } else {
+                format!("{:?}", option)
+            }
+        })
+        .collect();
+    format!("[{}]", entries.join(", "))
+}
+
+/// Children that are currently rendered
+///
+/// Collapsed accordion sections and unselected tabs are skipped, matching
+/// what the backends put on screen.
+fn children<M: Clone + Debug>(view: &View<M>) -> Vec<&View<M>> {
+    match view {
+        View::Row { children, .. } | View::Column { children, .. } => children.iter().collect(),
+        View::List { items, .. } => items.iter().collect(),
+        View::Container { child, .. } | View::Scrollable { child, .. } | View::Keyed { child, .. } => {
+            vec![child.as_ref()]
+        }
+        View::Sidebar { content, .. } => vec![content.as_ref()],
+        View::Table { headers, rows, .. } => headers.iter().chain(rows.iter().flatten()).collect(),
+        View::Accordion { items, .. } => items
+            .iter()
+            .filter(|item| item.expanded)
+            .flat_map(|item| item.children.iter())
+            .collect(),
+        View::Tabs { contents, selected, .. } => contents.get(*selected).into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Text a user would identify a node by
+fn node_text(node: &VNode) -> Option<&str> {
+    match &node.props {
+        VNodeProps::Text { content } => Some(content),
+        VNodeProps::Button { label, .. }
+        | VNodeProps::Checkbox { label, .. }
+        | VNodeProps::Radio { label, .. } => Some(label),
+        VNodeProps::Input { placeholder, .. } => Some(placeholder),
+        _ => None,
+    }
+}
+
+/// Pre-order traversal of the rendered tree
+fn visit<'a, M: Clone + Debug>(view: &'a View<M>, f: &mut impl FnMut(&'a View<M>)) {
+    if view.is_hidden() {
+        return;
+    }
+    f(view);
+    for child in children(view) {
+        visit(child, f);
+    }
+}
+
+/// First match of `f` in pre-order
+fn find_map<'a, M: Clone + Debug, T>(
+    view: &'a View<M>,
+    f: &mut impl FnMut(&'a View<M>) -> Option<T>,
+) -> Option<T> {
+    if view.is_hidden() {
+        return None;
+    }
+    f(view).or_else(|| children(view).into_iter().find_map(|child| find_map(child, f)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Form {
+        count: i32,
+        name: String,
+        subscribed: bool,
+        plan: Option<usize>,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Msg {
+        Inc,
+        Name(String),
+        Subscribe(bool),
+        Plan(usize),
+    }
+
+    impl Component for Form {
+        type Msg = Msg;
+
+        fn on(&mut self, msg: Msg) {
+            match msg {
+                Msg::Inc => self.count += 1,
+                Msg::Name(name) => self.name = name,
+                Msg::Subscribe(on) => self.subscribed = on,
+                Msg::Plan(index) => self.plan = Some(index),
+            }
+        }
+
+        fn view(&self) -> View<Msg> {
+            View::col()
+                .child(View::text(format!("Count: {}", self.count)))
+                .child(View::button("+", Msg::Inc))
+                .child(
+                    View::input("Name")
+                        .value(self.name.clone())
+                        .on_change(Msg::Name)
+                        .build(),
+                )
+                .child(View::checkbox(self.subscribed, "Subscribe").on_toggle(Msg::Subscribe(!self.subscribed)))
+                .child(
+                    View::select(vec!["Free".to_string(), "Pro".to_string()])
+                        .selected(self.plan.unwrap_or(0))
+                        .on_choose(|index, _| Msg::Plan(index)),
+                )
+                .child(View::checkbox(false, "Read only"))
+                .build()
+        }
+    }
+
+    #[test]
+    fn test_click_and_type() {
+        let mut harness = TestHarness::new(Form::default());
+
+        harness.click("+").unwrap();
+        harness.click("+").unwrap();
+        harness.type_text("Name", "Ada").unwrap();
+
+        assert!(harness.has_text("Count: 2"));
+        assert_eq!(harness.input_value("Name").as_deref(), Some("Ada"));
+        assert_eq!(harness.component().name, "Ada");
+    }
+
+    #[test]
+    fn test_toggle_and_select() {
+        let mut harness = TestHarness::new(Form::default());
+
+        harness.toggle("Subscribe").unwrap();
+        harness.select("Pro").unwrap();
+
+        assert!(harness.component().subscribed);
+        assert_eq!(harness.component().plan, Some(1));
+    }
+
+    #[test]
+    fn test_missing_targets() {
+        let mut harness = TestHarness::new(Form::default());
+
+        assert_eq!(
+            harness.click("-"),
+            Err(HarnessError::NotFound { kind: "clickable", label: "-".to_string() })
+        );
+        assert_eq!(
+            harness.toggle("Read only"),
+            Err(HarnessError::NoHandler { kind: "checkbox", label: "Read only".to_string() })
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct Checkout {
+        paid: bool,
+    }
+
+    impl Component for Checkout {
+        type Msg = Msg;
+
+        fn on(&mut self, _msg: Msg) {
+            self.paid = true;
+        }
+
+        fn view(&self) -> View<Msg> {
+            View::col()
+                .child(View::button("Pay", Msg::Inc).disabled(self.paid))
+                .child(View::input("Card").on_change(Msg::Name).disabled(self.paid).build())
+                .build()
+        }
+    }
+
+    #[test]
+    fn test_disabled_widgets_ignore_interaction() {
+        let mut harness = TestHarness::new(Checkout::default());
+        harness.click("Pay").unwrap();
+
+        assert_eq!(
+            harness.click("Pay"),
+            Err(HarnessError::Disabled { kind: "button", label: "Pay".to_string() })
+        );
+        assert_eq!(
+            harness.type_text("Card", "4242"),
+            Err(HarnessError::Disabled { kind: "input", label: "Card".to_string() })
+        );
+        assert_eq!(
+            harness.dump(),
+            "Column\n\
+             \x20 Button \"Pay\" (disabled)\n\
+             \x20 Input \"Card\" = \"\" (disabled)\n"
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct Shell;
+
+    impl Component for Shell {
+        type Msg = Msg;
+
+        fn on(&mut self, _msg: Msg) {}
+
+        fn view(&self) -> View<Msg> {
+            let compact = WindowSize::current().width < 768.0;
+            View::row()
+                .child(View::text_styled("Sidebar", "hidden md:block"))
+                .child(View::text_styled("Rail", "md:hidden"))
+                .child(View::text(if compact { "compact" } else { "wide" }))
+                .build()
+        }
+    }
+
+    #[test]
+    fn test_resize_switches_breakpoints() {
+        let mut harness = TestHarness::new(Shell);
+
+        harness.resize(500.0, 800.0);
+        assert_eq!(harness.texts(), vec!["Rail", "compact"]);
+
+        harness.resize(1200.0, 800.0);
+        assert_eq!(harness.texts(), vec!["Sidebar", "wide"]);
+        assert_eq!(harness.dump(), "Row\n  Text \"Sidebar\"\n  Text \"wide\"\n");
+    }
+
+    #[test]
+    fn test_layout_queries() {
+        let mut harness = TestHarness::new(Shell);
+
+        harness.resize(1200.0, 800.0);
+        let sidebar = harness.rect_of("Sidebar").unwrap();
+        let wide = harness.rect_of("wide").unwrap();
+        assert_eq!((sidebar.x, sidebar.y), (0.0, 0.0));
+        assert!(wide.x >= sidebar.right());
+        assert_eq!(wide.y, 0.0);
+        assert!(harness.rect_of("Rail").is_none());
+
+        let root = harness.vtree().root().unwrap().id;
+        assert_eq!(harness.layout().get(root).unwrap().width, 1200.0);
+
+        harness.resize(500.0, 800.0);
+        assert!(harness.rect_of("Sidebar").is_none());
+        assert_eq!(harness.rect_of("Rail").unwrap().x, 0.0);
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut harness = TestHarness::new(Form::default());
+        harness.click("+").unwrap();
+
+        assert_eq!(
+            harness.dump(),
+            "Column\n\
+             \x20 Text \"Count: 1\"\n\
+             \x20 Button \"+\"\n\
+             \x20 Input \"Name\" = \"\"\n\
+             \x20 Checkbox [ ] \"Subscribe\"\n\
+             \x20 Select [*\"Free\", \"Pro\"]\n\
+             \x20 Checkbox [ ] \"Read only\"\n"
+        );
+    }
+}
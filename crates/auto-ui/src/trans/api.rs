@@ -1,9 +1,62 @@
 // High-level Transpiler API for AutoUI
 //
-// Provides simple API for transpiling .at files to Rust code
+// Provides simple API for transpiling .at files to Rust, Vue, Kotlin (Compose)
+// or ArkTS (HarmonyOS) code
 
 use auto_lang::Parser;
 use std::path::Path;
+use std::str::FromStr;
+
+/// Output language of the transpiler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Target {
+    /// auto-ui `Component` implementations
+    #[default]
+    Rust,
+    /// Vue 3 single-file component (one widget per file)
+    Vue,
+    /// Kotlin `@Composable` functions for Jetpack Compose
+    Compose,
+    /// ArkTS `@Component struct`s for HarmonyOS ArkUI
+    ArkTs,
+}
+
+impl Target {
+    /// File extension of generated code
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Target::Rust => "rs",
+            Target::Vue => "vue",
+            Target::Compose => "kt",
+            Target::ArkTs => "ets",
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Target::Rust => "rust",
+            Target::Vue => "vue",
+            Target::Compose => "compose",
+            Target::ArkTs => "arkts",
+        })
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Ok(Target::Rust),
+            "vue" => Ok(Target::Vue),
+            "compose" | "kotlin" | "kt" => Ok(Target::Compose),
+            "arkts" | "ets" | "harmonyos" => Ok(Target::ArkTs),
+            _ => Err(format!("unknown target '{}' (expected rust, vue, compose or arkts)", s)),
+        }
+    }
+}
 
 /// Transpile Auto language file to Rust code
 ///
@@ -20,6 +73,24 @@ use std::path::Path;
 /// println!("{}", rust_code);
 /// ```
 pub fn transpile_file(input_path: impl AsRef<Path>, output_path: Option<&str>) -> Result<String, String> {
+    let rust_code = transpile_file_to(input_path, Target::Rust)?;
+
+    // Write to output file if specified
+    if let Some(output) = output_path {
+        std::fs::write(output, &rust_code)
+            .map_err(|e| format!("Failed to write file {}: {}", output, e))?;
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile Auto language file to code for `target`
+///
+/// # Example
+/// ```ignore
+/// let vue_code = transpile_file_to("scratch/counter_full.at", Target::Vue)?;
+/// ```
+pub fn transpile_file_to(input_path: impl AsRef<Path>, target: Target) -> Result<String, String> {
     let input_path = input_path.as_ref();
 
     // Read the .at file
@@ -37,16 +108,7 @@ pub fn transpile_file(input_path: impl AsRef<Path>, output_path: Option<&str>) -
     let ast = parser.parse()
         .map_err(|e| format!("Failed to parse {}: {:?}", input_path.display(), e))?;
 
-    // Generate Rust code
-    let rust_code = transpile_ast(&ast)?;
-
-    // Write to output file if specified
-    if let Some(output) = output_path {
-        std::fs::write(output, &rust_code)
-            .map_err(|e| format!("Failed to write file {}: {}", output, e))?;
-    }
-
-    Ok(rust_code)
+    transpile_ast_to(&ast, target)
 }
 
 /// Transpile parsed AST to Rust code
@@ -77,8 +139,46 @@ pub fn transpile_ast(ast: &auto_lang::ast::Code) -> Result<String, String> {
     Ok(code)
 }
 
+/// Transpile parsed AST to code for `target`
+pub fn transpile_ast_to(ast: &auto_lang::ast::Code, target: Target) -> Result<String, String> {
+    match target {
+        Target::Rust => transpile_ast(ast),
+        Target::Vue => {
+            use crate::trans::vue_gen::VueCodeGenerator;
+
+            // A .vue file holds exactly one component
+            let widgets: Vec<_> = ast.stmts.iter()
+                .filter_map(|stmt| match stmt {
+                    auto_lang::ast::Stmt::TypeDecl(type_decl) if is_widget_type(type_decl) => Some(type_decl),
+                    _ => None,
+                })
+                .collect();
+
+            match widgets.as_slice() {
+                [widget] => VueCodeGenerator::new().generate_widget(widget),
+                [] => Err("No widget found to generate a Vue component from".to_string()),
+                _ => Err(format!(
+                    "Vue output holds one widget per file, found {}: {}",
+                    widgets.len(),
+                    widgets.iter().map(|w| w.name.to_string()).collect::<Vec<_>>().join(", ")
+                )),
+            }
+        }
+        Target::Compose => {
+            use crate::trans::compose_gen::ComposeCodeGenerator;
+
+            ComposeCodeGenerator::new().generate(ast)
+        }
+        Target::ArkTs => {
+            use crate::trans::arkts_gen::ArkTsCodeGenerator;
+
+            ArkTsCodeGenerator::new().generate(ast)
+        }
+    }
+}
+
 /// Check if type declaration is a widget
-fn is_widget_type(type_decl: &auto_lang::ast::TypeDecl) -> bool {
+pub(crate) fn is_widget_type(type_decl: &auto_lang::ast::TypeDecl) -> bool {
     // Check if has Widget in specs (traits/specs implemented)
     // Spec is just a type alias for AutoStr (String)
     let has_widget_spec = type_decl.specs.iter()
@@ -95,6 +195,18 @@ fn is_widget_type(type_decl: &auto_lang::ast::TypeDecl) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_target_from_str() {
+        assert_eq!("vue".parse::<Target>(), Ok(Target::Vue));
+        assert_eq!("Rust".parse::<Target>(), Ok(Target::Rust));
+        assert!("svelte".parse::<Target>().is_err());
+        assert_eq!(Target::Vue.extension(), "vue");
+        assert_eq!("kotlin".parse::<Target>(), Ok(Target::Compose));
+        assert_eq!(Target::Compose.extension(), "kt");
+        assert_eq!("ArkTS".parse::<Target>(), Ok(Target::ArkTs));
+        assert_eq!(Target::ArkTs.to_string(), "arkts");
+    }
+
     #[test]
     fn test_transpile_hello() {
         // This test requires actual .at file
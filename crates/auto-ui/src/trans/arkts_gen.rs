@@ -0,0 +1,859 @@
+// ArkTS Code Generator for AutoUI Transpiler
+//
+// This module generates HarmonyOS ArkUI components (`.ets` files) from Auto
+// language widget definitions:
+//
+// - each widget becomes an `@Component struct` with a `build()` method
+// - fields become `@State` members, so assigning them re-renders
+// - message variants become an `enum Msg`, shared by the file's widgets
+// - `fn on` becomes an `on()` method, called from event handlers
+// - `style` strings are resolved with the active Theme into attribute
+//   methods (`.padding(16)`, `.backgroundColor('#FFFFFF')`, ...)
+//
+// Responsive (`md:`) and state (`hover:`) variants are skipped; ArkUI
+// expresses those with breakpoints and state styles that have no one-line
+// attribute equivalent.
+
+use super::api::is_widget_type;
+use super::rust_gen::RustCodeGenerator;
+use crate::style::{Color, SizeValue, Style, StyleClass, Theme};
+use auto_lang::ast::*;
+use auto_val::Op;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+/// ArkTS code generator for widgets
+pub struct ArkTsCodeGenerator {
+    /// ArkTS types of the current widget's fields, which are `@State`
+    fields: HashMap<String, String>,
+    /// Methods other than view() and on(), which become struct methods
+    actions: HashSet<String>,
+    /// Name of the current widget, used to group its radio buttons
+    widget: String,
+    /// Theme that style colors, radii and font sizes are resolved with
+    theme: Arc<Theme>,
+}
+
+impl ArkTsCodeGenerator {
+    pub fn new() -> Self {
+        Self::with_theme(Theme::active())
+    }
+
+    /// Generator resolving styles with `theme` instead of the active one
+    pub fn with_theme(theme: Arc<Theme>) -> Self {
+        Self {
+            fields: HashMap::new(),
+            actions: HashSet::new(),
+            widget: String::new(),
+            theme,
+        }
+    }
+
+    /// Generate a complete `.ets` file from the widgets in `ast`
+    pub fn generate(&mut self, ast: &Code) -> Result<String, String> {
+        let widgets: Vec<&TypeDecl> = ast
+            .stmts
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Stmt::TypeDecl(type_decl) if is_widget_type(type_decl) => Some(type_decl),
+                _ => None,
+            })
+            .collect();
+        if widgets.is_empty() {
+            return Err("No widget found to generate an ArkTS component from".to_string());
+        }
+
+        let mut code = String::new();
+        code.push_str("// Auto-generated from Auto language\n");
+        code.push_str("// DO NOT EDIT - changes will be overwritten\n\n");
+
+        // Message variants are found the same way as for Rust output; one
+        // enum serves all widgets of the file
+        let mut variants = BTreeSet::new();
+        for widget in &widgets {
+            let mut analysis = RustCodeGenerator::new();
+            analysis.analyze_widget(widget)?;
+            variants.extend(analysis.message_variants().into_iter().map(|v| v.name.clone()));
+        }
+        if !variants.is_empty() {
+            code.push_str("enum Msg {\n");
+            let variants: Vec<String> = variants.into_iter().collect();
+            code.push_str(&format!("  {}\n", variants.join(",\n  ")));
+            code.push_str("}\n\n");
+        }
+
+        for (i, widget) in widgets.iter().enumerate() {
+            if i > 0 {
+                code.push('\n');
+            }
+            code.push_str(&self.generate_widget(widget)?);
+        }
+        Ok(code)
+    }
+
+    /// Generate one `@Component struct`
+    fn generate_widget(&mut self, type_decl: &TypeDecl) -> Result<String, String> {
+        self.widget = type_decl.name.to_string();
+        self.fields = type_decl
+            .members
+            .iter()
+            .map(|m| (m.name.to_string(), self.ts_type(&m.ty)))
+            .collect();
+        self.actions = type_decl
+            .methods
+            .iter()
+            .filter(|m| m.name != "view" && m.name != "on")
+            .map(|m| m.name.to_string())
+            .collect();
+
+        let mut code = String::new();
+        code.push_str("@Component\n");
+        code.push_str(&format!("export struct {} {{\n", type_decl.name));
+
+        if !type_decl.members.is_empty() {
+            for member in &type_decl.members {
+                let ty = &self.fields[&member.name.to_string()];
+                line(
+                    &mut code,
+                    1,
+                    &format!("@State {}: {} = {}", member.name, ty, self.default_value(&member.ty)),
+                );
+            }
+            code.push('\n');
+        }
+
+        for method in type_decl.methods.iter().filter(|m| self.actions.contains(&m.name.to_string())) {
+            line(&mut code, 1, &format!("{}() {{", method.name));
+            code.push_str(&self.generate_body_stmts(&method.body, 2, "undefined"));
+            line(&mut code, 1, "}");
+            code.push('\n');
+        }
+
+        if let Some(method) = type_decl.methods.iter().find(|m| m.name == "on") {
+            let (param, ty) = match method.params.first() {
+                Some(param) => (param.name.to_string(), self.ts_type(&param.ty)),
+                None => ("msg".to_string(), "Msg".to_string()),
+            };
+            line(&mut code, 1, &format!("on({}: {}) {{", param, ty));
+            code.push_str(&self.generate_body_stmts(&method.body, 2, &param));
+            line(&mut code, 1, "}");
+            code.push('\n');
+        }
+
+        line(&mut code, 1, "build() {");
+        code.push_str(&self.generate_build(type_decl)?);
+        line(&mut code, 1, "}");
+        code.push_str("}\n");
+
+        self.fields.clear();
+        self.actions.clear();
+        Ok(code)
+    }
+
+    /// Generate the body of build() from view()
+    fn generate_build(&self, type_decl: &TypeDecl) -> Result<String, String> {
+        let Some(method) = type_decl.methods.iter().find(|m| m.name == "view") else {
+            return Ok(String::new());
+        };
+
+        let roots: Vec<&Stmt> = method.body.stmts.iter().filter(|stmt| Self::is_view_stmt(stmt)).collect();
+        let mut code = String::new();
+        if roots.len() > 1 {
+            // build() takes a single root - wrap in a col, like the Rust output
+            line(&mut code, 2, "Column() {");
+            for stmt in roots {
+                self.generate_view_stmt(stmt, 3, &mut code)?;
+            }
+            line(&mut code, 2, "}");
+        } else {
+            for stmt in roots {
+                self.generate_view_stmt(stmt, 2, &mut code)?;
+            }
+        }
+        Ok(code)
+    }
+
+    fn is_view_stmt(stmt: &Stmt) -> bool {
+        matches!(
+            stmt,
+            Stmt::Node(_) | Stmt::Expr(Expr::Node(_)) | Stmt::Expr(Expr::Call(_)) | Stmt::Expr(Expr::Ident(_))
+        )
+    }
+
+    fn generate_view_stmt(&self, stmt: &Stmt, depth: usize, code: &mut String) -> Result<(), String> {
+        match stmt {
+            Stmt::Node(node) | Stmt::Expr(Expr::Node(node)) => {
+                self.generate_element(node.name.as_str(), &node.args, Some(&node.body), depth, code)?
+            }
+            Stmt::Expr(Expr::Call(call)) => match call.name.as_ref() {
+                Expr::Ident(name) => self.generate_element(&name.to_string(), &call.args, None, depth, code)?,
+                _ => line(code, depth, "// unknown call"),
+            },
+            // Reference to field
+            Stmt::Expr(expr @ Expr::Ident(_)) => line(code, depth, &format!("Text({})", self.text_expr(expr))),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn generate_children(&self, body: Option<&Body>, depth: usize, code: &mut String) -> Result<(), String> {
+        for stmt in body.into_iter().flat_map(|body| &body.stmts) {
+            self.generate_view_stmt(stmt, depth, code)?;
+        }
+        Ok(())
+    }
+
+    /// Generate one widget; nodes and calls share this, calls have no body
+    fn generate_element(
+        &self,
+        name: &str,
+        args: &Args,
+        body: Option<&Body>,
+        depth: usize,
+        code: &mut String,
+    ) -> Result<(), String> {
+        let style = self.style(name, args)?;
+
+        match name {
+            "col" | "column" => {
+                let attrs = [style.attrs(), style.column_attrs()].concat();
+                self.generate_container(&format!("Column({})", style.space()), &attrs, body, depth, code)?;
+            }
+            "row" => {
+                let attrs = [style.attrs(), style.row_attrs()].concat();
+                self.generate_container(&format!("Row({})", style.space()), &attrs, body, depth, code)?;
+            }
+            "center" => {
+                let stack = "Stack({ alignContent: Alignment.Center })";
+                self.generate_container(stack, &style.attrs(), body, depth, code)?;
+            }
+            "container" => {
+                let center_x = self.prop_bool(args, "center_x").unwrap_or(false);
+                let center_y = self.prop_bool(args, "center_y").unwrap_or(false);
+                let alignment = match (center_x, center_y) {
+                    (true, true) => "Center",
+                    (true, false) => "Top",
+                    (false, true) => "Start",
+                    (false, false) => "TopStart",
+                };
+                let stack = format!("Stack({{ alignContent: Alignment.{} }})", alignment);
+                self.generate_container(&stack, &style.attrs(), body, depth, code)?;
+            }
+            "scrollable" => {
+                // Scroll takes a single child
+                line(code, depth, "Scroll() {");
+                self.generate_container("Column()", &style.column_attrs(), body, depth + 1, code)?;
+                line(code, depth, "}");
+                attributes(code, depth, &style.attrs());
+            }
+            "list" => {
+                line(code, depth, &format!("List({}) {{", style.space()));
+                for stmt in body.into_iter().flat_map(|body| &body.stmts) {
+                    if Self::is_view_stmt(stmt) {
+                        line(code, depth + 1, "ListItem() {");
+                        self.generate_view_stmt(stmt, depth + 2, code)?;
+                        line(code, depth + 1, "}");
+                    }
+                }
+                line(code, depth, "}");
+                attributes(code, depth, &style.attrs());
+            }
+            "text" | "label" => {
+                line(code, depth, &format!("Text({})", self.content(args)));
+                attributes(code, depth + 1, &[style.attrs(), style.text.clone()].concat());
+            }
+            "button" => {
+                line(code, depth, &format!("Button({})", self.content(args)));
+                let mut attrs = [style.attrs(), style.text.clone()].concat();
+                if let Some(onclick) = args.lookup("onclick") {
+                    attrs.push(format!(".onClick(() => {{ {} }})", self.handler(&onclick.get_expr())));
+                }
+                attributes(code, depth + 1, &attrs);
+            }
+            "input" => {
+                let mut options = Vec::new();
+                let bound = args
+                    .lookup("value")
+                    .or_else(|| args.get(0))
+                    .and_then(|arg| self.field_name(&arg.get_expr()));
+                if let Some(Expr::Str(s)) = args.get(0).map(|arg| arg.get_expr()) {
+                    options.push(format!("placeholder: {}", ts_string(&s.to_string())));
+                }
+                // input(name) binds the field directly
+                let mut attrs = [style.attrs(), style.text.clone()].concat();
+                if let Some(field) = &bound {
+                    options.push(format!("text: this.{}", field));
+                    attrs.push(format!(".onChange((value: string) => {{ this.{} = value }})", field));
+                }
+                let options = if options.is_empty() {
+                    String::new()
+                } else {
+                    format!("{{ {} }}", options.join(", "))
+                };
+                line(code, depth, &format!("TextInput({})", options));
+                attributes(code, depth + 1, &attrs);
+            }
+            "checkbox" => {
+                let state = args.lookup("is_checked").map(|arg| arg.get_expr());
+                let is_on = state.as_ref().map(|expr| self.expr(expr)).unwrap_or_else(|| "false".to_string());
+                line(code, depth, "Row() {");
+                line(
+                    code,
+                    depth + 1,
+                    &format!("Toggle({{ type: ToggleType.Checkbox, isOn: {} }})", is_on),
+                );
+                // Only a checkbox bound to a field toggles it; the rest are display-only
+                if let Some(field) = state.as_ref().and_then(|expr| self.field_name(expr)) {
+                    attributes(
+                        code,
+                        depth + 2,
+                        &[format!(".onChange((isOn: boolean) => {{ this.{} = isOn }})", field)],
+                    );
+                }
+                line(code, depth + 1, &format!("Text({})", self.content(args)));
+                attributes(code, depth + 2, &style.text);
+                line(code, depth, "}");
+                let mut attrs = style.attrs();
+                attrs.push(".alignItems(VerticalAlign.Center)".to_string());
+                attributes(code, depth, &attrs);
+            }
+            "radio" => {
+                let selected = args
+                    .lookup("is_selected")
+                    .map(|arg| self.expr(&arg.get_expr()))
+                    .unwrap_or_else(|| "false".to_string());
+                let label = self.content(args);
+                line(code, depth, "Row() {");
+                line(
+                    code,
+                    depth + 1,
+                    &format!("Radio({{ value: {}, group: {} }})", label, ts_string(&self.widget)),
+                );
+                attributes(code, depth + 2, &[format!(".checked({})", selected)]);
+                line(code, depth + 1, &format!("Text({})", label));
+                attributes(code, depth + 2, &style.text);
+                line(code, depth, "}");
+                let mut attrs = style.attrs();
+                attrs.push(".alignItems(VerticalAlign.Center)".to_string());
+                attributes(code, depth, &attrs);
+            }
+            "radio_group" => {
+                // radio_group("Small", "Large", selected: 0, onselect: Msg.Size)
+                let selected = args.lookup("selected").map(|arg| self.expr(&arg.get_expr()));
+                let onselect = args.lookup("onselect").map(|arg| self.handler(&arg.get_expr()));
+                let labels: Vec<String> = Self::positional(args)
+                    .iter()
+                    .filter_map(|option| match option {
+                        Expr::Str(label) => Some(label.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                // Radios are grouped by name; the options tell groups apart
+                let group = ts_string(&format!("{}.{}", self.widget, labels.join("|")));
+                line(code, depth, "Column() {");
+                for (index, label) in labels.iter().enumerate() {
+                    let label = ts_string(label);
+                    line(code, depth + 1, "Row() {");
+                    line(code, depth + 2, &format!("Radio({{ value: {}, group: {} }})", label, group));
+                    let mut attrs = Vec::new();
+                    if let Some(selected) = &selected {
+                        attrs.push(format!(".checked({} === {})", selected, index));
+                    }
+                    if let Some(onselect) = &onselect {
+                        attrs.push(format!(
+                            ".onChange((isChecked: boolean) => {{ if (isChecked) {{ {} }} }})",
+                            onselect
+                        ));
+                    }
+                    attributes(code, depth + 3, &attrs);
+                    line(code, depth + 2, &format!("Text({})", label));
+                    line(code, depth + 1, "}");
+                    attributes(code, depth + 1, &[".alignItems(VerticalAlign.Center)".to_string()]);
+                }
+                line(code, depth, "}");
+                attributes(code, depth, &[style.attrs(), style.column_attrs()].concat());
+            }
+            "select" => {
+                let options: Vec<String> = Self::positional(args)
+                    .iter()
+                    .filter_map(|option| match option {
+                        Expr::Str(s) => Some(format!("{{ value: {} }}", ts_string(&s.to_string()))),
+                        _ => None,
+                    })
+                    .collect();
+                line(code, depth, &format!("Select([{}])", options.join(", ")));
+                let mut attrs = style.attrs();
+                if let Some(Expr::Str(first)) = Self::positional(args).first() {
+                    attrs.insert(0, ".selected(0)".to_string());
+                    attrs.insert(1, format!(".value({})", ts_string(&first.to_string())));
+                }
+                attributes(code, depth + 1, &attrs);
+            }
+            _ => line(code, depth, &format!("// unknown widget: {}", name)),
+        }
+        Ok(())
+    }
+
+    /// A container with children; attributes follow its closing brace
+    fn generate_container(
+        &self,
+        component: &str,
+        attrs: &[String],
+        body: Option<&Body>,
+        depth: usize,
+        code: &mut String,
+    ) -> Result<(), String> {
+        line(code, depth, &format!("{} {{", component));
+        self.generate_children(body, depth + 1, code)?;
+        line(code, depth, "}");
+        attributes(code, depth, attrs);
+        Ok(())
+    }
+
+    /// Style of a widget, from legacy layout props and the style string
+    fn style(&self, name: &str, args: &Args) -> Result<ArkStyle, String> {
+        let mut style = ArkStyle::default();
+        if let Some(spacing) = self.prop_int(args, "spacing") {
+            style.space = Some(spacing as f32);
+        }
+        if let Some(padding) = self.prop_int(args, "padding") {
+            style.padding = (Some(padding as f32), Some(padding as f32));
+        }
+        if let Some(width) = self.prop_int(args, "width") {
+            style.other.push(format!(".width({})", width));
+        }
+        if let Some(height) = self.prop_int(args, "height") {
+            style.other.push(format!(".height({})", height));
+        }
+        if let Some(Expr::Str(s)) = args.lookup("style").map(|arg| arg.get_expr()) {
+            let parsed = Style::parse(&s.to_string()).map_err(|e| format!("Invalid style on {}: {}", name, e))?;
+            for class in parsed.classes.iter() {
+                style.apply(class, &self.theme);
+            }
+        }
+        Ok(style)
+    }
+
+    /// Text content from the first positional argument
+    fn content(&self, args: &Args) -> String {
+        match Self::positional(args).first() {
+            Some(expr) => self.text_expr(expr),
+            None => "''".to_string(),
+        }
+    }
+
+    /// An ArkTS `string` expression for `expr`
+    fn text_expr(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Str(s) => ts_string(&s.to_string()),
+            Expr::Int(n) => ts_string(&n.to_string()),
+            Expr::Bool(b) => ts_string(&b.to_string()),
+            _ => match self.field_name(expr) {
+                Some(field) if self.fields[&field] == "string" => format!("this.{}", field),
+                _ => format!("{}.toString()", self.expr(expr)),
+            },
+        }
+    }
+
+    /// Event handler calling on(), or an action method directly
+    fn handler(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Ident(name) if self.actions.contains(&name.to_string()) => format!("this.{}()", name),
+            _ => format!("this.on({})", self.expr(expr)),
+        }
+    }
+
+    fn positional(args: &Args) -> Vec<Expr> {
+        args.args
+            .iter()
+            .filter(|arg| !matches!(arg, Arg::Pair(..)))
+            .map(|arg| arg.get_expr())
+            .collect()
+    }
+
+    fn prop_int(&self, args: &Args, key: &str) -> Option<i64> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Int(n)) => Some(n as i64),
+            _ => None,
+        }
+    }
+
+    fn prop_bool(&self, args: &Args, key: &str) -> Option<bool> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Bool(b)) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The field an expression refers to: `count` or `self.count`
+    fn field_name(&self, expr: &Expr) -> Option<String> {
+        let name = match expr {
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+                (Expr::Ident(this), Expr::Ident(name)) if this.to_string() == "self" => name.to_string(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.fields.contains_key(&name).then_some(name)
+    }
+
+    /// Generate an ArkTS expression; fields are read through `this`
+    fn expr(&self, expr: &Expr) -> String {
+        if let Some(field) = self.field_name(expr) {
+            return format!("this.{}", field);
+        }
+
+        match expr {
+            Expr::Str(s) => ts_string(&s.to_string()),
+            Expr::Int(n) => n.to_string(),
+            Expr::Bool(b) => b.to_string(),
+            // Also covers Msg.Inc parsed as a single identifier
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => format!("{}.{}", self.expr(lhs), self.expr(rhs)),
+            Expr::Bina(lhs, op, rhs) => format!("{} {} {}", self.expr(lhs), op, self.expr(rhs)),
+            Expr::Call(call) => {
+                let args: Vec<String> = call.args.args.iter().map(|arg| self.expr(&arg.get_expr())).collect();
+                match call.name.as_ref() {
+                    Expr::Ident(name) if name.to_string() == "print" => format!("console.info({})", args.join(", ")),
+                    Expr::Ident(name) if self.actions.contains(&name.to_string()) => {
+                        format!("this.{}({})", name, args.join(", "))
+                    }
+                    name => format!("{}({})", self.expr(name), args.join(", ")),
+                }
+            }
+            _ => "undefined".to_string(),
+        }
+    }
+
+    /// Generate method statements
+    ///
+    /// `is` statements switch on `subject`, the message parameter of on().
+    fn generate_body_stmts(&self, body: &Body, depth: usize, subject: &str) -> String {
+        let mut code = String::new();
+        for stmt in &body.stmts {
+            match stmt {
+                Stmt::Store(store) => {
+                    let name = store.name.to_string();
+                    let value = self.expr(&store.expr);
+                    if self.fields.contains_key(&name) {
+                        line(&mut code, depth, &format!("this.{} = {}", name, value));
+                    } else {
+                        line(&mut code, depth, &format!("let {} = {}", name, value));
+                    }
+                }
+                Stmt::Is(is_stmt) => {
+                    line(&mut code, depth, &format!("switch ({}) {{", subject));
+                    for branch in &is_stmt.branches {
+                        if let IsBranch::EqBranch(pattern, body) = branch {
+                            line(&mut code, depth + 1, &format!("case {}:", self.expr(pattern)));
+                            code.push_str(&self.generate_body_stmts(body, depth + 2, subject));
+                            line(&mut code, depth + 2, "break");
+                        }
+                    }
+                    line(&mut code, depth, "}");
+                }
+                Stmt::Expr(expr) => line(&mut code, depth, &self.expr(expr)),
+                _ => line(&mut code, depth, "// unsupported statement"),
+            }
+        }
+        code
+    }
+
+    /// ArkTS type of a field or parameter
+    fn ts_type(&self, ty: &Type) -> String {
+        match ty {
+            Type::Int => "number".to_string(),
+            Type::Str(_) => "string".to_string(),
+            Type::Bool => "boolean".to_string(),
+            Type::User(user) => user.name.to_string(),
+            _ => "Object | null".to_string(),
+        }
+    }
+
+    /// Initial value of a `@State` member, from the field type
+    fn default_value(&self, ty: &Type) -> &'static str {
+        match ty {
+            Type::Int => "0",
+            Type::Str(_) => "''",
+            Type::Bool => "false",
+            _ => "null",
+        }
+    }
+}
+
+/// Style classes resolved into ArkUI attribute methods
+///
+/// Most attributes keep the order of their classes, so a later class
+/// overrides an earlier one like it does in CSS. Padding and margin are
+/// collected per axis instead: each call replaces all four sides.
+#[derive(Debug, Default)]
+struct ArkStyle {
+    /// Horizontal and vertical margin
+    margin: (Option<f32>, Option<f32>),
+    /// Horizontal and vertical padding
+    padding: (Option<f32>, Option<f32>),
+    /// Other attributes every component has (`.backgroundColor(...)`)
+    other: Vec<String>,
+    /// Font attributes, for components showing text
+    text: Vec<String>,
+    /// `space` option of Column, Row and List
+    space: Option<f32>,
+    items: Option<&'static str>,
+    justify: Option<&'static str>,
+}
+
+impl ArkStyle {
+    fn apply(&mut self, class: &StyleClass, theme: &Theme) {
+        let radius = |radius: f32| format!(".borderRadius({})", number(radius));
+        match class {
+            StyleClass::Padding(size) => self.padding = (Some(theme.spacing(size)), Some(theme.spacing(size))),
+            StyleClass::PaddingX(size) => self.padding.0 = Some(theme.spacing(size)),
+            StyleClass::PaddingY(size) => self.padding.1 = Some(theme.spacing(size)),
+            StyleClass::Margin(size) => self.margin = (Some(theme.spacing(size)), Some(theme.spacing(size))),
+            StyleClass::MarginX(size) => self.margin.0 = Some(theme.spacing(size)),
+            StyleClass::MarginY(size) => self.margin.1 = Some(theme.spacing(size)),
+            StyleClass::Gap(size) => self.space = Some(theme.spacing(size)),
+
+            StyleClass::BackgroundColor(color) => {
+                self.other.push(format!(".backgroundColor({})", ts_color(color, theme)))
+            }
+            StyleClass::TextColor(color) => self.text.push(format!(".fontColor({})", ts_color(color, theme))),
+
+            StyleClass::Flex1 => self.other.push(".layoutWeight(1)".to_string()),
+            StyleClass::ItemsStart => self.items = Some("Start"),
+            StyleClass::ItemsCenter => self.items = Some("Center"),
+            StyleClass::ItemsEnd => self.items = Some("End"),
+            StyleClass::JustifyStart => self.justify = Some("Start"),
+            StyleClass::JustifyCenter => self.justify = Some("Center"),
+            StyleClass::JustifyEnd => self.justify = Some("End"),
+            StyleClass::JustifyBetween => self.justify = Some("SpaceBetween"),
+
+            StyleClass::Width(size) => self.other.extend(length(size, theme).map(|width| format!(".width({})", width))),
+            StyleClass::Height(size) => {
+                self.other.extend(length(size, theme).map(|height| format!(".height({})", height)))
+            }
+
+            StyleClass::Rounded | StyleClass::RoundedMd => self.other.push(radius(theme.radii.md)),
+            StyleClass::RoundedSm => self.other.push(radius(theme.radii.sm)),
+            StyleClass::RoundedLg => self.other.push(radius(theme.radii.lg)),
+            StyleClass::RoundedXl => self.other.push(radius(theme.radii.xl)),
+            StyleClass::Rounded2Xl => self.other.push(radius(theme.radii.xxl)),
+            StyleClass::Rounded3Xl => self.other.push(radius(theme.radii.xxxl)),
+            StyleClass::RoundedFull => self.other.push(radius(theme.radii.full)),
+            StyleClass::RoundedPx(px) => self.other.push(radius(*px as f32)),
+
+            StyleClass::Border => self.other.push(format!(
+                ".border({{ width: 1, color: {} }})",
+                ts_color(&theme.colors.border, theme)
+            )),
+            StyleClass::Border0 => self.other.push(".borderWidth(0)".to_string()),
+            StyleClass::BorderColor(color) => self.other.push(format!(".borderColor({})", ts_color(color, theme))),
+
+            StyleClass::TextXs => self.font_size(theme.font_sizes.xs),
+            StyleClass::TextSm => self.font_size(theme.font_sizes.sm),
+            StyleClass::TextBase => self.font_size(theme.font_sizes.base),
+            StyleClass::TextLg => self.font_size(theme.font_sizes.lg),
+            StyleClass::TextXl => self.font_size(theme.font_sizes.xl),
+            StyleClass::Text2Xl => self.font_size(theme.font_sizes.xxl),
+            StyleClass::Text3Xl => self.font_size(theme.font_sizes.xxxl),
+            StyleClass::FontSize(px) => self.font_size(*px as f32),
+            StyleClass::FontBold => self.text.push(".fontWeight(FontWeight.Bold)".to_string()),
+            StyleClass::FontMedium => self.text.push(".fontWeight(FontWeight.Medium)".to_string()),
+            StyleClass::FontNormal => self.text.push(".fontWeight(FontWeight.Normal)".to_string()),
+            StyleClass::TextCenter => self.text.push(".textAlign(TextAlign.Center)".to_string()),
+            StyleClass::TextLeft => self.text.push(".textAlign(TextAlign.Start)".to_string()),
+            StyleClass::TextRight => self.text.push(".textAlign(TextAlign.End)".to_string()),
+
+            // ArkUI's preset shadows, closest to Tailwind's sizes
+            StyleClass::ShadowSm => self.other.push(".shadow(ShadowStyle.OUTER_DEFAULT_XS)".to_string()),
+            StyleClass::Shadow | StyleClass::ShadowMd => {
+                self.other.push(".shadow(ShadowStyle.OUTER_DEFAULT_SM)".to_string())
+            }
+            StyleClass::ShadowLg => self.other.push(".shadow(ShadowStyle.OUTER_DEFAULT_MD)".to_string()),
+            StyleClass::ShadowXl => self.other.push(".shadow(ShadowStyle.OUTER_DEFAULT_LG)".to_string()),
+            StyleClass::Shadow2Xl => self.other.push(".shadow(ShadowStyle.OUTER_FLOATING_MD)".to_string()),
+            StyleClass::Opacity(value) => self.other.push(format!(".opacity({})", number(*value as f32 / 100.0))),
+
+            StyleClass::ZIndex(z) => self.other.push(format!(".zIndex({})", z)),
+            StyleClass::OverflowHidden => self.other.push(".clip(true)".to_string()),
+            StyleClass::Hidden => self.other.push(".visibility(Visibility.None)".to_string()),
+
+            // Direction comes from the widget; scrolling needs a Scroll
+            // parent, and grids a Grid with items, neither an attribute
+            _ => {}
+        }
+    }
+
+    /// Attributes every component has, spacing first
+    fn attrs(&self) -> Vec<String> {
+        let mut attrs: Vec<String> = [spacing("margin", self.margin), spacing("padding", self.padding)]
+            .into_iter()
+            .flatten()
+            .collect();
+        attrs.extend(self.other.iter().cloned());
+        attrs
+    }
+
+    fn font_size(&mut self, size: f32) {
+        self.text.push(format!(".fontSize({})", number(size)));
+    }
+
+    /// `{ space: 8 }` option of Column and Row, or nothing
+    fn space(&self) -> String {
+        match self.space {
+            Some(space) => format!("{{ space: {} }}", number(space)),
+            None => String::new(),
+        }
+    }
+
+    /// Alignment attributes of a Column
+    fn column_attrs(&self) -> Vec<String> {
+        let mut attrs = Vec::new();
+        if let Some(items) = self.items {
+            attrs.push(format!(".alignItems(HorizontalAlign.{})", items));
+        }
+        if let Some(justify) = self.justify {
+            attrs.push(format!(".justifyContent(FlexAlign.{})", justify));
+        }
+        attrs
+    }
+
+    /// Alignment attributes of a Row
+    fn row_attrs(&self) -> Vec<String> {
+        let mut attrs = Vec::new();
+        if let Some(items) = self.items {
+            let align = match items {
+                "Start" => "Top",
+                "End" => "Bottom",
+                _ => "Center",
+            };
+            attrs.push(format!(".alignItems(VerticalAlign.{})", align));
+        }
+        if let Some(justify) = self.justify {
+            attrs.push(format!(".justifyContent(FlexAlign.{})", justify));
+        }
+        attrs
+    }
+}
+
+/// A length in vp, or a percentage of the parent; `None` for `auto`, which
+/// is ArkUI's default
+fn length(size: &SizeValue, theme: &Theme) -> Option<String> {
+    let percent = match size {
+        SizeValue::Full => "100%",
+        SizeValue::Half => "50%",
+        SizeValue::Third => "33.33%",
+        SizeValue::TwoThirds => "66.67%",
+        SizeValue::Quarter => "25%",
+        SizeValue::ThreeQuarters => "75%",
+        SizeValue::Auto => return None,
+        _ => return Some(number(theme.spacing(size))),
+    };
+    Some(ts_string(percent))
+}
+
+/// Padding or margin attribute from horizontal and vertical amounts, e.g.
+/// `.padding(16)` or `.padding({ left: 16, right: 16, top: 8, bottom: 8 })`
+fn spacing(attr: &str, (horizontal, vertical): (Option<f32>, Option<f32>)) -> Option<String> {
+    if let (Some(h), Some(v)) = (horizontal, vertical) {
+        if h == v {
+            return Some(format!(".{}({})", attr, number(h)));
+        }
+    }
+    let mut sides = Vec::new();
+    if let Some(h) = horizontal {
+        sides.push(format!("left: {}, right: {}", number(h), number(h)));
+    }
+    if let Some(v) = vertical {
+        sides.push(format!("top: {}, bottom: {}", number(v), number(v)));
+    }
+    (!sides.is_empty()).then(|| format!(".{}({{ {} }})", attr, sides.join(", ")))
+}
+
+/// `'#RRGGBB'`, or `'#AARRGGBB'` for translucent colors, under `theme`
+fn ts_color(color: &Color, theme: &Theme) -> String {
+    let (r, g, b, a) = theme.rgba(color);
+    let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    if byte(a) == 255 {
+        format!("'#{:02X}{:02X}{:02X}'", byte(r), byte(g), byte(b))
+    } else {
+        format!("'#{:02X}{:02X}{:02X}{:02X}'", byte(a), byte(r), byte(g), byte(b))
+    }
+}
+
+/// A number without a trailing `.0`
+fn number(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Append one line at `depth` levels of two-space indentation
+fn line(code: &mut String, depth: usize, text: &str) {
+    code.push_str(&"  ".repeat(depth));
+    code.push_str(text);
+    code.push('\n');
+}
+
+/// Append attribute method calls, one per line
+fn attributes(code: &mut String, depth: usize, attrs: &[String]) {
+    for attr in attrs {
+        line(code, depth, attr);
+    }
+}
+
+fn ts_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'").replace('\n', "\\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(classes: &str) -> ArkStyle {
+        let theme = Theme::light();
+        let mut style = ArkStyle::default();
+        for class in Style::parse(classes).unwrap().classes.iter() {
+            style.apply(class, &theme);
+        }
+        style
+    }
+
+    #[test]
+    fn test_style_attributes() {
+        let style = style("p-4 px-2 w-full h-10 bg-white opacity-50 gap-2 text-xl font-bold");
+
+        assert_eq!(
+            style.attrs(),
+            vec![
+                ".padding({ left: 8, right: 8, top: 16, bottom: 16 })",
+                ".width('100%')",
+                ".height(40)",
+                ".backgroundColor('#FFFFFF')",
+                ".opacity(0.5)",
+            ]
+        );
+        assert_eq!(style.text, vec![".fontSize(20)", ".fontWeight(FontWeight.Bold)"]);
+        assert_eq!(style.space(), "{ space: 8 }");
+    }
+
+    #[test]
+    fn test_alignment() {
+        let style = style("items-center justify-between");
+
+        assert_eq!(
+            style.column_attrs(),
+            vec![".alignItems(HorizontalAlign.Center)", ".justifyContent(FlexAlign.SpaceBetween)"]
+        );
+        assert_eq!(style.row_attrs()[0], ".alignItems(VerticalAlign.Center)");
+    }
+
+    #[test]
+    fn test_ts_string() {
+        assert_eq!(ts_string("it's"), "'it\\'s'");
+        assert_eq!(number(0.75), "0.75");
+    }
+}
@@ -0,0 +1,997 @@
+// Jetpack Compose Code Generator for AutoUI Transpiler
+//
+// This module generates Kotlin `@Composable` functions from Auto language
+// widget definitions, so Android projects can share widgets with the Rust side:
+//
+// - fields become `mutableStateOf` state, remembered across recompositions
+// - message variants become a `sealed class Msg`, shared by the file's widgets
+// - `fn on` becomes a local dispatcher, called from event handlers
+// - the view tree becomes Compose calls; `style` strings are resolved with the
+//   active Theme into `Modifier` chains, arrangements and text parameters
+//
+// Responsive (`md:`) and state (`hover:`) variants have no Modifier
+// equivalent and are skipped.
+
+use super::api::is_widget_type;
+use super::rust_gen::RustCodeGenerator;
+use super::{CodeSink, Trans};
+use crate::style::{Color, SizeValue, Style, StyleClass, Theme};
+use auto_lang::ast::*;
+use auto_val::Op;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+/// Compose code generator for widgets
+pub struct ComposeCodeGenerator {
+    /// Kotlin types of the current widget's fields, which are state
+    fields: HashMap<String, String>,
+    /// Methods other than view() and on(), which become local functions
+    actions: HashSet<String>,
+    /// Theme that style colors, radii and font sizes are resolved with
+    theme: Arc<Theme>,
+}
+
+impl ComposeCodeGenerator {
+    pub fn new() -> Self {
+        Self::with_theme(Theme::active())
+    }
+
+    /// Generator resolving styles with `theme` instead of the active one
+    pub fn with_theme(theme: Arc<Theme>) -> Self {
+        Self {
+            fields: HashMap::new(),
+            actions: HashSet::new(),
+            theme,
+        }
+    }
+
+    /// Generate a complete `.kt` file from the widgets in `ast`
+    pub fn generate(&mut self, ast: &Code) -> Result<String, String> {
+        let mut sink = CodeSink::with_imports(|path| format!("import {}", path));
+        self.generate_into(ast, &mut sink)?;
+
+        let mut code = String::new();
+        code.push_str("// Auto-generated from Auto language\n");
+        code.push_str("// DO NOT EDIT - changes will be overwritten\n\n");
+        code.push_str(&sink.done());
+        Ok(code)
+    }
+
+    fn generate_into(&mut self, ast: &Code, sink: &mut CodeSink) -> Result<(), String> {
+        let widgets: Vec<&TypeDecl> = ast
+            .stmts
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Stmt::TypeDecl(type_decl) if is_widget_type(type_decl) => Some(type_decl),
+                _ => None,
+            })
+            .collect();
+        if widgets.is_empty() {
+            return Err("No widget found to generate a composable from".to_string());
+        }
+
+        // Message variants are found the same way as for Rust output; Kotlin
+        // has one Msg per file, so variants of all widgets are merged
+        let mut variants = BTreeSet::new();
+        for widget in &widgets {
+            let mut analysis = RustCodeGenerator::new();
+            analysis.analyze_widget(widget)?;
+            variants.extend(analysis.message_variants().into_iter().map(|v| {
+                if v.has_fields {
+                    format!("class {}(val value: Any) : Msg()", v.name)
+                } else {
+                    format!("object {} : Msg()", v.name)
+                }
+            }));
+        }
+        if !variants.is_empty() {
+            sink.writeln("sealed class Msg {");
+            sink.indent();
+            for variant in &variants {
+                sink.writeln(variant);
+            }
+            sink.dedent();
+            sink.writeln("}");
+            sink.write("\n");
+        }
+
+        for (i, widget) in widgets.iter().enumerate() {
+            if i > 0 {
+                sink.write("\n");
+            }
+            self.generate_widget(widget, sink)?;
+        }
+        Ok(())
+    }
+
+    /// Generate one `@Composable` function
+    fn generate_widget(&mut self, type_decl: &TypeDecl, sink: &mut CodeSink) -> Result<(), String> {
+        self.fields = type_decl
+            .members
+            .iter()
+            .map(|m| (m.name.to_string(), self.kotlin_type(&m.ty)))
+            .collect();
+        self.actions = type_decl
+            .methods
+            .iter()
+            .filter(|m| m.name != "view" && m.name != "on")
+            .map(|m| m.name.to_string())
+            .collect();
+
+        sink.add_import("androidx.compose.runtime.Composable");
+        sink.writeln("@Composable");
+        sink.writeln(&format!("fun {}() {{", type_decl.name));
+        sink.indent();
+
+        if !type_decl.members.is_empty() {
+            for import in ["getValue", "mutableStateOf", "remember", "setValue"] {
+                sink.add_import(&format!("androidx.compose.runtime.{}", import));
+            }
+            for member in &type_decl.members {
+                sink.writeln(&format!(
+                    "var {} by remember {{ {} }}",
+                    member.name,
+                    self.initial_state(&member.ty)
+                ));
+            }
+            sink.write("\n");
+        }
+
+        for method in type_decl.methods.iter().filter(|m| self.actions.contains(&m.name.to_string())) {
+            sink.writeln(&format!("fun {}() {{", method.name));
+            sink.indent();
+            self.generate_body_stmts(&method.body, "Unit", false, sink);
+            sink.dedent();
+            sink.writeln("}");
+            sink.write("\n");
+        }
+
+        if let Some(method) = type_decl.methods.iter().find(|m| m.name == "on") {
+            let (param, ty) = match method.params.first() {
+                Some(param) => (param.name.to_string(), self.kotlin_type(&param.ty)),
+                None => ("msg".to_string(), "Any".to_string()),
+            };
+            // `when` over a sealed class is exhaustive, anything else needs `else`
+            let exhaustive = ty == "Msg";
+            sink.writeln(&format!("fun on({}: {}) {{", param, ty));
+            sink.indent();
+            self.generate_body_stmts(&method.body, &param, exhaustive, sink);
+            sink.dedent();
+            sink.writeln("}");
+            sink.write("\n");
+        }
+
+        self.generate_view(type_decl, sink)?;
+
+        sink.dedent();
+        sink.writeln("}");
+
+        self.fields.clear();
+        self.actions.clear();
+        Ok(())
+    }
+
+    /// Generate the UI from view()
+    fn generate_view(&self, type_decl: &TypeDecl, sink: &mut CodeSink) -> Result<(), String> {
+        let Some(method) = type_decl.methods.iter().find(|m| m.name == "view") else {
+            return Ok(());
+        };
+
+        let roots: Vec<&Stmt> = method.body.stmts.iter().filter(|stmt| Self::is_view_stmt(stmt)).collect();
+        if roots.len() > 1 {
+            // Multiple expressions - wrap in a col, like the Rust output
+            sink.add_import("androidx.compose.foundation.layout.Column");
+            sink.writeln("Column {");
+            sink.indent();
+            for stmt in roots {
+                self.generate_view_stmt(stmt, sink)?;
+            }
+            sink.dedent();
+            sink.writeln("}");
+        } else {
+            for stmt in roots {
+                self.generate_view_stmt(stmt, sink)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_view_stmt(stmt: &Stmt) -> bool {
+        matches!(
+            stmt,
+            Stmt::Node(_) | Stmt::Expr(Expr::Node(_)) | Stmt::Expr(Expr::Call(_)) | Stmt::Expr(Expr::Ident(_))
+        )
+    }
+
+    fn generate_view_stmt(&self, stmt: &Stmt, sink: &mut CodeSink) -> Result<(), String> {
+        match stmt {
+            Stmt::Node(node) | Stmt::Expr(Expr::Node(node)) => {
+                self.generate_element(node.name.as_str(), &node.args, Some(&node.body), sink)
+            }
+            Stmt::Expr(Expr::Call(call)) => match call.name.as_ref() {
+                Expr::Ident(name) => self.generate_element(&name.to_string(), &call.args, None, sink),
+                _ => {
+                    sink.writeln("// unknown call");
+                    Ok(())
+                }
+            },
+            Stmt::Expr(expr @ Expr::Ident(_)) => {
+                // Reference to field
+                sink.add_import("androidx.compose.material3.Text");
+                sink.writeln(&format!("Text(text = {})", self.text_expr(expr)));
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn generate_children(&self, body: Option<&Body>, sink: &mut CodeSink) -> Result<(), String> {
+        for stmt in body.into_iter().flat_map(|body| &body.stmts) {
+            self.generate_view_stmt(stmt, sink)?;
+        }
+        Ok(())
+    }
+
+    /// Generate one widget; nodes and calls share this, calls have no body
+    fn generate_element(&self, name: &str, args: &Args, body: Option<&Body>, sink: &mut CodeSink) -> Result<(), String> {
+        let style = self.style(name, args)?;
+
+        match name {
+            "col" | "column" | "list" => {
+                let mut params = self.modifier_param(&style, sink);
+                params.extend(style.column_params(sink));
+                self.generate_layout("Column", params, body, sink)?;
+            }
+            "row" => {
+                let mut params = self.modifier_param(&style, sink);
+                params.extend(style.row_params(sink));
+                self.generate_layout("Row", params, body, sink)?;
+            }
+            "center" => {
+                sink.add_import("androidx.compose.ui.Alignment");
+                let mut params = self.modifier_param(&style, sink);
+                params.push("contentAlignment = Alignment.Center".to_string());
+                self.generate_layout("Box", params, body, sink)?;
+            }
+            "container" => {
+                let center_x = self.prop_bool(args, "center_x").unwrap_or(false);
+                let center_y = self.prop_bool(args, "center_y").unwrap_or(false);
+                let mut params = self.modifier_param(&style, sink);
+                let alignment = match (center_x, center_y) {
+                    (true, true) => Some("Center"),
+                    (true, false) => Some("TopCenter"),
+                    (false, true) => Some("CenterStart"),
+                    (false, false) => None,
+                };
+                if let Some(alignment) = alignment {
+                    sink.add_import("androidx.compose.ui.Alignment");
+                    params.push(format!("contentAlignment = Alignment.{}", alignment));
+                }
+                self.generate_layout("Box", params, body, sink)?;
+            }
+            "scrollable" => {
+                let mut style = style;
+                style.scroll = Some("verticalScroll");
+                let mut params = self.modifier_param(&style, sink);
+                params.extend(style.column_params(sink));
+                self.generate_layout("Column", params, body, sink)?;
+            }
+            "text" | "label" => {
+                let mut params = vec![format!("text = {}", self.content(args))];
+                params.extend(self.modifier_param(&style, sink));
+                params.extend(style.text_params(&self.theme, sink));
+                self.call(sink, "androidx.compose.material3.Text", &params);
+            }
+            "button" => {
+                let onclick = args
+                    .lookup("onclick")
+                    .map(|arg| self.handler(&arg.get_expr()))
+                    .unwrap_or_default();
+                let mut params = vec![format!("onClick = {{{}}}", pad(&onclick))];
+                params.extend(self.modifier_param(&style, sink));
+                self.open(sink, "androidx.compose.material3.Button", &params);
+                let mut label = vec![format!("text = {}", self.content(args))];
+                label.extend(style.text_params(&self.theme, sink));
+                self.call(sink, "androidx.compose.material3.Text", &label);
+                self.close(sink);
+            }
+            "input" => {
+                let mut params = Vec::new();
+                let bound = args
+                    .lookup("value")
+                    .or_else(|| args.get(0))
+                    .and_then(|arg| self.field_name(&arg.get_expr()));
+                // input(name) binds the field directly
+                match bound {
+                    Some(field) => {
+                        params.push(format!("value = {}", field));
+                        params.push(format!("onValueChange = {{ {} = it }}", field));
+                    }
+                    None => {
+                        params.push("value = \"\"".to_string());
+                        params.push("onValueChange = {}".to_string());
+                    }
+                }
+                if let Some(Expr::Str(s)) = args.get(0).map(|arg| arg.get_expr()) {
+                    sink.add_import("androidx.compose.material3.Text");
+                    params.push(format!("placeholder = {{ Text(text = {}) }}", kotlin_string(&s.to_string())));
+                }
+                params.extend(self.modifier_param(&style, sink));
+                self.call(sink, "androidx.compose.material3.TextField", &params);
+            }
+            "checkbox" | "radio" => {
+                let key = if name == "checkbox" { "is_checked" } else { "is_selected" };
+                let state = args.lookup(key).map(|arg| arg.get_expr());
+                let value = state.as_ref().map(|expr| self.expr(expr)).unwrap_or_else(|| "false".to_string());
+                // Only a checkbox bound to a field toggles it; the rest are display-only
+                let field = state.as_ref().and_then(|expr| self.field_name(expr)).filter(|_| name == "checkbox");
+
+                sink.add_import("androidx.compose.ui.Alignment");
+                let mut params = self.modifier_param(&style, sink);
+                params.push("verticalAlignment = Alignment.CenterVertically".to_string());
+                self.open(sink, "androidx.compose.foundation.layout.Row", &params);
+                if name == "checkbox" {
+                    let on_change = match field {
+                        Some(field) => format!("{{ {} = it }}", field),
+                        None => "null".to_string(),
+                    };
+                    self.call(
+                        sink,
+                        "androidx.compose.material3.Checkbox",
+                        &[format!("checked = {}", value), format!("onCheckedChange = {}", on_change)],
+                    );
+                } else {
+                    self.call(
+                        sink,
+                        "androidx.compose.material3.RadioButton",
+                        &[format!("selected = {}", value), "onClick = null".to_string()],
+                    );
+                }
+                let mut label = vec![format!("text = {}", self.content(args))];
+                label.extend(style.text_params(&self.theme, sink));
+                self.call(sink, "androidx.compose.material3.Text", &label);
+                self.close(sink);
+            }
+            "radio_group" => {
+                // radio_group("Small", "Large", selected: 0, onselect: Msg.Size)
+                let selected = args.lookup("selected").map(|arg| self.expr(&arg.get_expr()));
+                let onselect = args.lookup("onselect").map(|arg| self.handler(&arg.get_expr()));
+                let mut params = self.modifier_param(&style, sink);
+                params.extend(style.column_params(sink));
+                self.open(sink, "androidx.compose.foundation.layout.Column", &params);
+                sink.add_import("androidx.compose.ui.Alignment");
+                for (index, option) in Self::positional(args).iter().enumerate() {
+                    let Expr::Str(label) = option else { continue };
+                    self.open(
+                        sink,
+                        "androidx.compose.foundation.layout.Row",
+                        &["verticalAlignment = Alignment.CenterVertically".to_string()],
+                    );
+                    let selected = match &selected {
+                        Some(selected) => format!("{} == {}", selected, index),
+                        None => "false".to_string(),
+                    };
+                    let onclick = match &onselect {
+                        Some(onselect) => format!("{{ {} }}", onselect),
+                        None => "null".to_string(),
+                    };
+                    self.call(
+                        sink,
+                        "androidx.compose.material3.RadioButton",
+                        &[format!("selected = {}", selected), format!("onClick = {}", onclick)],
+                    );
+                    self.call(
+                        sink,
+                        "androidx.compose.material3.Text",
+                        &[format!("text = {}", kotlin_string(&label.to_string()))],
+                    );
+                    self.close(sink);
+                }
+                self.close(sink);
+            }
+            "select" => {
+                // Material has no plain select; show the current option
+                sink.writeln("// select: shows the first option");
+                let mut params = vec![format!("text = {}", self.content(args))];
+                params.extend(self.modifier_param(&style, sink));
+                params.extend(style.text_params(&self.theme, sink));
+                self.call(sink, "androidx.compose.material3.Text", &params);
+            }
+            _ => sink.writeln(&format!("// unknown widget: {}", name)),
+        }
+        Ok(())
+    }
+
+    fn generate_layout(
+        &self,
+        composable: &str,
+        params: Vec<String>,
+        body: Option<&Body>,
+        sink: &mut CodeSink,
+    ) -> Result<(), String> {
+        self.open(sink, &format!("androidx.compose.foundation.layout.{}", composable), &params);
+        self.generate_children(body, sink)?;
+        self.close(sink);
+        Ok(())
+    }
+
+    /// Write a call without content, importing the composable by its full path
+    fn call(&self, sink: &mut CodeSink, path: &str, params: &[String]) {
+        sink.add_import(path);
+        sink.writeln(&format!("{}({})", simple_name(path), params.join(", ")));
+    }
+
+    /// Write the start of a call with a content lambda
+    fn open(&self, sink: &mut CodeSink, path: &str, params: &[String]) {
+        sink.add_import(path);
+        if params.is_empty() {
+            sink.writeln(&format!("{} {{", simple_name(path)));
+        } else {
+            sink.writeln(&format!("{}({}) {{", simple_name(path), params.join(", ")));
+        }
+        sink.indent();
+    }
+
+    fn close(&self, sink: &mut CodeSink) {
+        sink.dedent();
+        sink.writeln("}");
+    }
+
+    /// Style of a widget, from legacy layout props and the style string
+    fn style(&self, name: &str, args: &Args) -> Result<ComposeStyle, String> {
+        let mut style = ComposeStyle::default();
+        if let Some(spacing) = self.prop_int(args, "spacing") {
+            style.gap = Some(spacing as f32);
+        }
+        if let Some(padding) = self.prop_int(args, "padding") {
+            style.padding = (Some(padding as f32), Some(padding as f32));
+        }
+        if let Some(width) = self.prop_int(args, "width") {
+            style.width = Some(format!("width({})", dp(width as f32)));
+        }
+        if let Some(height) = self.prop_int(args, "height") {
+            style.height = Some(format!("height({})", dp(height as f32)));
+        }
+        if let Some(Expr::Str(s)) = args.lookup("style").map(|arg| arg.get_expr()) {
+            let parsed = Style::parse(&s.to_string()).map_err(|e| format!("Invalid style on {}: {}", name, e))?;
+            for class in parsed.classes.iter() {
+                style.apply(class, &self.theme);
+            }
+        }
+        Ok(style)
+    }
+
+    /// The `modifier = Modifier...` parameter, or none if the style needs no modifiers
+    fn modifier_param(&self, style: &ComposeStyle, sink: &mut CodeSink) -> Vec<String> {
+        let Some(chain) = style.modifier(&self.theme, sink) else {
+            return Vec::new();
+        };
+        sink.add_import("androidx.compose.ui.Modifier");
+        vec![format!("modifier = Modifier{}", chain)]
+    }
+
+    /// Text content from the first positional argument
+    fn content(&self, args: &Args) -> String {
+        match Self::positional(args).first() {
+            Some(expr) => self.text_expr(expr),
+            None => "\"\"".to_string(),
+        }
+    }
+
+    /// A Kotlin `String` expression for `expr`
+    fn text_expr(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Str(s) => kotlin_string(&s.to_string()),
+            Expr::Int(n) => kotlin_string(&n.to_string()),
+            Expr::Bool(b) => kotlin_string(&b.to_string()),
+            _ => match self.field_name(expr) {
+                Some(field) if self.fields[&field] == "String" => field,
+                _ => format!("{}.toString()", self.expr(expr)),
+            },
+        }
+    }
+
+    /// Event handler calling the dispatcher, or an action function directly
+    fn handler(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Ident(name) if self.actions.contains(&name.to_string()) => format!("{}()", name),
+            _ => format!("on({})", self.expr(expr)),
+        }
+    }
+
+    fn positional(args: &Args) -> Vec<Expr> {
+        args.args
+            .iter()
+            .filter(|arg| !matches!(arg, Arg::Pair(..)))
+            .map(|arg| arg.get_expr())
+            .collect()
+    }
+
+    fn prop_int(&self, args: &Args, key: &str) -> Option<i64> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Int(n)) => Some(n as i64),
+            _ => None,
+        }
+    }
+
+    fn prop_bool(&self, args: &Args, key: &str) -> Option<bool> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Bool(b)) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The field an expression refers to: `count` or `self.count`
+    fn field_name(&self, expr: &Expr) -> Option<String> {
+        let name = match expr {
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+                (Expr::Ident(this), Expr::Ident(name)) if this.to_string() == "self" => name.to_string(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.fields.contains_key(&name).then_some(name)
+    }
+
+    /// Generate a Kotlin expression; state fields are plain local variables
+    fn expr(&self, expr: &Expr) -> String {
+        if let Some(field) = self.field_name(expr) {
+            return field;
+        }
+
+        match expr {
+            Expr::Str(s) => kotlin_string(&s.to_string()),
+            Expr::Int(n) => n.to_string(),
+            Expr::Bool(b) => b.to_string(),
+            // Also covers Msg.Inc parsed as a single identifier
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => format!("{}.{}", self.expr(lhs), self.expr(rhs)),
+            Expr::Bina(lhs, op, rhs) => format!("{} {} {}", self.expr(lhs), op, self.expr(rhs)),
+            Expr::Call(call) => {
+                let args: Vec<String> = call.args.args.iter().map(|arg| self.expr(&arg.get_expr())).collect();
+                match call.name.as_ref() {
+                    Expr::Ident(name) if name.to_string() == "print" => format!("println({})", args.join(", ")),
+                    name => format!("{}({})", self.expr(name), args.join(", ")),
+                }
+            }
+            _ => "null".to_string(),
+        }
+    }
+
+    /// Generate Kotlin statements for a method body
+    ///
+    /// `is` statements become a `when` on `subject`, the message parameter of
+    /// on(); it needs an `else` branch unless `exhaustive`.
+    fn generate_body_stmts(&self, body: &Body, subject: &str, exhaustive: bool, sink: &mut CodeSink) {
+        for stmt in &body.stmts {
+            match stmt {
+                Stmt::Store(store) => {
+                    let name = store.name.to_string();
+                    let value = self.expr(&store.expr);
+                    if self.fields.contains_key(&name) {
+                        sink.writeln(&format!("{} = {}", name, value));
+                    } else {
+                        sink.writeln(&format!("val {} = {}", name, value));
+                    }
+                }
+                Stmt::Is(is_stmt) => {
+                    sink.writeln(&format!("when ({}) {{", subject));
+                    sink.indent();
+                    for branch in &is_stmt.branches {
+                        if let IsBranch::EqBranch(pattern, body) = branch {
+                            let pattern = self.expr(pattern);
+                            if body.stmts.is_empty() {
+                                sink.writeln(&format!("{} -> {{}}", pattern));
+                                continue;
+                            }
+                            sink.writeln(&format!("{} -> {{", pattern));
+                            sink.indent();
+                            self.generate_body_stmts(body, subject, exhaustive, sink);
+                            sink.dedent();
+                            sink.writeln("}");
+                        }
+                    }
+                    if !exhaustive {
+                        sink.writeln("else -> {}");
+                    }
+                    sink.dedent();
+                    sink.writeln("}");
+                }
+                Stmt::Expr(expr) => sink.writeln(&self.expr(expr)),
+                _ => sink.writeln("// unsupported statement"),
+            }
+        }
+    }
+
+    /// Kotlin type of a field or parameter
+    fn kotlin_type(&self, ty: &Type) -> String {
+        match ty {
+            Type::Int => "Int".to_string(),
+            Type::Str(_) => "String".to_string(),
+            Type::Bool => "Boolean".to_string(),
+            Type::User(user) => user.name.to_string(),
+            _ => "Any".to_string(),
+        }
+    }
+
+    /// `mutableStateOf` call with the default value of a field type
+    fn initial_state(&self, ty: &Type) -> &'static str {
+        match ty {
+            Type::Int => "mutableStateOf(0)",
+            Type::Str(_) => "mutableStateOf(\"\")",
+            Type::Bool => "mutableStateOf(false)",
+            _ => "mutableStateOf<Any?>(null)",
+        }
+    }
+}
+
+impl Trans for ComposeCodeGenerator {
+    fn trans(&mut self, ast: Code, sink: &mut CodeSink) -> Result<(), String> {
+        self.generate_into(&ast, sink)
+    }
+}
+
+/// Style classes resolved into Compose terms
+///
+/// Modifiers are kept by kind and chained in a fixed order by `modifier`:
+/// outer spacing, size, shape and decoration, then inner padding.
+#[derive(Debug, Default)]
+struct ComposeStyle {
+    margin: (Option<f32>, Option<f32>),
+    width: Option<String>,
+    height: Option<String>,
+    weight: bool,
+    shadow: Option<f32>,
+    radius: Option<f32>,
+    background: Option<Color>,
+    border: Option<Color>,
+    padding: (Option<f32>, Option<f32>),
+    scroll: Option<&'static str>,
+    opacity: Option<f32>,
+    // Layout parameters
+    gap: Option<f32>,
+    items: Option<&'static str>,
+    justify: Option<&'static str>,
+    // Text parameters
+    color: Option<Color>,
+    font_size: Option<f32>,
+    font_weight: Option<&'static str>,
+    text_align: Option<&'static str>,
+}
+
+impl ComposeStyle {
+    fn apply(&mut self, class: &StyleClass, theme: &Theme) {
+        match class {
+            StyleClass::Padding(size) => self.padding = (Some(theme.spacing(size)), Some(theme.spacing(size))),
+            StyleClass::PaddingX(size) => self.padding.0 = Some(theme.spacing(size)),
+            StyleClass::PaddingY(size) => self.padding.1 = Some(theme.spacing(size)),
+            StyleClass::Margin(size) => self.margin = (Some(theme.spacing(size)), Some(theme.spacing(size))),
+            StyleClass::MarginX(size) => self.margin.0 = Some(theme.spacing(size)),
+            StyleClass::MarginY(size) => self.margin.1 = Some(theme.spacing(size)),
+            StyleClass::Gap(size) => self.gap = Some(theme.spacing(size)),
+
+            StyleClass::BackgroundColor(color) => self.background = Some(*color),
+            StyleClass::TextColor(color) => self.color = Some(*color),
+
+            StyleClass::Flex1 => self.weight = true,
+            StyleClass::ItemsStart => self.items = Some("Start"),
+            StyleClass::ItemsCenter => self.items = Some("Center"),
+            StyleClass::ItemsEnd => self.items = Some("End"),
+            StyleClass::JustifyStart => self.justify = Some("Start"),
+            StyleClass::JustifyCenter => self.justify = Some("Center"),
+            StyleClass::JustifyEnd => self.justify = Some("End"),
+            StyleClass::JustifyBetween => self.justify = Some("SpaceBetween"),
+
+            StyleClass::Width(size) => self.width = Some(size_modifier("Width", "width", size, theme)),
+            StyleClass::Height(size) => self.height = Some(size_modifier("Height", "height", size, theme)),
+
+            StyleClass::Rounded | StyleClass::RoundedMd => self.radius = Some(theme.radii.md),
+            StyleClass::RoundedSm => self.radius = Some(theme.radii.sm),
+            StyleClass::RoundedLg => self.radius = Some(theme.radii.lg),
+            StyleClass::RoundedXl => self.radius = Some(theme.radii.xl),
+            StyleClass::Rounded2Xl => self.radius = Some(theme.radii.xxl),
+            StyleClass::Rounded3Xl => self.radius = Some(theme.radii.xxxl),
+            StyleClass::RoundedFull => self.radius = Some(theme.radii.full),
+            StyleClass::RoundedPx(px) => self.radius = Some(*px as f32),
+
+            StyleClass::Border => self.border = Some(self.border.unwrap_or(theme.colors.border)),
+            StyleClass::Border0 => self.border = None,
+            StyleClass::BorderColor(color) => self.border = Some(*color),
+
+            StyleClass::TextXs => self.font_size = Some(theme.font_sizes.xs),
+            StyleClass::TextSm => self.font_size = Some(theme.font_sizes.sm),
+            StyleClass::TextBase => self.font_size = Some(theme.font_sizes.base),
+            StyleClass::TextLg => self.font_size = Some(theme.font_sizes.lg),
+            StyleClass::TextXl => self.font_size = Some(theme.font_sizes.xl),
+            StyleClass::Text2Xl => self.font_size = Some(theme.font_sizes.xxl),
+            StyleClass::Text3Xl => self.font_size = Some(theme.font_sizes.xxxl),
+            StyleClass::FontSize(px) => self.font_size = Some(*px as f32),
+            StyleClass::FontBold => self.font_weight = Some("Bold"),
+            StyleClass::FontMedium => self.font_weight = Some("Medium"),
+            StyleClass::FontNormal => self.font_weight = Some("Normal"),
+            StyleClass::TextCenter => self.text_align = Some("Center"),
+            StyleClass::TextLeft => self.text_align = Some("Start"),
+            StyleClass::TextRight => self.text_align = Some("End"),
+
+            // Elevations roughly matching Tailwind's shadow sizes
+            StyleClass::ShadowSm => self.shadow = Some(1.0),
+            StyleClass::Shadow | StyleClass::ShadowMd => self.shadow = Some(4.0),
+            StyleClass::ShadowLg => self.shadow = Some(8.0),
+            StyleClass::ShadowXl => self.shadow = Some(12.0),
+            StyleClass::Shadow2Xl => self.shadow = Some(24.0),
+            StyleClass::ShadowNone => self.shadow = None,
+            StyleClass::Opacity(value) => self.opacity = Some(*value as f32 / 100.0),
+
+            StyleClass::OverflowAuto | StyleClass::OverflowScroll | StyleClass::OverflowYAuto => {
+                self.scroll = Some("verticalScroll")
+            }
+            StyleClass::OverflowXAuto => self.scroll = Some("horizontalScroll"),
+
+            // Direction comes from the widget, and Compose has no positioning,
+            // grid or display classes
+            _ => {}
+        }
+    }
+
+    /// The chained modifier calls, e.g. `.padding(16.dp).fillMaxWidth()`
+    fn modifier(&self, theme: &Theme, sink: &mut CodeSink) -> Option<String> {
+        let mut chain = Vec::new();
+        if self.weight {
+            // A RowScope/ColumnScope member, so it needs no import; flex-1
+            // only means something inside a row or column anyway
+            chain.push("weight(1f)".to_string());
+        }
+        let mut call = |sink: &mut CodeSink, import: &str, call: String| {
+            sink.add_import(import);
+            chain.push(call);
+        };
+
+        if let Some(padding) = padding_args(self.margin) {
+            call(sink, "androidx.compose.foundation.layout.padding", format!("padding({})", padding));
+        }
+        for size in [&self.width, &self.height].into_iter().flatten() {
+            let name = size.split('(').next().unwrap_or_default();
+            call(sink, &format!("androidx.compose.foundation.layout.{}", name), size.clone());
+        }
+
+        let shape = self.radius.map(|radius| {
+            sink.add_import("androidx.compose.foundation.shape.RoundedCornerShape");
+            format!("RoundedCornerShape({})", dp(radius))
+        });
+        if let Some(elevation) = self.shadow {
+            let shape = shape.as_ref().map(|shape| format!(", {}", shape)).unwrap_or_default();
+            call(sink, "androidx.compose.ui.draw.shadow", format!("shadow({}{})", dp(elevation), shape));
+        }
+        if let Some(shape) = &shape {
+            call(sink, "androidx.compose.ui.draw.clip", format!("clip({})", shape));
+        }
+        if let Some(color) = &self.background {
+            let color = kotlin_color(color, theme, sink);
+            call(sink, "androidx.compose.foundation.background", format!("background({})", color));
+        }
+        if let Some(color) = &self.border {
+            let color = kotlin_color(color, theme, sink);
+            let shape = shape.as_ref().map(|shape| format!(", {}", shape)).unwrap_or_default();
+            call(sink, "androidx.compose.foundation.border", format!("border({}, {}{})", dp(1.0), color, shape));
+        }
+        if let Some(scroll) = self.scroll {
+            sink.add_import("androidx.compose.foundation.rememberScrollState");
+            call(sink, &format!("androidx.compose.foundation.{}", scroll), format!("{}(rememberScrollState())", scroll));
+        }
+        if let Some(padding) = padding_args(self.padding) {
+            call(sink, "androidx.compose.foundation.layout.padding", format!("padding({})", padding));
+        }
+        if let Some(opacity) = self.opacity {
+            call(sink, "androidx.compose.ui.draw.alpha", format!("alpha({}f)", number(opacity)));
+        }
+
+        if chain.is_empty() {
+            return None;
+        }
+        if chain.iter().any(|call| call.contains(".dp")) {
+            sink.add_import("androidx.compose.ui.unit.dp");
+        }
+        Some(chain.iter().map(|call| format!(".{}", call)).collect())
+    }
+
+    /// Arrangement and alignment parameters of a Column
+    fn column_params(&self, sink: &mut CodeSink) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(arrangement) = self.arrangement(("Top", "Bottom"), "CenterVertically", sink) {
+            params.push(format!("verticalArrangement = {}", arrangement));
+        }
+        if let Some(items) = self.items {
+            let alignment = match items {
+                "Center" => "CenterHorizontally",
+                other => other,
+            };
+            sink.add_import("androidx.compose.ui.Alignment");
+            params.push(format!("horizontalAlignment = Alignment.{}", alignment));
+        }
+        params
+    }
+
+    /// Arrangement and alignment parameters of a Row
+    fn row_params(&self, sink: &mut CodeSink) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(arrangement) = self.arrangement(("Start", "End"), "CenterHorizontally", sink) {
+            params.push(format!("horizontalArrangement = {}", arrangement));
+        }
+        if let Some(items) = self.items {
+            let alignment = match items {
+                "Start" => "Top",
+                "End" => "Bottom",
+                _ => "CenterVertically",
+            };
+            sink.add_import("androidx.compose.ui.Alignment");
+            params.push(format!("verticalAlignment = Alignment.{}", alignment));
+        }
+        params
+    }
+
+    /// Main-axis arrangement from gap and justify
+    ///
+    /// `ends` names the axis's start and end arrangements, `center` its
+    /// centered alignment for combining with a gap.
+    fn arrangement(&self, ends: (&str, &str), center: &str, sink: &mut CodeSink) -> Option<String> {
+        let arrangement = match (self.gap, self.justify) {
+            (_, Some("SpaceBetween")) => "Arrangement.SpaceBetween".to_string(),
+            (Some(gap), Some("Center")) => {
+                sink.add_import("androidx.compose.ui.Alignment");
+                format!("Arrangement.spacedBy({}, Alignment.{})", dp(gap), center)
+            }
+            (Some(gap), _) => format!("Arrangement.spacedBy({})", dp(gap)),
+            (None, Some("Center")) => "Arrangement.Center".to_string(),
+            (None, Some("Start")) => format!("Arrangement.{}", ends.0),
+            (None, Some(_)) => format!("Arrangement.{}", ends.1),
+            (None, None) => return None,
+        };
+        sink.add_import("androidx.compose.foundation.layout.Arrangement");
+        if self.gap.is_some() {
+            sink.add_import("androidx.compose.ui.unit.dp");
+        }
+        Some(arrangement)
+    }
+
+    /// Parameters of a `Text`: color, size, weight and alignment
+    fn text_params(&self, theme: &Theme, sink: &mut CodeSink) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(color) = &self.color {
+            params.push(format!("color = {}", kotlin_color(color, theme, sink)));
+        }
+        if let Some(size) = self.font_size {
+            sink.add_import("androidx.compose.ui.unit.sp");
+            params.push(format!("fontSize = {}.sp", number(size)));
+        }
+        if let Some(weight) = self.font_weight {
+            sink.add_import("androidx.compose.ui.text.font.FontWeight");
+            params.push(format!("fontWeight = FontWeight.{}", weight));
+        }
+        if let Some(align) = self.text_align {
+            sink.add_import("androidx.compose.ui.text.style.TextAlign");
+            params.push(format!("textAlign = TextAlign.{}", align));
+        }
+        params
+    }
+}
+
+/// Size modifier call, e.g. `fillMaxWidth(0.5f)` or `width(256.dp)`
+fn size_modifier(axis: &str, name: &str, size: &SizeValue, theme: &Theme) -> String {
+    let fraction = match size {
+        SizeValue::Full => return format!("fillMax{}()", axis),
+        SizeValue::Auto => return format!("wrapContent{}()", axis),
+        SizeValue::Half => "0.5f",
+        SizeValue::Third => "0.333f",
+        SizeValue::TwoThirds => "0.667f",
+        SizeValue::Quarter => "0.25f",
+        SizeValue::ThreeQuarters => "0.75f",
+        _ => return format!("{}({})", name, dp(theme.spacing(size))),
+    };
+    format!("fillMax{}({})", axis, fraction)
+}
+
+/// Arguments of a `padding` call from horizontal and vertical amounts
+fn padding_args((horizontal, vertical): (Option<f32>, Option<f32>)) -> Option<String> {
+    match (horizontal, vertical) {
+        (None, None) => None,
+        (Some(h), Some(v)) if h == v => Some(dp(h)),
+        (Some(h), None) => Some(format!("horizontal = {}", dp(h))),
+        (None, Some(v)) => Some(format!("vertical = {}", dp(v))),
+        (Some(h), Some(v)) => Some(format!("horizontal = {}, vertical = {}", dp(h), dp(v))),
+    }
+}
+
+/// `Color(0xAARRGGBB)` of a color under `theme`
+fn kotlin_color(color: &Color, theme: &Theme, sink: &mut CodeSink) -> String {
+    sink.add_import("androidx.compose.ui.graphics.Color");
+    let (r, g, b, a) = theme.rgba(color);
+    let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("Color(0x{:02X}{:02X}{:02X}{:02X})", byte(a), byte(r), byte(g), byte(b))
+}
+
+fn dp(value: f32) -> String {
+    format!("{}.dp", number(value))
+}
+
+/// A number without a trailing `.0`, as Kotlin `Int.dp` and `Float.dp` both work
+fn number(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Last segment of an import path
+fn simple_name(path: &str) -> &str {
+    path.rsplit('.').next().unwrap_or(path)
+}
+
+/// Lambda body with spaces around it, or nothing for an empty lambda
+fn pad(body: &str) -> String {
+    if body.is_empty() {
+        String::new()
+    } else {
+        format!(" {} ", body)
+    }
+}
+
+fn kotlin_string(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_modifier_chain() {
+        let theme = Theme::light();
+        let mut style = ComposeStyle::default();
+        for class in Style::parse("p-4 w-full bg-white rounded-lg opacity-50").unwrap().classes.iter() {
+            style.apply(class, &theme);
+        }
+        let mut sink = CodeSink::new();
+        let chain = style.modifier(&theme, &mut sink).unwrap();
+
+        assert_eq!(
+            chain,
+            format!(
+                ".fillMaxWidth().clip(RoundedCornerShape({})).background(Color(0xFFFFFFFF)).padding(16.dp).alpha(0.5f)",
+                dp(theme.radii.lg)
+            )
+        );
+        assert!(sink.imports.contains("androidx.compose.foundation.layout.fillMaxWidth"));
+        assert!(sink.imports.contains("androidx.compose.ui.unit.dp"));
+    }
+
+    #[test]
+    fn test_arrangement() {
+        let mut style = ComposeStyle { gap: Some(8.0), ..Default::default() };
+        let mut sink = CodeSink::new();
+        assert_eq!(style.column_params(&mut sink), vec!["verticalArrangement = Arrangement.spacedBy(8.dp)"]);
+
+        style.justify = Some("Center");
+        style.items = Some("Center");
+        assert_eq!(
+            style.row_params(&mut sink),
+            vec![
+                "horizontalArrangement = Arrangement.spacedBy(8.dp, Alignment.CenterHorizontally)",
+                "verticalAlignment = Alignment.CenterVertically",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kotlin_string() {
+        assert_eq!(kotlin_string("a \"b\" $c\n"), "\"a \\\"b\\\" \\$c\\n\"");
+        assert_eq!(number(2.5), "2.5");
+        assert_eq!(number(16.0), "16");
+    }
+}
@@ -1,7 +1,8 @@
 // Auto UI Transpiler
 //
-// This module provides transpilation from Auto language .at files to auto-ui Rust code.
-// It parses Auto UI widget definitions and generates Component implementations.
+// This module provides transpilation from Auto language .at files to auto-ui Rust code
+// (Component implementations), to Vue single-file components, to Jetpack
+// Compose functions in Kotlin and to HarmonyOS ArkUI components in ArkTS.
 
 use auto_lang::ast::Code;
 use std::collections::HashSet;
@@ -9,28 +10,43 @@ use std::io::Write;
 
 pub mod auto_ui_trans;
 pub mod rust_gen;
+pub mod vue_gen;
+pub mod compose_gen;
+pub mod arkts_gen;
 pub mod api;
 
 pub use auto_ui_trans::AutoUITrans;
 pub use rust_gen::RustCodeGenerator;
+pub use vue_gen::VueCodeGenerator;
+pub use compose_gen::ComposeCodeGenerator;
+pub use arkts_gen::ArkTsCodeGenerator;
 
 /// High-level transpilation API
 #[cfg(feature = "transpiler")]
-pub use api::{transpile_file, transpile_ast};
+pub use api::{transpile_file, transpile_ast, transpile_file_to, transpile_ast_to, Target};
 
-/// Sink for collecting generated Rust code
+/// Sink for collecting generated code
 pub struct CodeSink {
     pub imports: HashSet<String>,
     pub body: Vec<u8>,
     pub indent: usize,
+    /// Renders one import path as a line of the target language
+    pub import_line: fn(&str) -> String,
 }
 
 impl CodeSink {
+    /// Sink for Rust code, with `use` imports
     pub fn new() -> Self {
+        Self::with_imports(|path| format!("use {};", path))
+    }
+
+    /// Sink for another language, e.g. `CodeSink::with_imports(|path| format!("import {}", path))`
+    pub fn with_imports(import_line: fn(&str) -> String) -> Self {
         Self {
             imports: HashSet::new(),
             body: Vec::new(),
             indent: 0,
+            import_line,
         }
     }
 
@@ -72,7 +88,8 @@ impl CodeSink {
             let mut imports: Vec<_> = self.imports.iter().cloned().collect();
             imports.sort();
             for import in imports {
-                result.push_str(&format!("use {};\n", import));
+                result.push_str(&(self.import_line)(&import));
+                result.push('\n');
             }
             result.push('\n');
         }
@@ -82,7 +82,10 @@ impl RustCodeGenerator {
     }
 
     /// Analyze widget to extract messages and imports
-    fn analyze_widget(&mut self, type_decl: &TypeDecl) -> Result<(), String> {
+    ///
+    /// Other generators run this too, and read the variants back through
+    /// `message_variants`.
+    pub(crate) fn analyze_widget(&mut self, type_decl: &TypeDecl) -> Result<(), String> {
         // Add default imports
         self.imports.insert("auto_ui::Component".to_string());
         self.imports.insert("auto_ui::View".to_string());
@@ -99,6 +102,13 @@ impl RustCodeGenerator {
         Ok(())
     }
 
+    /// Message variants found by `analyze_widget`, sorted by name
+    pub(crate) fn message_variants(&self) -> Vec<&MessageVariant> {
+        let mut messages: Vec<_> = self.messages.iter().collect();
+        messages.sort_by_key(|m| &m.name);
+        messages
+    }
+
     /// Analyze view() method to collect UI component usage
     fn analyze_view_method(&mut self, body: &Body) -> Result<(), String> {
         for stmt in &body.stmts {
@@ -139,7 +149,7 @@ impl RustCodeGenerator {
                     "checkbox" => {
                         self.imports.insert("auto_ui::View".to_string());
                     }
-                    "radio" => {
+                    "radio" | "radio_group" => {
                         self.imports.insert("auto_ui::View".to_string());
                     }
                     "select" => {
@@ -232,8 +242,7 @@ impl RustCodeGenerator {
 
     /// Generate message enum definition
     fn generate_message_enum(&self) -> String {
-        let mut messages: Vec<_> = self.messages.iter().collect();
-        messages.sort_by_key(|m| &m.name);
+        let messages = self.message_variants();
 
         let mut code = String::new();
         code.push_str("#[derive(Clone, Copy, Debug, PartialEq)]\n");
@@ -612,6 +621,7 @@ impl RustCodeGenerator {
             "input" => self.generate_input_node(node),
             "checkbox" => self.generate_checkbox_node(node),
             "radio" => self.generate_radio_node(node),
+            "radio_group" => self.generate_radio_group_node(node),
             "select" => self.generate_select_node(node),
             "list" => self.generate_list_node(node),
             "table" => self.generate_table_node(node),
@@ -843,6 +853,29 @@ impl RustCodeGenerator {
         Ok(format!("View::radio({}, {})", is_selected, label))
     }
 
+    fn generate_radio_group_node(&self, node: &Node) -> Result<String, String> {
+        // radio_group("Small", "Large", selected: 0, onselect: Msg.Size)
+        let options: Vec<String> = node.args.args.iter()
+            .filter(|arg| !matches!(arg, Arg::Pair(..)))
+            .filter_map(|arg| match arg.get_expr() {
+                Expr::Str(s) => Some(format!("\"{}\".to_string()", s)),
+                _ => None,
+            })
+            .collect();
+
+        let mut code = format!("View::radio_group(vec![{}])", options.join(", "));
+
+        if let Some(selected) = self.get_prop_u16(node, "selected") {
+            code.push_str(&format!(".selected({})", selected));
+        }
+        if let Some(arg) = node.args.lookup("onselect") {
+            let msg = self.expr_to_rust(&arg.get_expr());
+            code.push_str(&format!(".on_choose(|_index, _value| {})", msg));
+        }
+
+        Ok(code)
+    }
+
     fn generate_select_node(&self, node: &Node) -> Result<String, String> {
         let options = self.get_prop_string(node, "options").unwrap_or("\"[]\"".to_string());
 
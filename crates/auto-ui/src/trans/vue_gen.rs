@@ -0,0 +1,547 @@
+// Vue Code Generator for AutoUI Transpiler
+//
+// This module generates Vue 3 single-file components from Auto language
+// widget definitions, so web projects can share widgets with the Rust side:
+//
+// - fields become `ref` state
+// - `fn on` becomes a message dispatcher, called from event handlers
+// - the view tree becomes the template; `style` strings pass through as
+//   Tailwind classes, and spacing/padding become arbitrary-value classes
+
+use super::rust_gen::{MessageVariant, RustCodeGenerator};
+use auto_lang::ast::*;
+use auto_val::Op;
+use std::collections::HashSet;
+
+/// Where a generated expression is evaluated
+///
+/// Templates unwrap refs automatically; script code reads them via `.value`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scope {
+    Template,
+    Script,
+}
+
+/// Vue code generator for widgets
+pub struct VueCodeGenerator {
+    /// Field names of the current widget, which are refs
+    fields: HashSet<String>,
+    /// Methods other than view() and on(), which become plain functions
+    actions: HashSet<String>,
+}
+
+impl VueCodeGenerator {
+    pub fn new() -> Self {
+        Self {
+            fields: HashSet::new(),
+            actions: HashSet::new(),
+        }
+    }
+
+    /// Generate a complete `.vue` file from widget type declaration
+    pub fn generate_widget(&mut self, type_decl: &TypeDecl) -> Result<String, String> {
+        self.fields = type_decl.members.iter().map(|m| m.name.to_string()).collect();
+        self.actions = type_decl
+            .methods
+            .iter()
+            .filter(|m| m.name != "view" && m.name != "on")
+            .map(|m| m.name.to_string())
+            .collect();
+
+        // Message variants are found the same way as for Rust output
+        let mut analysis = RustCodeGenerator::new();
+        analysis.analyze_widget(type_decl)?;
+
+        let mut code = String::new();
+
+        // Add file header
+        code.push_str("<!-- Auto-generated from Auto language -->\n");
+        code.push_str("<!-- DO NOT EDIT - changes will be overwritten -->\n\n");
+
+        code.push_str("<script setup>\n");
+        code.push_str("import { ref } from 'vue'\n\n");
+
+        let variants = analysis.message_variants();
+        if !variants.is_empty() {
+            code.push_str(&self.generate_message_object(&variants));
+            code.push('\n');
+        }
+
+        if !type_decl.members.is_empty() {
+            for member in &type_decl.members {
+                code.push_str(&format!("const {} = ref({})\n", member.name, self.default_value(&member.ty)));
+            }
+            code.push('\n');
+        }
+
+        code.push_str(&self.generate_dispatcher(type_decl));
+
+        for method in type_decl.methods.iter().filter(|m| self.actions.contains(&m.name.to_string())) {
+            code.push('\n');
+            code.push_str(&format!("function {}() {{\n", method.name));
+            code.push_str(&self.generate_body_stmts(&method.body, 1, "undefined"));
+            code.push_str("}\n");
+        }
+
+        code.push_str("</script>\n\n");
+
+        code.push_str("<template>\n");
+        code.push_str(&self.generate_template(type_decl));
+        code.push_str("</template>\n");
+
+        self.fields.clear();
+        self.actions.clear();
+        Ok(code)
+    }
+
+    /// Generate the message variants as a frozen object, the JS stand-in for an enum
+    fn generate_message_object(&self, variants: &[&MessageVariant]) -> String {
+        let entries: Vec<String> = variants
+            .iter()
+            .map(|v| format!("{}: '{}'", v.name, v.name))
+            .collect();
+        format!("const Msg = Object.freeze({{ {} }})\n", entries.join(", "))
+    }
+
+    /// Generate the on() dispatcher
+    fn generate_dispatcher(&self, type_decl: &TypeDecl) -> String {
+        let Some(method) = type_decl.methods.iter().find(|m| m.name == "on") else {
+            return "function on(_msg) {}\n".to_string();
+        };
+
+        let param = method
+            .params
+            .first()
+            .map(|p| p.name.to_string())
+            .unwrap_or_else(|| "msg".to_string());
+
+        let mut code = format!("function on({}) {{\n", param);
+        code.push_str(&self.generate_body_stmts(&method.body, 1, &param));
+        code.push_str("}\n");
+        code
+    }
+
+    /// Generate the template from view()
+    fn generate_template(&self, type_decl: &TypeDecl) -> String {
+        let Some(method) = type_decl.methods.iter().find(|m| m.name == "view") else {
+            return String::new();
+        };
+
+        let roots: Vec<&Stmt> = method.body.stmts.iter().filter(|stmt| Self::is_view_stmt(stmt)).collect();
+        let mut code = String::new();
+        if roots.len() > 1 {
+            // Multiple expressions - wrap in a col, like the Rust output
+            code.push_str("  <div class=\"flex flex-col\">\n");
+            for stmt in roots {
+                self.generate_view_stmt(stmt, 2, &mut code);
+            }
+            code.push_str("  </div>\n");
+        } else {
+            for stmt in roots {
+                self.generate_view_stmt(stmt, 1, &mut code);
+            }
+        }
+        code
+    }
+
+    fn is_view_stmt(stmt: &Stmt) -> bool {
+        matches!(
+            stmt,
+            Stmt::Node(_) | Stmt::Expr(Expr::Node(_)) | Stmt::Expr(Expr::Call(_)) | Stmt::Expr(Expr::Ident(_))
+        )
+    }
+
+    fn generate_view_stmt(&self, stmt: &Stmt, depth: usize, code: &mut String) {
+        match stmt {
+            Stmt::Node(node) | Stmt::Expr(Expr::Node(node)) => {
+                self.generate_element(node.name.as_str(), &node.args, Some(&node.body), depth, code)
+            }
+            Stmt::Expr(Expr::Call(call)) => match call.name.as_ref() {
+                Expr::Ident(name) => self.generate_element(&name.to_string(), &call.args, None, depth, code),
+                _ => line(code, depth, "<!-- unknown call -->"),
+            },
+            Stmt::Expr(Expr::Ident(name)) => {
+                // Reference to field
+                line(code, depth, &format!("<span>{{{{ {} }}}}</span>", name));
+            }
+            _ => {}
+        }
+    }
+
+    fn generate_children(&self, body: Option<&Body>, depth: usize, code: &mut String) {
+        for stmt in body.into_iter().flat_map(|body| &body.stmts) {
+            self.generate_view_stmt(stmt, depth, code);
+        }
+    }
+
+    /// Generate one widget; nodes and calls share this, calls have no body
+    fn generate_element(&self, name: &str, args: &Args, body: Option<&Body>, depth: usize, code: &mut String) {
+        match name {
+            "col" | "column" => self.generate_layout("div", &["flex", "flex-col"], args, body, depth, code),
+            "row" => self.generate_layout("div", &["flex", "flex-row"], args, body, depth, code),
+            "center" => {
+                self.generate_layout("div", &["flex", "items-center", "justify-center"], args, body, depth, code)
+            }
+            "container" => {
+                let center_x = self.prop_bool(args, "center_x").unwrap_or(false);
+                let center_y = self.prop_bool(args, "center_y").unwrap_or(false);
+                let mut base = vec![];
+                if center_x || center_y {
+                    base.push("flex");
+                }
+                if center_x {
+                    base.push("justify-center");
+                }
+                if center_y {
+                    base.push("items-center");
+                }
+                self.generate_layout("div", &base, args, body, depth, code)
+            }
+            "scrollable" => self.generate_layout("div", &["overflow-auto"], args, body, depth, code),
+            "list" => {
+                line(code, depth, &format!("<ul{}>", self.class_attr(&[], args)));
+                for stmt in body.into_iter().flat_map(|body| &body.stmts) {
+                    if Self::is_view_stmt(stmt) {
+                        line(code, depth + 1, "<li>");
+                        self.generate_view_stmt(stmt, depth + 2, code);
+                        line(code, depth + 1, "</li>");
+                    }
+                }
+                line(code, depth, "</ul>");
+            }
+            "table" => line(code, depth, &format!("<table{}></table>", self.class_attr(&[], args))),
+            "text" | "label" => {
+                let content = self.content(args);
+                line(code, depth, &format!("<span{}>{}</span>", self.class_attr(&[], args), content));
+            }
+            "button" => {
+                let onclick = args
+                    .lookup("onclick")
+                    .map(|arg| format!(" @click=\"{}\"", escape_html(&self.handler(&arg.get_expr()))))
+                    .unwrap_or_default();
+                line(
+                    code,
+                    depth,
+                    &format!(
+                        "<button type=\"button\"{}{}>{}</button>",
+                        self.class_attr(&[], args),
+                        onclick,
+                        self.content(args)
+                    ),
+                );
+            }
+            "input" => {
+                let mut attrs = String::new();
+                match args.get(0).map(|arg| arg.get_expr()) {
+                    // input(name) binds the field directly
+                    Some(expr) if self.field_name(&expr).is_some() => {
+                        attrs.push_str(&format!(" v-model=\"{}\"", self.field_name(&expr).unwrap()));
+                    }
+                    Some(Expr::Str(s)) => attrs.push_str(&format!(" placeholder=\"{}\"", escape_html(&s.to_string()))),
+                    _ => {}
+                }
+                if let Some(value) = args.lookup("value").map(|arg| arg.get_expr()) {
+                    match self.field_name(&value) {
+                        Some(field) => attrs.push_str(&format!(" v-model=\"{}\"", field)),
+                        None => attrs.push_str(&format!(" :value=\"{}\"", escape_html(&self.expr(&value, Scope::Template)))),
+                    }
+                }
+                line(code, depth, &format!("<input type=\"text\"{}{}>", attrs, self.class_attr(&[], args)));
+            }
+            "checkbox" | "radio" => {
+                let key = if name == "checkbox" { "is_checked" } else { "is_selected" };
+                let state = match args.lookup(key).map(|arg| arg.get_expr()) {
+                    Some(expr) => match self.field_name(&expr) {
+                        Some(field) if name == "checkbox" => format!(" v-model=\"{}\"", field),
+                        _ => format!(" :checked=\"{}\"", escape_html(&self.expr(&expr, Scope::Template))),
+                    },
+                    None => String::new(),
+                };
+                line(
+                    code,
+                    depth,
+                    &format!(
+                        "<label{}><input type=\"{}\"{}> {}</label>",
+                        self.class_attr(&[], args),
+                        name,
+                        state,
+                        self.content(args)
+                    ),
+                );
+            }
+            "radio_group" => {
+                // radio_group("Small", "Large", selected: 0, onselect: Msg.Size)
+                let selected = args.lookup("selected").map(|arg| arg.get_expr());
+                let onselect = args.lookup("onselect").map(|arg| self.handler(&arg.get_expr()));
+                line(code, depth, &format!("<fieldset{}>", self.class_attr(&[], args)));
+                for (index, option) in Self::positional(args).iter().enumerate() {
+                    let Expr::Str(label) = option else { continue };
+                    let mut attrs = String::new();
+                    if let Some(selected) = &selected {
+                        let selected = self.expr(selected, Scope::Template);
+                        attrs.push_str(&format!(" :checked=\"{}\"", escape_html(&format!("{} === {}", selected, index))));
+                    }
+                    if let Some(onselect) = &onselect {
+                        attrs.push_str(&format!(" @change=\"{}\"", escape_html(onselect)));
+                    }
+                    line(
+                        code,
+                        depth + 1,
+                        &format!("<label><input type=\"radio\"{}> {}</label>", attrs, escape_html(&label.to_string())),
+                    );
+                }
+                line(code, depth, "</fieldset>");
+            }
+            "select" => {
+                line(code, depth, &format!("<select{}>", self.class_attr(&[], args)));
+                for option in Self::positional(args) {
+                    if let Expr::Str(s) = option {
+                        line(code, depth + 1, &format!("<option>{}</option>", escape_html(&s.to_string())));
+                    }
+                }
+                line(code, depth, "</select>");
+            }
+            _ => line(code, depth, &format!("<!-- unknown widget: {} -->", name)),
+        }
+    }
+
+    fn generate_layout(
+        &self,
+        tag: &str,
+        base: &[&str],
+        args: &Args,
+        body: Option<&Body>,
+        depth: usize,
+        code: &mut String,
+    ) {
+        line(code, depth, &format!("<{}{}>", tag, self.class_attr(base, args)));
+        self.generate_children(body, depth + 1, code);
+        line(code, depth, &format!("</{}>", tag));
+    }
+
+    /// `class` attribute from base classes, legacy layout props and the style string
+    fn class_attr(&self, base: &[&str], args: &Args) -> String {
+        let mut classes: Vec<String> = base.iter().map(|c| c.to_string()).collect();
+        if let Some(spacing) = self.prop_int(args, "spacing") {
+            classes.push(format!("gap-[{}px]", spacing));
+        }
+        if let Some(padding) = self.prop_int(args, "padding") {
+            classes.push(format!("p-[{}px]", padding));
+        }
+        if let Some(width) = self.prop_int(args, "width") {
+            classes.push(format!("w-[{}px]", width));
+        }
+        if let Some(height) = self.prop_int(args, "height") {
+            classes.push(format!("h-[{}px]", height));
+        }
+        if let Some(Expr::Str(style)) = args.lookup("style").map(|arg| arg.get_expr()) {
+            classes.push(style.to_string());
+        }
+
+        if classes.is_empty() {
+            String::new()
+        } else {
+            format!(" class=\"{}\"", escape_html(&classes.join(" ")))
+        }
+    }
+
+    /// Element content from the first positional argument
+    fn content(&self, args: &Args) -> String {
+        match Self::positional(args).first() {
+            Some(Expr::Str(s)) => escape_html(&s.to_string()),
+            Some(expr) => format!("{{{{ {} }}}}", self.expr(expr, Scope::Template)),
+            None => String::new(),
+        }
+    }
+
+    /// Event handler calling the dispatcher, or an action method directly
+    fn handler(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Ident(name) if self.actions.contains(&name.to_string()) => format!("{}()", name),
+            _ => format!("on({})", self.expr(expr, Scope::Template)),
+        }
+    }
+
+    fn positional(args: &Args) -> Vec<Expr> {
+        args.args
+            .iter()
+            .filter(|arg| !matches!(arg, Arg::Pair(..)))
+            .map(|arg| arg.get_expr())
+            .collect()
+    }
+
+    fn prop_int(&self, args: &Args, key: &str) -> Option<i64> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Int(n)) => Some(n as i64),
+            _ => None,
+        }
+    }
+
+    fn prop_bool(&self, args: &Args, key: &str) -> Option<bool> {
+        match args.lookup(key).map(|arg| arg.get_expr()) {
+            Some(Expr::Bool(b)) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The field an expression refers to: `count` or `self.count`
+    fn field_name(&self, expr: &Expr) -> Option<String> {
+        let name = match expr {
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+                (Expr::Ident(this), Expr::Ident(name)) if this.to_string() == "self" => name.to_string(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.fields.contains(&name).then_some(name)
+    }
+
+    /// Generate a JavaScript expression
+    fn expr(&self, expr: &Expr, scope: Scope) -> String {
+        if let Some(field) = self.field_name(expr) {
+            return match scope {
+                Scope::Template => field,
+                Scope::Script => format!("{}.value", field),
+            };
+        }
+
+        match expr {
+            Expr::Str(s) => js_string(&s.to_string()),
+            Expr::Int(n) => n.to_string(),
+            Expr::Bool(b) => b.to_string(),
+            // Also covers Msg.Inc parsed as a single identifier
+            Expr::Ident(name) => name.to_string(),
+            Expr::Bina(lhs, Op::Dot, rhs) => {
+                format!("{}.{}", self.expr(lhs, scope), self.expr(rhs, scope))
+            }
+            Expr::Bina(lhs, op, rhs) => {
+                format!("{} {} {}", self.expr(lhs, scope), op, self.expr(rhs, scope))
+            }
+            Expr::Call(call) => {
+                let args: Vec<String> = call.args.args.iter().map(|arg| self.expr(&arg.get_expr(), scope)).collect();
+                match call.name.as_ref() {
+                    Expr::Ident(name) if name.to_string() == "print" => format!("console.log({})", args.join(", ")),
+                    name => format!("{}({})", self.expr(name, scope), args.join(", ")),
+                }
+            }
+            _ => "undefined".to_string(),
+        }
+    }
+
+    /// Generate script statements for a method body
+    ///
+    /// `is` statements switch on `subject`, the message parameter of on().
+    fn generate_body_stmts(&self, body: &Body, depth: usize, subject: &str) -> String {
+        let mut code = String::new();
+        for stmt in &body.stmts {
+            match stmt {
+                Stmt::Store(store) => {
+                    let name = store.name.to_string();
+                    let value = self.expr(&store.expr, Scope::Script);
+                    if self.fields.contains(&name) {
+                        line(&mut code, depth, &format!("{}.value = {}", name, value));
+                    } else {
+                        line(&mut code, depth, &format!("let {} = {}", name, value));
+                    }
+                }
+                Stmt::Is(is_stmt) => {
+                    line(&mut code, depth, &format!("switch ({}) {{", subject));
+                    for branch in &is_stmt.branches {
+                        if let IsBranch::EqBranch(pattern, body) = branch {
+                            line(&mut code, depth + 1, &format!("case {}:", self.expr(pattern, Scope::Script)));
+                            code.push_str(&self.generate_body_stmts(body, depth + 2, subject));
+                            line(&mut code, depth + 2, "break");
+                        }
+                    }
+                    line(&mut code, depth, "}");
+                }
+                Stmt::Expr(expr) => line(&mut code, depth, &self.expr(expr, Scope::Script)),
+                _ => line(&mut code, depth, "// unsupported statement"),
+            }
+        }
+        code
+    }
+
+    /// Initial value of a ref, from the field type
+    fn default_value(&self, ty: &Type) -> &'static str {
+        match ty {
+            Type::Int => "0",
+            Type::Str(_) => "''",
+            Type::Bool => "false",
+            _ => "null",
+        }
+    }
+}
+
+/// Append one line at `depth` levels of two-space indentation
+fn line(code: &mut String, depth: usize, text: &str) {
+    code.push_str(&"  ".repeat(depth));
+    code.push_str(text);
+    code.push('\n');
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn js_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'").replace('\n', "\\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trans::api::{transpile_file_to, Target};
+    use std::path::PathBuf;
+
+    fn scratch(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../scratch").join(name)
+    }
+
+    #[test]
+    fn test_counter_sfc() {
+        let vue = transpile_file_to(scratch("counter_full.at"), Target::Vue).unwrap();
+
+        assert!(vue.contains("<script setup>\nimport { ref } from 'vue'\n"));
+        assert!(vue.contains("const Msg = Object.freeze({ Dec: 'Dec', Inc: 'Inc' })"));
+        assert!(vue.contains("const count = ref(0)"));
+        assert!(vue.contains("function on(ev) {\n  switch (ev) {\n    case Msg.Inc:\n      count.value += 1\n      break\n"));
+        assert!(vue.contains("  <div class=\"flex flex-col\">\n"));
+        assert!(vue.contains("<button type=\"button\" @click=\"on(Msg.Inc)\">+</button>"));
+        assert!(vue.contains("<span>{{ count }}</span>"));
+    }
+
+    #[test]
+    fn test_layout_props_become_classes() {
+        let vue = transpile_file_to(scratch("layout_showcase.at"), Target::Vue).unwrap();
+
+        assert!(vue.contains("<div class=\"flex flex-col gap-[10px] p-[20px]\">"));
+        assert!(vue.contains("<div class=\"flex flex-row gap-[10px] p-[5px]\">"));
+        assert!(vue.contains("<div class=\"flex items-center justify-center\">"));
+        assert!(vue.contains("@click=\"on(1)\""));
+        // Several view roots are wrapped like the Rust output
+        assert!(vue.contains("<template>\n  <div class=\"flex flex-col\">\n    <div"));
+    }
+
+    #[test]
+    fn test_form_widgets_bind_fields() {
+        let vue = transpile_file_to(scratch("simple_components.at"), Target::Vue).unwrap();
+
+        assert!(vue.contains("const enabled = ref(false)"));
+        assert!(vue.contains("<input type=\"text\" placeholder=\"Enter text\">"));
+        assert!(vue.contains("<label><input type=\"checkbox\" v-model=\"enabled\"> Enable</label>"));
+        assert!(vue.contains("<label><input type=\"radio\" :checked=\"true\"> Option A</label>"));
+        assert!(vue.contains("<option>Default</option>"));
+        assert!(vue.contains("case 3:\n      count.value = 0\n      enabled.value = true\n"));
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(escape_html("a < \"b\" & c"), "a &lt; &quot;b&quot; &amp; c");
+        assert_eq!(js_string("it's"), "'it\\'s'");
+    }
+}
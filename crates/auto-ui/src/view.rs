@@ -5,7 +5,9 @@
 
 use std::fmt::Debug;
 use std::sync::Arc;
-use crate::style::Style;
+use crate::component::Component;
+use crate::style::{Style, StyleError};
+use crate::subscription::WindowSize;
 
 /// Callback for select dropdown changes
 ///
@@ -36,6 +38,95 @@ impl<M> SelectCallback<M> {
     pub fn call(&self, index: usize, value: &str) -> M {
         (self.callback)(index, value)
     }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> SelectCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        SelectCallback::new(move |index, value| f(callback(index, value)))
+    }
+}
+
+/// Callback for slider value changes
+///
+/// Wraps a function that receives the new slider value and returns a message.
+/// Unlike a plain `fn` pointer, the closure may capture state such as a channel id.
+#[derive(Clone)]
+pub struct SliderCallback<M> {
+    callback: Arc<dyn Fn(f32) -> M + Send + Sync>,
+}
+
+impl<M> std::fmt::Debug for SliderCallback<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SliderCallback")
+            .finish()
+    }
+}
+
+impl<M> SliderCallback<M> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(f32) -> M + Send + Sync + 'static,
+    {
+        Self {
+            callback: Arc::new(f),
+        }
+    }
+
+    pub fn call(&self, value: f32) -> M {
+        (self.callback)(value)
+    }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> SliderCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        SliderCallback::new(move |value| f(callback(value)))
+    }
+}
+
+/// Callback for text input changes
+///
+/// Wraps a function that receives the full text of the input after each edit
+/// and returns a message.
+#[derive(Clone)]
+pub struct InputCallback<M> {
+    callback: Arc<dyn Fn(String) -> M + Send + Sync>,
+}
+
+impl<M> std::fmt::Debug for InputCallback<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputCallback")
+            .finish()
+    }
+}
+
+impl<M> InputCallback<M> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(String) -> M + Send + Sync + 'static,
+    {
+        Self {
+            callback: Arc::new(f),
+        }
+    }
+
+    pub fn call(&self, value: impl Into<String>) -> M {
+        (self.callback)(value.into())
+    }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> InputCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        InputCallback::new(move |value| f(callback(value)))
+    }
 }
 
 // ============================================================================
@@ -71,6 +162,15 @@ impl<M> AccordionToggleCallback<M> {
     pub fn call(&self, index: usize, expanded: bool) -> M {
         (self.callback)(index, expanded)
     }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> AccordionToggleCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        AccordionToggleCallback::new(move |index, expanded| f(callback(index, expanded)))
+    }
 }
 
 /// Callback for tab selection events
@@ -102,6 +202,15 @@ impl<M> TabsSelectCallback<M> {
     pub fn call(&self, index: usize) -> M {
         (self.callback)(index)
     }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> TabsSelectCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        TabsSelectCallback::new(move |index| f(callback(index)))
+    }
 }
 
 /// Callback for navigation rail selection events
@@ -133,6 +242,15 @@ impl<M> NavigationRailSelectCallback<M> {
     pub fn call(&self, index: usize) -> M {
         (self.callback)(index)
     }
+
+    /// Convert the produced message with `f`
+    pub fn map<N>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> NavigationRailSelectCallback<N>
+    where
+        M: 'static,
+    {
+        let callback = self.callback;
+        NavigationRailSelectCallback::new(move |index| f(callback(index)))
+    }
 }
 
 /// Sidebar position (left or right)
@@ -187,7 +305,7 @@ impl<M: Clone + Debug> AccordionItem<M> {
 }
 
 /// Navigation rail item
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NavigationRailItem {
     pub icon: char,
     pub label: String,
@@ -234,6 +352,7 @@ pub enum View<M: Clone + Debug> {
     Button {
         label: String,
         onclick: M,  // Direct message storage (Auto: `onclick: Msg.Inc`)
+        disabled: bool,  // Ignores clicks and enables `disabled:` style variants
         style: Option<Style>,  // ✅ NEW: Unified styling support
     },
 
@@ -257,9 +376,10 @@ pub enum View<M: Clone + Debug> {
     Input {
         placeholder: String,
         value: String,
-        on_change: Option<M>,
+        on_change: Option<InputCallback<M>>,
         width: Option<u16>,   // Legacy field
         password: bool,
+        disabled: bool,  // Read-only; enables `disabled:` style variants
         style: Option<Style>,  // ✅ NEW: Unified styling support
     },
 
@@ -298,6 +418,17 @@ pub enum View<M: Clone + Debug> {
         style: Option<Style>,  // ✅ NEW: Unified styling support
     },
 
+    /// Group of mutually exclusive radio buttons
+    ///
+    /// Only one option can be selected at a time; `on_select` receives the
+    /// index and label of the chosen option.
+    RadioGroup {
+        options: Vec<String>,
+        selected_index: Option<usize>,
+        on_select: Option<SelectCallback<M>>,
+        style: Option<Style>,
+    },
+
     /// Select dropdown with optional styling
     Select {
         options: Vec<String>,
@@ -327,7 +458,7 @@ pub enum View<M: Clone + Debug> {
         min: f32,
         max: f32,
         value: f32,
-        on_change: fn(f32) -> M,  // Function that creates message from new value
+        on_change: SliderCallback<M>,  // Creates a message from the new value
         step: Option<f32>,
         style: Option<Style>,
     },
@@ -378,6 +509,13 @@ pub enum View<M: Clone + Debug> {
         on_select: Option<NavigationRailSelectCallback<M>>,
         style: Option<Style>,
     },
+
+    /// Wraps a view with a user-provided identity key
+    /// Keyed siblings keep their identity across re-renders when reordered (VTree diffing)
+    Keyed {
+        key: String,
+        child: Box<View<M>>,
+    },
 }
 
 /// View builder for fluent layout construction
@@ -462,6 +600,9 @@ impl<M: Clone + Debug> ViewBuilder<M> {
 
     /// Set style using Tailwind CSS class string
     ///
+    /// Strings are parsed here and panic if invalid; a [`style!`](crate::style!)
+    /// value was already checked at compile time.
+    ///
     /// # Example
     /// ```
     /// # use auto_ui::View;
@@ -470,11 +611,17 @@ impl<M: Clone + Debug> ViewBuilder<M> {
     ///     .child(View::text("Hello"))
     ///     .build()
     /// ```
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -519,6 +666,7 @@ impl<M: Clone + Debug> View<M> {
         View::Button {
             label: label.into(),
             onclick,
+            disabled: false,
             style: None,  // ✅ NEW: style field
         }
     }
@@ -532,10 +680,10 @@ impl<M: Clone + Debug> View<M> {
     /// # use auto_ui::View;
     /// View::text_styled("Hello World", "text-lg font-bold text-blue-500")
     /// ```
-    pub fn text_styled(content: impl Into<String>, style_str: &str) -> Self {
+    pub fn text_styled(content: impl Into<String>, style: impl Into<Style>) -> Self {
         View::Text {
             content: content.into(),
-            style: Some(Style::parse(style_str).expect("Invalid style")),
+            style: Some(style.into()),
         }
     }
 
@@ -548,11 +696,12 @@ impl<M: Clone + Debug> View<M> {
     /// # enum Msg { Click }
     /// View::button_styled("Click Me", Msg::Click, "px-4 py-2 bg-blue-500 text-white rounded")
     /// ```
-    pub fn button_styled(label: impl Into<String>, onclick: M, style_str: &str) -> Self {
+    pub fn button_styled(label: impl Into<String>, onclick: M, style: impl Into<Style>) -> Self {
         View::Button {
             label: label.into(),
             onclick,
-            style: Some(Style::parse(style_str).expect("Invalid style")),
+            disabled: false,
+            style: Some(style.into()),
         }
     }
 
@@ -574,6 +723,7 @@ impl<M: Clone + Debug> View<M> {
             on_change: None,
             width: None,
             password: false,
+            disabled: false,
             style: None,  // ✅ NEW: style field
         }
     }
@@ -598,6 +748,16 @@ impl<M: Clone + Debug> View<M> {
         }
     }
 
+    /// Create a radio group with one button per option
+    pub fn radio_group(options: Vec<String>) -> Self {
+        View::RadioGroup {
+            options,
+            selected_index: None,
+            on_select: None,
+            style: None,
+        }
+    }
+
     /// Create select dropdown with options
     pub fn select(options: Vec<String>) -> Self {
         View::Select {
@@ -636,13 +796,23 @@ impl<M: Clone + Debug> View<M> {
     /// # #[derive(Clone, Copy, Debug)]
     /// # enum Msg { ValueChanged(f32) }
     /// View::slider(0.0..=100.0, 50.0, Msg::ValueChanged)
+    /// # ;
+    /// # #[derive(Clone, Copy, Debug)]
+    /// # enum Mixer { Volume(usize, f32) }
+    /// // Closures may capture state, e.g. the channel a slider belongs to
+    /// let channel = 3;
+    /// View::slider(0.0..=1.0, 0.5, move |v| Mixer::Volume(channel, v))
+    /// # ;
     /// ```
-    pub fn slider(range: std::ops::RangeInclusive<f32>, value: f32, on_change: fn(f32) -> M) -> ViewSliderBuilder<M> {
+    pub fn slider<F>(range: std::ops::RangeInclusive<f32>, value: f32, on_change: F) -> ViewSliderBuilder<M>
+    where
+        F: Fn(f32) -> M + Send + Sync + 'static,
+    {
         ViewSliderBuilder {
             min: *range.start(),
             max: *range.end(),
             value,
-            on_change,
+            on_change: SliderCallback::new(on_change),
             step: None,
             style: None,
         }
@@ -669,10 +839,10 @@ impl<M: Clone + Debug> View<M> {
     /// # use auto_ui::View;
     /// View::progress_bar_styled(0.75, "h-4 bg-blue-500 rounded")
     /// ```
-    pub fn progress_bar_styled(progress: f32, style_str: &str) -> Self {
+    pub fn progress_bar_styled(progress: f32, style: impl Into<Style>) -> Self {
         View::ProgressBar {
             progress: progress.clamp(0.0, 1.0),
-            style: Some(Style::parse(style_str).expect("Invalid style")),
+            style: Some(style.into()),
         }
     }
 
@@ -828,12 +998,18 @@ impl<M: Clone + Debug> ViewScrollableBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -865,12 +1041,18 @@ impl<M: Clone + Debug> ViewListBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -891,9 +1073,10 @@ impl<M: Clone + Debug> ViewListBuilder<M> {
 pub struct ViewInputBuilder<M: Clone + Debug> {
     placeholder: String,
     value: String,
-    on_change: Option<M>,
+    on_change: Option<InputCallback<M>>,
     width: Option<u16>,
     password: bool,
+    disabled: bool,
     style: Option<Style>,  // ✅ NEW: Unified styling support
 }
 
@@ -905,8 +1088,14 @@ impl<M: Clone + Debug> ViewInputBuilder<M> {
     }
 
     /// Set input change handler
-    pub fn on_change(mut self, msg: M) -> Self {
-        self.on_change = Some(msg);
+    ///
+    /// The callback receives the full text after each edit, so a tuple
+    /// variant like `Msg::NameChanged` can be passed directly.
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(String) -> M + Send + Sync + 'static,
+    {
+        self.on_change = Some(InputCallback::new(callback));
         self
     }
 
@@ -922,12 +1111,24 @@ impl<M: Clone + Debug> ViewInputBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Disable editing
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
         self
     }
 
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -942,6 +1143,7 @@ impl<M: Clone + Debug> ViewInputBuilder<M> {
             on_change: self.on_change,
             width: self.width,
             password: self.password,
+            disabled: self.disabled,
             style: self.style,
         }
     }
@@ -969,12 +1171,18 @@ impl<M: Clone + Debug> ViewTableBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -998,7 +1206,7 @@ pub struct ViewSliderBuilder<M: Clone + Debug> {
     min: f32,
     max: f32,
     value: f32,
-    on_change: fn(f32) -> M,
+    on_change: SliderCallback<M>,
     step: Option<f32>,
     style: Option<Style>,
 }
@@ -1010,12 +1218,18 @@ impl<M: Clone + Debug> ViewSliderBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -1084,12 +1298,18 @@ impl<M: Clone + Debug> ViewContainerBuilder<M> {
         self
     }
 
-    /// Set style using Tailwind CSS class string
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    /// Set style from a Tailwind CSS class string or a `style!` value
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     /// Set style using Style object
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = Some(style);
@@ -1110,6 +1330,272 @@ impl<M: Clone + Debug> ViewContainerBuilder<M> {
     }
 }
 
+impl<M: Clone + Debug> View<M> {
+    /// Attach an identity key to this view
+    ///
+    /// Keys only need to be unique among siblings. When a list is reordered,
+    /// `VTree::diff` matches keyed children by key instead of position and emits
+    /// moves rather than rebuilding every row.
+    pub fn key(self, key: impl Into<String>) -> Self {
+        View::Keyed {
+            key: key.into(),
+            child: Box::new(self),
+        }
+    }
+
+    /// Get the user key of this view, if any
+    pub fn key_str(&self) -> Option<&str> {
+        match self {
+            View::Keyed { key, .. } => Some(key.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Get the style of this view, looking through `Keyed` wrappers
+    pub fn style(&self) -> Option<&Style> {
+        match self {
+            View::Empty => None,
+            View::Text { style, .. }
+            | View::Button { style, .. }
+            | View::Row { style, .. }
+            | View::Column { style, .. }
+            | View::Input { style, .. }
+            | View::Checkbox { style, .. }
+            | View::Container { style, .. }
+            | View::Scrollable { style, .. }
+            | View::Radio { style, .. }
+            | View::RadioGroup { style, .. }
+            | View::Select { style, .. }
+            | View::List { style, .. }
+            | View::Table { style, .. }
+            | View::Slider { style, .. }
+            | View::ProgressBar { style, .. }
+            | View::Accordion { style, .. }
+            | View::Sidebar { style, .. }
+            | View::Tabs { style, .. }
+            | View::NavigationRail { style, .. } => style.as_ref(),
+            View::Keyed { child, .. } => child.style(),
+        }
+    }
+
+    /// Whether a `hidden` class removes this view at the current window width
+    ///
+    /// Backends skip hidden views entirely, so `hidden md:block` on a Sidebar
+    /// and `md:hidden` on a NavigationRail swap them at the `md` breakpoint.
+    pub fn is_hidden(&self) -> bool {
+        self.style()
+            .is_some_and(|style| style.is_hidden_at(WindowSize::current().width))
+    }
+}
+
+// Message mapping for composing components
+impl<M: Clone + Debug + 'static> View<M> {
+    /// Convert every message this view can produce with `f`
+    ///
+    /// Stored messages (button clicks, checkbox toggles, ...) are converted right
+    /// away; callbacks are wrapped so they convert when they fire. This lets a
+    /// parent render a child whose messages are a different type:
+    ///
+    /// ```ignore
+    /// View::col()
+    ///     .child(self.counter.view().map(Msg::Counter))
+    ///     .build()
+    /// ```
+    pub fn map<N: Clone + Debug + 'static>(self, f: impl Fn(M) -> N + Send + Sync + 'static) -> View<N> {
+        let f: Arc<dyn Fn(M) -> N + Send + Sync> = Arc::new(f);
+        self.map_with(&f)
+    }
+
+    /// Render a child component inside this view tree
+    ///
+    /// Shorthand for `child.view().map(wrap)`. Route the wrapped messages back
+    /// in the parent's `on` (or `update`, mapping the returned command):
+    ///
+    /// ```ignore
+    /// fn on(&mut self, msg: Msg) {
+    ///     match msg {
+    ///         Msg::Counter(msg) => self.counter.on(msg),
+    ///     }
+    /// }
+    ///
+    /// fn view(&self) -> View<Msg> {
+    ///     View::embed(&self.counter, Msg::Counter)
+    /// }
+    /// ```
+    pub fn embed<C: Component>(child: &C, wrap: impl Fn(C::Msg) -> M + Send + Sync + 'static) -> Self {
+        child.view().map(wrap)
+    }
+
+    fn map_with<N: Clone + Debug + 'static>(self, f: &Arc<dyn Fn(M) -> N + Send + Sync>) -> View<N> {
+        let lift = || {
+            let f = f.clone();
+            move |msg: M| f(msg)
+        };
+        let map_all = |views: Vec<View<M>>| -> Vec<View<N>> {
+            views.into_iter().map(|view| view.map_with(f)).collect()
+        };
+
+        match self {
+            View::Empty => View::Empty,
+            View::Text { content, style } => View::Text { content, style },
+            View::Button { label, onclick, disabled, style } => View::Button {
+                label,
+                onclick: f(onclick),
+                disabled,
+                style,
+            },
+            View::Row { children, spacing, padding, style } => View::Row {
+                children: map_all(children),
+                spacing,
+                padding,
+                style,
+            },
+            View::Column { children, spacing, padding, style } => View::Column {
+                children: map_all(children),
+                spacing,
+                padding,
+                style,
+            },
+            View::Input { placeholder, value, on_change, width, password, disabled, style } => View::Input {
+                placeholder,
+                value,
+                on_change: on_change.map(|cb| cb.map(lift())),
+                width,
+                password,
+                disabled,
+                style,
+            },
+            View::Checkbox { is_checked, label, on_toggle, style } => View::Checkbox {
+                is_checked,
+                label,
+                on_toggle: on_toggle.map(|msg| f(msg)),
+                style,
+            },
+            View::Container { child, padding, width, height, center_x, center_y, style } => View::Container {
+                child: Box::new(child.map_with(f)),
+                padding,
+                width,
+                height,
+                center_x,
+                center_y,
+                style,
+            },
+            View::Scrollable { child, width, height, style } => View::Scrollable {
+                child: Box::new(child.map_with(f)),
+                width,
+                height,
+                style,
+            },
+            View::Radio { label, is_selected, on_select, style } => View::Radio {
+                label,
+                is_selected,
+                on_select: on_select.map(|msg| f(msg)),
+                style,
+            },
+            View::RadioGroup { options, selected_index, on_select, style } => View::RadioGroup {
+                options,
+                selected_index,
+                on_select: on_select.map(|cb| cb.map(lift())),
+                style,
+            },
+            View::Select { options, selected_index, on_select, style } => View::Select {
+                options,
+                selected_index,
+                on_select: on_select.map(|cb| cb.map(lift())),
+                style,
+            },
+            View::List { items, spacing, style } => View::List {
+                items: map_all(items),
+                spacing,
+                style,
+            },
+            View::Table { headers, rows, spacing, col_spacing, style } => View::Table {
+                headers: map_all(headers),
+                rows: rows.into_iter().map(map_all).collect(),
+                spacing,
+                col_spacing,
+                style,
+            },
+            View::Slider { min, max, value, on_change, step, style } => View::Slider {
+                min,
+                max,
+                value,
+                on_change: on_change.map(lift()),
+                step,
+                style,
+            },
+            View::ProgressBar { progress, style } => View::ProgressBar { progress, style },
+            View::Accordion { items, allow_multiple, on_toggle, style } => View::Accordion {
+                items: items
+                    .into_iter()
+                    .map(|item| AccordionItem {
+                        title: item.title,
+                        icon: item.icon,
+                        children: map_all(item.children),
+                        expanded: item.expanded,
+                    })
+                    .collect(),
+                allow_multiple,
+                on_toggle: on_toggle.map(|cb| cb.map(lift())),
+                style,
+            },
+            View::Sidebar { content, width, collapsible, position, style } => View::Sidebar {
+                content: Box::new(content.map_with(f)),
+                width,
+                collapsible,
+                position,
+                style,
+            },
+            View::Tabs { labels, contents, selected, position, on_select, style } => View::Tabs {
+                labels,
+                contents: map_all(contents),
+                selected,
+                position,
+                on_select: on_select.map(|cb| cb.map(lift())),
+                style,
+            },
+            View::NavigationRail { items, selected, width, show_labels, on_select, style } => {
+                View::NavigationRail {
+                    items,
+                    selected,
+                    width,
+                    show_labels,
+                    on_select: on_select.map(|cb| cb.map(lift())),
+                    style,
+                }
+            }
+            View::Keyed { key, child } => View::Keyed {
+                key,
+                child: Box::new(child.map_with(f)),
+            },
+        }
+    }
+}
+
+// Chaining methods for Button and Input
+impl<M: Clone + Debug> View<M> {
+    /// Disable a button or input
+    ///
+    /// Disabled widgets ignore interaction and render their `disabled:` style variants.
+    ///
+    /// # Example
+    /// ```
+    /// # use auto_ui::View;
+    /// # #[derive(Clone, Copy, Debug)]
+    /// # enum Msg { Save }
+    /// # let is_saving = true;
+    /// View::button_styled("Save", Msg::Save, "bg-blue-500 hover:bg-blue-600 disabled:opacity-50")
+    ///     .disabled(is_saving)
+    /// # ;
+    /// ```
+    pub fn disabled(mut self, value: bool) -> Self {
+        if let View::Button { disabled, .. } | View::Input { disabled, .. } = &mut self {
+            *disabled = value;
+        }
+        self
+    }
+}
+
 // Chaining methods for Checkbox
 impl<M: Clone + Debug> View<M> {
     /// Set checkbox toggle handler
@@ -1132,11 +1618,11 @@ impl<M: Clone + Debug> View<M> {
     }
 }
 
-// Chaining methods for Select
+// Chaining methods for Select and RadioGroup
 impl<M: Clone + Debug> View<M> {
     /// Set selected option by index
     pub fn selected(mut self, index: usize) -> Self {
-        if let View::Select { selected_index, .. } = &mut self {
+        if let View::Select { selected_index, .. } | View::RadioGroup { selected_index, .. } = &mut self {
             *selected_index = Some(index);
         }
         self
@@ -1164,7 +1650,7 @@ impl<M: Clone + Debug> View<M> {
     where
         F: Fn(usize, &str) -> M + Send + Sync + 'static,
     {
-        if let View::Select { on_select, .. } = &mut self {
+        if let View::Select { on_select, .. } | View::RadioGroup { on_select, .. } = &mut self {
             *on_select = Some(SelectCallback::new(callback));
         }
         self
@@ -1202,11 +1688,17 @@ impl<M: Clone + Debug> AccordionBuilder<M> {
         self
     }
 
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> View<M> {
         View::Accordion {
             items: self.items,
@@ -1237,11 +1729,17 @@ impl<M: Clone + Debug> SidebarBuilder<M> {
         self
     }
 
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> View<M> {
         View::Sidebar {
             content: Box::new(self.content),
@@ -1287,11 +1785,17 @@ impl<M: Clone + Debug> TabsBuilder<M> {
         self
     }
 
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> View<M> {
         View::Tabs {
             labels: self.labels,
@@ -1343,11 +1847,17 @@ impl<M: Clone + Debug> NavigationRailBuilder<M> {
         self
     }
 
-    pub fn style(mut self, style_str: &str) -> Self {
-        self.style = Some(Style::parse(style_str).expect("Invalid style string"));
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
+    /// Like `style`, but returns the parse error instead of panicking
+    pub fn try_style(mut self, style_str: &str) -> Result<Self, StyleError> {
+        self.style = Some(Style::parse(style_str)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> View<M> {
         View::NavigationRail {
             items: self.items,
@@ -1365,9 +1875,9 @@ impl<M: Clone + Debug> NavigationRailBuilder<M> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::style::{Style, StyleClass};
+    use crate::style::{Style, StyleClass, StyleState};
 
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     enum TestMsg {
         Click,
         Change,
@@ -1603,6 +2113,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_disabled_button_with_state_variants() {
+        let view = View::button_styled("Save", TestMsg::Click, "bg-blue-500 hover:bg-blue-600 disabled:opacity-50")
+            .disabled(true);
+        match view {
+            View::Button { disabled, style: Some(style), .. } => {
+                assert!(disabled);
+                assert_eq!(style.classes.len(), 1);
+                assert_eq!(style.variants.len(), 2);
+            }
+            _ => panic!("Expected View::Button with style"),
+        }
+
+        // Only buttons and inputs can be disabled
+        assert!(matches!(View::<TestMsg>::text("Hi").disabled(true), View::Text { .. }));
+    }
+
     // ========== Task 4.1: Test backward compatibility ==========
 
     #[test]
@@ -1666,6 +2193,26 @@ mod tests {
         let _ = View::<TestMsg>::text_styled("Hello", "invalid-class-name-12345");
     }
 
+    #[test]
+    fn test_styled_constructors_accept_style_macro() {
+        let view = View::<TestMsg>::text_styled("Hello", crate::style!("text-lg hover:text-blue-600"));
+        let style = view.style().unwrap();
+
+        assert_eq!(style.classes, vec![StyleClass::TextLg]);
+        assert!(style.has_variant(StyleState::Hover));
+    }
+
+    #[test]
+    fn test_try_style_reports_error() {
+        let error = View::<TestMsg>::col().try_style("p-4 bg-blu-500").err().unwrap();
+        assert_eq!(error.class, "bg-blu-500");
+        assert_eq!(error.span, 4..14);
+        assert_eq!(error.suggestion.as_deref(), Some("bg-blue-500"));
+
+        let view = View::<TestMsg>::col().try_style("p-4 bg-blue-500").unwrap().build();
+        assert!(view.style().is_some());
+    }
+
     // ========== Task 4.1: Test complex nested views with styles ==========
 
     #[test]
@@ -1725,6 +2272,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_value_callbacks_receive_new_value() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Msg {
+            Volume(usize, f32),
+            Name(String),
+        }
+
+        let channel = 3;
+        match View::slider(0.0..=1.0, 0.5, move |v| Msg::Volume(channel, v)).build() {
+            View::Slider { on_change, .. } => assert_eq!(on_change.call(0.8), Msg::Volume(3, 0.8)),
+            _ => panic!("Expected View::Slider"),
+        }
+
+        match View::input("Name").on_change(Msg::Name).build() {
+            View::Input { on_change: Some(on_change), .. } => {
+                assert_eq!(on_change.call("Ada"), Msg::Name("Ada".to_string()));
+            }
+            _ => panic!("Expected View::Input with on_change"),
+        }
+    }
+
     #[test]
     fn test_view_container_builder_style() {
         let view: View<TestMsg> = View::container(View::text("Content"))
@@ -1859,4 +2428,70 @@ mod tests {
             _ => panic!("Expected View::Container"),
         }
     }
+
+    #[test]
+    fn test_view_key() {
+        let view: View<TestMsg> = View::text("Row 1").key("row-1");
+        assert_eq!(view.key_str(), Some("row-1"));
+        match view {
+            View::Keyed { key, child } => {
+                assert_eq!(key, "row-1");
+                assert!(matches!(*child, View::Text { .. }));
+            }
+            _ => panic!("Expected View::Keyed"),
+        }
+
+        let plain: View<TestMsg> = View::text("Row 2");
+        assert_eq!(plain.key_str(), None);
+    }
+
+    #[test]
+    fn test_view_map() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Parent {
+            Child(TestMsg),
+            Picked(usize),
+        }
+
+        let child: View<TestMsg> = View::col()
+            .child(View::button("Go", TestMsg::Click))
+            .child(View::checkbox(false, "Done").on_toggle(TestMsg::Change))
+            .child(View::select(vec!["A".to_string(), "B".to_string()]).on_choose(|_, _| TestMsg::Change))
+            .child(View::slider(0.0..=1.0, 0.5, |_| TestMsg::Change).build())
+            .build();
+
+        let mapped: View<Parent> = child.map(Parent::Child);
+        let children = match mapped {
+            View::Column { children, .. } => children,
+            _ => panic!("Expected View::Column"),
+        };
+
+        assert!(matches!(&children[0], View::Button { onclick: Parent::Child(TestMsg::Click), .. }));
+        assert!(matches!(
+            &children[1],
+            View::Checkbox { on_toggle: Some(Parent::Child(TestMsg::Change)), .. }
+        ));
+        match &children[2] {
+            View::Select { on_select: Some(cb), .. } => {
+                assert_eq!(cb.call(1, "B"), Parent::Child(TestMsg::Change));
+            }
+            _ => panic!("Expected View::Select"),
+        }
+        match &children[3] {
+            View::Slider { on_change, .. } => {
+                assert_eq!(on_change.call(0.2), Parent::Child(TestMsg::Change));
+            }
+            _ => panic!("Expected View::Slider"),
+        }
+
+        // Mapping composes with callbacks that already depend on their arguments
+        let tabs: View<usize> = View::tabs(vec!["One".to_string(), "Two".to_string()])
+            .contents(vec![View::empty(), View::empty()])
+            .on_select(|index| index)
+            .build();
+        match tabs.map(Parent::Picked) {
+            View::Tabs { on_select: Some(cb), .. } => assert_eq!(cb.call(1), Parent::Picked(1)),
+            _ => panic!("Expected View::Tabs"),
+        }
+    }
 }
@@ -24,6 +24,9 @@
 //! tree.set_root(root);
 //! ```
 
+use crate::style::Style;
+use crate::view::{NavigationRailItem, SidebarPosition, TabsPosition};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 /// VNode 唯一标识符
@@ -85,6 +88,9 @@ pub enum VNodeKind {
     /// 单选框
     Radio,
 
+    /// 单选组（互斥的一组单选框）
+    RadioGroup,
+
     /// 下拉选择框
     Select,
 
@@ -127,6 +133,7 @@ impl fmt::Display for VNodeKind {
             VNodeKind::Input => write!(f, "Input"),
             VNodeKind::Checkbox => write!(f, "Checkbox"),
             VNodeKind::Radio => write!(f, "Radio"),
+            VNodeKind::RadioGroup => write!(f, "RadioGroup"),
             VNodeKind::Select => write!(f, "Select"),
             VNodeKind::List => write!(f, "List"),
             VNodeKind::Table => write!(f, "Table"),
@@ -143,7 +150,7 @@ impl fmt::Display for VNodeKind {
 /// VNode 属性
 ///
 /// 每种 VNodeKind 对应不同的属性集合
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum VNodeProps {
     /// 空属性（用于 Empty 或占位符）
     Empty,
@@ -152,13 +159,15 @@ pub enum VNodeProps {
     Text { content: String },
 
     /// 按钮属性
-    Button { label: String },
+    Button { label: String, disabled: bool },
 
     /// 输入框属性
     Input {
         placeholder: String,
         value: String,
+        width: Option<u16>,
         password: bool,
+        disabled: bool,
     },
 
     /// 复选框属性
@@ -173,6 +182,12 @@ pub enum VNodeProps {
         is_selected: bool,
     },
 
+    /// 单选组属性
+    RadioGroup {
+        options: Vec<String>,
+        selected_index: Option<usize>,
+    },
+
     /// 下拉选择框属性
     Select {
         options: Vec<String>,
@@ -188,12 +203,17 @@ pub enum VNodeProps {
     /// 容器属性
     Container {
         padding: u16,
+        width: Option<u16>,
+        height: Option<u16>,
         center_x: bool,
         center_y: bool,
     },
 
     /// 可滚动容器属性
-    Scrollable,
+    Scrollable {
+        width: Option<u16>,
+        height: Option<u16>,
+    },
 
     /// 滑块属性
     Slider {
@@ -214,10 +234,71 @@ pub enum VNodeProps {
     },
 
     /// 表格属性
+    ///
+    /// 子节点按顺序为 `headers` 个表头单元格，随后是各行的单元格；
+    /// `rows` 记录每行的单元格数量
     Table {
         spacing: u16,
         col_spacing: u16,
+        headers: usize,
+        rows: Vec<usize>,
+    },
+
+    /// 手风琴属性
+    ///
+    /// 子节点按顺序为各分组的内容，`AccordionItemProps::children` 记录每组的子节点数量
+    Accordion {
+        items: Vec<AccordionItemProps>,
+        allow_multiple: bool,
     },
+
+    /// 侧边栏属性（唯一子节点为侧边栏内容）
+    Sidebar {
+        width: f32,
+        collapsible: bool,
+        position: SidebarPosition,
+    },
+
+    /// 标签页属性（子节点与 `labels` 一一对应）
+    Tabs {
+        labels: Vec<String>,
+        selected: usize,
+        position: TabsPosition,
+    },
+
+    /// 导航栏属性
+    NavigationRail {
+        items: Vec<NavigationRailItem>,
+        selected: usize,
+        width: f32,
+        show_labels: bool,
+    },
+}
+
+/// 手风琴分组属性
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccordionItemProps {
+    pub title: String,
+    pub icon: Option<char>,
+    pub expanded: bool,
+    /// 该分组拥有的子节点数量
+    pub children: usize,
+}
+
+/// 回调槽位
+///
+/// 记录节点上挂载的事件处理器类型，与 GPUI `EventRouter` 的 `EventType` 一一对应。
+/// 回调本身不是 VNode 的一部分，由 `VNodeHandlers` 按节点 ID 单独保存。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventSlot {
+    /// 点击（Button、Radio）
+    Click,
+    /// 值变更（Input、Slider）
+    Change,
+    /// 切换（Checkbox、Accordion）
+    Toggle,
+    /// 选择（Select、RadioGroup、Tabs、NavigationRail）
+    Select,
 }
 
 /// 虚拟节点 - 扁平表示的 View<M>
@@ -248,6 +329,15 @@ pub struct VNode {
 
     /// 调试标签（用于日志和调试）
     pub label: String,
+
+    /// 用户指定的 key（来自 `View::key`），diff 时用于在兄弟节点间保持身份
+    pub key: Option<String>,
+
+    /// 解析后的 Tailwind 样式（来自 View 的 `style` 字段）
+    pub style: Option<Style>,
+
+    /// 挂载的回调槽位（无回调时为 None）
+    pub event: Option<EventSlot>,
 }
 
 impl VNode {
@@ -260,6 +350,9 @@ impl VNode {
             children: Vec::new(),
             props,
             label: String::new(),
+            key: None,
+            style: None,
+            event: None,
         }
     }
 
@@ -269,6 +362,24 @@ impl VNode {
         self
     }
 
+    /// 设置 key
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// 设置样式
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// 设置回调槽位
+    pub fn with_event(mut self, event: EventSlot) -> Self {
+        self.event = Some(event);
+        self
+    }
+
     /// 设置父节点
     pub fn with_parent(mut self, parent: VNodeId) -> Self {
         self.parent = Some(parent);
@@ -317,6 +428,9 @@ pub struct VTree {
 
     /// ID 计数器（用于生成唯一 ID）
     next_id: u64,
+
+    /// ID → `nodes` 下标的索引，保证 O(1) 查找
+    index: HashMap<VNodeId, usize>,
 }
 
 impl VTree {
@@ -326,6 +440,7 @@ impl VTree {
             nodes: Vec::new(),
             root: None,
             next_id: 1,
+            index: HashMap::new(),
         }
     }
 
@@ -346,8 +461,7 @@ impl VTree {
     ///
     /// 根节点的 ID
     pub fn set_root(&mut self, node: VNode) -> VNodeId {
-        let id = node.id;
-        self.nodes.push(node);
+        let id = self.add_node(node);
         self.root = Some(id);
         id
     }
@@ -363,7 +477,9 @@ impl VTree {
     /// 节点的 ID
     pub fn add_node(&mut self, node: VNode) -> VNodeId {
         let id = node.id;
+        self.index.insert(id, self.nodes.len());
         self.nodes.push(node);
+        self.next_id = self.next_id.max(id.0 + 1);
         id
     }
 
@@ -377,7 +493,7 @@ impl VTree {
     ///
     /// 如果找到则返回节点引用，否则返回 None
     pub fn get(&self, id: VNodeId) -> Option<&VNode> {
-        self.nodes.iter().find(|n| n.id == id)
+        self.index.get(&id).map(|&i| &self.nodes[i])
     }
 
     /// 获取可变节点
@@ -390,7 +506,7 @@ impl VTree {
     ///
     /// 如果找到则返回可变节点引用，否则返回 None
     pub fn get_mut(&mut self, id: VNodeId) -> Option<&mut VNode> {
-        self.nodes.iter_mut().find(|n| n.id == id)
+        self.index.get(&id).map(|&i| &mut self.nodes[i])
     }
 
     /// 获取根节点
@@ -430,6 +546,8 @@ impl VTree {
     }
 
     /// 获取可变所有节点
+    ///
+    /// 注意：不要修改节点的 `id`，否则索引会失效
     pub fn nodes_mut(&mut self) -> &mut [VNode] {
         &mut self.nodes
     }
@@ -447,10 +565,61 @@ impl VTree {
     /// 清空树
     pub fn clear(&mut self) {
         self.nodes.clear();
+        self.index.clear();
         self.root = None;
         self.next_id = 1;
     }
 
+    /// 查看下一个将被分配的 ID（不消耗）
+    pub(crate) fn peek_next_id(&self) -> u64 {
+        self.next_id
+    }
+
+    /// 设置根节点 ID（节点必须已存在或为 None）
+    pub(crate) fn set_root_id(&mut self, root: Option<VNodeId>) {
+        self.root = root;
+    }
+
+    /// 删除一组节点（不修改其他节点的父子引用）
+    pub(crate) fn remove_nodes(&mut self, ids: &[VNodeId]) {
+        if ids.is_empty() {
+            return;
+        }
+        let ids: HashSet<VNodeId> = ids.iter().copied().collect();
+        if self.root.is_some_and(|root| ids.contains(&root)) {
+            self.root = None;
+        }
+        self.nodes.retain(|n| !ids.contains(&n.id));
+        self.rebuild_index();
+    }
+
+    /// 消耗树并返回所有节点
+    pub(crate) fn into_nodes(self) -> Vec<VNode> {
+        self.nodes
+    }
+
+    /// 获取以 `id` 为根的子树中所有节点 ID（包含自身，先序）
+    pub fn subtree_ids(&self, id: VNodeId) -> Vec<VNodeId> {
+        let mut ids = Vec::new();
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            if let Some(node) = self.get(current) {
+                ids.push(current);
+                stack.extend(node.children.iter().rev().copied());
+            }
+        }
+        ids
+    }
+
+    fn rebuild_index(&mut self) {
+        self.index = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id, i))
+            .collect();
+    }
+
     /// 验证树的完整性
     ///
     /// 检查：
@@ -653,7 +822,8 @@ mod tests {
         let mut tree = VTree::new();
         let id = tree.next_id();
         let node = VNode::new(id, VNodeKind::Button, VNodeProps::Button {
-            label: "Click".to_string()
+            label: "Click".to_string(),
+            disabled: false,
         });
 
         tree.set_root(node);
@@ -861,7 +1031,8 @@ mod tests {
             button_id,
             VNodeKind::Button,
             VNodeProps::Button {
-                label: "Click".to_string()
+                label: "Click".to_string(),
+                disabled: false,
             }
         )
         .with_parent(root_id);
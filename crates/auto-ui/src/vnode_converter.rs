@@ -6,7 +6,8 @@
 //!
 //! - **扁平化转换**：将嵌套的 View<M> 树转换为扁平的 VNode 列表
 //! - **ID 引用**：使用 ID 引用建立父子关系，而非直接嵌套
-//! - **完整支持**：支持所有 22 个 View 变体
+//! - **完整支持**：支持所有 View 变体，转换无损
+//! - **逆向转换**：`vtree_to_view` 借助回调表把 VTree 还原为 View
 //!
 //! ## 使用示例
 //!
@@ -26,9 +27,46 @@
 //! let vtree = view_to_vtree(view);
 //! assert_eq!(vtree.node_count(), 2); // Column + Text
 //! ```
+//!
+//! ## 回调
+//!
+//! VNode 不是泛型的，回调不保存在节点中：节点只记录回调槽位（`EventSlot`），
+//! 回调本身由 `view_to_vtree_with_handlers` 按节点 ID 收集到 `VNodeHandlers`。
+//!
+//! ```ignore
+//! let (vtree, handlers) = view_to_vtree_with_handlers(view);
+//! let view = vtree_to_view(&vtree, &handlers)?;
+//! ```
+
+use std::collections::HashMap;
+
+use crate::view::{
+    AccordionItem, AccordionToggleCallback, InputCallback, NavigationRailSelectCallback,
+    SelectCallback, SliderCallback, TabsSelectCallback, View,
+};
+use crate::vnode::{AccordionItemProps, EventSlot, VNode, VNodeId, VNodeKind, VNodeProps, VTree};
+
+/// 节点上挂载的回调
+#[derive(Debug, Clone)]
+pub enum VNodeHandler<M> {
+    /// 直接存储的消息（Button、Checkbox、Radio）
+    Message(M),
+    /// 输入框回调
+    Input(InputCallback<M>),
+    /// 下拉选择、单选组回调
+    Select(SelectCallback<M>),
+    /// 滑块回调
+    Slider(SliderCallback<M>),
+    /// 手风琴展开/折叠回调
+    AccordionToggle(AccordionToggleCallback<M>),
+    /// 标签页选择回调
+    TabsSelect(TabsSelectCallback<M>),
+    /// 导航栏选择回调
+    NavigationRailSelect(NavigationRailSelectCallback<M>),
+}
 
-use crate::view::View;
-use crate::vnode::{VNode, VNodeId, VNodeKind, VNodeProps, VTree};
+/// 按节点 ID 保存的回调表
+pub type VNodeHandlers<M> = HashMap<VNodeId, VNodeHandler<M>>;
 
 /// 主转换函数：View<M> → VTree
 ///
@@ -57,16 +95,27 @@ use crate::vnode::{VNode, VNodeId, VNodeKind, VNodeProps, VTree};
 /// let vtree = view_to_vtree(view);
 /// ```
 pub fn view_to_vtree<M>(view: View<M>) -> VTree
+where
+    M: Clone + std::fmt::Debug,
+{
+    view_to_vtree_with_handlers(view).0
+}
+
+/// 转换 View<M> → VTree，同时收集各节点的回调
+///
+/// 返回的回调表与 `vtree_to_view` 配合即可无损还原原始 View。
+pub fn view_to_vtree_with_handlers<M>(view: View<M>) -> (VTree, VNodeHandlers<M>)
 where
     M: Clone + std::fmt::Debug,
 {
     let mut tree = VTree::new();
+    let mut handlers = VNodeHandlers::new();
     let root_id = tree.next_id();
 
-    let root_node = convert_view_to_vnode(&view, root_id, None, &mut tree);
+    let root_node = convert_view_to_vnode(&view, root_id, None, &mut tree, &mut handlers);
     tree.set_root(root_node);
 
-    tree
+    (tree, handlers)
 }
 
 /// 将单个 View 转换为 VNode（递归处理子节点）
@@ -77,6 +126,7 @@ where
 /// * `id` - 为此节点分配的 ID
 /// * `parent_id` - 父节点 ID（如果有）
 /// * `tree` - VTree 用于添加子节点
+/// * `handlers` - 收集节点回调
 ///
 /// # 返回
 ///
@@ -86,13 +136,27 @@ fn convert_view_to_vnode<M>(
     id: VNodeId,
     parent_id: Option<VNodeId>,
     tree: &mut VTree,
+    handlers: &mut VNodeHandlers<M>,
 ) -> VNode
 where
     M: Clone + std::fmt::Debug,
 {
+    // Keyed 只是包装：转换内部视图并记录 key
+    if let View::Keyed { key, child } = view {
+        let mut vnode = convert_view_to_vnode(child, id, parent_id, tree, handlers);
+        vnode.key = Some(key.clone());
+        return vnode;
+    }
+
     let (kind, props) = extract_kind_and_props(view);
 
     let mut vnode = VNode::new(id, kind, props).with_label(format!("{}", kind));
+    vnode.style = view.style().cloned();
+
+    if let Some((slot, handler)) = extract_handler(view) {
+        vnode.event = Some(slot);
+        handlers.insert(id, handler);
+    }
 
     if let Some(parent) = parent_id {
         vnode = vnode.with_parent(parent);
@@ -102,7 +166,7 @@ where
     let children = extract_children(view);
     for child_view in children {
         let child_id = tree.next_id();
-        let child_node = convert_view_to_vnode(&child_view, child_id, Some(id), tree);
+        let child_node = convert_view_to_vnode(&child_view, child_id, Some(id), tree, handlers);
         tree.add_node(child_node);
         vnode.add_child(child_id);
     }
@@ -133,10 +197,11 @@ where
             },
         ),
 
-        View::Button { label, .. } => (
+        View::Button { label, disabled, .. } => (
             VNodeKind::Button,
             VNodeProps::Button {
                 label: label.clone(),
+                disabled: *disabled,
             },
         ),
 
@@ -159,14 +224,18 @@ where
         View::Input {
             placeholder,
             value,
+            width,
             password,
+            disabled,
             ..
         } => (
             VNodeKind::Input,
             VNodeProps::Input {
                 placeholder: placeholder.clone(),
                 value: value.clone(),
+                width: *width,
                 password: *password,
+                disabled: *disabled,
             },
         ),
 
@@ -190,6 +259,16 @@ where
             },
         ),
 
+        View::RadioGroup {
+            options, selected_index, ..
+        } => (
+            VNodeKind::RadioGroup,
+            VNodeProps::RadioGroup {
+                options: options.clone(),
+                selected_index: *selected_index,
+            },
+        ),
+
         View::Select {
             options, selected_index, ..
         } => (
@@ -202,6 +281,8 @@ where
 
         View::Container {
             padding,
+            width,
+            height,
             center_x,
             center_y,
             ..
@@ -209,12 +290,20 @@ where
             VNodeKind::Container,
             VNodeProps::Container {
                 padding: *padding,
+                width: *width,
+                height: *height,
                 center_x: *center_x,
                 center_y: *center_y,
             },
         ),
 
-        View::Scrollable { .. } => (VNodeKind::Scrollable, VNodeProps::Scrollable),
+        View::Scrollable { width, height, .. } => (
+            VNodeKind::Scrollable,
+            VNodeProps::Scrollable {
+                width: *width,
+                height: *height,
+            },
+        ),
 
         View::List { spacing, .. } => (
             VNodeKind::List,
@@ -222,12 +311,18 @@ where
         ),
 
         View::Table {
-            spacing, col_spacing, ..
+            headers,
+            rows,
+            spacing,
+            col_spacing,
+            ..
         } => (
             VNodeKind::Table,
             VNodeProps::Table {
                 spacing: *spacing,
                 col_spacing: *col_spacing,
+                headers: headers.len(),
+                rows: rows.iter().map(Vec::len).collect(),
             },
         ),
 
@@ -250,34 +345,110 @@ where
             },
         ),
 
-        // 高级组件（Plan 010）- 暂不支持，返回占位符
-        View::Accordion { .. } => (
-            VNodeKind::Text,
-            VNodeProps::Text {
-                content: "[Accordion 暂不支持]".to_string(),
+        // 高级组件（Plan 010）
+        View::Accordion {
+            items,
+            allow_multiple,
+            ..
+        } => (
+            VNodeKind::Accordion,
+            VNodeProps::Accordion {
+                items: items
+                    .iter()
+                    .map(|item| AccordionItemProps {
+                        title: item.title.clone(),
+                        icon: item.icon,
+                        expanded: item.expanded,
+                        children: item.children.len(),
+                    })
+                    .collect(),
+                allow_multiple: *allow_multiple,
             },
         ),
 
-        View::Sidebar { .. } => (
-            VNodeKind::Text,
-            VNodeProps::Text {
-                content: "[Sidebar 暂不支持]".to_string(),
+        View::Sidebar {
+            width,
+            collapsible,
+            position,
+            ..
+        } => (
+            VNodeKind::Sidebar,
+            VNodeProps::Sidebar {
+                width: *width,
+                collapsible: *collapsible,
+                position: *position,
             },
         ),
 
-        View::Tabs { .. } => (
-            VNodeKind::Text,
-            VNodeProps::Text {
-                content: "[Tabs 暂不支持]".to_string(),
+        View::Tabs {
+            labels,
+            selected,
+            position,
+            ..
+        } => (
+            VNodeKind::Tabs,
+            VNodeProps::Tabs {
+                labels: labels.clone(),
+                selected: *selected,
+                position: *position,
             },
         ),
 
-        View::NavigationRail { .. } => (
-            VNodeKind::Text,
-            VNodeProps::Text {
-                content: "[NavigationRail 暂不支持]".to_string(),
+        View::NavigationRail {
+            items,
+            selected,
+            width,
+            show_labels,
+            ..
+        } => (
+            VNodeKind::NavigationRail,
+            VNodeProps::NavigationRail {
+                items: items.clone(),
+                selected: *selected,
+                width: *width,
+                show_labels: *show_labels,
             },
         ),
+
+        View::Keyed { child, .. } => extract_kind_and_props(child),
+    }
+}
+
+/// 从 View 中提取回调及其槽位
+fn extract_handler<M>(view: &View<M>) -> Option<(EventSlot, VNodeHandler<M>)>
+where
+    M: Clone + std::fmt::Debug,
+{
+    match view {
+        View::Button { onclick, .. } => {
+            Some((EventSlot::Click, VNodeHandler::Message(onclick.clone())))
+        }
+        View::Input { on_change, .. } => on_change
+            .clone()
+            .map(|cb| (EventSlot::Change, VNodeHandler::Input(cb))),
+        View::Checkbox { on_toggle, .. } => on_toggle
+            .clone()
+            .map(|msg| (EventSlot::Toggle, VNodeHandler::Message(msg))),
+        View::Radio { on_select, .. } => on_select
+            .clone()
+            .map(|msg| (EventSlot::Click, VNodeHandler::Message(msg))),
+        View::Select { on_select, .. } | View::RadioGroup { on_select, .. } => on_select
+            .clone()
+            .map(|cb| (EventSlot::Select, VNodeHandler::Select(cb))),
+        View::Slider { on_change, .. } => {
+            Some((EventSlot::Change, VNodeHandler::Slider(on_change.clone())))
+        }
+        View::Accordion { on_toggle, .. } => on_toggle
+            .clone()
+            .map(|cb| (EventSlot::Toggle, VNodeHandler::AccordionToggle(cb))),
+        View::Tabs { on_select, .. } => on_select
+            .clone()
+            .map(|cb| (EventSlot::Select, VNodeHandler::TabsSelect(cb))),
+        View::NavigationRail { on_select, .. } => on_select
+            .clone()
+            .map(|cb| (EventSlot::Select, VNodeHandler::NavigationRailSelect(cb))),
+        View::Keyed { child, .. } => extract_handler(child),
+        _ => None,
     }
 }
 
@@ -309,17 +480,383 @@ where
             }
             children
         }
+        View::Accordion { items, .. } => items
+            .iter()
+            .flat_map(|item| item.children.iter().cloned())
+            .collect(),
+        View::Sidebar { content, .. } => vec![*content.clone()],
         View::Tabs { contents, .. } => contents.clone(),
+        View::Keyed { child, .. } => extract_children(child),
         _ => Vec::new(),
     }
 }
 
+/// 逆向转换：VTree → View<M>
+///
+/// `handlers` 通常来自 `view_to_vtree_with_handlers`。按钮和滑块的回调是必需的，
+/// 缺失时返回错误；其他回调缺失时还原为 None。
+///
+/// # 示例
+///
+/// ```ignore
+/// let (vtree, handlers) = view_to_vtree_with_handlers(view);
+/// let restored = vtree_to_view(&vtree, &handlers)?;
+/// ```
+pub fn vtree_to_view<M>(tree: &VTree, handlers: &VNodeHandlers<M>) -> Result<View<M>, String>
+where
+    M: Clone + std::fmt::Debug,
+{
+    let root = tree.root().ok_or_else(|| "VTree 没有根节点".to_string())?;
+    convert_vnode_to_view(tree, root.id, handlers)
+}
+
+/// 将单个 VNode（及其子树）还原为 View
+fn convert_vnode_to_view<M>(
+    tree: &VTree,
+    id: VNodeId,
+    handlers: &VNodeHandlers<M>,
+) -> Result<View<M>, String>
+where
+    M: Clone + std::fmt::Debug,
+{
+    let node = tree.get(id).ok_or_else(|| format!("节点 {} 不存在", id))?;
+    let children = node
+        .children
+        .iter()
+        .map(|child_id| convert_vnode_to_view(tree, *child_id, handlers))
+        .collect::<Result<Vec<_>, _>>()?;
+    let style = node.style.clone();
+    let handler = handlers.get(&id);
+
+    let view = match (node.kind, &node.props) {
+        (VNodeKind::Text, VNodeProps::Empty) => View::Empty,
+
+        (VNodeKind::Text, VNodeProps::Text { content }) => View::Text {
+            content: content.clone(),
+            style,
+        },
+
+        (VNodeKind::Button, VNodeProps::Button { label, disabled }) => View::Button {
+            label: label.clone(),
+            onclick: handler_message(handler)
+                .ok_or_else(|| format!("按钮节点 {} 缺少点击回调", id))?,
+            disabled: *disabled,
+            style,
+        },
+
+        (VNodeKind::Column, VNodeProps::Layout { spacing, padding }) => View::Column {
+            children,
+            spacing: *spacing,
+            padding: *padding,
+            style,
+        },
+
+        (VNodeKind::Row, VNodeProps::Layout { spacing, padding }) => View::Row {
+            children,
+            spacing: *spacing,
+            padding: *padding,
+            style,
+        },
+
+        (
+            VNodeKind::Container,
+            VNodeProps::Container {
+                padding,
+                width,
+                height,
+                center_x,
+                center_y,
+            },
+        ) => View::Container {
+            child: Box::new(single_child(children, id)?),
+            padding: *padding,
+            width: *width,
+            height: *height,
+            center_x: *center_x,
+            center_y: *center_y,
+            style,
+        },
+
+        // Center 没有对应的 View 变体，还原为双向居中的容器
+        (VNodeKind::Center, _) => View::Container {
+            child: Box::new(single_child(children, id)?),
+            padding: 0,
+            width: None,
+            height: None,
+            center_x: true,
+            center_y: true,
+            style,
+        },
+
+        (VNodeKind::Scrollable, VNodeProps::Scrollable { width, height }) => View::Scrollable {
+            child: Box::new(single_child(children, id)?),
+            width: *width,
+            height: *height,
+            style,
+        },
+
+        (
+            VNodeKind::Input,
+            VNodeProps::Input {
+                placeholder,
+                value,
+                width,
+                password,
+                disabled,
+            },
+        ) => View::Input {
+            placeholder: placeholder.clone(),
+            value: value.clone(),
+            on_change: match handler {
+                Some(VNodeHandler::Input(callback)) => Some(callback.clone()),
+                _ => None,
+            },
+            width: *width,
+            password: *password,
+            disabled: *disabled,
+            style,
+        },
+
+        (VNodeKind::Checkbox, VNodeProps::Checkbox { label, is_checked }) => View::Checkbox {
+            is_checked: *is_checked,
+            label: label.clone(),
+            on_toggle: handler_message(handler),
+            style,
+        },
+
+        (VNodeKind::Radio, VNodeProps::Radio { label, is_selected }) => View::Radio {
+            label: label.clone(),
+            is_selected: *is_selected,
+            on_select: handler_message(handler),
+            style,
+        },
+
+        (
+            VNodeKind::RadioGroup,
+            VNodeProps::RadioGroup {
+                options,
+                selected_index,
+            },
+        ) => View::RadioGroup {
+            options: options.clone(),
+            selected_index: *selected_index,
+            on_select: match handler {
+                Some(VNodeHandler::Select(callback)) => Some(callback.clone()),
+                _ => None,
+            },
+            style,
+        },
+
+        (
+            VNodeKind::Select,
+            VNodeProps::Select {
+                options,
+                selected_index,
+            },
+        ) => View::Select {
+            options: options.clone(),
+            selected_index: *selected_index,
+            on_select: match handler {
+                Some(VNodeHandler::Select(callback)) => Some(callback.clone()),
+                _ => None,
+            },
+            style,
+        },
+
+        (VNodeKind::List, VNodeProps::List { spacing }) => View::List {
+            items: children,
+            spacing: *spacing,
+            style,
+        },
+
+        (
+            VNodeKind::Table,
+            VNodeProps::Table {
+                spacing,
+                col_spacing,
+                headers,
+                rows,
+            },
+        ) => {
+            let counts = std::iter::once(*headers).chain(rows.iter().copied());
+            let mut groups = split_children(children, counts, id)?.into_iter();
+            View::Table {
+                headers: groups.next().unwrap_or_default(),
+                rows: groups.collect(),
+                spacing: *spacing,
+                col_spacing: *col_spacing,
+                style,
+            }
+        }
+
+        (
+            VNodeKind::Slider,
+            VNodeProps::Slider {
+                min,
+                max,
+                value,
+                step,
+            },
+        ) => View::Slider {
+            min: *min,
+            max: *max,
+            value: *value,
+            on_change: match handler {
+                Some(VNodeHandler::Slider(on_change)) => on_change.clone(),
+                _ => return Err(format!("滑块节点 {} 缺少变更回调", id)),
+            },
+            step: *step,
+            style,
+        },
+
+        (VNodeKind::ProgressBar, VNodeProps::ProgressBar { progress }) => View::ProgressBar {
+            progress: *progress,
+            style,
+        },
+
+        (
+            VNodeKind::Accordion,
+            VNodeProps::Accordion {
+                items,
+                allow_multiple,
+            },
+        ) => {
+            let groups = split_children(children, items.iter().map(|item| item.children), id)?;
+            View::Accordion {
+                items: items
+                    .iter()
+                    .zip(groups)
+                    .map(|(item, children)| AccordionItem {
+                        title: item.title.clone(),
+                        icon: item.icon,
+                        children,
+                        expanded: item.expanded,
+                    })
+                    .collect(),
+                allow_multiple: *allow_multiple,
+                on_toggle: match handler {
+                    Some(VNodeHandler::AccordionToggle(callback)) => Some(callback.clone()),
+                    _ => None,
+                },
+                style,
+            }
+        }
+
+        (
+            VNodeKind::Sidebar,
+            VNodeProps::Sidebar {
+                width,
+                collapsible,
+                position,
+            },
+        ) => View::Sidebar {
+            content: Box::new(single_child(children, id)?),
+            width: *width,
+            collapsible: *collapsible,
+            position: *position,
+            style,
+        },
+
+        (
+            VNodeKind::Tabs,
+            VNodeProps::Tabs {
+                labels,
+                selected,
+                position,
+            },
+        ) => View::Tabs {
+            labels: labels.clone(),
+            contents: children,
+            selected: *selected,
+            position: *position,
+            on_select: match handler {
+                Some(VNodeHandler::TabsSelect(callback)) => Some(callback.clone()),
+                _ => None,
+            },
+            style,
+        },
+
+        (
+            VNodeKind::NavigationRail,
+            VNodeProps::NavigationRail {
+                items,
+                selected,
+                width,
+                show_labels,
+            },
+        ) => View::NavigationRail {
+            items: items.clone(),
+            selected: *selected,
+            width: *width,
+            show_labels: *show_labels,
+            on_select: match handler {
+                Some(VNodeHandler::NavigationRailSelect(callback)) => Some(callback.clone()),
+                _ => None,
+            },
+            style,
+        },
+
+        (kind, _) => return Err(format!("节点 {} 的属性与类型 {} 不匹配", id, kind)),
+    };
+
+    Ok(match &node.key {
+        Some(key) => view.key(key.clone()),
+        None => view,
+    })
+}
+
+/// 取出以消息形式存储的回调
+fn handler_message<M: Clone>(handler: Option<&VNodeHandler<M>>) -> Option<M> {
+    match handler {
+        Some(VNodeHandler::Message(msg)) => Some(msg.clone()),
+        _ => None,
+    }
+}
+
+/// 取出唯一的子节点（Container、Scrollable、Sidebar）
+fn single_child<M>(children: Vec<View<M>>, id: VNodeId) -> Result<View<M>, String>
+where
+    M: Clone + std::fmt::Debug,
+{
+    if children.len() != 1 {
+        return Err(format!("节点 {} 应有 1 个子节点，实际为 {}", id, children.len()));
+    }
+    Ok(children.into_iter().next().unwrap())
+}
+
+/// 按给定数量把扁平的子节点切分为若干组（Table 的行、Accordion 的分组）
+fn split_children<M>(
+    children: Vec<View<M>>,
+    counts: impl Iterator<Item = usize> + Clone,
+    id: VNodeId,
+) -> Result<Vec<Vec<View<M>>>, String>
+where
+    M: Clone + std::fmt::Debug,
+{
+    let expected: usize = counts.clone().sum();
+    if children.len() != expected {
+        return Err(format!(
+            "节点 {} 应有 {} 个子节点，实际为 {}",
+            id,
+            expected,
+            children.len()
+        ));
+    }
+
+    let mut iter = children.into_iter();
+    Ok(counts
+        .map(|count| iter.by_ref().take(count).collect())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::view::{NavigationRailItem, SidebarPosition, TabsPosition};
+    use crate::style::Style;
 
     // 测试用的简化消息类型
-    #[derive(Debug, Clone, Copy)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
     enum TestMsg {
         Click,
         Change,
@@ -357,6 +894,7 @@ mod tests {
         let view: View<TestMsg> = View::Button {
             label: "Click Me".to_string(),
             onclick: TestMsg::Click,
+            disabled: false,
             style: None,
         };
 
@@ -365,7 +903,7 @@ mod tests {
         assert_eq!(tree.node_count(), 1);
         let root = tree.root().unwrap();
         assert_eq!(root.kind, VNodeKind::Button);
-        if let VNodeProps::Button { label } = &root.props {
+        if let VNodeProps::Button { label, .. } = &root.props {
             assert_eq!(label, "Click Me");
         } else {
             panic!("Expected Button props");
@@ -498,6 +1036,7 @@ mod tests {
             padding,
             center_x,
             center_y,
+            ..
         } = &root.props
         {
             assert_eq!(*padding, 20);
@@ -521,6 +1060,7 @@ mod tests {
             on_change: None,
             width: None,
             password: false,
+            disabled: false,
             style: None,
         };
 
@@ -534,6 +1074,7 @@ mod tests {
             placeholder,
             value,
             password,
+            ..
         } = &root.props
         {
             assert_eq!(placeholder, "Enter text");
@@ -594,6 +1135,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_radio_group_conversion() {
+        let view: View<TestMsg> = View::radio_group(vec!["Small".to_string(), "Large".to_string()])
+            .selected(1)
+            .on_choose(|_, _| TestMsg::Click);
+
+        let (tree, handlers) = view_to_vtree_with_handlers(view);
+
+        let root = tree.root().unwrap();
+        assert_eq!(root.kind, VNodeKind::RadioGroup);
+        assert_eq!(root.event, Some(EventSlot::Select));
+        assert_eq!(
+            root.props,
+            VNodeProps::RadioGroup {
+                options: vec!["Small".to_string(), "Large".to_string()],
+                selected_index: Some(1),
+            }
+        );
+
+        match vtree_to_view(&tree, &handlers).unwrap() {
+            View::RadioGroup { selected_index, on_select: Some(callback), .. } => {
+                assert_eq!(selected_index, Some(1));
+                assert_eq!(callback.call(0, "Small"), TestMsg::Click);
+            }
+            other => panic!("Expected RadioGroup, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_scrollable_conversion() {
         let view: View<TestMsg> = View::Scrollable {
@@ -644,7 +1213,7 @@ mod tests {
             max: 100.0,
             value: 50.0,
             step: Some(1.0),
-            on_change: |_v| TestMsg::Change,
+            on_change: SliderCallback::new(|_v| TestMsg::Change),
             style: None,
         };
 
@@ -707,6 +1276,7 @@ mod tests {
                 View::Button {
                     label: "Click".to_string(),
                     onclick: TestMsg::Click,
+                    disabled: false,
                     style: None,
                 },
             ],
@@ -728,24 +1298,51 @@ mod tests {
     }
 
     #[test]
-    fn test_advanced_components_placeholder() {
-        // 测试高级组件（暂不支持）返回占位符
-        let accordion_view: View<TestMsg> = View::Accordion {
-            items: vec![],
+    fn test_advanced_components_conversion() {
+        let view: View<TestMsg> = View::Accordion {
+            items: vec![
+                AccordionItem::new("General")
+                    .with_icon('⚙')
+                    .with_expanded(true)
+                    .with_children(vec![View::text("A"), View::text("B")]),
+                AccordionItem::new("Empty"),
+            ],
             allow_multiple: false,
-            on_toggle: None,
+            on_toggle: Some(AccordionToggleCallback::new(|_, _| TestMsg::Change)),
             style: None,
         };
 
-        let tree = view_to_vtree(accordion_view);
+        let tree = view_to_vtree(view);
         let root = tree.root().unwrap();
-        assert_eq!(root.kind, VNodeKind::Text);
+        assert_eq!(root.kind, VNodeKind::Accordion);
+        assert_eq!(root.event, Some(EventSlot::Toggle));
+        assert_eq!(root.children.len(), 2);
 
-        if let VNodeProps::Text { content } = &root.props {
-            assert!(content.contains("暂不支持"));
+        if let VNodeProps::Accordion { items, .. } = &root.props {
+            assert_eq!(items[0].title, "General");
+            assert_eq!(items[0].icon, Some('⚙'));
+            assert!(items[0].expanded);
+            assert_eq!(items[0].children, 2);
+            assert_eq!(items[1].children, 0);
         } else {
-            panic!("Expected placeholder text");
+            panic!("Expected Accordion props");
         }
+
+        let view: View<TestMsg> = View::Tabs {
+            labels: vec!["One".to_string(), "Two".to_string()],
+            contents: vec![View::text("First"), View::text("Second")],
+            selected: 1,
+            position: TabsPosition::Top,
+            on_select: None,
+            style: None,
+        };
+
+        let tree = view_to_vtree(view);
+        let root = tree.root().unwrap();
+        assert_eq!(root.kind, VNodeKind::Tabs);
+        assert_eq!(root.event, None);
+        assert_eq!(root.children.len(), 2);
+        assert!(matches!(&root.props, VNodeProps::Tabs { selected: 1, .. }));
     }
 
     #[test]
@@ -759,6 +1356,7 @@ mod tests {
                 View::Button {
                     label: "Click".to_string(),
                     onclick: TestMsg::Click,
+                    disabled: false,
                     style: None,
                 },
             ],
@@ -777,4 +1375,186 @@ mod tests {
         assert_eq!(stats.leaf_nodes, 2);
         assert_eq!(stats.max_depth, 2);
     }
+
+    #[test]
+    fn test_keyed_conversion() {
+        let view: View<TestMsg> = View::col()
+            .child(View::text("A").key("a"))
+            .child(View::text("B"))
+            .build();
+
+        let tree = view_to_vtree(view);
+
+        // Keyed 不产生额外节点
+        assert_eq!(tree.node_count(), 3);
+        let children = tree.children(tree.root().unwrap().id).unwrap();
+        assert_eq!(children[0].key.as_deref(), Some("a"));
+        assert_eq!(children[0].kind, VNodeKind::Text);
+        assert_eq!(children[1].key, None);
+    }
+
+    #[test]
+    fn test_style_conversion() {
+        let view: View<TestMsg> = View::col()
+            .style("p-4 gap-2")
+            .child(View::text_styled("Hi", "text-lg font-bold").key("title"))
+            .child(View::text("Plain"))
+            .build();
+
+        let tree = view_to_vtree(view);
+
+        let root = tree.root().unwrap();
+        assert_eq!(root.style, Some(Style::parse("p-4 gap-2").unwrap()));
+
+        let children = tree.children(root.id).unwrap();
+        // Keyed 包装下的样式同样保留
+        assert_eq!(children[0].style, Some(Style::parse("text-lg font-bold").unwrap()));
+        assert_eq!(children[1].style, None);
+    }
+
+    /// 正向再逆向转换后，再次正向转换应得到完全相同的树
+    fn assert_round_trip(view: View<TestMsg>) -> View<TestMsg> {
+        let (tree, handlers) = view_to_vtree_with_handlers(view);
+        let restored = vtree_to_view(&tree, &handlers).unwrap();
+
+        let (again, _) = view_to_vtree_with_handlers(restored.clone());
+        assert_eq!(VTree::diff(&tree, &again).len(), 0);
+        for (a, b) in tree.nodes().iter().zip(again.nodes()) {
+            assert_eq!(a.kind, b.kind);
+            assert_eq!(a.props, b.props);
+            assert_eq!(a.event, b.event);
+        }
+
+        restored
+    }
+
+    #[test]
+    fn test_round_trip_basic_components() {
+        let view = View::col()
+            .style("p-4 gap-2")
+            .child(View::text("Title").key("title"))
+            .child(View::button("Go", TestMsg::Click))
+            .child(View::Input {
+                placeholder: "Name".to_string(),
+                value: "Ada".to_string(),
+                on_change: Some(InputCallback::new(|_| TestMsg::Change)),
+                width: Some(120),
+                password: false,
+                disabled: false,
+                style: None,
+            })
+            .child(View::Container {
+                child: Box::new(View::Empty),
+                padding: 4,
+                width: Some(10),
+                height: None,
+                center_x: true,
+                center_y: false,
+                style: None,
+            })
+            .child(View::Slider {
+                min: 0.0,
+                max: 1.0,
+                value: 0.5,
+                on_change: SliderCallback::new(|_| TestMsg::Change),
+                step: Some(0.1),
+                style: None,
+            })
+            .build();
+
+        let restored = assert_round_trip(view);
+
+        if let View::Column { children, .. } = restored {
+            assert_eq!(children[0].key_str(), Some("title"));
+            assert!(matches!(&children[1], View::Button { onclick: TestMsg::Click, .. }));
+            assert!(matches!(&children[2], View::Input { width: Some(120), .. }));
+        } else {
+            panic!("Expected Column");
+        }
+    }
+
+    #[test]
+    fn test_round_trip_table() {
+        let view: View<TestMsg> = View::Table {
+            headers: vec![View::text("Name"), View::text("Age")],
+            rows: vec![
+                vec![View::text("Ada"), View::text("36")],
+                vec![View::text("Alan")],
+            ],
+            spacing: 5,
+            col_spacing: 10,
+            style: None,
+        };
+
+        if let View::Table { headers, rows, .. } = assert_round_trip(view) {
+            assert_eq!(headers.len(), 2);
+            assert_eq!(rows.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+        } else {
+            panic!("Expected Table");
+        }
+    }
+
+    #[test]
+    fn test_round_trip_navigation_components() {
+        let view: View<TestMsg> = View::Sidebar {
+            content: Box::new(
+                View::col()
+                    .child(View::NavigationRail {
+                        items: vec![
+                            NavigationRailItem::new('🏠', "Home"),
+                            NavigationRailItem::new('✉', "Inbox").with_badge("3"),
+                        ],
+                        selected: 1,
+                        width: 72.0,
+                        show_labels: true,
+                        on_select: Some(NavigationRailSelectCallback::new(|_| TestMsg::Click)),
+                        style: None,
+                    })
+                    .child(View::Accordion {
+                        items: vec![AccordionItem::new("More")
+                            .with_children(vec![View::text("Hidden")])],
+                        allow_multiple: true,
+                        on_toggle: None,
+                        style: None,
+                    })
+                    .child(View::Tabs {
+                        labels: vec!["A".to_string(), "B".to_string()],
+                        contents: vec![View::text("a"), View::text("b")],
+                        selected: 0,
+                        position: TabsPosition::Left,
+                        on_select: Some(TabsSelectCallback::new(|_| TestMsg::Click)),
+                        style: None,
+                    })
+                    .build(),
+            ),
+            width: 240.0,
+            collapsible: true,
+            position: SidebarPosition::Right,
+            style: None,
+        };
+
+        let restored = assert_round_trip(view);
+
+        let content = match restored {
+            View::Sidebar { content, position: SidebarPosition::Right, .. } => content,
+            other => panic!("Expected Sidebar, got {:?}", other),
+        };
+        let children = match *content {
+            View::Column { children, .. } => children,
+            other => panic!("Expected Column, got {:?}", other),
+        };
+        match &children[0] {
+            View::NavigationRail { items, on_select, .. } => {
+                assert_eq!(items[1].badge.as_deref(), Some("3"));
+                assert_eq!(on_select.as_ref().unwrap().call(0), TestMsg::Click);
+            }
+            other => panic!("Expected NavigationRail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_vtree_to_view_missing_handler() {
+        let tree = view_to_vtree(View::button("Go", TestMsg::Click));
+        assert!(vtree_to_view::<TestMsg>(&tree, &VNodeHandlers::new()).is_err());
+    }
 }
@@ -0,0 +1,681 @@
+//! VTree Diff - 计算两棵 VTree 之间的补丁列表
+//!
+//! `view_to_vtree` 每次渲染都会生成一棵全新的树。本模块比较新旧两棵树，
+//! 只输出发生变化的部分，渲染器据此增量更新，而不必重建整棵树。
+//!
+//! ## 核心算法
+//!
+//! - **同类型比较**：节点类型相同则比较属性，再递归比较子节点；类型不同则整体替换
+//! - **key 匹配**：带 key 的子节点按 key 匹配，不带 key 的按出现顺序匹配
+//! - **最少移动**：对匹配上的子节点求最长递增子序列（LIS），只移动不在 LIS 中的节点
+//!
+//! ## ID 约定
+//!
+//! 补丁中的 ID 都属于**旧树**。新插入的节点在 diff 时就从旧树的 ID 空间分配，
+//! 因此应用补丁后，未变化的节点保持原 ID，渲染器可以据此保留状态。
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! use auto_ui::vnode::VTree;
+//! use auto_ui::vnode_converter::view_to_vtree;
+//!
+//! let mut current = view_to_vtree(old_view);
+//! let next = view_to_vtree(new_view);
+//!
+//! let patches = VTree::diff(&current, &next);
+//! current.apply(patches)?;
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::style::Style;
+use crate::vnode::{EventSlot, VNodeId, VNodeProps, VTree};
+
+/// VTree 补丁
+#[derive(Debug, Clone)]
+pub enum Patch {
+    /// 插入新子树：`subtree` 的根节点插入到 `parent` 的子节点中，位于 `before` 之前
+    /// （`before` 为 None 表示追加到末尾；`parent` 为 None 表示成为整棵树的根）
+    Insert {
+        parent: Option<VNodeId>,
+        before: Option<VNodeId>,
+        subtree: VTree,
+    },
+
+    /// 删除节点及其整棵子树
+    Remove { id: VNodeId },
+
+    /// 在同一父节点内移动子节点到 `before` 之前（None 表示移到末尾）
+    Move {
+        parent: VNodeId,
+        id: VNodeId,
+        before: Option<VNodeId>,
+    },
+
+    /// 节点类型改变：用 `subtree` 替换该节点及其子树（子树根节点沿用原 ID）
+    Replace { id: VNodeId, subtree: VTree },
+
+    /// 节点属性改变
+    PropsChanged { id: VNodeId, props: VNodeProps },
+
+    /// 文本节点内容改变
+    TextChanged { id: VNodeId, content: String },
+
+    /// 节点样式改变（None 表示移除样式）
+    StyleChanged { id: VNodeId, style: Option<Style> },
+
+    /// 节点回调槽位改变（None 表示不再有回调）
+    EventChanged { id: VNodeId, event: Option<EventSlot> },
+}
+
+impl VTree {
+    /// 比较两棵树，返回把 `old` 变为 `new` 所需的补丁列表
+    ///
+    /// 两棵树完全相同时返回空列表。
+    pub fn diff(old: &VTree, new: &VTree) -> Vec<Patch> {
+        let mut differ = Differ {
+            old,
+            new,
+            next_id: old.peek_next_id(),
+            patches: Vec::new(),
+        };
+
+        match (old.root(), new.root()) {
+            (None, None) => {}
+            (Some(old_root), None) => differ.patches.push(Patch::Remove { id: old_root.id }),
+            (None, Some(new_root)) => {
+                let subtree = differ.copy_subtree(new_root.id, None, None);
+                differ.patches.push(Patch::Insert {
+                    parent: None,
+                    before: None,
+                    subtree,
+                });
+            }
+            (Some(old_root), Some(new_root)) => differ.diff_node(old_root.id, new_root.id),
+        }
+
+        differ.patches
+    }
+
+    /// 按顺序应用补丁
+    ///
+    /// 补丁必须来自以当前树为 `old` 的 `VTree::diff`。
+    pub fn apply(&mut self, patches: Vec<Patch>) -> Result<(), String> {
+        for patch in patches {
+            match patch {
+                Patch::Insert {
+                    parent,
+                    before,
+                    subtree,
+                } => {
+                    let root = subtree
+                        .root()
+                        .map(|r| r.id)
+                        .ok_or_else(|| "插入的子树为空".to_string())?;
+
+                    if let Some(parent) = parent {
+                        let siblings = &self
+                            .get(parent)
+                            .ok_or_else(|| format!("插入目标父节点 {} 不存在", parent))?
+                            .children;
+                        let index = insert_position(siblings, before)?;
+                        for node in subtree.into_nodes() {
+                            self.add_node(node);
+                        }
+                        if let Some(parent_node) = self.get_mut(parent) {
+                            parent_node.children.insert(index, root);
+                        }
+                    } else {
+                        for node in subtree.into_nodes() {
+                            self.add_node(node);
+                        }
+                        self.set_root_id(Some(root));
+                    }
+                }
+
+                Patch::Remove { id } => {
+                    let parent = self
+                        .get(id)
+                        .ok_or_else(|| format!("要删除的节点 {} 不存在", id))?
+                        .parent;
+                    if let Some(parent_node) = parent.and_then(|p| self.get_mut(p)) {
+                        parent_node.children.retain(|c| *c != id);
+                    }
+                    let ids = self.subtree_ids(id);
+                    self.remove_nodes(&ids);
+                }
+
+                Patch::Move { parent, id, before } => {
+                    let parent_node = self
+                        .get_mut(parent)
+                        .ok_or_else(|| format!("移动目标父节点 {} 不存在", parent))?;
+                    parent_node.children.retain(|c| *c != id);
+                    let index = insert_position(&parent_node.children, before)?;
+                    parent_node.children.insert(index, id);
+                }
+
+                Patch::Replace { id, subtree } => {
+                    let was_root = self.root().map(|r| r.id) == Some(id);
+                    let ids = self.subtree_ids(id);
+                    if ids.is_empty() {
+                        return Err(format!("要替换的节点 {} 不存在", id));
+                    }
+                    self.remove_nodes(&ids);
+                    for node in subtree.into_nodes() {
+                        self.add_node(node);
+                    }
+                    if was_root {
+                        self.set_root_id(Some(id));
+                    }
+                }
+
+                Patch::PropsChanged { id, props } => {
+                    self.get_mut(id)
+                        .ok_or_else(|| format!("节点 {} 不存在", id))?
+                        .props = props;
+                }
+
+                Patch::TextChanged { id, content } => {
+                    self.get_mut(id)
+                        .ok_or_else(|| format!("节点 {} 不存在", id))?
+                        .props = VNodeProps::Text { content };
+                }
+
+                Patch::StyleChanged { id, style } => {
+                    self.get_mut(id)
+                        .ok_or_else(|| format!("节点 {} 不存在", id))?
+                        .style = style;
+                }
+
+                Patch::EventChanged { id, event } => {
+                    self.get_mut(id)
+                        .ok_or_else(|| format!("节点 {} 不存在", id))?
+                        .event = event;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 计算 `before` 在兄弟列表中的插入位置
+fn insert_position(siblings: &[VNodeId], before: Option<VNodeId>) -> Result<usize, String> {
+    match before {
+        None => Ok(siblings.len()),
+        Some(anchor) => siblings
+            .iter()
+            .position(|c| *c == anchor)
+            .ok_or_else(|| format!("锚点节点 {} 不在父节点中", anchor)),
+    }
+}
+
+/// diff 过程中的状态
+struct Differ<'a> {
+    old: &'a VTree,
+    new: &'a VTree,
+    /// 为新插入节点分配的下一个 ID（位于旧树的 ID 空间）
+    next_id: u64,
+    patches: Vec<Patch>,
+}
+
+impl Differ<'_> {
+    fn alloc_id(&mut self) -> VNodeId {
+        let id = VNodeId::new(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// 比较一对已匹配的节点
+    fn diff_node(&mut self, old_id: VNodeId, new_id: VNodeId) {
+        let (old, new) = (self.old, self.new);
+        let (old_node, new_node) = match (old.get(old_id), new.get(new_id)) {
+            (Some(o), Some(n)) => (o, n),
+            _ => return,
+        };
+
+        if old_node.kind != new_node.kind {
+            let subtree = self.copy_subtree(new_id, Some(old_id), old_node.parent);
+            self.patches.push(Patch::Replace { id: old_id, subtree });
+            return;
+        }
+
+        if old_node.props != new_node.props {
+            let patch = match (&old_node.props, &new_node.props) {
+                (VNodeProps::Text { .. }, VNodeProps::Text { content }) => Patch::TextChanged {
+                    id: old_id,
+                    content: content.clone(),
+                },
+                (_, props) => Patch::PropsChanged {
+                    id: old_id,
+                    props: props.clone(),
+                },
+            };
+            self.patches.push(patch);
+        }
+
+        if old_node.style != new_node.style {
+            self.patches.push(Patch::StyleChanged {
+                id: old_id,
+                style: new_node.style.clone(),
+            });
+        }
+
+        if old_node.event != new_node.event {
+            self.patches.push(Patch::EventChanged {
+                id: old_id,
+                event: new_node.event,
+            });
+        }
+
+        self.diff_children(old_id, &old_node.children, &new_node.children);
+    }
+
+    /// 比较两组子节点：匹配、删除、递归、移动、插入
+    fn diff_children(&mut self, parent: VNodeId, old_kids: &[VNodeId], new_kids: &[VNodeId]) {
+        let (old, new) = (self.old, self.new);
+
+        // 1. 匹配：带 key 的按 key，不带 key 的按出现顺序
+        let mut keyed: HashMap<&str, VNodeId> = HashMap::new();
+        let mut unkeyed: VecDeque<VNodeId> = VecDeque::new();
+        for &id in old_kids {
+            match old.get(id).and_then(|n| n.key.as_deref()) {
+                Some(key) => {
+                    keyed.insert(key, id);
+                }
+                None => unkeyed.push_back(id),
+            }
+        }
+
+        let matches: Vec<Option<VNodeId>> = new_kids
+            .iter()
+            .map(|&id| match new.get(id).and_then(|n| n.key.as_deref()) {
+                Some(key) => keyed.remove(key),
+                None => unkeyed.pop_front(),
+            })
+            .collect();
+
+        // 2. 删除未匹配的旧节点
+        let matched: HashSet<VNodeId> = matches.iter().flatten().copied().collect();
+        for &id in old_kids {
+            if !matched.contains(&id) {
+                self.patches.push(Patch::Remove { id });
+            }
+        }
+
+        // 3. 递归比较匹配上的节点
+        for (new_id, old_id) in new_kids.iter().zip(&matches) {
+            if let Some(old_id) = old_id {
+                self.diff_node(*old_id, *new_id);
+            }
+        }
+
+        // 4. 求 LIS，位于 LIS 中的节点保持不动
+        let old_positions: HashMap<VNodeId, usize> =
+            old_kids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let sequence: Vec<(usize, usize)> = matches
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| m.map(|id| (i, old_positions[&id])))
+            .collect();
+        let positions: Vec<usize> = sequence.iter().map(|&(_, pos)| pos).collect();
+        let stable: HashSet<usize> = longest_increasing_subsequence(&positions)
+            .into_iter()
+            .map(|k| sequence[k].0)
+            .collect();
+
+        // 5. 从后往前移动/插入，锚点总是已就位的下一个兄弟节点
+        let mut anchor = None;
+        for (i, (&new_id, old_match)) in new_kids.iter().zip(&matches).enumerate().rev() {
+            match *old_match {
+                Some(old_id) => {
+                    if !stable.contains(&i) {
+                        self.patches.push(Patch::Move {
+                            parent,
+                            id: old_id,
+                            before: anchor,
+                        });
+                    }
+                    anchor = Some(old_id);
+                }
+                None => {
+                    let subtree = self.copy_subtree(new_id, None, Some(parent));
+                    let root = subtree.root().map(|r| r.id);
+                    self.patches.push(Patch::Insert {
+                        parent: Some(parent),
+                        before: anchor,
+                        subtree,
+                    });
+                    anchor = root;
+                }
+            }
+        }
+    }
+
+    /// 复制新树中的子树，并在旧树的 ID 空间中重新编号
+    fn copy_subtree(
+        &mut self,
+        new_id: VNodeId,
+        root_id: Option<VNodeId>,
+        parent: Option<VNodeId>,
+    ) -> VTree {
+        let new = self.new;
+        let ids = new.subtree_ids(new_id);
+
+        let mut mapping: HashMap<VNodeId, VNodeId> = HashMap::with_capacity(ids.len());
+        for &id in &ids {
+            let mapped = match root_id {
+                Some(root_id) if id == new_id => root_id,
+                _ => self.alloc_id(),
+            };
+            mapping.insert(id, mapped);
+        }
+
+        let mut tree = VTree::new();
+        for &id in &ids {
+            let source = match new.get(id) {
+                Some(node) => node,
+                None => continue,
+            };
+
+            let mut node = source.clone();
+            node.id = mapping[&id];
+            node.parent = if id == new_id {
+                parent
+            } else {
+                source.parent.map(|p| mapping[&p])
+            };
+            node.children = source.children.iter().map(|c| mapping[c]).collect();
+
+            if id == new_id {
+                tree.set_root(node);
+            } else {
+                tree.add_node(node);
+            }
+        }
+
+        tree
+    }
+}
+
+/// 最长严格递增子序列，返回其在输入中的下标（升序）
+fn longest_increasing_subsequence(sequence: &[usize]) -> Vec<usize> {
+    // tails[k]: 长度为 k+1 的递增子序列的最小结尾下标
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; sequence.len()];
+
+    for (i, &value) in sequence.iter().enumerate() {
+        let pos = tails.partition_point(|&t| sequence[t] < value);
+        if pos > 0 {
+            previous[i] = Some(tails[pos - 1]);
+        }
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result.push(i);
+        current = previous[i];
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::View;
+    use crate::vnode::VNodeKind;
+    use crate::vnode_converter::view_to_vtree;
+
+    #[derive(Debug, Clone, Copy)]
+    enum TestMsg {
+        Click,
+    }
+
+    fn keyed_list(keys: &[&str]) -> VTree {
+        let mut col = View::col();
+        for key in keys {
+            col = col.child(View::<TestMsg>::text(key.to_string()).key(*key));
+        }
+        view_to_vtree(col.build())
+    }
+
+    /// 比较两棵树结构是否一致（忽略 ID）
+    fn assert_same_shape(a: &VTree, b: &VTree) {
+        fn compare(a: &VTree, a_id: VNodeId, b: &VTree, b_id: VNodeId) {
+            let (na, nb) = (a.get(a_id).unwrap(), b.get(b_id).unwrap());
+            assert_eq!(na.kind, nb.kind);
+            assert_eq!(na.props, nb.props);
+            assert_eq!(na.key, nb.key);
+            assert_eq!(na.style, nb.style);
+            assert_eq!(na.event, nb.event);
+            assert_eq!(na.children.len(), nb.children.len());
+            for (ca, cb) in na.children.iter().zip(&nb.children) {
+                assert_eq!(a.get(*ca).unwrap().parent, Some(a_id));
+                compare(a, *ca, b, *cb);
+            }
+        }
+
+        assert_eq!(a.node_count(), b.node_count());
+        compare(a, a.root().unwrap().id, b, b.root().unwrap().id);
+        assert!(a.validate().is_ok());
+    }
+
+    fn diff_and_apply(old: &VTree, new: &VTree) -> Vec<Patch> {
+        let patches = VTree::diff(old, new);
+        let mut applied = old.clone();
+        applied.apply(patches.clone()).unwrap();
+        assert_same_shape(&applied, new);
+        patches
+    }
+
+    #[test]
+    fn test_identical_trees() {
+        let old = keyed_list(&["a", "b", "c"]);
+        let new = keyed_list(&["a", "b", "c"]);
+        assert!(VTree::diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_text_changed() {
+        let old = view_to_vtree(View::<TestMsg>::text("Hello"));
+        let new = view_to_vtree(View::<TestMsg>::text("World"));
+
+        let patches = diff_and_apply(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::TextChanged { content, .. } if content == "World"));
+    }
+
+    #[test]
+    fn test_props_changed() {
+        let old = view_to_vtree(View::<TestMsg>::checkbox(false, "Remember"));
+        let new = view_to_vtree(View::<TestMsg>::checkbox(true, "Remember"));
+
+        let patches = diff_and_apply(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(
+            &patches[0],
+            Patch::PropsChanged { props: VNodeProps::Checkbox { is_checked: true, .. }, .. }
+        ));
+    }
+
+    #[test]
+    fn test_style_changed() {
+        let old = view_to_vtree(View::<TestMsg>::text_styled("Hi", "text-sm"));
+        let new = view_to_vtree(View::<TestMsg>::text_styled("Hi", "text-lg font-bold"));
+
+        let patches = diff_and_apply(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::StyleChanged { style: Some(_), .. }));
+
+        let patches = diff_and_apply(&new, &view_to_vtree(View::<TestMsg>::text("Hi")));
+        assert!(matches!(&patches[0], Patch::StyleChanged { style: None, .. }));
+    }
+
+    #[test]
+    fn test_event_changed() {
+        let old = view_to_vtree(View::<TestMsg>::checkbox(false, "Remember"));
+        let new = view_to_vtree(View::Checkbox {
+            is_checked: false,
+            label: "Remember".to_string(),
+            on_toggle: Some(TestMsg::Click),
+            style: None,
+        });
+
+        let patches = diff_and_apply(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::EventChanged { event: Some(EventSlot::Toggle), .. }));
+    }
+
+    #[test]
+    fn test_kind_changed_replaces() {
+        let old = view_to_vtree(View::<TestMsg>::col().child(View::text("A")).build());
+        let new = view_to_vtree(View::col().child(View::button("A", TestMsg::Click)).build());
+
+        let patches = diff_and_apply(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::Replace { .. }));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let old = keyed_list(&["a", "b", "c"]);
+
+        let patches = diff_and_apply(&old, &keyed_list(&["a", "b", "x", "c"]));
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::Insert { before: Some(_), .. }));
+
+        let patches = diff_and_apply(&old, &keyed_list(&["a", "c"]));
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::Remove { .. }));
+    }
+
+    #[test]
+    fn test_keyed_move_is_minimal() {
+        let old = keyed_list(&["a", "b", "c", "d"]);
+
+        // 把第一个移到末尾：只需要一次移动
+        let patches = diff_and_apply(&old, &keyed_list(&["b", "c", "d", "a"]));
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::Move { before: None, .. }));
+
+        // 交换两端
+        let patches = diff_and_apply(&old, &keyed_list(&["d", "b", "c", "a"]));
+        assert_eq!(patches.len(), 2);
+        assert!(patches.iter().all(|p| matches!(p, Patch::Move { .. })));
+    }
+
+    #[test]
+    fn test_keyed_move_keeps_ids() {
+        let old = keyed_list(&["a", "b", "c"]);
+        let new = keyed_list(&["c", "a", "b"]);
+
+        let id_of = |tree: &VTree, key: &str| {
+            tree.nodes()
+                .iter()
+                .find(|n| n.key.as_deref() == Some(key))
+                .unwrap()
+                .id
+        };
+
+        let mut applied = old.clone();
+        applied.apply(VTree::diff(&old, &new)).unwrap();
+
+        for key in ["a", "b", "c"] {
+            assert_eq!(id_of(&applied, key), id_of(&old, key));
+        }
+    }
+
+    #[test]
+    fn test_mixed_changes() {
+        let old = view_to_vtree(
+            View::col()
+                .child(View::<TestMsg>::text("Title"))
+                .child(View::text("1").key("1"))
+                .child(View::text("2").key("2"))
+                .child(View::text("3").key("3"))
+                .build(),
+        );
+        let new = view_to_vtree(
+            View::col()
+                .child(View::<TestMsg>::text("New Title"))
+                .child(View::text("3").key("3"))
+                .child(View::text("two").key("2"))
+                .child(View::text("4").key("4"))
+                .build(),
+        );
+
+        diff_and_apply(&old, &new);
+    }
+
+    #[test]
+    fn test_nested_insert_ids_are_fresh() {
+        let old = view_to_vtree(View::<TestMsg>::col().child(View::text("A")).build());
+        let new = view_to_vtree(
+            View::<TestMsg>::col()
+                .child(View::text("A"))
+                .child(View::row().child(View::text("B")).child(View::text("C")).build())
+                .build(),
+        );
+
+        let mut applied = old.clone();
+        applied.apply(VTree::diff(&old, &new)).unwrap();
+        assert_same_shape(&applied, &new);
+
+        let ids: HashSet<VNodeId> = applied.nodes().iter().map(|n| n.id).collect();
+        assert_eq!(ids.len(), applied.node_count());
+    }
+
+    #[test]
+    fn test_empty_trees() {
+        let empty = VTree::new();
+        let tree = keyed_list(&["a"]);
+
+        let patches = VTree::diff(&empty, &tree);
+        let mut applied = empty.clone();
+        applied.apply(patches).unwrap();
+        assert_same_shape(&applied, &tree);
+
+        let mut applied = tree.clone();
+        applied.apply(VTree::diff(&tree, &empty)).unwrap();
+        assert!(applied.is_empty());
+        assert!(applied.root().is_none());
+    }
+
+    #[test]
+    fn test_large_list_single_edit() {
+        let keys: Vec<String> = (0..5000).map(|i| format!("row-{}", i)).collect();
+
+        let build = |edited: Option<usize>| {
+            let mut col = View::<TestMsg>::col();
+            for (i, key) in keys.iter().enumerate() {
+                let content = if Some(i) == edited { "edited".to_string() } else { key.clone() };
+                col = col.child(View::text(content).key(key.clone()));
+            }
+            view_to_vtree(col.build())
+        };
+
+        let old = build(None);
+        let new = build(Some(2500));
+
+        let patches = VTree::diff(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::TextChanged { .. }));
+        assert_eq!(old.root().unwrap().kind, VNodeKind::Column);
+    }
+
+    #[test]
+    fn test_lis() {
+        assert_eq!(longest_increasing_subsequence(&[]), Vec::<usize>::new());
+        assert_eq!(longest_increasing_subsequence(&[0, 1, 2]), vec![0, 1, 2]);
+        assert_eq!(longest_increasing_subsequence(&[1, 2, 3, 0]), vec![0, 1, 2]);
+        assert_eq!(longest_increasing_subsequence(&[3, 1, 2, 0]).len(), 2);
+    }
+}
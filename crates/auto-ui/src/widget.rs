@@ -6,7 +6,9 @@
 
 use crate::view::View;
 use std::fmt::Debug;
-use std::marker::PhantomData;
+
+// Command now lives in command.rs and is shared with the Component trait
+pub use crate::command::Command;
 
 /// Legacy trait for UI widgets - superseded by Component trait
 ///
@@ -24,21 +26,3 @@ pub trait Widget: Sized {
         Command::none()
     }
 }
-
-/// Command type for side effects (kept for potential future use)
-pub enum Command<M> {
-    None,
-    Batch(Vec<Box<Command<M>>>),
-    // More to be added
-    _Marker(PhantomData<M>),
-}
-
-impl<M> Command<M> {
-    pub fn none() -> Self {
-        Command::None
-    }
-
-    pub fn batch(commands: Vec<Command<M>>) -> Self {
-        Command::Batch(commands.into_iter().map(Box::new).collect())
-    }
-}
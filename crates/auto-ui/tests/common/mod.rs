@@ -0,0 +1,37 @@
+// Shared golden file check for the transpiler targets
+//
+// Every target transpiles the same scratch/*.at samples and compares them
+// against tests/golden/<dir>/<sample>.<ext>. After an intended change to the
+// output, regenerate the files with UPDATE_GOLDEN=1.
+
+use auto_ui::trans::{transpile_file_to, Target};
+use std::path::PathBuf;
+
+/// Samples from scratch/ that every target is checked against
+pub const SAMPLES: [&str; 5] = [
+    "text_simple",
+    "col_test",
+    "simple_components",
+    "ui_components",
+    "layout_showcase",
+];
+
+/// Transpile `sample` for `target` and compare it with its golden file
+pub fn check_golden(target: Target, dir: &str, ext: &str, sample: &str) {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let input = manifest_dir.join("../../scratch").join(format!("{}.at", sample));
+    let golden = manifest_dir.join("tests/golden").join(dir).join(format!("{}.{}", sample, ext));
+
+    let output = transpile_file_to(&input, target)
+        .unwrap_or_else(|e| panic!("Failed to transpile {}: {}", input.display(), e));
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&golden, &output).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&golden).unwrap_or_else(|e| {
+        panic!("Missing golden file {} ({}); run with UPDATE_GOLDEN=1 to create it", golden.display(), e)
+    });
+    assert_eq!(output, expected, "Output for {} differs from its golden file", sample);
+}
@@ -0,0 +1,23 @@
+// Golden file tests for the Jetpack Compose transpiler target
+//
+// Each scratch/*.at sample in `common::SAMPLES` is transpiled and compared
+// against tests/golden/compose/<sample>.kt. After an intended change to the
+// output, regenerate the files with:
+//
+//   UPDATE_GOLDEN=1 cargo test -p auto-ui --features transpiler --test compose_golden_test
+
+#[cfg(feature = "transpiler")]
+mod common;
+
+#[cfg(feature = "transpiler")]
+mod tests {
+    use crate::common::{check_golden, SAMPLES};
+    use auto_ui::trans::Target;
+
+    #[test]
+    fn test_compose_golden() {
+        for sample in SAMPLES {
+            check_golden(Target::Compose, "compose", "kt", sample);
+        }
+    }
+}
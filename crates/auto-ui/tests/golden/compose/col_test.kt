@@ -0,0 +1,20 @@
+// Auto-generated from Auto language
+// DO NOT EDIT - changes will be overwritten
+
+import androidx.compose.foundation.layout.Column
+import androidx.compose.material3.Text
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+
+@Composable
+fun Hello() {
+    var msg by remember { mutableStateOf("") }
+
+    Column {
+        Text(text = msg)
+        Text(text = "World")
+    }
+}
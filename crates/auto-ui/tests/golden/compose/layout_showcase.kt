@@ -0,0 +1,75 @@
+// Auto-generated from Auto language
+// DO NOT EDIT - changes will be overwritten
+
+import androidx.compose.foundation.layout.Arrangement
+import androidx.compose.foundation.layout.Box
+import androidx.compose.foundation.layout.Column
+import androidx.compose.foundation.layout.Row
+import androidx.compose.foundation.layout.padding
+import androidx.compose.material3.Button
+import androidx.compose.material3.Text
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+import androidx.compose.ui.Alignment
+import androidx.compose.ui.Modifier
+import androidx.compose.ui.unit.dp
+
+@Composable
+fun LayoutShowcase() {
+    var count by remember { mutableStateOf(0) }
+
+    fun on(ev: Int) {
+        when (ev) {
+            1 -> {
+                count += 1
+            }
+            2 -> {
+                count -= 1
+            }
+            else -> {}
+        }
+    }
+
+    Column {
+        Column(modifier = Modifier.padding(20.dp), verticalArrangement = Arrangement.spacedBy(10.dp)) {
+            Text(text = "Example 1: Basic Spacing & Padding")
+            Text(text = "Items have 10px spacing between them")
+            Text(text = "Container has 20px padding")
+        }
+        Column(verticalArrangement = Arrangement.spacedBy(15.dp)) {
+            Text(text = "Example 2: Nested Layouts")
+            Row(modifier = Modifier.padding(5.dp), horizontalArrangement = Arrangement.spacedBy(10.dp)) {
+                Text(text = "Row Item 1")
+                Text(text = "Row Item 2")
+                Text(text = "Row Item 3")
+            }
+        }
+        Box(contentAlignment = Alignment.Center) {
+            Text(text = "Example 3: Centered Content")
+        }
+        Box(modifier = Modifier.padding(15.dp)) {
+            Text(text = "Example 4: Container with padding")
+            Text(text = "Containers wrap their content")
+        }
+        Row(modifier = Modifier.padding(10.dp), horizontalArrangement = Arrangement.spacedBy(20.dp)) {
+            Text(text = "Example 5: Row Layout")
+            Text(text = "Item 2")
+            Text(text = "Item 3")
+        }
+        Column(verticalArrangement = Arrangement.spacedBy(10.dp)) {
+            Text(text = "Example 6: Interactive Counter")
+            Text(text = count.toString())
+            Row(horizontalArrangement = Arrangement.spacedBy(10.dp)) {
+                Button(onClick = { on(1) }) {
+                    Text(text = "Increment")
+                }
+                Button(onClick = { on(2) }) {
+                    Text(text = "Decrement")
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Auto-generated from Auto language
+// DO NOT EDIT - changes will be overwritten
+
+import androidx.compose.foundation.layout.Column
+import androidx.compose.foundation.layout.Row
+import androidx.compose.material3.Button
+import androidx.compose.material3.Checkbox
+import androidx.compose.material3.RadioButton
+import androidx.compose.material3.Text
+import androidx.compose.material3.TextField
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+import androidx.compose.ui.Alignment
+
+@Composable
+fun SimpleTest() {
+    var count by remember { mutableStateOf(0) }
+    var enabled by remember { mutableStateOf(false) }
+
+    fun on(ev: Int) {
+        when (ev) {
+            1 -> {
+                count += 1
+            }
+            2 -> {
+                count -= 1
+            }
+            3 -> {
+                count = 0
+                enabled = true
+            }
+            else -> {}
+        }
+    }
+
+    Column {
+        Text(text = "Simple Components Test")
+        Text(text = "Counter:")
+        Text(text = count.toString())
+        Row {
+            Button(onClick = { on(1) }) {
+                Text(text = "+")
+            }
+            Button(onClick = { on(2) }) {
+                Text(text = "-")
+            }
+        }
+        Text(text = "Form elements:")
+        TextField(value = "", onValueChange = {}, placeholder = { Text(text = "Enter text") })
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            Checkbox(checked = enabled, onCheckedChange = { enabled = it })
+            Text(text = "Enable")
+        }
+        Text(text = "Radio options:")
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            RadioButton(selected = true, onClick = null)
+            Text(text = "Option A")
+        }
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            RadioButton(selected = false, onClick = null)
+            Text(text = "Option B")
+        }
+        Text(text = "Select:")
+        // select: shows the first option
+        Text(text = "Default")
+        Button(onClick = { on(3) }) {
+            Text(text = "Reset")
+        }
+    }
+}
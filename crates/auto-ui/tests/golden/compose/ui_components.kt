@@ -0,0 +1,79 @@
+// Auto-generated from Auto language
+// DO NOT EDIT - changes will be overwritten
+
+import androidx.compose.foundation.layout.Column
+import androidx.compose.foundation.layout.Row
+import androidx.compose.material3.Button
+import androidx.compose.material3.Checkbox
+import androidx.compose.material3.RadioButton
+import androidx.compose.material3.Text
+import androidx.compose.material3.TextField
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+import androidx.compose.ui.Alignment
+
+@Composable
+fun ComponentsDemo() {
+    var username by remember { mutableStateOf("") }
+    var email by remember { mutableStateOf("") }
+    var agree_terms by remember { mutableStateOf(false) }
+    var subscribe_newsletter by remember { mutableStateOf(false) }
+    var plan_choice by remember { mutableStateOf(0) }
+    var country by remember { mutableStateOf("") }
+
+    fun on(ev: Int) {
+        when (ev) {
+            1 -> {}
+            2 -> {
+                username = ""
+                email = ""
+                agree_terms = false
+                subscribe_newsletter = false
+                plan_choice = 1
+            }
+            else -> {}
+        }
+    }
+
+    Column {
+        Text(text = "UI Components Showcase")
+        Text(text = "─────────────────────────")
+        Text(text = "Text Inputs:")
+        TextField(value = "", onValueChange = {}, placeholder = { Text(text = "Enter username") })
+        TextField(value = "", onValueChange = {}, placeholder = { Text(text = "Enter email") })
+        Text(text = "Checkboxes:")
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            Checkbox(checked = false, onCheckedChange = null)
+            Text(text = "I agree to the terms")
+        }
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            Checkbox(checked = false, onCheckedChange = null)
+            Text(text = "Subscribe to newsletter")
+        }
+        Text(text = "Choose a Plan:")
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            RadioButton(selected = true, onClick = null)
+            Text(text = "Free Plan")
+        }
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            RadioButton(selected = false, onClick = null)
+            Text(text = "Pro Plan")
+        }
+        Row(verticalAlignment = Alignment.CenterVertically) {
+            RadioButton(selected = false, onClick = null)
+            Text(text = "Enterprise Plan")
+        }
+        Text(text = "Country:")
+        // select: shows the first option
+        Text(text = "China")
+        Button(onClick = { on(1) }) {
+            Text(text = "Submit")
+        }
+        Button(onClick = { on(2) }) {
+            Text(text = "Reset")
+        }
+    }
+}
@@ -33,6 +33,7 @@ fn test_complete_view_conversion() {
             View::Button {
                 label: "Click Me".to_string(),
                 onclick: TestMessage::Click,
+                disabled: false,
                 style: None,
             },
             View::Row {
@@ -177,6 +178,7 @@ fn test_mixed_component_types() {
             View::Button {
                 label: "Submit".to_string(),
                 onclick: TestMessage::Submit,
+                disabled: false,
                 style: None,
             },
             View::Input {
@@ -185,6 +187,7 @@ fn test_mixed_component_types() {
                 on_change: None,
                 width: None,
                 password: false,
+                disabled: false,
                 style: None,
             },
             View::Checkbox {
@@ -309,11 +312,13 @@ fn test_tree_stats_accuracy() {
             View::Button {
                 label: "Button 1".to_string(),
                 onclick: TestMessage::Click,
+                disabled: false,
                 style: None,
             },
             View::Button {
                 label: "Button 2".to_string(),
                 onclick: TestMessage::Click,
+                disabled: false,
                 style: None,
             },
             View::Row {
@@ -393,7 +398,7 @@ fn test_slider_conversion() {
         max: 100.0,
         value: 75.0,
         step: Some(5.0),
-        on_change: |_| TestMessage::Change,
+        on_change: SliderCallback::new(|_| TestMessage::Change),
         style: None,
     };
 
@@ -434,6 +439,7 @@ fn test_complex_form_structure() {
                 on_change: None,
                 width: None,
                 password: false,
+                disabled: false,
                 style: None,
             },
             View::Input {
@@ -442,6 +448,7 @@ fn test_complex_form_structure() {
                 on_change: None,
                 width: None,
                 password: true,
+                disabled: false,
                 style: None,
             },
             View::Checkbox {
@@ -453,6 +460,7 @@ fn test_complex_form_structure() {
             View::Button {
                 label: "Register".to_string(),
                 onclick: TestMessage::Submit,
+                disabled: false,
                 style: None,
             },
         ],
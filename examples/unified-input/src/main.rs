@@ -75,7 +75,7 @@ impl InputApp {
                         View::input("Enter your username")
                             .value(&self.username)
                             .width(300)
-                            .on_change(Message::UsernameChanged(self.username.clone()))
+                            .on_change(Message::UsernameChanged)
                             .build(),
                     )
                     .build(),
@@ -88,7 +88,7 @@ impl InputApp {
                         View::input("user@example.com")
                             .value(&self.email)
                             .width(300)
-                            .on_change(Message::EmailChanged(self.email.clone()))
+                            .on_change(Message::EmailChanged)
                             .build(),
                     )
                     .build(),
@@ -102,7 +102,7 @@ impl InputApp {
                             .value(&self.password)
                             .width(300)
                             .password()
-                            .on_change(Message::PasswordChanged(self.password.clone()))
+                            .on_change(Message::PasswordChanged)
                             .build(),
                     )
                     .build(),
@@ -115,7 +115,7 @@ impl InputApp {
                         View::input("Tell us about yourself")
                             .value(&self.bio)
                             .width(400)
-                            .on_change(Message::BioChanged(self.bio.clone()))
+                            .on_change(Message::BioChanged)
                             .build(),
                     )
                     .build(),